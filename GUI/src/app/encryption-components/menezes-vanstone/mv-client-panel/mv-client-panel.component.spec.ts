@@ -57,7 +57,8 @@ describe('MvClientPanelComponent', () => {
             keyPair: {
                 public_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '13',
                         order_of_subgroup: '7',
                         generator: {
@@ -74,7 +75,8 @@ describe('MvClientPanelComponent', () => {
                 },
                 private_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '19',
                         order_of_subgroup: '13',
                         generator: {
@@ -135,7 +137,8 @@ describe('MvClientPanelComponent', () => {
             keyPair: {
                 public_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '13',
                         order_of_subgroup: '7',
                         generator: {
@@ -152,7 +155,8 @@ describe('MvClientPanelComponent', () => {
                 },
                 private_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '19',
                         order_of_subgroup: '13',
                         generator: {
@@ -183,7 +187,8 @@ describe('MvClientPanelComponent', () => {
             keyPair: {
                 public_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '13',
                         order_of_subgroup: '7',
                         generator: {
@@ -200,7 +205,8 @@ describe('MvClientPanelComponent', () => {
                 },
                 private_key: {
                     curve: {
-                        a: 0,
+                        a: '0',
+                        b: '0',
                         prime: '19',
                         order_of_subgroup: '13',
                         generator: {
@@ -74,7 +74,7 @@ export class MvClientData extends ClientData {
             {
                 public_key: {
                     curve: {
-                        a: NaN, prime: "Empty",
+                        a: "Empty", b: "Empty", prime: "Empty",
                         generator: {
                             x: "Empty",
                             y: "Empty",
@@ -90,7 +90,7 @@ export class MvClientData extends ClientData {
                 },
                 private_key: {
                     curve: {
-                        a: NaN, prime: "Empty",
+                        a: "Empty", b: "Empty", prime: "Empty",
                         generator: {
                             x: "Empty",
                             y: "Empty",
@@ -1,5 +1,6 @@
 export interface EllipticCurve {
-    a: number;
+    a: string;
+    b: string;
     prime: string;
     order_of_subgroup: string;
     generator: EcPoint;
@@ -31,6 +32,7 @@ export interface MvCreateKeyPairRequest { // TODO Mit Lucas KeyGenConfig zusamme
     miller_rabin_rounds: number;
     coef_a: number;
     random_seed: number;
+    curve_name?: string; // z.B. "P-256", ersetzt modulus_width, miller_rabin_rounds und coef_a
 }
 
 export interface MvEncryptRequest {
@@ -59,6 +61,7 @@ export interface MvSignature {
     r: string,
     s: string,
     string_representation: string;
+    hash_algorithm?: string;
 }
 
 export interface MvVerifyRequest {
@@ -117,7 +120,8 @@ export function copyMvSignature(signature: MvSignature): MvSignature {
     return {
         r: signature.r,
         s: signature.s,
-        string_representation: signature.string_representation
+        string_representation: signature.string_representation,
+        hash_algorithm: signature.hash_algorithm
     };
 }
 
@@ -0,0 +1,46 @@
+use bigdecimal::num_bigint::BigInt;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use encryption_tool::math_core::ecc::named_curve::NamedCurve;
+use encryption_tool::math_core::ecc::scalar_multiplication::{FixedBaseComb, ScalarKind};
+
+/// Vergleicht die Verfahren der Skalarmultiplikation: Montgomery-Leiter für geheime und w-NAF
+/// für öffentliche Skalare in jacobischen Koordinaten, den Kamm für den Generator und die
+/// affine Rechnung, die bei jeder Addition und Verdopplung invertiert.
+fn scalar_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar_multiplication");
+    for named_curve in [NamedCurve::P256, NamedCurve::P384] {
+        let parameters = named_curve.domain_parameters();
+        let scalar: BigInt = &parameters.order_of_subgroup - BigInt::from(12345);
+        let name = named_curve.to_string();
+
+        for (label, scalar_kind) in [("ladder", ScalarKind::Secret), ("wnaf", ScalarKind::Public)] {
+            group.bench_with_input(BenchmarkId::new(label, &name), &scalar, |b, scalar| {
+                b.iter(|| {
+                    parameters
+                        .generator
+                        .multiply_with(black_box(scalar), &parameters.curve, scalar_kind)
+                        .unwrap()
+                })
+            });
+        }
+
+        let comb = FixedBaseComb::new(&parameters.generator, &parameters.curve, 4).unwrap();
+        group.bench_with_input(BenchmarkId::new("comb", &name), &scalar, |b, scalar| {
+            b.iter(|| comb.multiply(black_box(scalar)).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("affine", &name), &scalar, |b, scalar| {
+            b.iter(|| {
+                parameters
+                    .generator
+                    .multiply_affine(black_box(scalar), &parameters.curve)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scalar_multiplication);
+criterion_main!(benches);
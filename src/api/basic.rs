@@ -4,9 +4,14 @@ use log::info;
 use serde::Serialize;
 
 use crate::api::endpoints::math_endpoints::{
-    euclid_endpoint, exponentiation, modular_inverse_endpoint, shanks_endpoint,
+    chinese_remainder_endpoint, curve_order_endpoint, element_order_endpoint, euclid_endpoint,
+    exponentiation, factorization_endpoint, gaussian_endpoint, hmac_endpoint, kdf_endpoint,
+    modular_inverse_endpoint, multiplicative_group_endpoint, primality_test_endpoint,
+    primitive_roots_endpoint, randomness_report, shanks_endpoint, sum_of_two_squares_endpoint,
+};
+use crate::api::endpoints::{
+    aes, classic, ecdsa, ed25519, el_gamal, hash, key_exchange, mv, rsa, schnorr,
 };
-use crate::api::endpoints::{mv, rsa};
 use crate::api::serializable_models::SingleStringResponse;
 
 #[derive(Serialize)]
@@ -21,24 +26,97 @@ pub fn config_app(cfg: &mut web::ServiceConfig) {
                 .route("/createKeyPair", web::post().to(rsa::create_key_pair))
                 .route("/encrypt", web::post().to(rsa::encrypt))
                 .route("/decrypt", web::post().to(rsa::decrypt))
+                .route("/encryptBytes", web::post().to(rsa::encrypt_bytes))
+                .route("/decryptBytes", web::post().to(rsa::decrypt_bytes))
+                .route("/encryptContainer", web::post().to(rsa::encrypt_container))
+                .route("/decryptContainer", web::post().to(rsa::decrypt_container))
                 .route("/sign", web::post().to(rsa::sign))
                 .route("/verify", web::post().to(rsa::verify))
-                .route("/multiplication", web::post().to(rsa::multiplication)),
+                .route("/multiplication", web::post().to(rsa::multiplication))
+                .route("/hybridEncrypt", web::post().to(rsa::hybrid_encrypt))
+                .route("/hybridDecrypt", web::post().to(rsa::hybrid_decrypt)),
         )
         .service(
             web::scope("/menezesVanstone")
                 .route("/createKeyPair", web::post().to(mv::create_key_pair))
                 .route("/encrypt", web::post().to(mv::encrypt))
                 .route("/decrypt", web::post().to(mv::decrypt))
+                .route("/encryptBytes", web::post().to(mv::encrypt_bytes))
+                .route("/decryptBytes", web::post().to(mv::decrypt_bytes))
+                .route("/encryptContainer", web::post().to(mv::encrypt_container))
+                .route("/decryptContainer", web::post().to(mv::decrypt_container))
                 .route("/sign", web::post().to(mv::sign))
-                .route("/verify", web::post().to(mv::verify)),
+                .route("/verify", web::post().to(mv::verify))
+                .route("/hybridEncrypt", web::post().to(mv::hybrid_encrypt))
+                .route("/hybridDecrypt", web::post().to(mv::hybrid_decrypt))
+                .route("/auditCurve", web::post().to(mv::audit_curve)),
+        )
+        .service(
+            web::scope("/elGamal")
+                .route("/createKeyPair", web::post().to(el_gamal::create_key_pair))
+                .route("/hybridEncrypt", web::post().to(el_gamal::hybrid_encrypt))
+                .route("/hybridDecrypt", web::post().to(el_gamal::hybrid_decrypt)),
+        )
+        .service(
+            web::scope("/ecdsa")
+                .route("/sign", web::post().to(ecdsa::sign))
+                .route("/verify", web::post().to(ecdsa::verify))
+                .route("/recover", web::post().to(ecdsa::recover)),
+        )
+        .service(
+            web::scope("/schnorr")
+                .route("/sign", web::post().to(schnorr::sign))
+                .route("/verify", web::post().to(schnorr::verify)),
+        )
+        .service(
+            web::scope("/ed25519")
+                .route("/createKeyPair", web::post().to(ed25519::create_key_pair))
+                .route("/sign", web::post().to(ed25519::sign))
+                .route("/verify", web::post().to(ed25519::verify)),
+        )
+        .route("/keyExchange", web::post().to(key_exchange::key_exchange))
+        .service(
+            web::scope("/aes")
+                .route("/createKey", web::post().to(aes::create_key))
+                .route("/encrypt", web::post().to(aes::encrypt))
+                .route("/decrypt", web::post().to(aes::decrypt)),
+        )
+        .route(
+            "/classic/statistics",
+            web::post().to(classic::text_statistics),
+        )
+        .service(
+            web::scope("/classic/{cipher}")
+                .route("/createKey", web::post().to(classic::create_key))
+                .route("/encrypt", web::post().to(classic::encrypt))
+                .route("/decrypt", web::post().to(classic::decrypt))
+                .route("/break", web::post().to(classic::break_cipher)),
         )
+        .service(web::scope("/hash").route("/trace", web::post().to(hash::trace)))
         .service(
             web::scope("/math")
                 .route("/exponentiation", web::post().to(exponentiation))
                 .route("/extended_euclid", web::post().to(euclid_endpoint))
                 .route("/shanks", web::post().to(shanks_endpoint))
-                .route("/modular_inverse", web::post().to(modular_inverse_endpoint)),
+                .route("/modular_inverse", web::post().to(modular_inverse_endpoint))
+                .route("/primality_test", web::post().to(primality_test_endpoint))
+                .route("/crt", web::post().to(chinese_remainder_endpoint))
+                .route("/factorize", web::post().to(factorization_endpoint))
+                .route(
+                    "/multiplicative_group",
+                    web::post().to(multiplicative_group_endpoint),
+                )
+                .route("/order", web::post().to(element_order_endpoint))
+                .route("/primitive_roots", web::post().to(primitive_roots_endpoint))
+                .route("/gaussian", web::post().to(gaussian_endpoint))
+                .route(
+                    "/gaussian/two_squares",
+                    web::post().to(sum_of_two_squares_endpoint),
+                )
+                .route("/curve_order", web::post().to(curve_order_endpoint))
+                .route("/randomness_report", web::post().to(randomness_report))
+                .route("/hmac", web::post().to(hmac_endpoint))
+                .route("/kdf", web::post().to(kdf_endpoint)),
         )
         .default_service(web::route().to(not_found));
 }
@@ -0,0 +1,160 @@
+use crate::api::serializable_models::SingleStringResponse;
+use crate::encryption::core::aes::aes_scheme::AesScheme;
+use crate::encryption::core::aes::keys::{
+    AesKey, AesKeyGenConfig, AesKeyPair, AesMode, AesModeName,
+};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptor, SymmetricKeyPair,
+};
+use actix_web::web::Json;
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct AesCreateKeyRequestBean {
+    pub key_size: usize,
+    pub mode: String,
+}
+
+/// Ein AES-Schlüssel, dessen Bytefolgen Base64-kodiert sind.
+/// Der Initialisierungsvektor gehört nicht zum Schlüssel, sondern wird bei jeder Verschlüsselung
+/// neu gewählt und dem Geheimtext vorangestellt.
+///
+/// # Felder
+/// * `key` - Der Schlüssel mit 16, 24 oder 32 Bytes.
+/// * `mode` - Der Betriebsmodus ("ECB", "CBC", "CTR" oder "GCM").
+/// * `aad` - Zusätzliche authentifizierte Daten. Wird nur im Modus GCM verwendet.
+#[derive(Serialize, Deserialize)]
+pub struct AesKeyBean {
+    pub key: String,
+    pub mode: String,
+    #[serde(default)]
+    pub aad: String,
+}
+
+impl AesKeyBean {
+    /// Wandelt die Bean in ein Schlüsselpaar um, bei dem beide Schlüssel identisch sind.
+    ///
+    /// # Fehler
+    /// * `DecodeError` - Falls eine der Bytefolgen kein gültiges Base64 ist.
+    /// * `AesError` - Falls der Modus unbekannt ist.
+    fn to_key_pair(&self) -> Result<AesKeyPair> {
+        let mode = match self.mode.parse::<AesModeName>()? {
+            AesModeName::Ecb => AesMode::Ecb,
+            AesModeName::Cbc => AesMode::Cbc,
+            AesModeName::Ctr => AesMode::Ctr,
+            AesModeName::Gcm => AesMode::Gcm {
+                aad: STANDARD.decode(&self.aad)?,
+            },
+        };
+        Ok(AesKeyPair {
+            key: AesKey {
+                key: STANDARD.decode(&self.key)?,
+                mode,
+            },
+        })
+    }
+}
+
+impl From<AesKey> for AesKeyBean {
+    fn from(key: AesKey) -> Self {
+        let mode = key.mode.name().to_string();
+        let aad = match key.mode {
+            AesMode::Gcm { aad } => aad,
+            _ => vec![],
+        };
+        AesKeyBean {
+            key: STANDARD.encode(key.key),
+            mode,
+            aad: STANDARD.encode(aad),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AesEncryptRequestBean {
+    pub message: String,
+    pub key: AesKeyBean,
+}
+
+#[derive(Deserialize)]
+pub struct AesDecryptRequestBean {
+    pub ciphertext: String,
+    pub key: AesKeyBean,
+}
+
+/// Wandelt das Ergebnis in eine Antwort um. Fehler werden als BadRequest zurückgegeben.
+fn to_response(result: Result<String>) -> HttpResponse {
+    match result {
+        Ok(message) => HttpResponse::Ok().json(SingleStringResponse { message }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt zum Erstellen eines zufälligen AES-Schlüssels.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Schlüssellänge in Bit und den Betriebsmodus enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Schlüssel enthält.
+pub(crate) async fn create_key(req_body: Json<AesCreateKeyRequestBean>) -> impl Responder {
+    info!("Endpunkt /aes/createKey wurde aufgerufen");
+    let req_body: AesCreateKeyRequestBean = req_body.into_inner();
+
+    let config = req_body
+        .mode
+        .parse::<AesModeName>()
+        .and_then(|mode| AesKeyGenConfig::new(req_body.key_size, mode));
+
+    match config {
+        Ok(config) => {
+            HttpResponse::Ok().json(AesKeyBean::from(config.generate_keypair().encryption()))
+        }
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt zum Verschlüsseln einer Nachricht mit AES.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht und den Schlüssel enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Base64-kodierten Geheimtext samt vorangestelltem
+///   Initialisierungsvektor enthält.
+pub(crate) async fn encrypt(req_body: Json<AesEncryptRequestBean>) -> impl Responder {
+    info!("Endpunkt /aes/encrypt wurde aufgerufen");
+    let req_body: AesEncryptRequestBean = req_body.into_inner();
+
+    to_response(req_body.key.to_key_pair().and_then(|key_pair| {
+        let ciphertext = AesScheme::encrypt(req_body.message.as_bytes(), &key_pair.encryption())?;
+        Ok(STANDARD.encode(ciphertext))
+    }))
+}
+
+/// Endpunkt zum Entschlüsseln eines mit `/aes/encrypt` erstellten Geheimtextes.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Base64-kodierten Geheimtext und den Schlüssel enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn decrypt(req_body: Json<AesDecryptRequestBean>) -> impl Responder {
+    info!("Endpunkt /aes/decrypt wurde aufgerufen");
+    let req_body: AesDecryptRequestBean = req_body.into_inner();
+
+    to_response(req_body.key.to_key_pair().and_then(|key_pair| {
+        let ciphertext = STANDARD.decode(&req_body.ciphertext)?;
+        let plaintext = AesScheme::decrypt(&ciphertext, &key_pair.decryption())?;
+        Ok(String::from_utf8(plaintext)?)
+    }))
+}
@@ -0,0 +1,271 @@
+use crate::api::serializable_models::SingleStringResponse;
+use crate::encryption::classic::classic_cipher::{
+    ClassicCipher, DEFAULT_MAX_VIGENERE_KEY_LENGTH, MAXIMUM_VIGENERE_KEY_LENGTH,
+};
+use crate::encryption::classic::cryptanalysis::language::Language;
+use crate::encryption::classic::cryptanalysis::statistics::{
+    index_of_coincidence, letter_frequencies, KeyCandidate,
+};
+use crate::encryption::classic::cryptanalysis::vigenere_attack::{
+    estimate_key_lengths, friedman_estimate, kasiski_examination,
+};
+use actix_web::web::{Json, Path};
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct ClassicCreateKeyRequestBean {
+    pub key_length: usize,
+    pub random_seed: u32,
+}
+
+/// Eine Anfrage zum Ver- oder Entschlüsseln. Der Schlüssel wird in der Textform der jeweiligen
+/// Chiffre übergeben, z.B. "3" für Caesar oder "3,3,2,5" für eine Hill-Matrix.
+#[derive(Deserialize)]
+pub struct ClassicEncryptDecryptRequestBean {
+    pub message: String,
+    pub key: String,
+}
+
+/// Eine Anfrage zum Brechen eines Geheimtexts.
+///
+/// * `language` - Die Sprache des Klartexts, "de" oder "en".
+/// * `known_plaintext` - Der bekannte Anfang des Klartexts, nur für die Hill-Chiffre nötig.
+/// * `key_length` - Bei Vigenère die größte untersuchte Schlüssellänge, bei Hill die Dimension.
+#[derive(Deserialize)]
+pub struct ClassicBreakRequestBean {
+    pub ciphertext: String,
+    pub language: String,
+    pub known_plaintext: Option<String>,
+    pub key_length: Option<usize>,
+    pub max_candidates: usize,
+}
+
+/// Ein Schlüsselkandidat in Textform. Kleinere Bewertungen sind besser.
+#[derive(Serialize)]
+pub struct KeyCandidateBean {
+    pub key: String,
+    pub score: f64,
+    pub plaintext: String,
+}
+
+impl From<KeyCandidate<String>> for KeyCandidateBean {
+    fn from(candidate: KeyCandidate<String>) -> Self {
+        KeyCandidateBean {
+            key: candidate.key,
+            score: candidate.score,
+            plaintext: candidate.plaintext,
+        }
+    }
+}
+
+/// Eine Anfrage für die statistische Auswertung eines Texts.
+#[derive(Deserialize)]
+pub struct TextStatisticsRequestBean {
+    pub text: String,
+    pub language: String,
+    pub max_key_length: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct KasiskiBean {
+    pub key_length: usize,
+    pub matching_distances: usize,
+}
+
+#[derive(Serialize)]
+pub struct KeyLengthBean {
+    pub key_length: usize,
+    pub score: f64,
+}
+
+/// Die statistische Auswertung eines Texts.
+///
+/// * `letter_frequencies` - Die relativen Häufigkeiten der Buchstaben von A bis Z.
+/// * `expected_frequencies` - Die Häufigkeiten in Texten der gewählten Sprache.
+/// * `friedman_estimate` - Die Schätzung der Vigenère-Schlüssellänge nach Friedman, falls der
+///   Koinzidenzindex über dem Zufallswert liegt.
+/// * `key_lengths` - Die Schlüssellängen, nach dem Koinzidenzindex ihrer Spalten bewertet.
+#[derive(Serialize)]
+pub struct TextStatisticsResponse {
+    pub letter_frequencies: Vec<f64>,
+    pub expected_frequencies: Vec<f64>,
+    pub index_of_coincidence: f64,
+    pub expected_index_of_coincidence: f64,
+    pub friedman_estimate: Option<f64>,
+    pub kasiski: Vec<KasiskiBean>,
+    pub key_lengths: Vec<KeyLengthBean>,
+}
+
+/// Wandelt das Ergebnis in eine Antwort um. Fehler werden als BadRequest zurückgegeben.
+fn to_response(result: Result<String>) -> HttpResponse {
+    match result {
+        Ok(message) => HttpResponse::Ok().json(SingleStringResponse { message }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt zum Erzeugen eines zufälligen Schlüssels für eine klassische Chiffre.
+///
+/// # Argumente
+/// * `cipher` - Der Name der Chiffre aus dem Pfad.
+/// * `req_body` - Die Anfrage, die die Schlüssellänge und den Seed enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Schlüssel in Textform enthält.
+pub(crate) async fn create_key(
+    cipher: Path<String>,
+    req_body: Json<ClassicCreateKeyRequestBean>,
+) -> impl Responder {
+    info!("Endpunkt /classic/{}/createKey wurde aufgerufen", cipher);
+    let req_body: ClassicCreateKeyRequestBean = req_body.into_inner();
+
+    to_response(
+        cipher
+            .parse::<ClassicCipher>()
+            .and_then(|cipher| cipher.generate_key(req_body.key_length, req_body.random_seed)),
+    )
+}
+
+/// Endpunkt zum Verschlüsseln einer Nachricht mit einer klassischen Chiffre.
+///
+/// # Argumente
+/// * `cipher` - Der Name der Chiffre aus dem Pfad.
+/// * `req_body` - Die Anfrage, die die Nachricht und den Schlüssel enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Geheimtext enthält.
+pub(crate) async fn encrypt(
+    cipher: Path<String>,
+    req_body: Json<ClassicEncryptDecryptRequestBean>,
+) -> impl Responder {
+    info!("Endpunkt /classic/{}/encrypt wurde aufgerufen", cipher);
+    let req_body: ClassicEncryptDecryptRequestBean = req_body.into_inner();
+
+    to_response(
+        cipher
+            .parse::<ClassicCipher>()
+            .and_then(|cipher| cipher.encrypt(&req_body.key, &req_body.message)),
+    )
+}
+
+/// Endpunkt zum Entschlüsseln einer Nachricht mit einer klassischen Chiffre.
+///
+/// # Argumente
+/// * `cipher` - Der Name der Chiffre aus dem Pfad.
+/// * `req_body` - Die Anfrage, die den Geheimtext und den Schlüssel zum Verschlüsseln enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Klartext enthält.
+pub(crate) async fn decrypt(
+    cipher: Path<String>,
+    req_body: Json<ClassicEncryptDecryptRequestBean>,
+) -> impl Responder {
+    info!("Endpunkt /classic/{}/decrypt wurde aufgerufen", cipher);
+    let req_body: ClassicEncryptDecryptRequestBean = req_body.into_inner();
+
+    to_response(
+        cipher
+            .parse::<ClassicCipher>()
+            .and_then(|cipher| cipher.decrypt(&req_body.key, &req_body.message)),
+    )
+}
+
+/// Endpunkt zum Brechen eines Geheimtexts einer klassischen Chiffre.
+///
+/// # Argumente
+/// * `cipher` - Der Name der Chiffre aus dem Pfad.
+/// * `req_body` - Die Anfrage, die den Geheimtext, die Sprache und optional bekannten Klartext
+///   enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Schlüsselkandidaten, der wahrscheinlichste zuerst,
+///   enthält.
+pub(crate) async fn break_cipher(
+    cipher: Path<String>,
+    req_body: Json<ClassicBreakRequestBean>,
+) -> impl Responder {
+    info!("Endpunkt /classic/{}/break wurde aufgerufen", cipher);
+    let req_body: ClassicBreakRequestBean = req_body.into_inner();
+
+    let result = cipher.parse::<ClassicCipher>().and_then(|cipher| {
+        cipher.break_ciphertext(
+            &req_body.ciphertext,
+            req_body.language.parse()?,
+            req_body.known_plaintext.as_deref(),
+            req_body.key_length,
+            req_body.max_candidates,
+        )
+    });
+    match result {
+        Ok(candidates) => HttpResponse::Ok().json(
+            candidates
+                .into_iter()
+                .map(KeyCandidateBean::from)
+                .collect::<Vec<KeyCandidateBean>>(),
+        ),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt für die statistische Auswertung eines Texts: Buchstabenhäufigkeiten,
+/// Koinzidenzindex, Kasiski-Test und Friedman-Schätzung der Vigenère-Schlüssellänge.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Text und die Sprache enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Auswertung enthält.
+pub(crate) async fn text_statistics(req_body: Json<TextStatisticsRequestBean>) -> impl Responder {
+    info!("Endpunkt /classic/statistics wurde aufgerufen");
+    let req_body: TextStatisticsRequestBean = req_body.into_inner();
+
+    let language = match req_body.language.parse::<Language>() {
+        Ok(language) => language,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
+    };
+    let max_key_length = req_body
+        .max_key_length
+        .unwrap_or(DEFAULT_MAX_VIGENERE_KEY_LENGTH);
+    if max_key_length > MAXIMUM_VIGENERE_KEY_LENGTH {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!(
+                "Die Schlüssellänge darf höchstens {} betragen",
+                MAXIMUM_VIGENERE_KEY_LENGTH
+            ),
+        });
+    }
+    let friedman = friedman_estimate(&req_body.text, language);
+
+    HttpResponse::Ok().json(TextStatisticsResponse {
+        letter_frequencies: letter_frequencies(&req_body.text).to_vec(),
+        expected_frequencies: language.letter_probabilities().to_vec(),
+        index_of_coincidence: index_of_coincidence(&req_body.text),
+        expected_index_of_coincidence: language.index_of_coincidence(),
+        friedman_estimate: friedman.is_finite().then_some(friedman),
+        kasiski: kasiski_examination(&req_body.text, max_key_length)
+            .into_iter()
+            .map(|result| KasiskiBean {
+                key_length: result.key_length,
+                matching_distances: result.matching_distances,
+            })
+            .collect(),
+        key_lengths: estimate_key_lengths(&req_body.text, language, max_key_length)
+            .into_iter()
+            .map(|candidate| KeyLengthBean {
+                key_length: candidate.key,
+                score: candidate.score,
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,245 @@
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bigdecimal::num_bigint::BigInt;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::endpoints::mv::{
+    EcPointBean, EllipticCurveBean, MvPrivateKeyBean, MvPublicKeyBean,
+};
+use crate::api::serializable_models::{default_hash_algorithm, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::Verifier;
+use crate::encryption::core::ecdsa::ecdsa_scheme::{EcdsaScheme, EcdsaSignature};
+use crate::encryption::core::ecdsa::keys::{EcdsaPrivateKey, EcdsaPublicKey};
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+
+/// Eine ECDSA-Signatur. Beim Verifizieren genügt entweder `der` oder das Paar `r` und `s`, ist
+/// `der` gesetzt, wird es bevorzugt.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EcdsaSignatureBean {
+    #[serde(default)]
+    pub r: String,
+    #[serde(default)]
+    pub s: String,
+    #[serde(default)]
+    pub recovery_id: Option<u8>,
+    /// Die Base64-kodierte DER-Darstellung der Signatur.
+    #[serde(default)]
+    pub der: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+}
+
+impl From<EcdsaSignature> for EcdsaSignatureBean {
+    fn from(signature: EcdsaSignature) -> Self {
+        EcdsaSignatureBean {
+            der: STANDARD.encode(signature.to_der()),
+            r: signature.r.to_string(),
+            s: signature.s.to_string(),
+            recovery_id: signature.recovery_id,
+            hash_algorithm: signature.hash_algorithm.to_string(),
+        }
+    }
+}
+
+impl TryFrom<EcdsaSignatureBean> for EcdsaSignature {
+    type Error = anyhow::Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: EcdsaSignatureBean) -> Result<Self> {
+        let hash_algorithm = bean.hash_algorithm.parse::<HashAlgorithm>()?;
+        if !bean.der.is_empty() {
+            let signature = EcdsaSignature::from_der(&STANDARD.decode(bean.der)?, hash_algorithm)?;
+            return Ok(EcdsaSignature {
+                recovery_id: bean.recovery_id,
+                ..signature
+            });
+        }
+        Ok(EcdsaSignature {
+            r: bean.r.parse::<BigInt>()?,
+            s: bean.s.parse::<BigInt>()?,
+            recovery_id: bean.recovery_id,
+            hash_algorithm,
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EcdsaSignRequestBean {
+    pub private_key: MvPrivateKeyBean,
+    pub message: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EcdsaVerifyRequestBean {
+    pub public_key: MvPublicKeyBean,
+    pub message: String,
+    pub signature: EcdsaSignatureBean,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EcdsaRecoverRequestBean {
+    pub curve: EllipticCurveBean,
+    pub message: String,
+    pub signature: EcdsaSignatureBean,
+}
+
+/// Signiert eine Nachricht mit ECDSA. Die Nonce wird nach RFC 6979 bestimmt, die Signatur hat
+/// immer ein kleines s.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, den privaten Schlüssel und das Hashverfahren enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Signatur mit Recovery-ID und DER-Kodierung.
+pub(crate) async fn sign(
+    req_body: Json<EcdsaSignRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /ecdsa/sign wurde aufgerufen");
+
+    let req_body: &EcdsaSignRequestBean = &req_body.into_inner();
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = EcdsaPrivateKey {
+            mv_key: req_body.private_key.clone().into(),
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let hash_algorithm = match req_body.hash_algorithm.parse::<HashAlgorithm>() {
+            Ok(hash_algorithm) => hash_algorithm,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
+        let signature = EcdsaScheme::sign_with_hash(
+            &private_key,
+            req_body.message.as_bytes(),
+            hash_algorithm,
+            service,
+        );
+
+        match signature {
+            Ok(signature) => Ok(HttpResponse::Ok().json(EcdsaSignatureBean::from(signature))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Verifiziert eine ECDSA-Signatur.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und die Signatur enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - "true" oder "false".
+pub(crate) async fn verify(
+    req_body: Json<EcdsaVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /ecdsa/verify wurde aufgerufen");
+
+    let req_body: &EcdsaVerifyRequestBean = &req_body.into_inner();
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = EcdsaPublicKey {
+            mv_key: req_body.public_key.clone().into(),
+        };
+        let signature = match EcdsaSignature::try_from(req_body.signature.clone()) {
+            Ok(signature) => signature,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let verified = EcdsaScheme::verify(
+            &public_key,
+            &signature,
+            req_body.message.as_bytes(),
+            service,
+        );
+
+        match verified {
+            Ok(verified) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: verified.to_string(),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Berechnet den öffentlichen Schlüssel aus einer ECDSA-Signatur mit Recovery-ID.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Kurve, die Nachricht und die Signatur enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Der Punkt Q des öffentlichen Schlüssels.
+pub(crate) async fn recover(
+    req_body: Json<EcdsaRecoverRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /ecdsa/recover wurde aufgerufen");
+
+    let req_body: &EcdsaRecoverRequestBean = &req_body.into_inner();
+    call_checked_with_parsed_big_ints(|| {
+        let curve = SecureFiniteFieldEllipticCurve::from(req_body.curve.clone());
+        let signature = match EcdsaSignature::try_from(req_body.signature.clone()) {
+            Ok(signature) => signature,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let public_key = EcdsaScheme::recover_public_key(
+            &curve,
+            &signature,
+            req_body.message.as_bytes(),
+            service,
+        );
+
+        match public_key {
+            Ok(public_key) => Ok(HttpResponse::Ok().json(EcPointBean::from(public_key))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
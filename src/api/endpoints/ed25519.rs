@@ -0,0 +1,114 @@
+use actix_web::web::Json;
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::api::serializable_models::SingleStringResponse;
+use crate::encryption::asymmetric_encryption_types::{Signer, Verifier};
+use crate::encryption::core::eddsa::ed25519_scheme::{Ed25519Scheme, Ed25519Signature};
+use crate::encryption::core::eddsa::keys::{Ed25519PrivateKey, Ed25519PublicKey};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+/// Ein Ed25519-Schlüsselpaar, dessen Schlüssel Base64-kodiert sind.
+///
+/// # Felder
+/// * `private_key` - Der 32 Bytes lange Seed.
+/// * `public_key` - Der nach RFC 8032 kodierte Punkt A.
+#[derive(Serialize)]
+pub struct Ed25519KeyPairBean {
+    pub private_key: String,
+    pub public_key: String,
+}
+
+#[derive(Deserialize)]
+pub struct Ed25519SignRequestBean {
+    pub private_key: String,
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+pub struct Ed25519VerifyRequestBean {
+    pub public_key: String,
+    pub message: String,
+    pub signature: String,
+}
+
+/// Wandelt das Ergebnis in eine Antwort um. Fehler werden als BadRequest zurückgegeben.
+fn to_response(result: Result<String>) -> HttpResponse {
+    match result {
+        Ok(message) => HttpResponse::Ok().json(SingleStringResponse { message }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt zum Erstellen eines zufälligen Ed25519-Schlüsselpaares.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den privaten und den öffentlichen Schlüssel enthält.
+pub(crate) async fn create_key_pair() -> impl Responder {
+    info!("Endpunkt /ed25519/createKeyPair wurde aufgerufen");
+
+    let private_key = Ed25519PrivateKey::generate();
+    match Ed25519Scheme::public_key(&private_key) {
+        Ok(public_key) => HttpResponse::Ok().json(Ed25519KeyPairBean {
+            private_key: STANDARD.encode(private_key.seed),
+            public_key: STANDARD.encode(public_key.encoded),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Endpunkt zum Signieren einer Nachricht mit Ed25519.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht und den Base64-kodierten Seed enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Base64-kodierte Signatur R || S enthält.
+pub(crate) async fn sign(req_body: Json<Ed25519SignRequestBean>) -> impl Responder {
+    info!("Endpunkt /ed25519/sign wurde aufgerufen");
+    let req_body: Ed25519SignRequestBean = req_body.into_inner();
+
+    to_response((|| {
+        let private_key = Ed25519PrivateKey::from_bytes(&STANDARD.decode(&req_body.private_key)?)?;
+        let signature = Ed25519Scheme::sign(
+            &private_key,
+            req_body.message.as_bytes(),
+            NumberTheoryService::new(Fast),
+        )?;
+        Ok(STANDARD.encode(signature.bytes))
+    })())
+}
+
+/// Endpunkt zum Verifizieren einer Ed25519-Signatur.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und die Signatur
+///   enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - "true" oder "false".
+pub(crate) async fn verify(req_body: Json<Ed25519VerifyRequestBean>) -> impl Responder {
+    info!("Endpunkt /ed25519/verify wurde aufgerufen");
+    let req_body: Ed25519VerifyRequestBean = req_body.into_inner();
+
+    to_response((|| {
+        let public_key = Ed25519PublicKey::from_bytes(&STANDARD.decode(&req_body.public_key)?)?;
+        let signature = Ed25519Signature::from_bytes(&STANDARD.decode(&req_body.signature)?)?;
+        let verified = Ed25519Scheme::verify(
+            &public_key,
+            &signature,
+            req_body.message.as_bytes(),
+            NumberTheoryService::new(Fast),
+        );
+        Ok(verified.to_string())
+    })())
+}
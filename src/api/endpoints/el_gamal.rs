@@ -0,0 +1,209 @@
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::serializable_models::{HybridCiphertextBean, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptor, AsymmetricKeyPair, KeyGenerator,
+};
+use crate::encryption::core::el_gamal::el_gamal_scheme::{ElGamalKeyGenConfig, ElGamalScheme};
+use crate::encryption::core::el_gamal::keys::{ElGamalPrivateKey, ElGamalPublicKey};
+use crate::encryption::hybrid::data_encapsulation::{AeadAlgorithm, HybridCiphertext};
+use crate::encryption::hybrid::el_gamal_kem_scheme::ElGamalKemScheme;
+use crate::encryption::hybrid::keys::{ElGamalKemPrivateKey, ElGamalKemPublicKey};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use bigdecimal::num_bigint::ParseBigIntError;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct ElGamalCreateKeyPairRequestBean {
+    pub modulus_width: u32,
+    pub miller_rabin_rounds: u32,
+    pub random_seed: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ElGamalKeyPairBean {
+    pub modulus: String,
+    pub generator: String,
+    pub y: String,
+    pub x: String,
+}
+
+impl ElGamalKeyPairBean {
+    /// Wandelt das serialisierte Schlüsselpaar in einen privaten Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_private_key(&self) -> Result<ElGamalPrivateKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PrivateKey");
+        Ok(ElGamalPrivateKey {
+            p: self.modulus.parse()?,
+            x: self.x.parse()?,
+        })
+    }
+
+    /// Wandelt das serialisierte Schlüsselpaar in einen öffentlichen Schlüssel um.
+    ///
+    /// # Fehler
+    /// * `ParseBigIntError` - Falls die BigInts nicht geparst werden können.
+    fn to_public_key(&self) -> Result<ElGamalPublicKey, ParseBigIntError> {
+        debug!("Serialisiere KeyPair zu PublicKey");
+        Ok(ElGamalPublicKey {
+            p: self.modulus.parse()?,
+            g: self.generator.parse()?,
+            y: self.y.parse()?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalHybridEncryptRequestBean {
+    pub message: String,
+    pub key_pair: ElGamalKeyPairBean,
+    pub aead: String,
+}
+
+#[derive(Deserialize)]
+pub struct ElGamalHybridDecryptRequestBean {
+    pub ciphertext: HybridCiphertextBean,
+    pub key_pair: ElGamalKeyPairBean,
+}
+
+/// Erstellt ein neues ElGamal-Schlüsselpaar mit einer sicheren Primzahl als Modulus.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Parameter für die Erstellung des Schlüsselpaares enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die das Schlüsselpaar enthält.
+pub(crate) async fn create_key_pair(
+    req_body: Json<ElGamalCreateKeyPairRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/createKeyPair wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalCreateKeyPairRequestBean = req_body.into_inner();
+
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let config = ElGamalKeyGenConfig {
+        modulus_width: req_body.modulus_width,
+        miller_rabin_iterations: req_body.miller_rabin_rounds,
+        random_seed: req_body.random_seed,
+        number_theory_service,
+    };
+
+    let key_pair = ElGamalScheme::generate_keypair(&config);
+    let public_key = key_pair.public();
+    let private_key = key_pair.private();
+
+    HttpResponse::Ok().json(ElGamalKeyPairBean {
+        modulus: public_key.p.to_str_radix(10),
+        generator: public_key.g.to_str_radix(10),
+        y: public_key.y.to_str_radix(10),
+        x: private_key.x.to_str_radix(10),
+    })
+}
+
+/// Endpunkt zum hybriden Verschlüsseln einer Nachricht mit dem ElGamal-KEM und einem AEAD-Verfahren.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und das AEAD-Verfahren enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den hybriden Chiffretext enthält.
+pub(crate) async fn hybrid_encrypt(
+    req_body: Json<ElGamalHybridEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/hybridEncrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalHybridEncryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let el_gamal_key = req_body.key_pair.to_public_key()?;
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let ciphertext = req_body.aead.parse::<AeadAlgorithm>().and_then(|aead| {
+            let public_key = ElGamalKemPublicKey {
+                el_gamal_key: el_gamal_key.clone(),
+                aead,
+            };
+            ElGamalKemScheme::encrypt(
+                &public_key,
+                req_body.message.as_bytes(),
+                number_theory_service,
+            )
+        });
+
+        match ciphertext {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(HybridCiphertextBean::from(ciphertext))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Entschlüsseln eines mit `/elGamal/hybridEncrypt` erstellten Chiffretextes.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den hybriden Chiffretext und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn hybrid_decrypt(
+    req_body: Json<ElGamalHybridDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /elGamal/hybridDecrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ElGamalHybridDecryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = ElGamalKemPrivateKey {
+            el_gamal_key: req_body.key_pair.to_private_key()?,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext = HybridCiphertext::try_from(req_body.ciphertext.clone())
+            .and_then(|ciphertext| {
+                ElGamalKemScheme::decrypt(&private_key, &ciphertext, number_theory_service)
+            })
+            .and_then(|plaintext| Ok(String::from_utf8(plaintext)?));
+
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
@@ -0,0 +1,139 @@
+use crate::api::serializable_models::SingleStringResponse;
+use crate::shared::hashing::educational::trace::{
+    BlockTrace, HashTrace, RoundTrace, TracedHashAlgorithm,
+};
+use actix_web::web::Json;
+use actix_web::{HttpResponse, Responder};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Höchstlänge der Nachricht in Bytes, deren Berechnung protokolliert wird. Das Protokoll
+/// wächst mit jedem Block um mehrere Kilobyte.
+const MAXIMUM_TRACE_MESSAGE_LENGTH: usize = 1024;
+
+/// Eine Anfrage für das Protokoll einer Hashberechnung.
+///
+/// # Felder
+/// * `algorithm` - Die Hashfunktion, "MD5", "SHA-1" oder "SHA-256".
+/// * `message` - Die Nachricht im Klartext.
+#[derive(Deserialize)]
+pub struct HashTraceRequestBean {
+    pub algorithm: String,
+    pub message: String,
+}
+
+/// Das Protokoll einer Hashberechnung. Alle Bytefolgen und 32-Bit-Wörter sind hexadezimal
+/// dargestellt.
+#[derive(Serialize)]
+pub struct HashTraceBean {
+    pub algorithm: String,
+    pub working_variable_names: Vec<String>,
+    pub message_length: usize,
+    pub padding: String,
+    pub blocks: Vec<BlockTraceBean>,
+    pub digest: String,
+}
+
+#[derive(Serialize)]
+pub struct BlockTraceBean {
+    pub message_schedule: Vec<String>,
+    pub initial_state: Vec<String>,
+    pub rounds: Vec<RoundTraceBean>,
+    pub final_state: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct RoundTraceBean {
+    pub round: usize,
+    pub word: String,
+    pub constant: String,
+    pub working_variables: Vec<String>,
+}
+
+fn to_hex_words(words: &[u32]) -> Vec<String> {
+    words.iter().map(|word| format!("{:08x}", word)).collect()
+}
+
+fn to_hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl From<HashTrace> for HashTraceBean {
+    /// Mapped das Domain-Modell in die Bean
+    fn from(trace: HashTrace) -> Self {
+        HashTraceBean {
+            algorithm: trace.algorithm.to_string(),
+            working_variable_names: trace
+                .algorithm
+                .working_variable_names()
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            message_length: trace.message_length,
+            padding: to_hex_bytes(&trace.padding),
+            blocks: trace.blocks.into_iter().map(BlockTraceBean::from).collect(),
+            digest: to_hex_bytes(&trace.digest),
+        }
+    }
+}
+
+impl From<BlockTrace> for BlockTraceBean {
+    fn from(block: BlockTrace) -> Self {
+        BlockTraceBean {
+            message_schedule: to_hex_words(&block.message_schedule),
+            initial_state: to_hex_words(&block.initial_state),
+            rounds: block
+                .rounds
+                .into_iter()
+                .enumerate()
+                .map(|(round, trace)| RoundTraceBean::from_round(round, trace))
+                .collect(),
+            final_state: to_hex_words(&block.final_state),
+        }
+    }
+}
+
+impl RoundTraceBean {
+    fn from_round(round: usize, trace: RoundTrace) -> Self {
+        RoundTraceBean {
+            round,
+            word: format!("{:08x}", trace.word),
+            constant: format!("{:08x}", trace.constant),
+            working_variables: to_hex_words(&trace.working_variables),
+        }
+    }
+}
+
+/// Endpunkt, der eine Nachricht mit MD5, SHA-1 oder SHA-256 hasht und dabei Padding,
+/// Nachrichtenexpansion und die Arbeitsvariablen nach jeder Runde protokolliert.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Hashfunktion und die Nachricht enthält.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die das Protokoll und den Hashwert enthält.
+pub(crate) async fn trace(req_body: Json<HashTraceRequestBean>) -> impl Responder {
+    info!(
+        "Endpunkt /hash/trace wurde aufgerufen, algorithm: {}",
+        req_body.algorithm
+    );
+    let req_body: HashTraceRequestBean = req_body.into_inner();
+
+    if req_body.message.len() > MAXIMUM_TRACE_MESSAGE_LENGTH {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!(
+                "Die Nachricht darf höchstens {} Bytes lang sein",
+                MAXIMUM_TRACE_MESSAGE_LENGTH
+            ),
+        });
+    }
+
+    match req_body.algorithm.parse::<TracedHashAlgorithm>() {
+        Ok(algorithm) => HttpResponse::Ok().json(HashTraceBean::from(
+            algorithm.trace(req_body.message.as_bytes()),
+        )),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
@@ -0,0 +1,264 @@
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use anyhow::{ensure, Result};
+use atomic_counter::RelaxedCounter;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::api::serializable_models::{default_hash_algorithm, SingleStringResponse, UseFastQuery};
+use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::MenezesVanstoneCurveConfig;
+use crate::encryption::key_exchange::diffie_hellman::DiffieHellman;
+use crate::encryption::key_exchange::x25519::{X25519, X25519_KEY_LENGTH};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::i2osp;
+use crate::math_core::ecc::montgomery_curve::MontgomeryCurve;
+use crate::math_core::ecc::twisted_edwards_curve::to_little_endian;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::math_core::prime_order_group::{PrimeFieldSubgroup, PrimeOrderGroup};
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
+use crate::shared::errors::KeyExchangeError::UnknownKeyExchangeMethodError;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use crate::shared::hashing::kdf::hkdf;
+
+/// Die größte Schlüssellänge in Bytes, die abgeleitet werden darf.
+const MAXIMUM_DERIVED_KEY_LENGTH: usize = 1024;
+
+/// Eine Anfrage, die eine Schlüsselvereinbarung zwischen Alice und Bob simuliert.
+///
+/// # Felder
+/// * `method` - Das Verfahren, "DH", "ECDH" oder "X25519".
+/// * `random_seed` - Der Seed, aus dem die Schlüssel beider Parteien erzeugt werden.
+/// * `modulus_width` - Bei DH die Bitbreite der sicheren Primzahl, bei ECDH ohne
+///   `curve_name` die Bitbreite des Modulus der erzeugten Kurve.
+/// * `miller_rabin_rounds` - Die Anzahl der Miller-Rabin-Runden für die Primzahlsuche.
+/// * `coef_a` - Bei ECDH ohne `curve_name` der Wert n der Kurve y^2 = x^3 - n^2 · x.
+/// * `curve_name` - Bei ECDH eine Standardkurve, z.B. "P-256".
+/// * `hash_algorithm` - Die Hashfunktion von HKDF.
+/// * `salt` - Das Base64-kodierte Salt von HKDF.
+/// * `info` - Die Base64-kodierten Kontextinformationen von HKDF.
+/// * `key_length` - Die Länge des abgeleiteten Schlüssels in Bytes.
+#[derive(Deserialize)]
+pub struct KeyExchangeRequestBean {
+    pub method: String,
+    pub random_seed: u32,
+    #[serde(default)]
+    pub modulus_width: u32,
+    #[serde(default)]
+    pub miller_rabin_rounds: u32,
+    #[serde(default)]
+    pub coef_a: i32,
+    #[serde(default)]
+    pub curve_name: Option<String>,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+    #[serde(default)]
+    pub salt: String,
+    #[serde(default)]
+    pub info: String,
+    #[serde(default = "default_key_length")]
+    pub key_length: usize,
+}
+
+fn default_key_length() -> usize {
+    32
+}
+
+/// Die Schlüssel einer Partei, jeweils Base64-kodiert. Der öffentliche Wert ist die Kodierung,
+/// die übertragen wird, bei ECDH also der komprimierte SEC1-Punkt.
+#[derive(Serialize)]
+pub struct KeyExchangePartyBean {
+    pub private_key: String,
+    pub public_value: String,
+}
+
+/// Das Ergebnis der Schlüsselvereinbarung.
+///
+/// # Felder
+/// * `parameters` - Die verwendete Gruppe bzw. Kurve.
+/// * `alice`, `bob` - Die Schlüssel der beiden Parteien.
+/// * `shared_secret` - Das von Alice berechnete gemeinsame Geheimnis, Base64-kodiert.
+/// * `secrets_match` - Ob Bob dasselbe Geheimnis berechnet hat.
+/// * `derived_key` - Der mit HKDF aus dem Geheimnis abgeleitete Schlüssel, Base64-kodiert.
+#[derive(Serialize)]
+pub struct KeyExchangeResponseBean {
+    pub parameters: String,
+    pub alice: KeyExchangePartyBean,
+    pub bob: KeyExchangePartyBean,
+    pub shared_secret: String,
+    pub secrets_match: bool,
+    pub derived_key: String,
+}
+
+/// Die Schlüssel von Alice und Bob und die Geheimnisse, die beide berechnet haben.
+struct Simulation {
+    parameters: String,
+    alice: KeyExchangePartyBean,
+    bob: KeyExchangePartyBean,
+    alice_secret: Vec<u8>,
+    bob_secret: Vec<u8>,
+}
+
+/// Simuliert eine Schlüsselvereinbarung zwischen Alice und Bob mit DH, ECDH oder X25519 und
+/// leitet aus dem gemeinsamen Geheimnis mit HKDF einen Schlüssel ab.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Verfahren, dessen Parameter und die Parameter von HKDF
+///   enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Schlüssel beider Parteien, das Geheimnis und der abgeleitete Schlüssel.
+pub(crate) async fn key_exchange(
+    req_body: Json<KeyExchangeRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /keyExchange wurde aufgerufen, method: {}",
+        req_body.method
+    );
+    let req_body: KeyExchangeRequestBean = req_body.into_inner();
+
+    let service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    match simulate_key_exchange(&req_body, service) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn simulate_key_exchange(
+    request: &KeyExchangeRequestBean,
+    service: NumberTheoryService,
+) -> Result<KeyExchangeResponseBean> {
+    let hash_algorithm = request.hash_algorithm.parse::<HashAlgorithm>()?;
+    let salt = STANDARD.decode(&request.salt)?;
+    let info = STANDARD.decode(&request.info)?;
+    ensure!(
+        request.key_length <= MAXIMUM_DERIVED_KEY_LENGTH,
+        "Die Schlüssellänge darf höchstens {} Bytes betragen",
+        MAXIMUM_DERIVED_KEY_LENGTH
+    );
+
+    let random_generator = PseudoRandomNumberGenerator::new(request.random_seed, service);
+    let counter = RelaxedCounter::new(1);
+    let simulation = match request.method.to_uppercase().as_str() {
+        "DH" => {
+            ensure!(
+                request.modulus_width >= 8,
+                "Die Bitbreite der Primzahl muss mindestens 8 betragen"
+            );
+            let (prime, primitive_root) = random_generator
+                .generate_secure_prime_with_primitive_root(
+                    request.modulus_width,
+                    request.miller_rabin_rounds,
+                    &counter,
+                );
+            let group = PrimeFieldSubgroup::from_safe_prime(&prime, &primitive_root)?;
+            let parameters = format!(
+                "p = {}, q = {}, g = {}",
+                group.prime, group.order, group.generator
+            );
+            simulate_diffie_hellman(group, parameters, &random_generator, &counter)?
+        }
+        "ECDH" => {
+            let curve_config = match &request.curve_name {
+                Some(curve_name) => MenezesVanstoneCurveConfig::Named(curve_name.parse()?),
+                None => MenezesVanstoneCurveConfig::generated(
+                    request.coef_a,
+                    request.modulus_width,
+                    request.miller_rabin_rounds,
+                ),
+            };
+            let curve = curve_config.create_curve()?;
+            let parameters = format!("{},\nOrdnung: {}", curve, curve.order_of_subgroup);
+            simulate_diffie_hellman(curve, parameters, &random_generator, &counter)?
+        }
+        "X25519" => simulate_x25519(&random_generator, &counter)?,
+        _ => return Err(UnknownKeyExchangeMethodError(request.method.clone()).into()),
+    };
+
+    let derived_key = hkdf(
+        hash_algorithm,
+        &salt,
+        &simulation.alice_secret,
+        &info,
+        request.key_length,
+    )?;
+    Ok(KeyExchangeResponseBean {
+        parameters: simulation.parameters,
+        alice: simulation.alice,
+        bob: simulation.bob,
+        secrets_match: simulation.alice_secret == simulation.bob_secret,
+        shared_secret: STANDARD.encode(simulation.alice_secret),
+        derived_key: STANDARD.encode(derived_key),
+    })
+}
+
+fn simulate_diffie_hellman<G: PrimeOrderGroup>(
+    group: G,
+    parameters: String,
+    random_generator: &impl RandomNumberGenerator,
+    counter: &RelaxedCounter,
+) -> Result<Simulation> {
+    let key_exchange = DiffieHellman::new(group);
+    let private_key_length = (key_exchange.group.order().bits() as usize).div_ceil(8);
+    let alice = key_exchange.generate_key_pair(random_generator, counter)?;
+    let bob = key_exchange.generate_key_pair(random_generator, counter)?;
+
+    let to_bean = |private_key: &BigInt, public_value: &G::Element| -> Result<_> {
+        Ok(KeyExchangePartyBean {
+            private_key: STANDARD.encode(i2osp(private_key, private_key_length)?),
+            public_value: STANDARD.encode(key_exchange.group.encode(public_value)?),
+        })
+    };
+    Ok(Simulation {
+        parameters,
+        alice_secret: key_exchange.shared_secret(&alice.private_key, &bob.public_value)?,
+        bob_secret: key_exchange.shared_secret(&bob.private_key, &alice.public_value)?,
+        alice: to_bean(&alice.private_key, &alice.public_value)?,
+        bob: to_bean(&bob.private_key, &bob.public_value)?,
+    })
+}
+
+fn simulate_x25519(
+    random_generator: &impl RandomNumberGenerator,
+    counter: &RelaxedCounter,
+) -> Result<Simulation> {
+    let maximum = (BigInt::one() << (8 * X25519_KEY_LENGTH)) - 1;
+    let generate_private_key = || -> [u8; X25519_KEY_LENGTH] {
+        let value = random_generator.take(&0.into(), &maximum, counter);
+        to_little_endian(&value, X25519_KEY_LENGTH)
+            .try_into()
+            .unwrap()
+    };
+    let alice_private = generate_private_key();
+    let bob_private = generate_private_key();
+    let alice_public = X25519::public_key(&alice_private);
+    let bob_public = X25519::public_key(&bob_private);
+
+    Ok(Simulation {
+        parameters: MontgomeryCurve::curve25519().to_string(),
+        alice_secret: X25519::shared_secret(&alice_private, &bob_public)?.to_vec(),
+        bob_secret: X25519::shared_secret(&bob_private, &alice_public)?.to_vec(),
+        alice: KeyExchangePartyBean {
+            private_key: STANDARD.encode(alice_private),
+            public_value: STANDARD.encode(alice_public),
+        },
+        bob: KeyExchangePartyBean {
+            private_key: STANDARD.encode(bob_private),
+            public_value: STANDARD.encode(bob_public),
+        },
+    })
+}
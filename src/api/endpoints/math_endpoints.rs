@@ -1,15 +1,40 @@
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{
+    default_hash_algorithm, SingleStringResponse, TraceQuery, UseFastQuery,
+};
 use crate::math_core::babystep_giantstep::Shanks;
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::point_counting::PointCountingMethod;
+use crate::math_core::gaussian_integer::{
+    gaussian_extended_euclid, sum_of_two_squares, GaussianInteger,
+};
+use crate::math_core::number_theory::computation_trace::{
+    EuclidStep, MillerRabinRound, ShanksStep, SquareAndMultiplyStep, Traced,
+};
+use crate::math_core::number_theory::congruence::Congruence;
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
+use crate::math_core::number_theory::factorization::Factorization;
+use crate::math_core::number_theory::multiplicative_group::MultiplicativeGroup;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
 };
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::randomness::bit_source::{CsprngBitSource, PseudoRandomBitSource};
+use crate::math_core::randomness::nist_test_suite::{
+    analyse_bit_source, RandomnessTestResult, MINIMUM_SUITE_LENGTH,
+};
+use crate::shared::errors::HashError::UnknownAlgorithmError;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use crate::shared::hashing::hmac::Hmac;
+use crate::shared::hashing::kdf::{hkdf_expand, hkdf_extract, pbkdf2};
 use actix_web::web::{Json, Query};
 use actix_web::{HttpResponse, Responder};
+use anyhow::{ensure, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use bigdecimal::num_bigint::BigInt;
 use log::info;
 use serde::{Deserialize, Serialize};
@@ -48,6 +73,440 @@ pub struct ExponentiationRequest {
     pub modulus: String,
 }
 
+/// Höchstzahl an Runden, die für einen Primzahltest angefordert werden können.
+const MAXIMUM_PRIMALITY_TEST_REPEATS: u32 = 1000;
+
+#[derive(Deserialize)]
+pub struct PrimalityTestRequest {
+    pub number: String,
+    pub repeats: u32,
+    pub random_seed: u32,
+}
+
+/// Eine Kongruenz x ≡ `remainder` mod `modulus`.
+#[derive(Deserialize, Serialize)]
+pub struct CongruenceBean {
+    pub remainder: String,
+    pub modulus: String,
+}
+
+#[derive(Deserialize)]
+pub struct ChineseRemainderRequest {
+    pub congruences: Vec<CongruenceBean>,
+}
+
+impl From<Congruence> for CongruenceBean {
+    fn from(congruence: Congruence) -> Self {
+        CongruenceBean {
+            remainder: congruence.remainder.to_string(),
+            modulus: congruence.modulus.to_string(),
+        }
+    }
+}
+
+/// Eine Antwort mit Rechenschritten. Die Felder der eigentlichen Antwort bleiben unverändert
+/// und werden um `steps` ergänzt, sodass Clients ohne Protokoll die Antwort wie bisher lesen.
+#[derive(Serialize)]
+pub struct TracedResponse<R, S> {
+    #[serde(flatten)]
+    pub result: R,
+    pub steps: Vec<S>,
+}
+
+impl<R, S> TracedResponse<R, S> {
+    /// Mapped das Ergebnis mit `to_response` und jeden Schritt in seine Bean.
+    fn from_traced<T, D>(traced: Traced<T, D>, to_response: impl FnOnce(T) -> R) -> Self
+    where
+        S: From<D>,
+    {
+        TracedResponse {
+            result: to_response(traced.result),
+            steps: traced.steps.into_iter().map(S::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct EuclidStepBean {
+    pub dividend: String,
+    pub divisor: String,
+    pub quotient: String,
+    pub remainder: String,
+    pub x: String,
+    pub y: String,
+}
+
+impl From<EuclidStep> for EuclidStepBean {
+    fn from(step: EuclidStep) -> Self {
+        EuclidStepBean {
+            dividend: step.dividend.to_string(),
+            divisor: step.divisor.to_string(),
+            quotient: step.quotient.to_string(),
+            remainder: step.remainder.to_string(),
+            x: step.x.to_string(),
+            y: step.y.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SquareAndMultiplyStepBean {
+    pub bit: bool,
+    pub power: String,
+    pub result: String,
+}
+
+impl From<SquareAndMultiplyStep> for SquareAndMultiplyStepBean {
+    fn from(step: SquareAndMultiplyStep) -> Self {
+        SquareAndMultiplyStepBean {
+            bit: step.bit,
+            power: step.power.to_string(),
+            result: step.result.to_string(),
+        }
+    }
+}
+
+/// Ein Schritt von Shanks. `kind` ist "giant_step" oder "baby_step".
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ShanksStepBean {
+    GiantStep {
+        j: String,
+        value: String,
+    },
+    BabyStep {
+        i: String,
+        value: String,
+        matching_giant_step: Option<String>,
+    },
+}
+
+impl From<ShanksStep> for ShanksStepBean {
+    fn from(step: ShanksStep) -> Self {
+        match step {
+            ShanksStep::GiantStep { j, value } => ShanksStepBean::GiantStep {
+                j: j.to_string(),
+                value: value.to_string(),
+            },
+            ShanksStep::BabyStep {
+                i,
+                value,
+                matching_giant_step,
+            } => ShanksStepBean::BabyStep {
+                i: i.to_string(),
+                value: value.to_string(),
+                matching_giant_step: matching_giant_step.map(|j| j.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MillerRabinRoundBean {
+    pub witness: String,
+    pub sequence: Vec<String>,
+    pub passed: bool,
+}
+
+impl From<MillerRabinRound> for MillerRabinRoundBean {
+    fn from(round: MillerRabinRound) -> Self {
+        MillerRabinRoundBean {
+            witness: round.witness.to_string(),
+            sequence: round.sequence.iter().map(|x| x.to_string()).collect(),
+            passed: round.passed,
+        }
+    }
+}
+
+/// Maximale Anzahl an Bits, die im Randomness-Report untersucht werden.
+/// Begrenzt die Laufzeit der quadratischen Fourier-Transformation.
+const MAXIMUM_RANDOMNESS_SAMPLE_SIZE: usize = 20_000;
+
+#[derive(Deserialize)]
+pub struct RandomnessReportRequest {
+    pub random_seed: u32,
+    pub sample_size: usize,
+}
+
+#[derive(Serialize)]
+pub struct RandomnessTestResultBean {
+    pub name: String,
+    pub p_values: Vec<f64>,
+    pub passed: bool,
+}
+
+impl From<RandomnessTestResult> for RandomnessTestResultBean {
+    /// Mapped das Domain-Modell in die Bean
+    fn from(result: RandomnessTestResult) -> Self {
+        Self {
+            name: result.name,
+            p_values: result.p_values,
+            passed: result.passed,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RandomnessReportResponse {
+    pub sample_size: usize,
+    pub pseudo_random_number_generator: Vec<RandomnessTestResultBean>,
+    pub csprng: Vec<RandomnessTestResultBean>,
+}
+
+/// Höchstzahl an PBKDF2-Iterationen, die über den Endpunkt angefordert werden können.
+const MAXIMUM_PBKDF2_ITERATIONS: u32 = 1_000_000;
+
+/// Höchstlänge eines über den Endpunkt abgeleiteten Schlüssels in Bytes.
+const MAXIMUM_DERIVED_KEY_LENGTH: usize = 1024;
+
+/// Höchste Bitlänge von p, für die der Endpunkt die Punkte einer Kurve zählt. Schoof braucht
+/// dafür im Release-Build etwa 5 Sekunden, größere p würden einen Worker zu lange blockieren.
+const MAXIMUM_CURVE_ORDER_BITS: u64 = 64;
+
+/// Eine Anfrage für einen HMAC.
+///
+/// # Felder
+/// * `hash_algorithm` - Die Hashfunktion, z.B. "SHA-256" oder "SHA-512".
+/// * `key` - Der Base64-kodierte Schlüssel.
+/// * `message` - Die Nachricht im Klartext.
+/// * `expected_mac` - Optional ein Base64-kodierter MAC, der in konstanter Zeit mit dem
+///   berechneten verglichen wird.
+#[derive(Deserialize)]
+pub struct HmacRequest {
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+    pub key: String,
+    pub message: String,
+    #[serde(default)]
+    pub expected_mac: Option<String>,
+}
+
+/// Die Antwort auf eine HMAC-Anfrage. `valid` ist nur gesetzt, wenn ein erwarteter MAC
+/// übergeben wurde.
+#[derive(Serialize)]
+pub struct HmacResponse {
+    pub hash_algorithm: String,
+    pub mac: String,
+    pub valid: Option<bool>,
+}
+
+/// Eine Anfrage zur Schlüsselableitung. Alle Bytefolgen sind Base64-kodiert.
+///
+/// # Felder
+/// * `kdf` - Das Verfahren, "HKDF" oder "PBKDF2".
+/// * `hash_algorithm` - Die Hashfunktion des HMAC.
+/// * `secret` - Das Ausgangsmaterial bzw. das Passwort.
+/// * `salt` - Das Salt.
+/// * `info` - Die Kontextinformationen. Wird nur von HKDF verwendet.
+/// * `iterations` - Die Anzahl der Iterationen. Wird nur von PBKDF2 verwendet.
+/// * `length` - Die Länge des abgeleiteten Schlüssels in Bytes.
+#[derive(Deserialize)]
+pub struct KdfRequest {
+    pub kdf: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+    pub secret: String,
+    #[serde(default)]
+    pub salt: String,
+    #[serde(default)]
+    pub info: String,
+    #[serde(default)]
+    pub iterations: u32,
+    pub length: usize,
+}
+
+/// Das Ergebnis einer Schlüsselableitung. Bei HKDF enthält `pseudo_random_key` zusätzlich
+/// das Zwischenergebnis des Extract-Schritts.
+#[derive(Serialize)]
+pub struct KdfResponse {
+    pub key: String,
+    pub pseudo_random_key: Option<String>,
+}
+
+/// Höchste Bitlänge der Zahlen, die über die Endpunkte faktorisiert werden. Pollards
+/// Rho-Methode braucht für zwei gleich große Primfaktoren etwa 2^(Bitlänge / 4) Schritte.
+const MAXIMUM_FACTORIZATION_BITS: u64 = 80;
+
+/// Höchste Gruppenordnung φ(n), für die alle Primitivwurzeln aufgezählt werden.
+const MAXIMUM_PRIMITIVE_ROOT_ENUMERATION_ORDER: u32 = 100_000;
+
+#[derive(Deserialize)]
+pub struct FactorizationRequest {
+    pub number: String,
+}
+
+#[derive(Serialize)]
+pub struct PrimeFactorBean {
+    pub prime: String,
+    pub exponent: u32,
+}
+
+#[derive(Serialize)]
+pub struct FactorizationResponse {
+    pub factors: Vec<PrimeFactorBean>,
+}
+
+impl From<Factorization> for FactorizationResponse {
+    fn from(factorization: Factorization) -> Self {
+        FactorizationResponse {
+            factors: factorization
+                .prime_powers()
+                .map(|(prime, exponent)| PrimeFactorBean {
+                    prime: prime.to_string(),
+                    exponent,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MultiplicativeGroupRequest {
+    pub modulus: String,
+}
+
+/// Die Kenngrößen der Einheitengruppe (Z/nZ)*.
+///
+/// # Felder
+/// * `euler_phi` - Die Gruppenordnung φ(n).
+/// * `carmichael_lambda` - Der Gruppenexponent λ(n).
+/// * `primitive_root` - Die kleinste Primitivwurzel, falls die Gruppe zyklisch ist.
+#[derive(Serialize)]
+pub struct MultiplicativeGroupResponse {
+    pub modulus: String,
+    pub euler_phi: String,
+    pub carmichael_lambda: String,
+    pub primitive_root: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ElementOrderRequest {
+    pub element: String,
+    pub modulus: String,
+}
+
+#[derive(Serialize)]
+pub struct ElementOrderResponse {
+    pub order: String,
+    pub is_primitive_root: bool,
+}
+
+#[derive(Serialize)]
+pub struct PrimitiveRootsResponse {
+    pub primitive_roots: Vec<String>,
+}
+
+/// Eine ganze gaußsche Zahl `real` + `imaginary` * i.
+#[derive(Deserialize, Serialize)]
+pub struct GaussianIntegerBean {
+    pub real: String,
+    pub imaginary: String,
+}
+
+impl From<GaussianInteger> for GaussianIntegerBean {
+    fn from(number: GaussianInteger) -> Self {
+        GaussianIntegerBean {
+            real: number.real.to_string(),
+            imaginary: number.imaginary.to_string(),
+        }
+    }
+}
+
+impl GaussianIntegerBean {
+    fn parse(&self) -> Result<GaussianInteger> {
+        Ok(GaussianInteger::new(
+            BigInt::from_str(&self.real)?,
+            BigInt::from_str(&self.imaginary)?,
+        ))
+    }
+}
+
+/// Eine Anfrage zur Untersuchung einer gaußschen Zahl.
+///
+/// # Felder
+/// * `a` - Die zu untersuchende Zahl.
+/// * `b` - Optional ein Divisor bzw. zweites Argument des erweiterten euklidischen Algorithmus.
+#[derive(Deserialize)]
+pub struct GaussianRequest {
+    pub a: GaussianIntegerBean,
+    pub b: Option<GaussianIntegerBean>,
+}
+
+#[derive(Serialize)]
+pub struct GaussianFactorBean {
+    pub factor: GaussianIntegerBean,
+    pub exponent: u32,
+}
+
+#[derive(Serialize)]
+pub struct GaussianFactorizationBean {
+    pub unit: GaussianIntegerBean,
+    pub factors: Vec<GaussianFactorBean>,
+}
+
+#[derive(Serialize)]
+pub struct GaussianDivisionBean {
+    pub quotient: GaussianIntegerBean,
+    pub remainder: GaussianIntegerBean,
+}
+
+#[derive(Serialize)]
+pub struct GaussianEuclidBean {
+    pub ggt: GaussianIntegerBean,
+    pub x: GaussianIntegerBean,
+    pub y: GaussianIntegerBean,
+}
+
+/// Die Eigenschaften einer gaußschen Zahl a und, falls b angegeben ist, deren Division mit Rest
+/// durch b und der erweiterte euklidische Algorithmus für (a, b).
+///
+/// # Felder
+/// * `factorization` - Die Zerlegung in gaußsche Primzahlen. Fehlt, falls a = 0 ist oder die
+///   Norm von a zu groß zum Faktorisieren ist.
+/// * `division` - Fehlt, falls b nicht angegeben oder null ist.
+/// * `extended_euclid` - Fehlt, falls b nicht angegeben ist.
+#[derive(Serialize)]
+pub struct GaussianResponse {
+    pub norm: String,
+    pub is_gaussian_prime: bool,
+    pub factorization: Option<GaussianFactorizationBean>,
+    pub division: Option<GaussianDivisionBean>,
+    pub extended_euclid: Option<GaussianEuclidBean>,
+}
+
+#[derive(Deserialize)]
+pub struct SumOfTwoSquaresRequest {
+    pub prime: String,
+}
+
+#[derive(Serialize)]
+pub struct SumOfTwoSquaresResponse {
+    pub x: String,
+    pub y: String,
+}
+
+/// Eine Anfrage zur Bestimmung der Ordnung der Kurve y^2 = x^3 + ax + b über F_p.
+///
+/// # Felder
+/// * `method` - Optional "naive", "bsgs" oder "schoof". Fehlt es, wird das Verfahren anhand der
+///   Größe von p gewählt. Der Endpunkt akzeptiert p mit höchstens 64 Bit.
+#[derive(Deserialize)]
+pub struct CurveOrderRequest {
+    pub a: String,
+    pub b: String,
+    pub prime: String,
+    #[serde(default)]
+    pub method: Option<String>,
+}
+
+/// Die Ordnung N = p + 1 - t einer Kurve, ihre Frobenius-Spur t und das verwendete Verfahren.
+#[derive(Serialize)]
+pub struct CurveOrderResponse {
+    pub order: String,
+    pub trace: String,
+    pub method: String,
+}
+
 impl ExtendedEuclidResponse {
     /// Erstellt eine neue Instanz der ExtendedEuclidResponse anhand eines ExtendedEuclidResult.
     fn from(result: ExtendedEuclidResult) -> ExtendedEuclidResponse {
@@ -64,12 +523,15 @@ impl ExtendedEuclidResponse {
 /// # Arguments
 /// * `req_body` - Die Anfrage, die die Parameter für die modulare Inverse enthält.
 /// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+/// * `trace_query` - Die Abfrage, ob die Tabelle des erweiterten euklidischen Algorithmus
+///   zurückgegeben werden soll.
 ///
 /// # Returns
 /// * `HttpResponse` - Die Antwort, die das Ergebnis der modularen Inverse enthält.
 pub(crate) async fn modular_inverse_endpoint(
     req_body: Json<ModulInverseRequest>,
     query: Query<UseFastQuery>,
+    trace_query: Query<TraceQuery>,
 ) -> impl Responder {
     info!(
         "Endpunkt /math/modular_inverse wurde aufgerufen, use_fast: {}",
@@ -87,19 +549,28 @@ pub(crate) async fn modular_inverse_endpoint(
             false => NumberTheoryService::new(Slow),
         };
 
-        let result = number_theory_service.modulo_inverse(&n, &modul);
+        let to_response = |x: BigInt| SingleStringResponse {
+            message: x.to_string(),
+        };
+        let result = match trace_query.trace {
+            true => number_theory_service
+                .modulo_inverse_traced(&n, &modul)
+                .map(|traced| {
+                    HttpResponse::Ok().json(TracedResponse::<_, EuclidStepBean>::from_traced(
+                        traced,
+                        to_response,
+                    ))
+                }),
+            false => number_theory_service
+                .modulo_inverse(&n, &modul)
+                .map(|x| HttpResponse::Ok().json(to_response(x))),
+        };
 
-        let response = match result {
-            Ok(x) => {
-                let response = SingleStringResponse {
-                    message: x.to_string(),
-                };
-                HttpResponse::Ok().json(response)
-            }
-            Err(_) => HttpResponse::BadRequest().json(SingleStringResponse {
+        let response = result.unwrap_or_else(|_| {
+            HttpResponse::BadRequest().json(SingleStringResponse {
                 message: "Kein Ergebnis gefunden".to_string(),
-            }),
-        };
+            })
+        });
 
         Ok(response)
     })
@@ -110,12 +581,14 @@ pub(crate) async fn modular_inverse_endpoint(
 /// # Arguments
 /// * `req_body` - Die Anfrage, die die Parameter für die Berechnung des diskreten Logarithmus enthält.
 /// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+/// * `trace_query` - Die Abfrage, ob die Giantsteps und Babysteps zurückgegeben werden sollen.
 ///
 /// # Returns
 /// * `HttpResponse` - Die Antwort, die das Ergebnis des diskreten Logarithmus enthält.
 pub(crate) async fn shanks_endpoint(
     req_body: Json<ShanksRequest>,
     query: Query<UseFastQuery>,
+    trace_query: Query<TraceQuery>,
 ) -> impl Responder {
     info!(
         "Endpunkt /math/shanks wurde aufgerufen, use_fast: {}",
@@ -136,18 +609,28 @@ pub(crate) async fn shanks_endpoint(
         let element = BigInt::from_str(&req_body.element)?;
         let modul = BigInt::from_str(&req_body.modul)?;
 
-        let result = shanks_service.calculate(&base, &element, &modul);
-        let response = match result {
-            Ok(x) => {
-                let response = SingleStringResponse {
-                    message: x.to_string(),
-                };
-                HttpResponse::Ok().json(response)
-            }
-            Err(_) => HttpResponse::BadRequest().json(SingleStringResponse {
-                message: "Fehler beim Berechnen des diskreten Logarithmus".to_string(),
-            }),
+        let to_response = |x: BigInt| SingleStringResponse {
+            message: x.to_string(),
+        };
+        let result = match trace_query.trace {
+            true => shanks_service
+                .calculate_traced(&base, &element, &modul)
+                .map(|traced| {
+                    HttpResponse::Ok().json(TracedResponse::<_, ShanksStepBean>::from_traced(
+                        traced,
+                        to_response,
+                    ))
+                }),
+            false => shanks_service
+                .calculate(&base, &element, &modul)
+                .map(|x| HttpResponse::Ok().json(to_response(x))),
         };
+
+        let response = result.unwrap_or_else(|_| {
+            HttpResponse::BadRequest().json(SingleStringResponse {
+                message: "Fehler beim Berechnen des diskreten Logarithmus".to_string(),
+            })
+        });
         Ok(response)
     })
 }
@@ -157,12 +640,14 @@ pub(crate) async fn shanks_endpoint(
 /// # Arguments
 /// * `req_body` - Die Anfrage, die die Parameter für die Berechnung des erweiterten Euklidischen Algorithmus enthält.
 /// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+/// * `trace_query` - Die Abfrage, ob die Tabelle der Divisionen zurückgegeben werden soll.
 ///
 /// # Returns
 /// * `HttpResponse` - Die Antwort, die die Ergebnisse des erweiterten Euklidischen Algorithmus enthält.
 pub(crate) async fn euclid_endpoint(
     req_body: Json<ExtendedEuclidRequest>,
     query: Query<UseFastQuery>,
+    trace_query: Query<TraceQuery>,
 ) -> impl Responder {
     info!(
         "Endpunkt /math/extended_euclid wurde aufgerufen, use_fast: {}",
@@ -180,6 +665,16 @@ pub(crate) async fn euclid_endpoint(
             false => NumberTheoryService::new(Slow),
         };
 
+        if trace_query.trace {
+            let traced = number_theory_service.extended_euclid_traced(a, b);
+            return Ok(
+                HttpResponse::Ok().json(TracedResponse::<_, EuclidStepBean>::from_traced(
+                    traced,
+                    ExtendedEuclidResponse::from,
+                )),
+            );
+        }
+
         let extended_euclid_result = number_theory_service.extended_euclid(a, b);
 
         let response = ExtendedEuclidResponse::from(extended_euclid_result);
@@ -192,12 +687,15 @@ pub(crate) async fn euclid_endpoint(
 /// # Arguments
 /// * `req_body` - Die Anfrage, die die Parameter für die Exponentiation enthält.
 /// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+/// * `trace_query` - Die Abfrage, ob die Schritte von Square-and-Multiply zurückgegeben werden
+///   sollen.
 ///
 /// # Returns
 /// * `HttpResponse` - Die Antwort, die das Ergebnis der Exponentiation enthält.
 pub(crate) async fn exponentiation(
     req_body: Json<ExponentiationRequest>,
     query: Query<UseFastQuery>,
+    trace_query: Query<TraceQuery>,
 ) -> impl Responder {
     info!(
         "Endpunkt /math/exponentiation wurde aufgerufen, use_fast: {}",
@@ -216,6 +714,17 @@ pub(crate) async fn exponentiation(
             false => NumberTheoryService::new(Slow),
         };
 
+        if trace_query.trace {
+            let traced = number_theory_service.fast_exponentiation_traced(base, exponent, modulus);
+            return Ok(HttpResponse::Ok().json(
+                TracedResponse::<_, SquareAndMultiplyStepBean>::from_traced(traced, |result| {
+                    SingleStringResponse {
+                        message: result.to_str_radix(10),
+                    }
+                }),
+            ));
+        }
+
         let result = number_theory_service
             .fast_exponentiation(base, exponent, modulus)
             .to_str_radix(10);
@@ -225,3 +734,626 @@ pub(crate) async fn exponentiation(
         Ok(HttpResponse::Ok().json(response))
     })
 }
+
+/// Prüft mit dem Miller-Rabin-Test, ob eine Zahl wahrscheinlich prim ist.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Zahl, die Anzahl der Runden und den Seed der Basen enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+/// * `trace_query` - Die Abfrage, ob Basis und Potenzfolge jeder Runde zurückgegeben werden sollen.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die "true" enthält, wenn die Zahl wahrscheinlich prim ist.
+pub(crate) async fn primality_test_endpoint(
+    req_body: Json<PrimalityTestRequest>,
+    query: Query<UseFastQuery>,
+    trace_query: Query<TraceQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/primality_test wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: PrimalityTestRequest = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    if !(1..=MAXIMUM_PRIMALITY_TEST_REPEATS).contains(&req_body.repeats) {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!(
+                "Die Anzahl der Runden muss zwischen 1 und {} liegen",
+                MAXIMUM_PRIMALITY_TEST_REPEATS
+            ),
+        });
+    }
+
+    call_checked_with_parsed_big_ints(|| {
+        let number = BigInt::from_str(&req_body.number)?;
+        if number < BigInt::from(3) {
+            return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: "Die Zahl muss mindestens 3 sein".to_string(),
+            }));
+        }
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+        let random_generator =
+            PseudoRandomNumberGenerator::new(req_body.random_seed, number_theory_service);
+
+        let to_response = |is_prime: bool| SingleStringResponse {
+            message: is_prime.to_string(),
+        };
+        let response = match trace_query.trace {
+            true => {
+                let traced = number_theory_service.is_probably_prime_traced(
+                    &number,
+                    req_body.repeats,
+                    &random_generator,
+                );
+                HttpResponse::Ok().json(TracedResponse::<_, MillerRabinRoundBean>::from_traced(
+                    traced,
+                    to_response,
+                ))
+            }
+            false => HttpResponse::Ok().json(to_response(number_theory_service.is_probably_prime(
+                &number,
+                req_body.repeats,
+                &random_generator,
+            ))),
+        };
+        Ok(response)
+    })
+}
+
+/// Löst ein System von Kongruenzen nach dem chinesischen Restsatz. Die Moduln müssen nicht
+/// teilerfremd sein.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Kongruenzen x ≡ a_i mod m_i enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Lösung modulo des kgV der Moduln enthält, oder einen
+///   BadRequest, falls das System widersprüchlich ist.
+pub(crate) async fn chinese_remainder_endpoint(
+    req_body: Json<ChineseRemainderRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/crt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: ChineseRemainderRequest = req_body.into_inner();
+
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    match solve_congruences(&req_body, number_theory_service) {
+        Ok(solution) => HttpResponse::Ok().json(CongruenceBean::from(solution)),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn solve_congruences(
+    request: &ChineseRemainderRequest,
+    number_theory_service: NumberTheoryService,
+) -> Result<Congruence> {
+    let congruences = request
+        .congruences
+        .iter()
+        .map(|congruence| {
+            Congruence::new(
+                &BigInt::from_str(&congruence.remainder)?,
+                &BigInt::from_str(&congruence.modulus)?,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    number_theory_service.chinese_remainder(&congruences)
+}
+
+/// Untersucht die Ausgabe des PseudoRandomNumberGenerator mit der Testsuite aus NIST SP 800-22
+/// und stellt ihr die Ergebnisse eines kryptographisch sicheren Generators gegenüber.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die den Seed und die Anzahl der zu untersuchenden Bits enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die p-Werte beider Generatoren enthält.
+pub(crate) async fn randomness_report(
+    req_body: Json<RandomnessReportRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/randomness_report wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RandomnessReportRequest = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    let sample_size = req_body.sample_size;
+    if !(MINIMUM_SUITE_LENGTH..=MAXIMUM_RANDOMNESS_SAMPLE_SIZE).contains(&sample_size) {
+        return HttpResponse::BadRequest().json(SingleStringResponse {
+            message: format!(
+                "Die Anzahl der Bits muss zwischen {} und {} liegen",
+                MINIMUM_SUITE_LENGTH, MAXIMUM_RANDOMNESS_SAMPLE_SIZE
+            ),
+        });
+    }
+
+    let number_theory_service = match use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let mut pseudo_random_source =
+        PseudoRandomBitSource::new(req_body.random_seed, number_theory_service);
+    let mut csprng_source = CsprngBitSource::new(req_body.random_seed);
+
+    let reports = analyse_bit_source(&mut pseudo_random_source, sample_size).and_then(
+        |pseudo_random_results| {
+            Ok((
+                pseudo_random_results,
+                analyse_bit_source(&mut csprng_source, sample_size)?,
+            ))
+        },
+    );
+
+    match reports {
+        Ok((pseudo_random_results, csprng_results)) => {
+            HttpResponse::Ok().json(RandomnessReportResponse {
+                sample_size,
+                pseudo_random_number_generator: pseudo_random_results
+                    .into_iter()
+                    .map(RandomnessTestResultBean::from)
+                    .collect(),
+                csprng: csprng_results
+                    .into_iter()
+                    .map(RandomnessTestResultBean::from)
+                    .collect(),
+            })
+        }
+        Err(_) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: "Fehler beim Erstellen des Randomness-Reports".to_string(),
+        }),
+    }
+}
+
+/// Berechnet den HMAC einer Nachricht.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Hashfunktion, den Schlüssel und die Nachricht enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den Base64-kodierten MAC und, falls ein erwarteter MAC
+///   übergeben wurde, das Ergebnis des Vergleichs enthält.
+pub(crate) async fn hmac_endpoint(req_body: Json<HmacRequest>) -> impl Responder {
+    info!("Endpunkt /math/hmac wurde aufgerufen");
+    let req_body: HmacRequest = req_body.into_inner();
+
+    match compute_hmac(&req_body) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn compute_hmac(request: &HmacRequest) -> Result<HmacResponse> {
+    let hash_algorithm = request.hash_algorithm.parse::<HashAlgorithm>()?;
+    let key = STANDARD.decode(&request.key)?;
+    let mut mac = Hmac::new(hash_algorithm, &key)?;
+    mac.update(request.message.as_bytes());
+    let valid = match &request.expected_mac {
+        Some(expected_mac) => Some(mac.clone().verify(&STANDARD.decode(expected_mac)?)),
+        None => None,
+    };
+    Ok(HmacResponse {
+        hash_algorithm: hash_algorithm.to_string(),
+        mac: STANDARD.encode(mac.finalize()),
+        valid,
+    })
+}
+
+/// Leitet mit HKDF oder PBKDF2 einen Schlüssel ab.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Verfahren und dessen Parameter enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den Base64-kodierten Schlüssel enthält.
+pub(crate) async fn kdf_endpoint(req_body: Json<KdfRequest>) -> impl Responder {
+    info!("Endpunkt /math/kdf wurde aufgerufen, kdf: {}", req_body.kdf);
+    let req_body: KdfRequest = req_body.into_inner();
+
+    match derive_key(&req_body) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn derive_key(request: &KdfRequest) -> Result<KdfResponse> {
+    let hash_algorithm = request.hash_algorithm.parse::<HashAlgorithm>()?;
+    let secret = STANDARD.decode(&request.secret)?;
+    let salt = STANDARD.decode(&request.salt)?;
+    ensure!(
+        request.length <= MAXIMUM_DERIVED_KEY_LENGTH,
+        "Die Schlüssellänge darf höchstens {} Bytes betragen",
+        MAXIMUM_DERIVED_KEY_LENGTH
+    );
+
+    match request.kdf.to_uppercase().as_str() {
+        "HKDF" => {
+            let info = STANDARD.decode(&request.info)?;
+            let pseudo_random_key = hkdf_extract(hash_algorithm, &salt, &secret)?;
+            let key = hkdf_expand(hash_algorithm, &pseudo_random_key, &info, request.length)?;
+            Ok(KdfResponse {
+                key: STANDARD.encode(key),
+                pseudo_random_key: Some(STANDARD.encode(pseudo_random_key)),
+            })
+        }
+        "PBKDF2" => {
+            ensure!(
+                request.iterations <= MAXIMUM_PBKDF2_ITERATIONS,
+                "Die Anzahl der Iterationen darf höchstens {} betragen",
+                MAXIMUM_PBKDF2_ITERATIONS
+            );
+            let key = pbkdf2(
+                hash_algorithm,
+                &secret,
+                &salt,
+                request.iterations,
+                request.length,
+            )?;
+            Ok(KdfResponse {
+                key: STANDARD.encode(key),
+                pseudo_random_key: None,
+            })
+        }
+        _ => Err(UnknownAlgorithmError(request.kdf.clone()).into()),
+    }
+}
+
+/// Zerlegt eine Zahl mit Probedivision und Pollards Rho-Methode in Primfaktoren.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die zu zerlegende Zahl enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Primfaktoren mit ihren Exponenten enthält.
+pub(crate) async fn factorization_endpoint(
+    req_body: Json<FactorizationRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/factorize wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let result = parse_factorizable(&req_body.number)
+        .and_then(|number| Factorization::factorize(&number, number_theory_service));
+    match result {
+        Ok(factorization) => HttpResponse::Ok().json(FactorizationResponse::from(factorization)),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Berechnet φ(n), λ(n) und die kleinste Primitivwurzel modulo n.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Modul enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Kenngrößen der Einheitengruppe enthält.
+pub(crate) async fn multiplicative_group_endpoint(
+    req_body: Json<MultiplicativeGroupRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/multiplicative_group wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+
+    match describe_multiplicative_group(&req_body.modulus, query.use_fast) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Berechnet die Ordnung eines Elements modulo n und prüft, ob es eine Primitivwurzel ist.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Element und das Modul enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Ordnung enthält, oder einen BadRequest, falls das
+///   Element nicht teilerfremd zum Modul ist.
+pub(crate) async fn element_order_endpoint(
+    req_body: Json<ElementOrderRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/order wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+
+    match compute_element_order(&req_body, query.use_fast) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Zählt alle Primitivwurzeln modulo n auf.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die das Modul enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Primitivwurzeln in aufsteigender Reihenfolge enthält.
+pub(crate) async fn primitive_roots_endpoint(
+    req_body: Json<MultiplicativeGroupRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/primitive_roots wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+
+    match enumerate_primitive_roots(&req_body.modulus, query.use_fast) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn parse_factorizable(number: &str) -> Result<BigInt> {
+    let number = BigInt::from_str(number)?;
+    ensure!(
+        number.bits() <= MAXIMUM_FACTORIZATION_BITS,
+        "Die Zahl darf höchstens {} Bit lang sein",
+        MAXIMUM_FACTORIZATION_BITS
+    );
+    Ok(number)
+}
+
+fn create_multiplicative_group(modulus: &str, use_fast: bool) -> Result<MultiplicativeGroup> {
+    let number_theory_service = match use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+    MultiplicativeGroup::new(&parse_factorizable(modulus)?, number_theory_service)
+}
+
+fn describe_multiplicative_group(
+    modulus: &str,
+    use_fast: bool,
+) -> Result<MultiplicativeGroupResponse> {
+    let group = create_multiplicative_group(modulus, use_fast)?;
+    Ok(MultiplicativeGroupResponse {
+        modulus: group.modulus().to_string(),
+        euler_phi: group.euler_phi().to_string(),
+        carmichael_lambda: group.carmichael_lambda().to_string(),
+        primitive_root: group
+            .primitive_root()
+            .ok()
+            .map(|primitive_root| primitive_root.to_string()),
+    })
+}
+
+fn compute_element_order(
+    request: &ElementOrderRequest,
+    use_fast: bool,
+) -> Result<ElementOrderResponse> {
+    let group = create_multiplicative_group(&request.modulus, use_fast)?;
+    let element = BigInt::from_str(&request.element)?;
+    Ok(ElementOrderResponse {
+        order: group.order(&element)?.to_string(),
+        is_primitive_root: group.is_primitive_root(&element),
+    })
+}
+
+fn enumerate_primitive_roots(modulus: &str, use_fast: bool) -> Result<PrimitiveRootsResponse> {
+    let group = create_multiplicative_group(modulus, use_fast)?;
+    ensure!(
+        group.euler_phi() <= BigInt::from(MAXIMUM_PRIMITIVE_ROOT_ENUMERATION_ORDER),
+        "Primitivwurzeln werden nur für φ(n) <= {} aufgezählt",
+        MAXIMUM_PRIMITIVE_ROOT_ENUMERATION_ORDER
+    );
+    Ok(PrimitiveRootsResponse {
+        primitive_roots: group
+            .primitive_roots()?
+            .iter()
+            .map(|primitive_root| primitive_root.to_string())
+            .collect(),
+    })
+}
+
+/// Untersucht eine gaußsche Zahl: Norm, Primalität in Z[i] und Primfaktorzerlegung sowie
+/// optional Division mit Rest und erweiterter euklidischer Algorithmus mit einer zweiten Zahl.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die gaußsche Zahl a und optional b enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Eigenschaften von a enthält.
+pub(crate) async fn gaussian_endpoint(
+    req_body: Json<GaussianRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/gaussian wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+
+    match analyse_gaussian_integer(&req_body, query.use_fast) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Schreibt eine Primzahl p = 2 oder p ≡ 1 mod 4 mit dem Algorithmus von Cornacchia als Summe
+/// zweier Quadrate.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Primzahl enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die x und y mit p = x^2 + y^2 enthält.
+pub(crate) async fn sum_of_two_squares_endpoint(
+    req_body: Json<SumOfTwoSquaresRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/gaussian/two_squares wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let number_theory_service = match query.use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+
+    let result = BigInt::from_str(&req_body.prime)
+        .map_err(anyhow::Error::from)
+        .and_then(|prime| sum_of_two_squares(&prime, number_theory_service));
+    match result {
+        Ok((x, y)) => HttpResponse::Ok().json(SumOfTwoSquaresResponse {
+            x: x.to_string(),
+            y: y.to_string(),
+        }),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn analyse_gaussian_integer(request: &GaussianRequest, use_fast: bool) -> Result<GaussianResponse> {
+    let number_theory_service = match use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+    let a = request.a.parse()?;
+    let b = request
+        .b
+        .as_ref()
+        .map(GaussianIntegerBean::parse)
+        .transpose()?;
+
+    let factorization = match a.is_zero() || a.norm().bits() > MAXIMUM_FACTORIZATION_BITS {
+        true => None,
+        false => {
+            let factorization = a.factorize(number_theory_service)?;
+            Some(GaussianFactorizationBean {
+                unit: factorization.unit.into(),
+                factors: factorization
+                    .factors
+                    .into_iter()
+                    .map(|(factor, exponent)| GaussianFactorBean {
+                        factor: factor.into(),
+                        exponent,
+                    })
+                    .collect(),
+            })
+        }
+    };
+
+    let division = b
+        .as_ref()
+        .and_then(|b| a.div_rem(b).ok())
+        .map(|(quotient, remainder)| GaussianDivisionBean {
+            quotient: quotient.into(),
+            remainder: remainder.into(),
+        });
+    let extended_euclid = b.as_ref().map(|b| {
+        let result = gaussian_extended_euclid(&a, b);
+        GaussianEuclidBean {
+            ggt: result.ggt.into(),
+            x: result.x.into(),
+            y: result.y.into(),
+        }
+    });
+
+    Ok(GaussianResponse {
+        norm: a.norm().to_string(),
+        is_gaussian_prime: a.is_gaussian_prime(number_theory_service),
+        factorization,
+        division,
+        extended_euclid,
+    })
+}
+
+/// Bestimmt die Anzahl der Punkte einer elliptischen Kurve über F_p.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Kurve und optional das Verfahren enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Ordnung und die Spur der Kurve enthält.
+pub(crate) async fn curve_order_endpoint(
+    req_body: Json<CurveOrderRequest>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /math/curve_order wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+
+    match count_curve_points(&req_body, query.use_fast) {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => HttpResponse::BadRequest().json(SingleStringResponse {
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn count_curve_points(request: &CurveOrderRequest, use_fast: bool) -> Result<CurveOrderResponse> {
+    let number_theory_service = match use_fast {
+        true => NumberTheoryService::new(Fast),
+        false => NumberTheoryService::new(Slow),
+    };
+    let curve = FiniteFieldEllipticCurve::new(
+        BigInt::from_str(&request.a)?,
+        BigInt::from_str(&request.b)?,
+        BigInt::from_str(&request.prime)?,
+    )?;
+    ensure!(
+        curve.prime.bits() <= MAXIMUM_CURVE_ORDER_BITS,
+        "Die Primzahl darf höchstens {} Bit haben",
+        MAXIMUM_CURVE_ORDER_BITS
+    );
+    let method = match &request.method {
+        Some(method) => method.parse()?,
+        None => PointCountingMethod::for_prime(&curve.prime)?,
+    };
+    let order = method.count_points(&curve, number_theory_service)?;
+    Ok(CurveOrderResponse {
+        trace: (&curve.prime + BigInt::from(1) - &order).to_string(),
+        order: order.to_string(),
+        method: method.to_string(),
+    })
+}
@@ -1,3 +1,11 @@
+pub mod aes;
+pub mod classic;
+pub mod ecdsa;
+pub mod ed25519;
+pub mod el_gamal;
+pub mod hash;
+pub mod key_exchange;
 pub mod math_endpoints;
 pub mod mv;
 pub mod rsa;
+pub mod schnorr;
@@ -1,46 +1,64 @@
 use actix_web::web::{Json, Query};
 use actix_web::{HttpResponse, Responder};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use log::info;
 use serde::{Deserialize, Serialize};
 
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{
+    default_hash_algorithm, HybridCiphertextBean, SingleStringResponse, UseFastQuery,
+};
 use crate::encryption::asymmetric_encryption_types::{
-    AsymmetricDecryptor, AsymmetricEncryptor, Signer, Verifier,
+    AsymmetricDecryptor, AsymmetricEncryptor, Verifier,
 };
 use crate::encryption::core::menezes_vanstone::keys::{
     MenezesVanstoneKeyPair, MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
 };
 use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
-    MenezesVanstoneScheme, MenezesVanstoneSignature,
+    MenezesVanstoneCurveConfig, MenezesVanstoneScheme, MenezesVanstoneSignature,
 };
+use crate::encryption::hybrid::data_encapsulation::{AeadAlgorithm, HybridCiphertext};
+use crate::encryption::hybrid::ec_kem_scheme::EcKemScheme;
+use crate::encryption::hybrid::keys::{EcKemPrivateKey, EcKemPublicKey};
+use crate::encryption::string_schemes::ciphertext_container::CiphertextContainer;
 use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
 use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::string_schemes::menezes_vanstone::keys::{
     MenezesVanstoneStringPrivateKey, MenezesVanstoneStringPublicKey,
 };
 use crate::encryption::string_schemes::menezes_vanstone::menezes_vanstone_string_scheme::{
-    MenezesVanstoneStringScheme, MvStringCiphertext,
+    MenezesVanstoneStringScheme, MvByteCiphertext, MvStringCiphertext,
 };
 use crate::encryption::symmetric_encryption_types::SymmetricEncryptor;
+use crate::math_core::ecc::curve_audit::CurveAudit;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
 use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
 };
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
 
+/// Die Parameter der Schlüsselerzeugung. Ist `curve_name` gesetzt, z.B. "P-256", wird die
+/// Standardkurve verwendet und `modulus_width`, `miller_rabin_rounds` und `coef_a` entfallen.
 #[derive(Deserialize, Clone)]
 pub struct MvCreateKeyPairRequestBean {
+    #[serde(default)]
     pub modulus_width: u32,
+    #[serde(default)]
     pub miller_rabin_rounds: u32,
+    #[serde(default)]
     pub coef_a: i32,
     pub random_seed: u32,
+    #[serde(default)]
+    pub curve_name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct EllipticCurveBean {
-    pub a: i64,
+    pub a: String,
+    pub b: String,
     pub prime: String,
     pub order_of_subgroup: String,
     pub generator: EcPointBean,
@@ -49,8 +67,9 @@ pub struct EllipticCurveBean {
 impl From<SecureFiniteFieldEllipticCurve> for EllipticCurveBean {
     fn from(curve: SecureFiniteFieldEllipticCurve) -> Self {
         EllipticCurveBean {
-            a: curve.a,
-            prime: curve.prime.to_string(),
+            a: curve.curve.a.to_string(),
+            b: curve.curve.b.to_string(),
+            prime: curve.curve.prime.to_string(),
             order_of_subgroup: curve.order_of_subgroup.to_string(),
             generator: EcPointBean::from(curve.generator),
         }
@@ -143,6 +162,55 @@ impl From<MvStringCiphertext> for MvCipherTextBean {
     }
 }
 
+/// Eine Anfrage zum Verschlüsseln einer Base64-kodierten Bytefolge.
+#[derive(Deserialize)]
+pub struct MvEncryptBytesRequestBean {
+    pub public_key: MvPublicKeyBean,
+    pub message: String,
+}
+
+/// Der Chiffretext einer Bytefolge, dessen verschlüsselte Bytes Base64-kodiert sind.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct MvByteCipherTextBean {
+    pub encrypted_message: String,
+    pub points: Vec<EcPointBean>,
+}
+
+impl From<MvByteCiphertext> for MvByteCipherTextBean {
+    fn from(ciphertext: MvByteCiphertext) -> Self {
+        MvByteCipherTextBean {
+            encrypted_message: STANDARD.encode(ciphertext.ciphertext),
+            points: ciphertext.points.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<MvByteCipherTextBean> for MvByteCiphertext {
+    type Error = anyhow::Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: MvByteCipherTextBean) -> anyhow::Result<Self> {
+        Ok(MvByteCiphertext {
+            ciphertext: STANDARD.decode(bean.encrypted_message)?,
+            points: bean.points.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MvDecryptBytesRequestBean {
+    pub private_key: MvPrivateKeyBean,
+    pub cipher_text: MvByteCipherTextBean,
+}
+
+/// Eine Anfrage zum Entschlüsseln eines Base64-kodierten Containers.
+/// Die Basis wird aus dem Container übernommen.
+#[derive(Deserialize)]
+pub struct MvDecryptContainerRequestBean {
+    pub private_key: MvPrivateKeyBean,
+    pub container: String,
+}
+
 #[derive(Deserialize)]
 pub struct MvDecryptRequestBean {
     pub private_key: MvPrivateKeyBean,
@@ -155,6 +223,8 @@ pub struct MvSignatureBean {
     pub r: String,
     pub s: String,
     pub string_representation: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
 }
 
 impl From<MenezesVanstoneSignature> for MvSignatureBean {
@@ -171,6 +241,7 @@ impl From<MenezesVanstoneSignature> for MvSignatureBean {
             r: signature.r.to_string(),
             s: signature.s.to_string(),
             string_representation,
+            hash_algorithm: signature.hash_algorithm.to_string(),
         }
     }
 }
@@ -179,6 +250,8 @@ impl From<MenezesVanstoneSignature> for MvSignatureBean {
 pub struct MvSignRequestBean {
     pub private_key: MvPrivateKeyBean,
     pub message: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
 }
 
 #[derive(Deserialize, Clone)]
@@ -188,6 +261,59 @@ pub struct MvVerifyRequestBean {
     pub signature: MvSignatureBean,
 }
 
+#[derive(Deserialize)]
+pub struct MvHybridEncryptRequestBean {
+    pub public_key: MvPublicKeyBean,
+    pub message: String,
+    pub aead: String,
+}
+
+#[derive(Deserialize)]
+pub struct MvHybridDecryptRequestBean {
+    pub private_key: MvPrivateKeyBean,
+    pub ciphertext: HybridCiphertextBean,
+}
+
+/// Der Sicherheitsbericht einer Kurve, siehe `CurveAudit`.
+#[derive(Serialize)]
+pub struct CurveAuditBean {
+    pub curve_order: String,
+    pub trace: String,
+    pub cofactor: String,
+    pub subgroup_order_is_prime: bool,
+    pub embedding_degree: Option<u64>,
+    pub is_anomalous: bool,
+    pub is_supersingular: bool,
+    pub rho_security_bits: f64,
+    pub twist_order: String,
+    pub twist_largest_prime_factor: Option<String>,
+    pub twist_rho_security_bits: Option<f64>,
+    pub weaknesses: Vec<String>,
+    pub is_secure: bool,
+}
+
+impl From<CurveAudit> for CurveAuditBean {
+    fn from(audit: CurveAudit) -> Self {
+        CurveAuditBean {
+            weaknesses: audit.weaknesses().iter().map(ToString::to_string).collect(),
+            is_secure: audit.is_secure(),
+            curve_order: audit.curve_order.to_string(),
+            trace: audit.trace.to_string(),
+            cofactor: audit.cofactor.to_string(),
+            subgroup_order_is_prime: audit.subgroup_order_is_prime,
+            embedding_degree: audit.embedding_degree,
+            is_anomalous: audit.is_anomalous,
+            is_supersingular: audit.is_supersingular,
+            rho_security_bits: audit.rho_security_bits,
+            twist_order: audit.twist_order.to_string(),
+            twist_largest_prime_factor: audit
+                .twist_largest_prime_factor
+                .map(|factor| factor.to_string()),
+            twist_rho_security_bits: audit.twist_rho_security_bits,
+        }
+    }
+}
+
 /// Erstellt ein neues Schlüsselpaar für das MenezesVanstone-Schema.
 ///
 /// # Arguments
@@ -211,12 +337,15 @@ pub(crate) async fn create_key_pair(
         false => NumberTheoryService::new(Slow),
     };
 
-    let key_pair = MenezesVanstoneScheme::generate_keypair(
-        req_body.coef_a,
-        req_body.modulus_width,
-        req_body.miller_rabin_rounds,
-        req_body.random_seed,
-    );
+    let curve_config = match MenezesVanstoneCurveConfig::try_from(&req_body) {
+        Ok(curve_config) => curve_config,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })
+        }
+    };
+    let key_pair = MenezesVanstoneScheme::generate_keypair(&curve_config, req_body.random_seed);
 
     match key_pair {
         Ok(key_pair) => {
@@ -309,6 +438,170 @@ pub(crate) async fn decrypt(
     })
 }
 
+/// Verschlüsselt eine beliebige Bytefolge mit dem MenezesVanstone-Schema, z.B. den Inhalt einer Datei.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Base64-kodierte Bytefolge und den öffentlichen Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die verschlüsselte Bytefolge und die verwendeten Punkte enthält.
+pub(crate) async fn encrypt_bytes(
+    req_body: Json<MvEncryptBytesRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/encryptBytes wurde aufgerufen");
+    let req_body: MvEncryptBytesRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = MenezesVanstoneStringPublicKey {
+            mv_key: req_body.public_key.clone().into(),
+            radix: 10,
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let ciphertext = STANDARD
+            .decode(&req_body.message)
+            .map_err(anyhow::Error::from)
+            .and_then(|plaintext| {
+                MenezesVanstoneStringScheme::encrypt_bytes(&public_key, &plaintext, service)
+            });
+
+        match ciphertext {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(MvByteCipherTextBean::from(ciphertext))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt eine mit `/menezesVanstone/encryptBytes` verschlüsselte Bytefolge.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die verschlüsselte Bytefolge und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die Base64-kodierte entschlüsselte Bytefolge enthält.
+pub(crate) async fn decrypt_bytes(
+    req_body: Json<MvDecryptBytesRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/decryptBytes wurde aufgerufen");
+    let req_body: MvDecryptBytesRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = MenezesVanstoneStringPrivateKey {
+            mv_key: req_body.private_key.clone().into(),
+            radix: 10,
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext =
+            MvByteCiphertext::try_from(req_body.cipher_text.clone()).and_then(|ciphertext| {
+                MenezesVanstoneStringScheme::decrypt_bytes(&private_key, &ciphertext, service)
+            });
+
+        match plaintext {
+            Ok(plaintext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: STANDARD.encode(plaintext),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Verschlüsselt eine Nachricht mit dem MenezesVanstone-Schema in einen selbstbeschreibenden Container.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht und den öffentlichen Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den Base64-kodierten Container enthält.
+pub(crate) async fn encrypt_container(
+    req_body: Json<MvEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/encryptContainer wurde aufgerufen");
+    let req_body: MvEncryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = MenezesVanstoneStringPublicKey {
+            mv_key: req_body.public_key.clone().into(),
+            radix: req_body.radix,
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let container = MenezesVanstoneStringScheme::encrypt_to_container(
+            &public_key,
+            &req_body.message,
+            service,
+        );
+
+        match container {
+            Ok(container) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: container.to_base64(),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt einen mit `/menezesVanstone/encryptContainer` erstellten Container.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die den Base64-kodierten Container und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn decrypt_container(
+    req_body: Json<MvDecryptContainerRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/decryptContainer wurde aufgerufen");
+    let req_body: MvDecryptContainerRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext =
+            CiphertextContainer::from_base64(&req_body.container).and_then(|container| {
+                let private_key = MenezesVanstoneStringPrivateKey {
+                    mv_key: req_body.private_key.clone().into(),
+                    radix: container.encoding.radix,
+                };
+                MenezesVanstoneStringScheme::decrypt_container(&private_key, &container, service)
+            });
+
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
 pub(crate) async fn sign(
     req_body: Json<MvSignRequestBean>,
     query: Query<UseFastQuery>,
@@ -325,7 +618,17 @@ pub(crate) async fn sign(
             false => NumberTheoryService::new(Slow),
         };
 
-        let signature = MenezesVanstoneScheme::sign(&private_key, message, service);
+        let hash_algorithm = match req_body.hash_algorithm.parse::<HashAlgorithm>() {
+            Ok(hash_algorithm) => hash_algorithm,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
+        let signature =
+            MenezesVanstoneScheme::sign_with_hash(&private_key, message, hash_algorithm, service);
 
         match signature {
             Ok(signature) => {
@@ -347,14 +650,21 @@ pub(crate) async fn verify(
     call_checked_with_parsed_big_ints(|| {
         let public_key = req_body.public_key.clone().into();
         let message = &req_body.message;
-        let signature = &req_body.signature.clone().into();
+        let signature = match MenezesVanstoneSignature::try_from(req_body.signature.clone()) {
+            Ok(signature) => signature,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
 
         let service = match query.use_fast {
             true => NumberTheoryService::new(Fast),
             false => NumberTheoryService::new(Slow),
         };
 
-        let verified = MenezesVanstoneScheme::verify(&public_key, signature, message, service);
+        let verified = MenezesVanstoneScheme::verify(&public_key, &signature, message, service);
 
         match verified {
             Ok(verified) => {
@@ -367,3 +677,111 @@ pub(crate) async fn verify(
         }
     })
 }
+
+/// Verschlüsselt eine Nachricht hybrid mit einem KEM über der Kurve des MenezesVanstone-Schlüssels
+/// und einem AEAD-Verfahren für die Nutzdaten.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und das AEAD-Verfahren enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den hybriden Chiffretext enthält.
+pub(crate) async fn hybrid_encrypt(
+    req_body: Json<MvHybridEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/hybridEncrypt wurde aufgerufen");
+    let req_body: MvHybridEncryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let ciphertext = req_body.aead.parse::<AeadAlgorithm>().and_then(|aead| {
+            let public_key = EcKemPublicKey {
+                mv_key: req_body.public_key.clone().into(),
+                aead,
+            };
+            EcKemScheme::encrypt(&public_key, req_body.message.as_bytes(), service)
+        });
+
+        match ciphertext {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(HybridCiphertextBean::from(ciphertext))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Entschlüsselt einen mit `/menezesVanstone/hybridEncrypt` erstellten Chiffretext.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die den hybriden Chiffretext und den privaten Schlüssel enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn hybrid_decrypt(
+    req_body: Json<MvHybridDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/hybridDecrypt wurde aufgerufen");
+    let req_body: MvHybridDecryptRequestBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = EcKemPrivateKey {
+            mv_key: req_body.private_key.clone().into(),
+        };
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext = HybridCiphertext::try_from(req_body.ciphertext.clone())
+            .and_then(|ciphertext| EcKemScheme::decrypt(&private_key, &ciphertext, service))
+            .and_then(|plaintext| Ok(String::from_utf8(plaintext)?));
+
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Prüft eine Kurve mit Untergruppe auf Einbettungsgrad, anomale und supersinguläre Kurven,
+/// die Sicherheit des Twists und die Größe der Untergruppe.
+///
+/// # Arguments
+/// * `req_body` - Die Kurve mit Generator und Ordnung der Untergruppe, etwa aus einem Schlüssel.
+///
+/// # Returns
+/// * `HttpResponse` - Die Antwort, die den Sicherheitsbericht enthält.
+pub(crate) async fn audit_curve(
+    req_body: Json<EllipticCurveBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /menezesVanstone/auditCurve wurde aufgerufen");
+    let req_body: EllipticCurveBean = req_body.into_inner();
+
+    call_checked_with_parsed_big_ints(|| {
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let curve = SecureFiniteFieldEllipticCurve::from(req_body.clone());
+        match CurveAudit::new(&curve, service) {
+            Ok(audit) => Ok(HttpResponse::Ok().json(CurveAuditBean::from(audit))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
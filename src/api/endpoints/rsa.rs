@@ -1,22 +1,33 @@
 use crate::api::basic::call_checked_with_parsed_big_ints;
-use crate::api::serializable_models::{SingleStringResponse, UseFastQuery};
+use crate::api::serializable_models::{
+    default_hash_algorithm, HybridCiphertextBean, SingleStringResponse, UseFastQuery,
+};
 use crate::encryption::asymmetric_encryption_types::{
-    AsymmetricDecryptor, AsymmetricEncryptor, AsymmetricKeyPair, KeyGenerator, Signer, Verifier,
+    AsymmetricDecryptor, AsymmetricEncryptor, AsymmetricKeyPair, KeyGenerator, Verifier,
 };
 use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
 use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+use crate::encryption::hybrid::data_encapsulation::{AeadAlgorithm, HybridCiphertext};
+use crate::encryption::hybrid::keys::{RsaKemPrivateKey, RsaKemPublicKey};
+use crate::encryption::hybrid::rsa_kem_scheme::RsaKemScheme;
+use crate::encryption::string_schemes::ciphertext_container::CiphertextContainer;
 use crate::encryption::string_schemes::rsa::keys::{
     RsaWithStringPrivateKey, RsaWithStringPublicKey,
 };
-use crate::encryption::string_schemes::rsa::rsa_with_string_scheme::RsaWithStringScheme;
+use crate::encryption::string_schemes::rsa::rsa_with_string_scheme::{
+    RsaWithStringScheme, RsaWithStringSignature,
+};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
     Fast, Slow,
 };
 use crate::math_core::traits::logarithm::Logarithm;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
 use actix_web::http::StatusCode;
 use actix_web::web::{Json, Query};
 use actix_web::{HttpResponse, HttpResponseBuilder, Responder};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use bigdecimal::num_bigint::{BigInt, ParseBigIntError};
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
@@ -78,11 +89,20 @@ pub struct RsaEncryptDecryptRequestBean {
     pub number_system_base: u32,
 }
 
+/// Eine Anfrage zum Ver- oder Entschlüsseln einer Base64-kodierten Bytefolge.
+#[derive(Deserialize)]
+pub struct RsaBytesRequestBean {
+    pub message: String,
+    pub key_pair: RsaKeyPairBean,
+}
+
 #[derive(Deserialize)]
 pub struct RsaSignRequestBean {
     pub plaintext: String,
     pub key_pair: RsaKeyPairBean,
     pub radix: u32,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
 }
 
 #[derive(Deserialize)]
@@ -91,6 +111,15 @@ pub struct RsaVerifyRequestBean {
     pub signature: String,
     pub key_pair: RsaKeyPairBean,
     pub radix: u32,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+}
+
+/// Die Signatur zusammen mit dem Hashverfahren, das beim Verifizieren angegeben werden muss.
+#[derive(Serialize)]
+pub struct RsaSignatureResponseBean {
+    pub message: String,
+    pub hash_algorithm: String,
 }
 
 #[derive(Deserialize)]
@@ -100,6 +129,19 @@ pub struct RsaMultiplicationRequestBean {
     pub key_pair: RsaKeyPairBean,
 }
 
+#[derive(Deserialize)]
+pub struct RsaHybridEncryptRequestBean {
+    pub message: String,
+    pub key_pair: RsaKeyPairBean,
+    pub aead: String,
+}
+
+#[derive(Deserialize)]
+pub struct RsaHybridDecryptRequestBean {
+    pub ciphertext: HybridCiphertextBean,
+    pub key_pair: RsaKeyPairBean,
+}
+
 #[derive(Serialize)]
 pub struct RsaMultiplicationResponseBean {
     pub encrypted_factor_one: String,
@@ -237,6 +279,197 @@ pub(crate) async fn decrypt(
     })
 }
 
+/// Endpunkt zum Verschlüsseln einer beliebigen Bytefolge mit RSA, z.B. dem Inhalt einer Datei.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Base64-kodierte Bytefolge und den öffentlichen Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Base64-kodierte verschlüsselte Bytefolge enthält.
+pub(crate) async fn encrypt_bytes(
+    req_body: Json<RsaBytesRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/encryptBytes wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaBytesRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = RsaWithStringPublicKey {
+            rsa_public_key: req_body.key_pair.to_public_key()?,
+            radix: 10,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let ciphertext = STANDARD
+            .decode(&req_body.message)
+            .map_err(anyhow::Error::from)
+            .and_then(|plaintext| {
+                RsaWithStringScheme::encrypt_bytes(&public_key, &plaintext, number_theory_service)
+            });
+
+        match ciphertext {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: STANDARD.encode(ciphertext),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Entschlüsseln einer mit `/rsa/encryptBytes` verschlüsselten Bytefolge.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Base64-kodierte verschlüsselte Bytefolge und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die Base64-kodierte entschlüsselte Bytefolge enthält.
+pub(crate) async fn decrypt_bytes(
+    req_body: Json<RsaBytesRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/decryptBytes wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaBytesRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = RsaWithStringPrivateKey {
+            rsa_private_key: req_body.key_pair.to_private_key()?,
+            radix: 10,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext = STANDARD
+            .decode(&req_body.message)
+            .map_err(anyhow::Error::from)
+            .and_then(|ciphertext| {
+                RsaWithStringScheme::decrypt_bytes(&private_key, &ciphertext, number_theory_service)
+            });
+
+        match plaintext {
+            Ok(plaintext) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: STANDARD.encode(plaintext),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Verschlüsseln einer Nachricht mit RSA in einen selbstbeschreibenden Container.
+/// Der Container enthält neben den Blöcken das Schema, den Fingerabdruck des Schlüssels und die
+/// Parameter der Decimal-Unicode-Abbildung.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und die Basis enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den Base64-kodierten Container enthält.
+pub(crate) async fn encrypt_container(
+    req_body: Json<RsaEncryptDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/encryptContainer wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaEncryptDecryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let public_key = RsaWithStringPublicKey {
+            rsa_public_key: req_body.key_pair.to_public_key()?,
+            radix: req_body.number_system_base,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let container = RsaWithStringScheme::encrypt_to_container(
+            &public_key,
+            &req_body.message,
+            number_theory_service,
+        );
+
+        match container {
+            Ok(container) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: container.to_base64(),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Entschlüsseln eines mit `/rsa/encryptContainer` erstellten Containers.
+/// Die Basis wird dabei aus dem Container übernommen, `number_system_base` wird ignoriert.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den Base64-kodierten Container und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn decrypt_container(
+    req_body: Json<RsaEncryptDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/decryptContainer wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaEncryptDecryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = RsaWithStringPrivateKey {
+            rsa_private_key: req_body.key_pair.to_private_key()?,
+            radix: req_body.number_system_base,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext = CiphertextContainer::from_base64(&req_body.message).and_then(|container| {
+            RsaWithStringScheme::decrypt_container(&private_key, &container, number_theory_service)
+        });
+
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
 /// Endpunkt zum Signieren einer Nachricht mit RSA.
 ///
 /// # Argumente
@@ -272,9 +505,25 @@ pub(crate) async fn sign(
             radix,
         };
 
-        let signature =
-            RsaWithStringScheme::sign(&rsa_with_string_key, &plaintext, number_theory_service);
-        let response = SingleStringResponse { message: signature };
+        let hash_algorithm = match req_body.hash_algorithm.parse::<HashAlgorithm>() {
+            Ok(hash_algorithm) => hash_algorithm,
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
+        let signature = RsaWithStringScheme::sign_with_hash(
+            &rsa_with_string_key,
+            &plaintext,
+            hash_algorithm,
+            number_theory_service,
+        );
+        let response = RsaSignatureResponseBean {
+            message: signature.signature,
+            hash_algorithm: signature.hash_algorithm.to_string(),
+        };
 
         Ok(HttpResponse::Ok().json(response))
     })
@@ -316,6 +565,18 @@ pub(crate) async fn verify(
             radix,
         };
 
+        let signature = match req_body.hash_algorithm.parse::<HashAlgorithm>() {
+            Ok(hash_algorithm) => RsaWithStringSignature {
+                hash_algorithm,
+                signature: signature.clone(),
+            },
+            Err(e) => {
+                return Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                    message: e.to_string(),
+                }))
+            }
+        };
+
         let plaintext = RsaWithStringScheme::verify(
             &rsa_with_string_key,
             &signature,
@@ -384,3 +645,97 @@ pub(crate) async fn multiplication(
         Ok(HttpResponse::Ok().json(response))
     })
 }
+
+/// Endpunkt zum hybriden Verschlüsseln einer Nachricht mit RSA-KEM und einem AEAD-Verfahren.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und das AEAD-Verfahren enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die den hybriden Chiffretext enthält.
+pub(crate) async fn hybrid_encrypt(
+    req_body: Json<RsaHybridEncryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/hybridEncrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaHybridEncryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let rsa_key = req_body.key_pair.to_public_key()?;
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let ciphertext = req_body.aead.parse::<AeadAlgorithm>().and_then(|aead| {
+            let public_key = RsaKemPublicKey {
+                rsa_key: rsa_key.clone(),
+                aead,
+            };
+            RsaKemScheme::encrypt(
+                &public_key,
+                req_body.message.as_bytes(),
+                number_theory_service,
+            )
+        });
+
+        match ciphertext {
+            Ok(ciphertext) => Ok(HttpResponse::Ok().json(HybridCiphertextBean::from(ciphertext))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Endpunkt zum Entschlüsseln eines mit `/rsa/hybridEncrypt` erstellten Chiffretextes.
+///
+/// # Argumente
+/// * `req_body` - Die Anfrage, die den hybriden Chiffretext und den privaten Schlüssel enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Rückgabe
+/// * `HttpResponse` - Die Antwort, die die entschlüsselte Nachricht enthält.
+pub(crate) async fn hybrid_decrypt(
+    req_body: Json<RsaHybridDecryptRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!(
+        "Endpunkt /rsa/hybridDecrypt wurde aufgerufen, use_fast: {}",
+        query.use_fast
+    );
+    let req_body: RsaHybridDecryptRequestBean = req_body.into_inner();
+    let use_fast = query.use_fast;
+
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = RsaKemPrivateKey {
+            rsa_key: req_body.key_pair.to_private_key()?,
+        };
+
+        let number_theory_service = match use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let plaintext = HybridCiphertext::try_from(req_body.ciphertext.clone())
+            .and_then(|ciphertext| {
+                RsaKemScheme::decrypt(&private_key, &ciphertext, number_theory_service)
+            })
+            .and_then(|plaintext| Ok(String::from_utf8(plaintext)?));
+
+        match plaintext {
+            Ok(plaintext) => {
+                Ok(HttpResponse::Ok().json(SingleStringResponse { message: plaintext }))
+            }
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
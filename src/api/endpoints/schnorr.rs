@@ -0,0 +1,150 @@
+use actix_web::web::{Json, Query};
+use actix_web::{HttpResponse, Responder};
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::api::basic::call_checked_with_parsed_big_ints;
+use crate::api::endpoints::mv::{MvPrivateKeyBean, MvPublicKeyBean};
+use crate::api::serializable_models::{default_hash_algorithm, SingleStringResponse, UseFastQuery};
+use crate::encryption::asymmetric_encryption_types::Verifier;
+use crate::encryption::core::menezes_vanstone::keys::{
+    MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+};
+use crate::encryption::core::schnorr::keys::{SchnorrPrivateKey, SchnorrPublicKey};
+use crate::encryption::core::schnorr::schnorr_scheme::{SchnorrScheme, SchnorrSignature};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+    Fast, Slow,
+};
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+
+/// Eine Schnorr-Signatur aus Challenge e und Antwort s.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SchnorrSignatureBean {
+    pub e: String,
+    pub s: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+}
+
+impl From<SchnorrSignature> for SchnorrSignatureBean {
+    fn from(signature: SchnorrSignature) -> Self {
+        SchnorrSignatureBean {
+            e: signature.e.to_string(),
+            s: signature.s.to_string(),
+            hash_algorithm: signature.hash_algorithm.to_string(),
+        }
+    }
+}
+
+impl TryFrom<SchnorrSignatureBean> for SchnorrSignature {
+    type Error = anyhow::Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: SchnorrSignatureBean) -> Result<Self> {
+        Ok(SchnorrSignature {
+            e: bean.e.parse::<BigInt>()?,
+            s: bean.s.parse::<BigInt>()?,
+            hash_algorithm: bean.hash_algorithm.parse::<HashAlgorithm>()?,
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SchnorrSignRequestBean {
+    pub private_key: MvPrivateKeyBean,
+    pub message: String,
+    #[serde(default = "default_hash_algorithm")]
+    pub hash_algorithm: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SchnorrVerifyRequestBean {
+    pub public_key: MvPublicKeyBean,
+    pub message: String,
+    pub signature: SchnorrSignatureBean,
+}
+
+/// Signiert eine Nachricht mit Schnorr-Signaturen auf der Kurve des Schlüssels.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, den privaten Schlüssel und das Hashverfahren enthält.
+///
+/// # Returns
+/// * `HttpResponse` - Die Signatur (e, s).
+pub(crate) async fn sign(req_body: Json<SchnorrSignRequestBean>) -> impl Responder {
+    info!("Endpunkt /schnorr/sign wurde aufgerufen");
+
+    let req_body: &SchnorrSignRequestBean = &req_body.into_inner();
+    call_checked_with_parsed_big_ints(|| {
+        let private_key = SchnorrPrivateKey::from(MenezesVanstonePrivateKey::from(
+            req_body.private_key.clone(),
+        ));
+
+        let signature =
+            req_body
+                .hash_algorithm
+                .parse::<HashAlgorithm>()
+                .and_then(|hash_algorithm| {
+                    SchnorrScheme::sign_with_hash(
+                        &private_key,
+                        req_body.message.as_bytes(),
+                        hash_algorithm,
+                    )
+                });
+
+        match signature {
+            Ok(signature) => Ok(HttpResponse::Ok().json(SchnorrSignatureBean::from(signature))),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
+
+/// Verifiziert eine Schnorr-Signatur.
+///
+/// # Arguments
+/// * `req_body` - Die Anfrage, die die Nachricht, den öffentlichen Schlüssel und die Signatur enthält.
+/// * `query` - Die Abfrage, ob der schnelle oder der langsame Algorithmus verwendet werden soll.
+///
+/// # Returns
+/// * `HttpResponse` - "true" oder "false".
+pub(crate) async fn verify(
+    req_body: Json<SchnorrVerifyRequestBean>,
+    query: Query<UseFastQuery>,
+) -> impl Responder {
+    info!("Endpunkt /schnorr/verify wurde aufgerufen");
+
+    let req_body: &SchnorrVerifyRequestBean = &req_body.into_inner();
+    call_checked_with_parsed_big_ints(|| {
+        let public_key =
+            SchnorrPublicKey::from(MenezesVanstonePublicKey::from(req_body.public_key.clone()));
+
+        let service = match query.use_fast {
+            true => NumberTheoryService::new(Fast),
+            false => NumberTheoryService::new(Slow),
+        };
+
+        let verified =
+            SchnorrSignature::try_from(req_body.signature.clone()).and_then(|signature| {
+                SchnorrScheme::verify(
+                    &public_key,
+                    &signature,
+                    req_body.message.as_bytes(),
+                    service,
+                )
+            });
+
+        match verified {
+            Ok(verified) => Ok(HttpResponse::Ok().json(SingleStringResponse {
+                message: verified.to_string(),
+            })),
+            Err(e) => Ok(HttpResponse::BadRequest().json(SingleStringResponse {
+                message: e.to_string(),
+            })),
+        }
+    })
+}
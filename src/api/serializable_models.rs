@@ -1,11 +1,64 @@
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 
+use crate::encryption::hybrid::data_encapsulation::HybridCiphertext;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+
 #[derive(Serialize)]
 pub struct SingleStringResponse {
     pub message: String,
 }
 
+/// Das Hashverfahren, das Signaturanfragen ohne Angabe eines Verfahrens verwenden.
+pub fn default_hash_algorithm() -> String {
+    HashAlgorithm::default().to_string()
+}
+
 #[derive(Deserialize)]
 pub struct UseFastQuery {
     pub use_fast: bool,
 }
+
+/// Gibt an, ob zusätzlich zum Ergebnis die einzelnen Rechenschritte zurückgegeben werden sollen.
+/// Ohne Angabe werden nur die Ergebnisse zurückgegeben.
+#[derive(Deserialize)]
+pub struct TraceQuery {
+    #[serde(default)]
+    pub trace: bool,
+}
+
+/// Ein hybrider Chiffretext, dessen Bytefolgen Base64-kodiert sind.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HybridCiphertextBean {
+    pub aead: String,
+    pub encapsulated_key: String,
+    pub nonce: String,
+    pub payload: String,
+}
+
+impl From<HybridCiphertext> for HybridCiphertextBean {
+    fn from(ciphertext: HybridCiphertext) -> Self {
+        HybridCiphertextBean {
+            aead: ciphertext.aead.to_string(),
+            encapsulated_key: STANDARD.encode(ciphertext.encapsulated_key),
+            nonce: STANDARD.encode(ciphertext.nonce),
+            payload: STANDARD.encode(ciphertext.payload),
+        }
+    }
+}
+
+impl TryFrom<HybridCiphertextBean> for HybridCiphertext {
+    type Error = anyhow::Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    fn try_from(bean: HybridCiphertextBean) -> Result<Self> {
+        Ok(HybridCiphertext {
+            aead: bean.aead.parse()?,
+            encapsulated_key: STANDARD.decode(bean.encapsulated_key)?,
+            nonce: STANDARD.decode(bean.nonce)?,
+            payload: STANDARD.decode(bean.payload)?,
+        })
+    }
+}
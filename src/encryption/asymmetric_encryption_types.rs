@@ -1,5 +1,7 @@
 use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor, Key};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 
 use std::fmt::Debug;
 
@@ -64,13 +66,33 @@ where
 {
     type KeyPair: AsymmetricKeyPair<Public, Private, Scheme>;
     /// Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema.
+    /// Als Zufallszahlengenerator wird der PseudoRandomNumberGenerator mit dem Seed aus der
+    /// Konfiguration verwendet.
     ///
     /// # Argumente
     /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
     ///
     /// # Rückgabe
     /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair;
+    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair {
+        let random_generator =
+            PseudoRandomNumberGenerator::new(config.random_seed(), config.number_theory_service());
+        Self::generate_keypair_with_generator(config, &random_generator)
+    }
+
+    /// Generiert ein Schlüsselpaar für das asymmetrische Verschlüsselungsschema mit einem
+    /// beliebigen Zufallszahlengenerator.
+    ///
+    /// # Argumente
+    /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random_generator` - Der Zufallszahlengenerator, aus dem die Schlüssel erzeugt werden.
+    ///
+    /// # Rückgabe
+    /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
+    fn generate_keypair_with_generator(
+        config: &impl KeyGenWithPrimeConfig,
+        random_generator: &impl RandomNumberGenerator,
+    ) -> Self::KeyPair;
 }
 
 /// Die Konfiguration für die Schlüsselgenerierung für ein Verschlüsselungsschema, welches Primzahlen verwendet.
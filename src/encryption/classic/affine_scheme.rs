@@ -0,0 +1,97 @@
+use crate::encryption::classic::alphabet::{substitute_letters, KeyRandomness, ALPHABET_SIZE};
+use crate::encryption::classic::keys::{AffineKey, ClassicKeyPair};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+/// Die affine Chiffre E(x) = a * x + b mod 26 mit der Umkehrung D(y) = a^-1 * (y - b) mod 26.
+/// Groß- und Kleinschreibung bleiben erhalten, andere Zeichen werden nicht verändert.
+pub struct AffineScheme {}
+
+impl EncryptionScheme for AffineScheme {}
+
+impl SymmetricEncryptionScheme for AffineScheme {}
+
+impl Encryptor<AffineScheme> for AffineScheme {
+    type Input = str;
+    type Output = String;
+    type Key = AffineKey;
+}
+
+impl SymmetricEncryptor<AffineScheme> for AffineScheme {
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        substitute_letters(plaintext, |index, _| {
+            ((key.a() as u32 * index as u32 + key.b() as u32) % ALPHABET_SIZE as u32) as u8
+        })
+    }
+}
+
+impl Decryptor<AffineScheme> for AffineScheme {
+    type Input = str;
+    type Output = String;
+    type Key = AffineKey;
+}
+
+impl SymmetricDecryptor<AffineScheme> for AffineScheme {
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        substitute_letters(ciphertext, |index, _| {
+            let shifted = (index + ALPHABET_SIZE - key.b()) as u32;
+            ((key.a_inverse() as u32 * shifted) % ALPHABET_SIZE as u32) as u8
+        })
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen affinen Schlüssels.
+pub struct AffineKeyGenConfig {
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<AffineKey, AffineKey, AffineScheme> for AffineKeyGenConfig {
+    type KeyPair = ClassicKeyPair<AffineKey>;
+
+    /// Wählt a zufällig aus den zu 26 teilerfremden Zahlen und b aus 0 bis 25.
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let random = KeyRandomness::new(self.random_seed);
+        let units = [1, 3, 5, 7, 9, 11, 15, 17, 19, 21, 23, 25];
+        let a = units[random.below(units.len())];
+        let b = random.below(ALPHABET_SIZE as usize) as i64;
+        // Das unwrap() schlägt niemals fehl, weil a teilerfremd zu 26 ist.
+        let key = AffineKey::new(a, b, NumberTheoryService::new(Fast)).unwrap();
+        ClassicKeyPair::symmetric(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key: AffineKey = "5,8".parse().unwrap();
+        assert_eq!(
+            AffineScheme::encrypt("Affine cipher", &key),
+            "Ihhwvc swfrcp"
+        );
+        assert_eq!(
+            AffineScheme::decrypt("Ihhwvc swfrcp", &key),
+            "Affine cipher"
+        );
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        for random_seed in [2, 5, 11] {
+            let key_pair = AffineKeyGenConfig { random_seed }.generate_keypair();
+            let message = "The quick brown fox jumps over the lazy dog";
+            let ciphertext = AffineScheme::encrypt(message, &key_pair.encryption());
+            assert_eq!(
+                AffineScheme::decrypt(&ciphertext, &key_pair.decryption()),
+                message
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Die Anzahl der Buchstaben des lateinischen Alphabets, über dem die klassischen Chiffren arbeiten.
+pub const ALPHABET_SIZE: u8 = 26;
+
+/// Der Index eines ASCII-Buchstabens im Alphabet (A = 0, ..., Z = 25), unabhängig von der
+/// Groß- und Kleinschreibung. Für alle anderen Zeichen wird `None` zurückgegeben.
+pub fn letter_index(character: char) -> Option<u8> {
+    character
+        .is_ascii_alphabetic()
+        .then(|| character.to_ascii_uppercase() as u8 - b'A')
+}
+
+/// Der Großbuchstabe zum Index im Alphabet.
+pub fn letter(index: u8) -> char {
+    (b'A' + index % ALPHABET_SIZE) as char
+}
+
+/// Bildet jeden ASCII-Buchstaben mit der gegebenen Funktion auf einen anderen Buchstaben ab.
+/// Groß- und Kleinschreibung bleiben erhalten, alle anderen Zeichen werden unverändert übernommen.
+///
+/// # Argumente
+/// * `text` - Der Text.
+/// * `substitute` - Erhält den Index des Buchstabens und die Anzahl der bisher ersetzten Buchstaben
+///   und gibt den Index des neuen Buchstabens zurück.
+pub fn substitute_letters(text: &str, mut substitute: impl FnMut(u8, usize) -> u8) -> String {
+    let mut position = 0;
+    text.chars()
+        .map(|character| match letter_index(character) {
+            Some(index) => {
+                let substituted = letter(substitute(index, position));
+                position += 1;
+                if character.is_ascii_lowercase() {
+                    substituted.to_ascii_lowercase()
+                } else {
+                    substituted
+                }
+            }
+            None => character,
+        })
+        .collect()
+}
+
+/// Entfernt alle Zeichen außer ASCII-Buchstaben und gibt die Indizes der Buchstaben zurück.
+pub fn letter_indices(text: &str) -> Vec<u8> {
+    text.chars().filter_map(letter_index).collect()
+}
+
+/// Zufallsquelle für die Schlüsselerzeugung der klassischen Chiffren.
+/// Anders als bei den Primzahlen der asymmetrischen Verfahren wird hier nicht der
+/// `PseudoRandomNumberGenerator` verwendet: Dessen Folge n * sqrt(m) mod 1 liefert für kleine
+/// Bereiche nahezu arithmetische Folgen, sodass z.B. die Zeilen einer Hill-Matrix linear abhängig
+/// und die Matrix nie invertierbar wäre. Stattdessen wird ein mit dem Seed initialisierter
+/// `StdRng` verwendet, der ebenfalls deterministisch ist.
+pub(crate) struct KeyRandomness {
+    rng: RefCell<StdRng>,
+}
+
+impl KeyRandomness {
+    pub(crate) fn new(random_seed: u32) -> Self {
+        KeyRandomness {
+            rng: RefCell::new(StdRng::seed_from_u64(random_seed as u64)),
+        }
+    }
+
+    /// Eine Zufallszahl aus dem Bereich von 0 bis `upper - 1`.
+    pub(crate) fn below(&self, upper: usize) -> usize {
+        self.rng.borrow_mut().gen_range(0..upper)
+    }
+
+    /// Ein zufälliges Wort aus Großbuchstaben.
+    pub(crate) fn word(&self, length: usize) -> String {
+        (0..length)
+            .map(|_| letter(self.below(ALPHABET_SIZE as usize) as u8))
+            .collect()
+    }
+
+    /// Eine zufällige Permutation der Zahlen von 0 bis `length - 1` (Fisher-Yates).
+    pub(crate) fn permutation(&self, length: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..length).collect();
+        for i in (1..length).rev() {
+            permutation.swap(i, self.below(i + 1));
+        }
+        permutation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitute_letters_keeps_case_and_other_characters() {
+        let shifted = substitute_letters("Hallo, Welt! Äpfel", |index, _| index + 1);
+        assert_eq!(shifted, "Ibmmp, Xfmu! Äqgfm");
+    }
+
+    #[test]
+    fn test_letter_indices() {
+        assert_eq!(letter_indices("a-Z 1"), vec![0, 25]);
+    }
+
+    #[test]
+    fn test_key_randomness_is_deterministic() {
+        let first = KeyRandomness::new(17);
+        let second = KeyRandomness::new(17);
+        assert_eq!(first.word(10), second.word(10));
+
+        let mut permutation = first.permutation(8);
+        permutation.sort();
+        assert_eq!(permutation, (0..8).collect::<Vec<usize>>());
+        assert!((0..100).all(|_| first.below(5) < 5));
+    }
+}
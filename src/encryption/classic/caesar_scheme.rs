@@ -0,0 +1,90 @@
+use crate::encryption::classic::alphabet::{substitute_letters, KeyRandomness, ALPHABET_SIZE};
+use crate::encryption::classic::keys::{CaesarKey, ClassicKeyPair};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+
+/// Die Caesar-Chiffre, die jeden Buchstaben um eine feste Anzahl an Stellen im Alphabet verschiebt.
+/// Groß- und Kleinschreibung bleiben erhalten, andere Zeichen werden nicht verändert.
+pub struct CaesarScheme {}
+
+impl EncryptionScheme for CaesarScheme {}
+
+impl SymmetricEncryptionScheme for CaesarScheme {}
+
+impl Encryptor<CaesarScheme> for CaesarScheme {
+    type Input = str;
+    type Output = String;
+    type Key = CaesarKey;
+}
+
+impl SymmetricEncryptor<CaesarScheme> for CaesarScheme {
+    /// Verschiebt jeden Buchstaben um `key.shift` Stellen nach rechts.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        substitute_letters(plaintext, |index, _| index + key.shift)
+    }
+}
+
+impl Decryptor<CaesarScheme> for CaesarScheme {
+    type Input = str;
+    type Output = String;
+    type Key = CaesarKey;
+}
+
+impl SymmetricDecryptor<CaesarScheme> for CaesarScheme {
+    /// Verschiebt jeden Buchstaben um `key.shift` Stellen nach links.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        substitute_letters(ciphertext, |index, _| index + ALPHABET_SIZE - key.shift)
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen Caesar-Schlüssels.
+pub struct CaesarKeyGenConfig {
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<CaesarKey, CaesarKey, CaesarScheme> for CaesarKeyGenConfig {
+    type KeyPair = ClassicKeyPair<CaesarKey>;
+
+    /// Wählt eine Verschiebung zwischen 1 und 25.
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let random = KeyRandomness::new(self.random_seed);
+        let shift = 1 + random.below(ALPHABET_SIZE as usize - 1) as u8;
+        ClassicKeyPair::symmetric(CaesarKey { shift })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key = CaesarKey { shift: 3 };
+        assert_eq!(
+            CaesarScheme::encrypt("Attack at dawn!", &key),
+            "Dwwdfn dw gdzq!"
+        );
+        assert_eq!(
+            CaesarScheme::decrypt("Dwwdfn dw gdzq!", &key),
+            "Attack at dawn!"
+        );
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        let key_pair = CaesarKeyGenConfig { random_seed: 3 }.generate_keypair();
+        let key = key_pair.encryption();
+        assert!((1..26).contains(&key.shift));
+
+        let message = "Veni, vidi, vici";
+        let ciphertext = CaesarScheme::encrypt(message, &key);
+        assert_ne!(ciphertext, message);
+        assert_eq!(
+            CaesarScheme::decrypt(&ciphertext, &key_pair.decryption()),
+            message
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{ensure, Result};
+
+use crate::encryption::classic::affine_scheme::{AffineKeyGenConfig, AffineScheme};
+use crate::encryption::classic::caesar_scheme::{CaesarKeyGenConfig, CaesarScheme};
+use crate::encryption::classic::columnar_transposition_scheme::{
+    ColumnarTranspositionKeyGenConfig, ColumnarTranspositionScheme,
+};
+use crate::encryption::classic::cryptanalysis::hill_attack::known_plaintext_attack;
+use crate::encryption::classic::cryptanalysis::language::Language;
+use crate::encryption::classic::cryptanalysis::statistics::KeyCandidate;
+use crate::encryption::classic::cryptanalysis::substitution_attack::{break_affine, break_caesar};
+use crate::encryption::classic::cryptanalysis::vigenere_attack::break_vigenere;
+use crate::encryption::classic::hill_scheme::{HillKeyGenConfig, HillScheme};
+use crate::encryption::classic::keys::{
+    AffineKey, CaesarKey, ColumnarTranspositionKey, HillKey, PlayfairKey, VigenereKey,
+    MAXIMUM_HILL_DIMENSION,
+};
+use crate::encryption::classic::playfair_scheme::{PlayfairKeyGenConfig, PlayfairScheme};
+use crate::encryption::classic::vigenere_scheme::{VigenereKeyGenConfig, VigenereScheme};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptor, SymmetricKeyPair,
+};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::shared::errors::ClassicCipherError::{
+    InsufficientTextError, InvalidKeyError, UnknownCipherError, UnsupportedAttackError,
+};
+
+/// Die größte Schlüssellänge, die beim Brechen der Vigenère-Chiffre ohne Angabe untersucht wird.
+pub const DEFAULT_MAX_VIGENERE_KEY_LENGTH: usize = 16;
+
+/// Die größte Schlüssellänge, die beim Brechen der Vigenère-Chiffre und in der Textstatistik
+/// untersucht werden darf. Sie begrenzt auch die Länge erzeugter Schlüsselwörter für Vigenère
+/// und Playfair.
+pub const MAXIMUM_VIGENERE_KEY_LENGTH: usize = 256;
+
+/// Die Dimension der Hill-Matrix, die ohne Angabe beim Angriff angenommen wird.
+pub const DEFAULT_HILL_DIMENSION: usize = 2;
+
+/// Die verfügbaren klassischen Chiffren. Schlüssel werden in ihrer Textform übergeben, die über
+/// `FromStr` und `Display` der jeweiligen Schlüssel definiert ist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassicCipher {
+    Caesar,
+    Affine,
+    Vigenere,
+    Playfair,
+    Hill,
+    ColumnarTransposition,
+}
+
+impl ClassicCipher {
+    /// Verschlüsselt den Klartext.
+    ///
+    /// # Argumente
+    /// * `key` - Der Schlüssel in Textform, z.B. "3" (Caesar), "5,8" (affin), "LEMON" (Vigenère,
+    ///   Playfair, Spaltentransposition) oder "3,3,2,5" (Hill-Matrix zeilenweise).
+    /// * `plaintext` - Der Klartext.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidKeyError` - Falls der Schlüssel ungültig ist.
+    pub fn encrypt(&self, key: &str, plaintext: &str) -> Result<String> {
+        Ok(match self {
+            ClassicCipher::Caesar => CaesarScheme::encrypt(plaintext, &key.parse::<CaesarKey>()?),
+            ClassicCipher::Affine => AffineScheme::encrypt(plaintext, &key.parse::<AffineKey>()?),
+            ClassicCipher::Vigenere => {
+                VigenereScheme::encrypt(plaintext, &key.parse::<VigenereKey>()?)
+            }
+            ClassicCipher::Playfair => {
+                PlayfairScheme::encrypt(plaintext, &key.parse::<PlayfairKey>()?)
+            }
+            ClassicCipher::Hill => HillScheme::encrypt(plaintext, &key.parse::<HillKey>()?),
+            ClassicCipher::ColumnarTransposition => ColumnarTranspositionScheme::encrypt(
+                plaintext,
+                &key.parse::<ColumnarTranspositionKey>()?,
+            ),
+        })
+    }
+
+    /// Entschlüsselt den Geheimtext. Bei der Hill-Chiffre wird die Schlüsselmatrix zum
+    /// Verschlüsseln übergeben und hier invertiert.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError` - Falls der Schlüssel oder der Geheimtext ungültig ist.
+    pub fn decrypt(&self, key: &str, ciphertext: &str) -> Result<String> {
+        match self {
+            ClassicCipher::Caesar => Ok(CaesarScheme::decrypt(ciphertext, &key.parse()?)),
+            ClassicCipher::Affine => Ok(AffineScheme::decrypt(ciphertext, &key.parse()?)),
+            ClassicCipher::Vigenere => Ok(VigenereScheme::decrypt(ciphertext, &key.parse()?)),
+            ClassicCipher::Playfair => PlayfairScheme::decrypt(ciphertext, &key.parse()?),
+            ClassicCipher::Hill => {
+                let key = key
+                    .parse::<HillKey>()?
+                    .inverse(NumberTheoryService::new(Fast));
+                HillScheme::decrypt(ciphertext, &key)
+            }
+            ClassicCipher::ColumnarTransposition => Ok(ColumnarTranspositionScheme::decrypt(
+                ciphertext,
+                &key.parse()?,
+            )),
+        }
+    }
+
+    /// Erzeugt einen zufälligen Schlüssel zum Verschlüsseln in Textform.
+    ///
+    /// # Argumente
+    /// * `key_length` - Die Länge des Schlüsselworts, die Anzahl der Spalten bzw. die Dimension
+    ///   der Hill-Matrix. Wird von Caesar und der affinen Chiffre ignoriert.
+    /// * `random_seed` - Der Seed für den Zufallszahlengenerator.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidKeyError` - Falls die Dimension der Hill-Matrix größer als
+    ///   `MAXIMUM_HILL_DIMENSION` oder das Schlüsselwort länger als
+    ///   `MAXIMUM_VIGENERE_KEY_LENGTH` ist.
+    pub fn generate_key(&self, key_length: usize, random_seed: u32) -> Result<String> {
+        Ok(match self {
+            ClassicCipher::Caesar => CaesarKeyGenConfig { random_seed }
+                .generate_keypair()
+                .encryption()
+                .to_string(),
+            ClassicCipher::Affine => AffineKeyGenConfig { random_seed }
+                .generate_keypair()
+                .encryption()
+                .to_string(),
+            ClassicCipher::Vigenere => {
+                ensure_keyword_length(key_length)?;
+                VigenereKeyGenConfig {
+                    key_length,
+                    random_seed,
+                }
+                .generate_keypair()
+                .encryption()
+                .to_string()
+            }
+            ClassicCipher::Playfair => {
+                ensure_keyword_length(key_length)?;
+                PlayfairKeyGenConfig {
+                    key_length,
+                    random_seed,
+                }
+                .generate_keypair()
+                .encryption()
+                .to_string()
+            }
+            ClassicCipher::Hill => {
+                ensure!(
+                    key_length <= MAXIMUM_HILL_DIMENSION,
+                    InvalidKeyError(format!(
+                        "dimension {} exceeds the maximum of {}",
+                        key_length, MAXIMUM_HILL_DIMENSION
+                    ))
+                );
+                HillKeyGenConfig {
+                    dimension: key_length,
+                    random_seed,
+                }
+                .generate_keypair()
+                .encryption()
+                .to_string()
+            }
+            ClassicCipher::ColumnarTransposition => ColumnarTranspositionKeyGenConfig {
+                columns: key_length,
+                random_seed,
+            }
+            .generate_keypair()
+            .encryption()
+            .to_string(),
+        })
+    }
+
+    /// Bricht den Geheimtext und gibt Schlüsselkandidaten in Textform zurück.
+    ///
+    /// # Argumente
+    /// * `ciphertext` - Der Geheimtext.
+    /// * `language` - Die angenommene Sprache des Klartexts für die statistischen Angriffe.
+    /// * `known_plaintext` - Der bekannte Anfang des Klartexts, nur für die Hill-Chiffre nötig.
+    /// * `key_length` - Bei Vigenère die größte untersuchte Schlüssellänge, bei Hill die Dimension
+    ///   der Matrix. Ohne Angabe werden `DEFAULT_MAX_VIGENERE_KEY_LENGTH` bzw.
+    ///   `DEFAULT_HILL_DIMENSION` verwendet.
+    /// * `max_candidates` - Die maximale Anzahl der zurückgegebenen Kandidaten.
+    ///
+    /// # Rückgabe
+    /// * Die Kandidaten, der wahrscheinlichste zuerst. Kleinere Bewertungen sind besser.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::UnsupportedAttackError` - Für Playfair und die Spaltentransposition.
+    /// * `ClassicCipherError::InvalidKeyError` - Falls die Schlüssellänge bzw. Dimension größer als
+    ///   `MAXIMUM_VIGENERE_KEY_LENGTH` bzw. `MAXIMUM_HILL_DIMENSION` ist.
+    /// * `ClassicCipherError::InsufficientTextError` - Falls der Text für den Angriff nicht
+    ///   ausreicht oder bei Hill kein bekannter Klartext übergeben wird.
+    pub fn break_ciphertext(
+        &self,
+        ciphertext: &str,
+        language: Language,
+        known_plaintext: Option<&str>,
+        key_length: Option<usize>,
+        max_candidates: usize,
+    ) -> Result<Vec<KeyCandidate<String>>> {
+        fn to_text<K: ToString>(candidates: Vec<KeyCandidate<K>>) -> Vec<KeyCandidate<String>> {
+            candidates
+                .into_iter()
+                .map(|candidate| candidate.map_key(|key| key.to_string()))
+                .collect()
+        }
+
+        match self {
+            ClassicCipher::Caesar => {
+                Ok(to_text(break_caesar(ciphertext, language, max_candidates)))
+            }
+            ClassicCipher::Affine => {
+                Ok(to_text(break_affine(ciphertext, language, max_candidates)))
+            }
+            ClassicCipher::Vigenere => {
+                let max_key_length = key_length.unwrap_or(DEFAULT_MAX_VIGENERE_KEY_LENGTH);
+                ensure_keyword_length(max_key_length)?;
+                break_vigenere(ciphertext, language, max_key_length, max_candidates).map(to_text)
+            }
+            ClassicCipher::Hill => {
+                let known_plaintext = known_plaintext.ok_or_else(|| {
+                    InsufficientTextError("the Hill attack requires known plaintext".to_string())
+                })?;
+                known_plaintext_attack(
+                    known_plaintext,
+                    ciphertext,
+                    key_length.unwrap_or(DEFAULT_HILL_DIMENSION),
+                    max_candidates,
+                )
+                .map(to_text)
+            }
+            ClassicCipher::Playfair | ClassicCipher::ColumnarTransposition => {
+                Err(UnsupportedAttackError(self.to_string()).into())
+            }
+        }
+    }
+}
+
+/// Stellt sicher, dass ein zu erzeugendes Schlüsselwort höchstens `MAXIMUM_VIGENERE_KEY_LENGTH`
+/// Buchstaben hat.
+fn ensure_keyword_length(key_length: usize) -> Result<()> {
+    ensure!(
+        key_length <= MAXIMUM_VIGENERE_KEY_LENGTH,
+        InvalidKeyError(format!(
+            "key length {} exceeds the maximum of {}",
+            key_length, MAXIMUM_VIGENERE_KEY_LENGTH
+        ))
+    );
+    Ok(())
+}
+
+impl Display for ClassicCipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClassicCipher::Caesar => write!(f, "caesar"),
+            ClassicCipher::Affine => write!(f, "affine"),
+            ClassicCipher::Vigenere => write!(f, "vigenere"),
+            ClassicCipher::Playfair => write!(f, "playfair"),
+            ClassicCipher::Hill => write!(f, "hill"),
+            ClassicCipher::ColumnarTransposition => write!(f, "columnar"),
+        }
+    }
+}
+
+impl FromStr for ClassicCipher {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "caesar" => Ok(ClassicCipher::Caesar),
+            "affine" => Ok(ClassicCipher::Affine),
+            "vigenere" => Ok(ClassicCipher::Vigenere),
+            "playfair" => Ok(ClassicCipher::Playfair),
+            "hill" => Ok(ClassicCipher::Hill),
+            "columnar" => Ok(ClassicCipher::ColumnarTransposition),
+            _ => Err(UnknownCipherError(name.to_string()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_ciphers_roundtrip() {
+        let message = "DEFENDTHEWALLOFTHECASTLE";
+        for cipher in [
+            ClassicCipher::Caesar,
+            ClassicCipher::Affine,
+            ClassicCipher::Vigenere,
+            ClassicCipher::Playfair,
+            ClassicCipher::Hill,
+            ClassicCipher::ColumnarTransposition,
+        ] {
+            let key = cipher.generate_key(4, 31).unwrap();
+            let ciphertext = cipher.encrypt(&key, message).unwrap();
+            assert_ne!(ciphertext, message, "{}", cipher);
+            // Playfair und Hill füllen nur auf, wenn es nötig ist; diese Nachricht hat eine
+            // passende Länge und keine doppelten Buchstaben in einem Paar.
+            assert_eq!(
+                cipher.decrypt(&key, &ciphertext).unwrap(),
+                message,
+                "{}",
+                cipher
+            );
+            assert_eq!(cipher.to_string().parse::<ClassicCipher>().unwrap(), cipher);
+        }
+        assert!(ClassicCipher::Hill
+            .generate_key(MAXIMUM_HILL_DIMENSION + 1, 31)
+            .is_err());
+        for cipher in [ClassicCipher::Vigenere, ClassicCipher::Playfair] {
+            assert!(cipher
+                .generate_key(MAXIMUM_VIGENERE_KEY_LENGTH + 1, 31)
+                .is_err());
+            assert!(cipher.generate_key(usize::MAX, 31).is_err());
+        }
+    }
+
+    #[test]
+    fn test_encrypt_and_break() {
+        let message = "Wer den Schluessel nicht kennt, muss den Text mit Statistik angreifen. \
+            Je laenger der Geheimtext ist, desto zuverlaessiger gelingt dieser Angriff. Bei der \
+            Vigenere-Chiffre wird zuerst die Laenge des Schluesselworts bestimmt, indem man den \
+            Text in Spalten zerlegt und fuer jede Spalte den Koinzidenzindex berechnet. Danach \
+            wird jede Spalte wie eine Caesar-Chiffre gebrochen, denn alle Buchstaben einer Spalte \
+            wurden mit demselben Buchstaben des Schluessels verschoben.";
+        for (cipher, key) in [
+            (ClassicCipher::Caesar, "7"),
+            (ClassicCipher::Affine, "5,8"),
+            (ClassicCipher::Vigenere, "KEY"),
+        ] {
+            let ciphertext = cipher.encrypt(key, message).unwrap();
+            let candidates = cipher
+                .break_ciphertext(&ciphertext, Language::German, None, None, 3)
+                .unwrap();
+            assert_eq!(candidates[0].key, key, "{}", cipher);
+            assert_eq!(candidates[0].plaintext, message, "{}", cipher);
+        }
+
+        let ciphertext = ClassicCipher::Hill.encrypt("3,3,2,5", message).unwrap();
+        let candidates = ClassicCipher::Hill
+            .break_ciphertext(&ciphertext, Language::German, Some("Wer den"), None, 1)
+            .unwrap();
+        assert_eq!(candidates[0].key, "3,3,2,5");
+        assert!(ClassicCipher::Hill
+            .break_ciphertext(&ciphertext, Language::German, None, None, 1)
+            .is_err());
+        assert!(ClassicCipher::Playfair
+            .break_ciphertext(&ciphertext, Language::German, None, None, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_unknown_cipher() {
+        assert!("enigma".parse::<ClassicCipher>().is_err());
+    }
+}
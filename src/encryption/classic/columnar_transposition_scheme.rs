@@ -0,0 +1,124 @@
+use crate::encryption::classic::alphabet::{letter, KeyRandomness};
+use crate::encryption::classic::keys::{ClassicKeyPair, ColumnarTranspositionKey};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+
+/// Die Spaltentransposition, die den Text zeilenweise in so viele Spalten schreibt, wie das
+/// Schlüsselwort Buchstaben hat, und die Spalten in der alphabetischen Reihenfolge der Buchstaben
+/// ausliest. Die letzte Zeile wird nicht aufgefüllt (irreguläre Transposition), daher haben
+/// Klar- und Geheimtext dieselbe Länge. Es werden alle Zeichen einschließlich Leerzeichen permutiert.
+pub struct ColumnarTranspositionScheme {}
+
+impl EncryptionScheme for ColumnarTranspositionScheme {}
+
+impl SymmetricEncryptionScheme for ColumnarTranspositionScheme {}
+
+impl Encryptor<ColumnarTranspositionScheme> for ColumnarTranspositionScheme {
+    type Input = str;
+    type Output = String;
+    type Key = ColumnarTranspositionKey;
+}
+
+impl SymmetricEncryptor<ColumnarTranspositionScheme> for ColumnarTranspositionScheme {
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let characters: Vec<char> = plaintext.chars().collect();
+        let columns = key.column_order().len();
+        key.column_order()
+            .iter()
+            .flat_map(|column| characters.iter().skip(*column).step_by(columns))
+            .collect()
+    }
+}
+
+impl Decryptor<ColumnarTranspositionScheme> for ColumnarTranspositionScheme {
+    type Input = str;
+    type Output = String;
+    type Key = ColumnarTranspositionKey;
+}
+
+impl SymmetricDecryptor<ColumnarTranspositionScheme> for ColumnarTranspositionScheme {
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let characters: Vec<char> = ciphertext.chars().collect();
+        let columns = key.column_order().len();
+        let full_rows = characters.len() / columns;
+        let long_columns = characters.len() % columns;
+
+        // Die Spalten werden in der Reihenfolge des Schlüssels wieder aus dem Geheimtext geschnitten.
+        let mut plaintext = vec![' '; characters.len()];
+        let mut offset = 0;
+        for column in key.column_order() {
+            let length = full_rows + usize::from(*column < long_columns);
+            for (row, character) in characters[offset..offset + length].iter().enumerate() {
+                plaintext[row * columns + column] = *character;
+            }
+            offset += length;
+        }
+        plaintext.into_iter().collect()
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen Schlüsselworts aus verschiedenen Buchstaben.
+///
+/// # Felder
+/// * `columns` - Die Anzahl der Spalten, zwischen 1 und 26.
+/// * `random_seed` - Der Seed für den Zufallszahlengenerator.
+pub struct ColumnarTranspositionKeyGenConfig {
+    pub columns: usize,
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<ColumnarTranspositionKey, ColumnarTranspositionKey, ColumnarTranspositionScheme>
+    for ColumnarTranspositionKeyGenConfig
+{
+    type KeyPair = ClassicKeyPair<ColumnarTranspositionKey>;
+
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let random = KeyRandomness::new(self.random_seed);
+        let keyword: String = random
+            .permutation(26)
+            .into_iter()
+            .take(self.columns.clamp(1, 26))
+            .map(|index| letter(index as u8))
+            .collect();
+        // Das unwrap() schlägt niemals fehl, weil das Wort aus mindestens einem Buchstaben besteht.
+        ClassicKeyPair::symmetric(keyword.parse().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key: ColumnarTranspositionKey = "ZEBRAS".parse().unwrap();
+        let plaintext = "WEAREDISCOVEREDFLEEATONCE";
+        let ciphertext = ColumnarTranspositionScheme::encrypt(plaintext, &key);
+        assert_eq!(ciphertext, "EVLNACDTESEAROFODEECWIREE");
+        assert_eq!(
+            ColumnarTranspositionScheme::decrypt(&ciphertext, &key),
+            plaintext
+        );
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        let key_pair = ColumnarTranspositionKeyGenConfig {
+            columns: 5,
+            random_seed: 21,
+        }
+        .generate_keypair();
+        assert_eq!(key_pair.encryption().column_order().len(), 5);
+
+        for message in ["", "Kurz", "Eine etwas längere Nachricht mit Ümlauten."] {
+            let ciphertext = ColumnarTranspositionScheme::encrypt(message, &key_pair.encryption());
+            assert_eq!(
+                ColumnarTranspositionScheme::decrypt(&ciphertext, &key_pair.decryption()),
+                message
+            );
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::classic::alphabet::{letter_indices, ALPHABET_SIZE};
+use crate::encryption::classic::cryptanalysis::statistics::{rank, KeyCandidate};
+use crate::encryption::classic::hill_scheme::HillScheme;
+use crate::encryption::classic::keys::{HillKey, MAXIMUM_HILL_DIMENSION};
+use crate::encryption::symmetric_encryption_types::SymmetricDecryptor;
+use crate::math_core::modular_matrix::ModularMatrix;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::shared::errors::ClassicCipherError::{InsufficientTextError, InvalidKeyError};
+
+/// Die maximale Anzahl an Auswahlen von Klartextblöcken, die auf Invertierbarkeit geprüft werden.
+const MAX_BLOCK_SELECTIONS: usize = 1000;
+
+/// Bekannter-Klartext-Angriff auf die Hill-Chiffre. Fasst man n Klartextblöcke als Spalten einer
+/// Matrix P und die zugehörigen Geheimtextblöcke als Spalten von C auf, gilt K * P = C und damit
+/// K = C * P^-1, sofern P modulo 26 invertierbar ist. Es werden so lange Auswahlen von n Blöcken
+/// probiert, bis genügend invertierbare Matrizen P gefunden sind.
+///
+/// # Argumente
+/// * `known_plaintext` - Der bekannte Anfang des Klartexts. Andere Zeichen als Buchstaben werden
+///   wie bei der Verschlüsselung ignoriert.
+/// * `ciphertext` - Der vollständige Geheimtext.
+/// * `dimension` - Die Dimension n der Schlüsselmatrix.
+/// * `max_candidates` - Die maximale Anzahl der zurückgegebenen Kandidaten.
+///
+/// # Rückgabe
+/// * Die Kandidaten für die Schlüsselmatrix zum Verschlüsseln mit dem entschlüsselten Geheimtext.
+///   Die Bewertung ist der Anteil der bekannten Blöcke, die der Schlüssel nicht korrekt
+///   verschlüsselt; 0 bedeutet, dass der Schlüssel zu allen bekannten Blöcken passt.
+///
+/// # Fehler
+/// * `ClassicCipherError::InsufficientTextError` - Falls weniger als n bekannte Blöcke vorliegen
+///   oder keine Auswahl von Blöcken eine invertierbare Matrix ergibt.
+/// * `ClassicCipherError::InvalidKeyError` - Falls n größer als `MAXIMUM_HILL_DIMENSION` ist.
+pub fn known_plaintext_attack(
+    known_plaintext: &str,
+    ciphertext: &str,
+    dimension: usize,
+    max_candidates: usize,
+) -> Result<Vec<KeyCandidate<HillKey>>> {
+    let service = NumberTheoryService::new(Fast);
+    let plaintext_letters = letter_indices(known_plaintext);
+    let ciphertext_letters = letter_indices(ciphertext);
+    ensure!(
+        dimension > 0,
+        InsufficientTextError("the dimension must be positive".to_string())
+    );
+    ensure!(
+        dimension <= MAXIMUM_HILL_DIMENSION,
+        InvalidKeyError(format!(
+            "dimension {} exceeds the maximum of {}",
+            dimension, MAXIMUM_HILL_DIMENSION
+        ))
+    );
+    let known_blocks = plaintext_letters.len().min(ciphertext_letters.len()) / dimension;
+    ensure!(
+        known_blocks >= dimension,
+        InsufficientTextError(format!(
+            "{} known blocks of length {} are required, but only {} are available",
+            dimension, dimension, known_blocks
+        ))
+    );
+    let block = |letters: &[u8], index: usize| -> Vec<BigInt> {
+        letters[index * dimension..(index + 1) * dimension]
+            .iter()
+            .map(|letter| BigInt::from(*letter))
+            .collect()
+    };
+    let plaintext_blocks: Vec<Vec<BigInt>> = (0..known_blocks)
+        .map(|index| block(&plaintext_letters, index))
+        .collect();
+    let ciphertext_blocks: Vec<Vec<BigInt>> = (0..known_blocks)
+        .map(|index| block(&ciphertext_letters, index))
+        .collect();
+
+    let mut candidates: Vec<KeyCandidate<HillKey>> = Vec::new();
+    for selection in selections(known_blocks, dimension).take(MAX_BLOCK_SELECTIONS) {
+        let plaintext_matrix = columns_to_matrix(&plaintext_blocks, &selection)?;
+        let Ok(plaintext_inverse) = plaintext_matrix.inverse(service) else {
+            continue;
+        };
+        let ciphertext_matrix = columns_to_matrix(&ciphertext_blocks, &selection)?;
+        let Ok(key) = HillKey::new(ciphertext_matrix.multiply(&plaintext_inverse)?, service) else {
+            continue;
+        };
+        if candidates.iter().any(|candidate| candidate.key == key) {
+            continue;
+        }
+
+        let mut mismatches = 0;
+        for (plaintext_block, ciphertext_block) in plaintext_blocks.iter().zip(&ciphertext_blocks) {
+            if &key.matrix().multiply_vector(plaintext_block)? != ciphertext_block {
+                mismatches += 1;
+            }
+        }
+        let plaintext = HillScheme::decrypt(ciphertext, &key.inverse(service))?;
+        candidates.push(KeyCandidate {
+            key,
+            score: mismatches as f64 / known_blocks as f64,
+            plaintext,
+        });
+        if mismatches == 0 && candidates.len() >= max_candidates {
+            break;
+        }
+    }
+    ensure!(
+        !candidates.is_empty(),
+        InsufficientTextError("no selection of known blocks is invertible modulo 26".to_string())
+    );
+    Ok(rank(candidates, max_candidates))
+}
+
+/// Die Matrix, deren Spalten die ausgewählten Blöcke sind.
+fn columns_to_matrix(blocks: &[Vec<BigInt>], selection: &[usize]) -> Result<ModularMatrix> {
+    let dimension = selection.len();
+    let rows = (0..dimension)
+        .map(|row| {
+            selection
+                .iter()
+                .map(|column| blocks[*column][row].clone())
+                .collect()
+        })
+        .collect();
+    ModularMatrix::new(rows, BigInt::from(ALPHABET_SIZE))
+}
+
+/// Alle Auswahlen von `size` verschiedenen Indizes aus 0 bis `count - 1` in lexikographischer
+/// Reihenfolge. Auswahlen, die gleiche Blöcke nur anders anordnen, ergeben denselben Schlüssel
+/// und werden daher nicht erzeugt.
+fn selections(count: usize, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut next = (size <= count).then(|| (0..size).collect::<Vec<usize>>());
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        // Die letzte Stelle suchen, die noch erhöht werden kann, und alle folgenden zurücksetzen.
+        if let Some(position) = (0..size).rev().find(|i| current[*i] < count - size + i) {
+            let mut following = current.clone();
+            following[position] += 1;
+            for i in position + 1..size {
+                following[i] = following[i - 1] + 1;
+            }
+            next = Some(following);
+        }
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricEncryptor;
+
+    const PLAINTEXT: &str = "Meet me at the usual place at ten rather than eight oclock";
+
+    #[test]
+    fn test_known_plaintext_attack() {
+        let key: HillKey = "6,24,1,13,16,10,20,17,15".parse().unwrap();
+        let ciphertext = HillScheme::encrypt("Retreat now, we are done here", &key);
+        let candidates = known_plaintext_attack("Retreat now", &ciphertext, 3, 2).unwrap();
+        assert_eq!(candidates[0].key, key);
+        assert_eq!(candidates[0].score, 0.0);
+        assert_eq!(candidates[0].plaintext, "RETREATNOWWEAREDONEHEREX");
+    }
+
+    #[test]
+    fn test_known_plaintext_without_invertible_selection() {
+        // Alle Auswahlen aus den ersten fünf Blöcken haben eine gerade Determinante.
+        let key: HillKey = "6,24,1,13,16,10,20,17,15".parse().unwrap();
+        let ciphertext = HillScheme::encrypt(PLAINTEXT, &key);
+        assert!(known_plaintext_attack("Meet me at the usual", &ciphertext, 3, 1).is_err());
+    }
+
+    #[test]
+    fn test_known_plaintext_attack_two_by_two() {
+        let key: HillKey = "3,3,2,5".parse().unwrap();
+        let ciphertext = HillScheme::encrypt(PLAINTEXT, &key);
+        let candidates = known_plaintext_attack(PLAINTEXT, &ciphertext, 2, 5).unwrap();
+        assert_eq!(candidates[0].key, key);
+        // Bei vollständig bekanntem Klartext ist der Schlüssel eindeutig.
+        assert_eq!(candidates.len(), 1);
+    }
+
+    #[test]
+    fn test_insufficient_plaintext() {
+        let key: HillKey = "3,3,2,5".parse().unwrap();
+        let ciphertext = HillScheme::encrypt(PLAINTEXT, &key);
+        assert!(known_plaintext_attack("Mee", &ciphertext, 2, 1).is_err());
+        // Ein Klartext aus nur einem wiederholten Buchstaben liefert keine invertierbare Matrix.
+        let ciphertext = HillScheme::encrypt("AAAAAAAA", &key);
+        assert!(known_plaintext_attack("AAAAAAAA", &ciphertext, 2, 1).is_err());
+        assert!(
+            known_plaintext_attack(PLAINTEXT, &ciphertext, MAXIMUM_HILL_DIMENSION + 1, 1).is_err()
+        );
+    }
+
+    #[test]
+    fn test_selections() {
+        let all: Vec<Vec<usize>> = selections(4, 2).collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+        assert_eq!(selections(1, 2).count(), 0);
+    }
+}
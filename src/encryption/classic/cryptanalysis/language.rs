@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::shared::errors::ClassicCipherError::UnknownLanguageError;
+
+/// Relative Buchstabenhäufigkeiten englischer Texte in Prozent (A bis Z).
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+/// Relative Buchstabenhäufigkeiten deutscher Texte in Prozent (A bis Z). Umlaute und ß sind
+/// ihren Umschreibungen zugeschlagen, da die klassischen Chiffren nur A bis Z kennen.
+const GERMAN_FREQUENCIES: [f64; 26] = [
+    6.51, 1.89, 3.06, 5.08, 17.40, 1.66, 3.01, 4.76, 7.55, 0.27, 1.21, 3.44, 2.53, 9.78, 2.51,
+    0.79, 0.02, 7.00, 7.27, 6.15, 4.35, 0.67, 1.89, 0.03, 0.04, 1.13,
+];
+
+/// Die Sprache des Klartexts, deren Buchstabenverteilung für die Kryptoanalyse angenommen wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    German,
+    English,
+}
+
+impl Language {
+    /// Die erwartete Wahrscheinlichkeit jedes Buchstabens von A bis Z.
+    pub fn letter_probabilities(&self) -> [f64; 26] {
+        let frequencies = match self {
+            Language::German => GERMAN_FREQUENCIES,
+            Language::English => ENGLISH_FREQUENCIES,
+        };
+        let total: f64 = frequencies.iter().sum();
+        frequencies.map(|frequency| frequency / total)
+    }
+
+    /// Der erwartete Koinzidenzindex eines Klartexts, d.h. die Wahrscheinlichkeit, dass zwei
+    /// zufällig gewählte Buchstaben gleich sind: die Summe der quadrierten Wahrscheinlichkeiten.
+    pub fn index_of_coincidence(&self) -> f64 {
+        self.letter_probabilities()
+            .iter()
+            .map(|probability| probability * probability)
+            .sum()
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Language::German => write!(f, "de"),
+            Language::English => write!(f, "en"),
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "de" | "german" | "deutsch" => Ok(Language::German),
+            "en" | "english" | "englisch" => Ok(Language::English),
+            _ => Err(UnknownLanguageError(name.to_string()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        for language in [Language::German, Language::English] {
+            let sum: f64 = language.letter_probabilities().iter().sum();
+            assert!((sum - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_index_of_coincidence() {
+        assert!((Language::English.index_of_coincidence() - 0.0655).abs() < 0.001);
+        assert!((Language::German.index_of_coincidence() - 0.0762).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("DE".parse::<Language>().unwrap(), Language::German);
+        assert_eq!("english".parse::<Language>().unwrap(), Language::English);
+        assert!("fr".parse::<Language>().is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod hill_attack;
+pub mod language;
+pub mod statistics;
+pub mod substitution_attack;
+pub mod vigenere_attack;
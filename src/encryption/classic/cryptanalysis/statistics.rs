@@ -0,0 +1,148 @@
+use crate::encryption::classic::alphabet::{letter_indices, ALPHABET_SIZE};
+use crate::encryption::classic::cryptanalysis::language::Language;
+
+/// Ein Schlüsselkandidat eines Angriffs mit seiner Bewertung und dem zugehörigen Klartext.
+/// Für alle Angriffe gilt: Je kleiner die Bewertung, desto wahrscheinlicher ist der Schlüssel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyCandidate<K> {
+    pub key: K,
+    pub score: f64,
+    pub plaintext: String,
+}
+
+impl<K> KeyCandidate<K> {
+    /// Ersetzt den Schlüssel, z.B. durch seine Textform.
+    pub fn map_key<T>(self, map: impl FnOnce(K) -> T) -> KeyCandidate<T> {
+        KeyCandidate {
+            key: map(self.key),
+            score: self.score,
+            plaintext: self.plaintext,
+        }
+    }
+}
+
+/// Sortiert die Kandidaten aufsteigend nach ihrer Bewertung und behält die besten.
+pub(crate) fn rank<K>(
+    mut candidates: Vec<KeyCandidate<K>>,
+    max_candidates: usize,
+) -> Vec<KeyCandidate<K>> {
+    candidates.sort_by(|a, b| a.score.total_cmp(&b.score));
+    candidates.truncate(max_candidates);
+    candidates
+}
+
+/// Zählt, wie oft jeder Buchstabe von A bis Z vorkommt.
+pub fn letter_counts(text: &str) -> [usize; 26] {
+    counts_of_indices(&letter_indices(text))
+}
+
+pub(crate) fn counts_of_indices(indices: &[u8]) -> [usize; 26] {
+    let mut counts = [0; ALPHABET_SIZE as usize];
+    for index in indices {
+        counts[*index as usize] += 1;
+    }
+    counts
+}
+
+/// Die relativen Häufigkeiten der Buchstaben von A bis Z. Enthält der Text keine Buchstaben,
+/// sind alle Häufigkeiten 0.
+pub fn letter_frequencies(text: &str) -> [f64; 26] {
+    let counts = letter_counts(text);
+    let total: usize = counts.iter().sum();
+    counts.map(|count| match total {
+        0 => 0.0,
+        _ => count as f64 / total as f64,
+    })
+}
+
+/// Der Koinzidenzindex eines Texts: die Wahrscheinlichkeit, dass zwei zufällig ohne Zurücklegen
+/// gezogene Buchstaben gleich sind. Monoalphabetisch verschlüsselte Texte behalten den Index der
+/// Sprache (Deutsch etwa 0.076), polyalphabetische nähern sich dem Zufallswert 1/26 an.
+pub fn index_of_coincidence(text: &str) -> f64 {
+    index_of_coincidence_of_indices(&letter_indices(text))
+}
+
+pub(crate) fn index_of_coincidence_of_indices(indices: &[u8]) -> f64 {
+    let total = indices.len();
+    if total < 2 {
+        return 0.0;
+    }
+    let coincidences: usize = counts_of_indices(indices)
+        .iter()
+        .map(|count| count * count.saturating_sub(1))
+        .sum();
+    coincidences as f64 / (total * (total - 1)) as f64
+}
+
+/// Die Chi-Quadrat-Statistik der Buchstabenhäufigkeiten gegenüber der Verteilung der Sprache.
+/// Kleine Werte bedeuten, dass der Text gut zur Sprache passt.
+pub fn chi_squared(text: &str, language: Language) -> f64 {
+    chi_squared_of_counts(&letter_counts(text), language)
+}
+
+pub(crate) fn chi_squared_of_counts(counts: &[usize; 26], language: Language) -> f64 {
+    let total: usize = counts.iter().sum();
+    counts
+        .iter()
+        .zip(language.letter_probabilities())
+        .map(|(count, probability)| {
+            let expected = total as f64 * probability;
+            let difference = *count as f64 - expected;
+            difference * difference / expected
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENGLISH_TEXT: &str = "It was the best of times, it was the worst of times, it was the \
+        age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch \
+        of incredulity, it was the season of light, it was the season of darkness";
+
+    #[test]
+    fn test_letter_counts_and_frequencies() {
+        let counts = letter_counts("Abba, c!");
+        assert_eq!(&counts[..4], &[2, 2, 1, 0]);
+        let frequencies = letter_frequencies("Abba, c!");
+        assert!((frequencies[0] - 0.4).abs() < 1e-12);
+        assert_eq!(letter_frequencies("123"), [0.0; 26]);
+    }
+
+    #[test]
+    fn test_index_of_coincidence() {
+        assert_eq!(index_of_coincidence("AAAA"), 1.0);
+        assert_eq!(index_of_coincidence("ABCD"), 0.0);
+        assert_eq!(index_of_coincidence("A"), 0.0);
+        let index = index_of_coincidence(ENGLISH_TEXT);
+        assert!(index > 0.055, "{}", index);
+    }
+
+    #[test]
+    fn test_chi_squared_prefers_language() {
+        let shifted: String = ENGLISH_TEXT
+            .chars()
+            .map(|c| match c {
+                'a'..='y' => (c as u8 + 1) as char,
+                _ => c,
+            })
+            .collect();
+        assert!(
+            chi_squared(ENGLISH_TEXT, Language::English) < chi_squared(&shifted, Language::English)
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        let candidates = (0..5)
+            .map(|i| KeyCandidate {
+                key: i,
+                score: (3 - i) as f64,
+                plaintext: String::new(),
+            })
+            .collect();
+        let ranked: Vec<i32> = rank(candidates, 2).into_iter().map(|c| c.key).collect();
+        assert_eq!(ranked, vec![4, 3]);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::encryption::classic::affine_scheme::AffineScheme;
+use crate::encryption::classic::alphabet::ALPHABET_SIZE;
+use crate::encryption::classic::caesar_scheme::CaesarScheme;
+use crate::encryption::classic::cryptanalysis::language::Language;
+use crate::encryption::classic::cryptanalysis::statistics::{chi_squared, rank, KeyCandidate};
+use crate::encryption::classic::keys::{AffineKey, CaesarKey};
+use crate::encryption::symmetric_encryption_types::SymmetricDecryptor;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+/// Bricht die Caesar-Chiffre, indem alle 26 Verschiebungen ausprobiert und die entschlüsselten
+/// Texte mit dem Chi-Quadrat-Test gegen die Buchstabenverteilung der Sprache bewertet werden.
+///
+/// # Argumente
+/// * `ciphertext` - Der Geheimtext.
+/// * `language` - Die angenommene Sprache des Klartexts.
+/// * `max_candidates` - Die maximale Anzahl der zurückgegebenen Kandidaten.
+///
+/// # Rückgabe
+/// * Die Kandidaten, aufsteigend nach ihrer Chi-Quadrat-Statistik sortiert.
+pub fn break_caesar(
+    ciphertext: &str,
+    language: Language,
+    max_candidates: usize,
+) -> Vec<KeyCandidate<CaesarKey>> {
+    let candidates = (0..ALPHABET_SIZE)
+        .map(|shift| {
+            let key = CaesarKey { shift };
+            let plaintext = CaesarScheme::decrypt(ciphertext, &key);
+            KeyCandidate {
+                key,
+                score: chi_squared(&plaintext, language),
+                plaintext,
+            }
+        })
+        .collect();
+    rank(candidates, max_candidates)
+}
+
+/// Bricht die affine Chiffre durch Ausprobieren aller 12 * 26 = 312 Schlüssel. Die Bewertung
+/// erfolgt wie bei `break_caesar` über den Chi-Quadrat-Test.
+///
+/// # Argumente
+/// * `ciphertext` - Der Geheimtext.
+/// * `language` - Die angenommene Sprache des Klartexts.
+/// * `max_candidates` - Die maximale Anzahl der zurückgegebenen Kandidaten.
+///
+/// # Rückgabe
+/// * Die Kandidaten, aufsteigend nach ihrer Chi-Quadrat-Statistik sortiert.
+pub fn break_affine(
+    ciphertext: &str,
+    language: Language,
+    max_candidates: usize,
+) -> Vec<KeyCandidate<AffineKey>> {
+    let service = NumberTheoryService::new(Fast);
+    let modulus = ALPHABET_SIZE as i64;
+    let candidates = (0..modulus)
+        .flat_map(|a| (0..modulus).map(move |b| (a, b)))
+        // Faktoren, die nicht teilerfremd zu 26 sind, ergeben keinen gültigen Schlüssel.
+        .filter_map(|(a, b)| AffineKey::new(a, b, service).ok())
+        .map(|key| {
+            let plaintext = AffineScheme::decrypt(ciphertext, &key);
+            KeyCandidate {
+                key,
+                score: chi_squared(&plaintext, language),
+                plaintext,
+            }
+        })
+        .collect();
+    rank(candidates, max_candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricEncryptor;
+
+    const GERMAN_TEXT: &str = "Die Kryptoanalyse untersucht, wie sich verschluesselte Nachrichten \
+        ohne Kenntnis des Schluessels lesen lassen. Bei einfachen Chiffren genuegt dafuer oft \
+        schon die Haeufigkeit der einzelnen Buchstaben.";
+
+    #[test]
+    fn test_break_caesar() {
+        let ciphertext = CaesarScheme::encrypt(GERMAN_TEXT, &CaesarKey { shift: 11 });
+        let candidates = break_caesar(&ciphertext, Language::German, 3);
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].key, CaesarKey { shift: 11 });
+        assert_eq!(candidates[0].plaintext, GERMAN_TEXT);
+        assert!(candidates[0].score < candidates[1].score);
+    }
+
+    #[test]
+    fn test_break_affine() {
+        let key = AffineKey::new(7, 3, NumberTheoryService::new(Fast)).unwrap();
+        let ciphertext = AffineScheme::encrypt(GERMAN_TEXT, &key);
+        let candidates = break_affine(&ciphertext, Language::German, 5);
+        assert_eq!(candidates.len(), 5);
+        assert_eq!(candidates[0].key, key);
+        assert_eq!(candidates[0].plaintext, GERMAN_TEXT);
+    }
+
+    #[test]
+    fn test_break_affine_considers_all_keys() {
+        assert_eq!(break_affine("abc", Language::English, 1000).len(), 312);
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use anyhow::{ensure, Result};
+
+use crate::encryption::classic::alphabet::{letter, letter_indices, ALPHABET_SIZE};
+use crate::encryption::classic::cryptanalysis::language::Language;
+use crate::encryption::classic::cryptanalysis::statistics::{
+    chi_squared, chi_squared_of_counts, counts_of_indices, index_of_coincidence_of_indices, rank,
+    KeyCandidate,
+};
+use crate::encryption::classic::keys::VigenereKey;
+use crate::encryption::classic::vigenere_scheme::VigenereScheme;
+use crate::encryption::symmetric_encryption_types::SymmetricDecryptor;
+use crate::shared::errors::ClassicCipherError::InsufficientTextError;
+
+/// Die Länge der Buchstabenfolgen, deren Wiederholungen beim Kasiski-Test gesucht werden.
+const KASISKI_SEQUENCE_LENGTH: usize = 3;
+
+/// Die Mindestanzahl an Buchstaben je Spalte, damit beim Brechen der Chi-Quadrat-Test einer
+/// Spalte aussagekräftig ist. Bei kürzeren Spalten passen lange, falsche Schlüsselwörter oft
+/// besser als das richtige.
+const MIN_COLUMN_LETTERS: usize = 20;
+
+/// Die Anzahl der wahrscheinlichsten Schlüssellängen, für die beim Brechen ein Schlüsselwort
+/// bestimmt wird.
+const KEY_LENGTHS_TO_TRY: usize = 5;
+
+/// Das Ergebnis des Kasiski-Tests für eine Schlüssellänge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KasiskiResult {
+    /// Die untersuchte Schlüssellänge.
+    pub key_length: usize,
+    /// Die Anzahl der Abstände wiederholter Buchstabenfolgen, die durch die Länge teilbar sind.
+    pub matching_distances: usize,
+}
+
+/// Der Kasiski-Test: Wiederholte Folgen von drei Buchstaben im Geheimtext entstehen meist aus
+/// gleichen Klartextstellen, die mit derselben Stelle des Schlüsselworts verschlüsselt wurden.
+/// Ihre Abstände sind daher häufig Vielfache der Schlüssellänge.
+///
+/// # Argumente
+/// * `ciphertext` - Der Geheimtext.
+/// * `max_key_length` - Die größte untersuchte Schlüssellänge. Längen über der Anzahl der
+///   Buchstaben werden nicht untersucht, da kein Abstand so groß sein kann.
+///
+/// # Rückgabe
+/// * Die Ergebnisse für die Längen von 2 bis `max_key_length`, absteigend nach der Anzahl der
+///   teilbaren Abstände sortiert. Bei Gleichstand steht die kürzere Länge vorne.
+pub fn kasiski_examination(ciphertext: &str, max_key_length: usize) -> Vec<KasiskiResult> {
+    let letters = letter_indices(ciphertext);
+    let max_key_length = max_key_length.min(letters.len());
+    let mut last_positions: HashMap<&[u8], usize> = HashMap::new();
+    let mut distances = Vec::new();
+    for (position, sequence) in letters.windows(KASISKI_SEQUENCE_LENGTH).enumerate() {
+        if let Some(previous) = last_positions.insert(sequence, position) {
+            distances.push(position - previous);
+        }
+    }
+
+    let mut results: Vec<KasiskiResult> = (2..=max_key_length)
+        .map(|key_length| KasiskiResult {
+            key_length,
+            matching_distances: distances
+                .iter()
+                .filter(|distance| distance.is_multiple_of(key_length))
+                .count(),
+        })
+        .collect();
+    results.sort_by_key(|result| std::cmp::Reverse(result.matching_distances));
+    results
+}
+
+/// Schätzt die Schlüssellänge mit der Formel von Friedman aus dem Koinzidenzindex des Geheimtexts:
+/// L = (κ_p - κ_r) / (κ_o - κ_r) mit dem Index der Sprache κ_p, dem Zufallsindex κ_r = 1/26 und
+/// dem beobachteten Index κ_o. Die Schätzung ist nur für längere Texte brauchbar.
+///
+/// # Rückgabe
+/// * Die geschätzte Länge. Ist der beobachtete Index nicht größer als der Zufallsindex, wird
+///   `f64::INFINITY` zurückgegeben.
+pub fn friedman_estimate(ciphertext: &str, language: Language) -> f64 {
+    let observed = index_of_coincidence_of_indices(&letter_indices(ciphertext));
+    let random = 1.0 / ALPHABET_SIZE as f64;
+    if observed <= random {
+        return f64::INFINITY;
+    }
+    (language.index_of_coincidence() - random) / (observed - random)
+}
+
+/// Bewertet alle Schlüssellängen von 1 bis `max_key_length`, indem der Geheimtext in so viele
+/// Spalten aufgeteilt wird, wie die Länge angibt. Bei der richtigen Länge ist jede Spalte
+/// monoalphabetisch verschlüsselt und ihr Koinzidenzindex liegt nahe am Index der Sprache.
+///
+/// # Rückgabe
+/// * Die Längen, aufsteigend nach dem Abstand des mittleren Spaltenindex zum Index der Sprache
+///   sortiert. Längen, bei denen eine Spalte weniger als zwei Buchstaben hätte, fehlen.
+pub fn estimate_key_lengths(
+    ciphertext: &str,
+    language: Language,
+    max_key_length: usize,
+) -> Vec<KeyCandidate<usize>> {
+    let letters = letter_indices(ciphertext);
+    let expected = language.index_of_coincidence();
+    let candidates = (1..=max_key_length.min(letters.len() / 2))
+        .map(|key_length| {
+            let mean_index = columns(&letters, key_length)
+                .iter()
+                .map(|column| index_of_coincidence_of_indices(column))
+                .sum::<f64>()
+                / key_length as f64;
+            KeyCandidate {
+                key: key_length,
+                score: (mean_index - expected).abs(),
+                plaintext: String::new(),
+            }
+        })
+        .collect();
+    rank(candidates, max_key_length)
+}
+
+/// Bricht die Vigenère-Chiffre: Für die wahrscheinlichsten Schlüssellängen wird jede Spalte wie
+/// eine Caesar-Chiffre mit dem Chi-Quadrat-Test gebrochen.
+///
+/// Eine Länge gilt als plausibel, wenn der mittlere Koinzidenzindex ihrer Spalten näher am Index
+/// der Sprache als am Zufallsindex liegt. Plausible Längen werden aufsteigend untersucht, ihre
+/// Vielfachen übersprungen: Sie liefern nur das wiederholte Schlüsselwort, dessen kürzere Spalten
+/// aber häufiger falsch gebrochen werden. Danach folgen die übrigen Längen nach ihrer Bewertung.
+/// Es werden nur Längen untersucht, bei denen jede Spalte mindestens `MIN_COLUMN_LETTERS`
+/// Buchstaben enthält.
+///
+/// # Argumente
+/// * `ciphertext` - Der Geheimtext.
+/// * `language` - Die angenommene Sprache des Klartexts.
+/// * `max_key_length` - Die größte untersuchte Schlüssellänge.
+/// * `max_candidates` - Die maximale Anzahl der zurückgegebenen Kandidaten.
+///
+/// # Rückgabe
+/// * Die Kandidaten, aufsteigend nach der Chi-Quadrat-Statistik des Klartexts sortiert.
+///
+/// # Fehler
+/// * `ClassicCipherError::InsufficientTextError` - Falls der Geheimtext keine zwei Buchstaben
+///   enthält.
+pub fn break_vigenere(
+    ciphertext: &str,
+    language: Language,
+    max_key_length: usize,
+    max_candidates: usize,
+) -> Result<Vec<KeyCandidate<VigenereKey>>> {
+    let letters = letter_indices(ciphertext);
+    ensure!(
+        letters.len() >= 2,
+        InsufficientTextError("the ciphertext must contain at least two letters".to_string())
+    );
+
+    let max_key_length = max_key_length.min((letters.len() / MIN_COLUMN_LETTERS).max(1));
+    let plausible_distance = (language.index_of_coincidence() - 1.0 / ALPHABET_SIZE as f64) / 2.0;
+    let (mut plausible, others): (Vec<KeyCandidate<usize>>, Vec<KeyCandidate<usize>>) =
+        estimate_key_lengths(ciphertext, language, max_key_length)
+            .into_iter()
+            .partition(|length| length.score < plausible_distance);
+    plausible.sort_by_key(|length| length.key);
+
+    let mut plausible_lengths: Vec<usize> = Vec::new();
+    let mut lengths: Vec<usize> = Vec::new();
+    for length in plausible.iter().map(|length| length.key) {
+        if !plausible_lengths
+            .iter()
+            .any(|shorter| length.is_multiple_of(*shorter))
+        {
+            plausible_lengths.push(length);
+            lengths.push(length);
+        }
+    }
+    lengths.extend(others.iter().map(|length| length.key));
+
+    let mut candidates: Vec<KeyCandidate<VigenereKey>> = Vec::new();
+    for length in lengths.into_iter().take(KEY_LENGTHS_TO_TRY) {
+        let shifts: Vec<u8> = columns(&letters, length)
+            .iter()
+            .map(|column| best_shift(column, language))
+            .collect();
+        let keyword: String = shortest_period(&shifts)
+            .iter()
+            .map(|shift| letter(*shift))
+            .collect();
+        // Das unwrap() schlägt niemals fehl, weil das Schlüsselwort nur aus Buchstaben besteht.
+        let key: VigenereKey = keyword.parse().unwrap();
+        if candidates.iter().any(|candidate| candidate.key == key) {
+            continue;
+        }
+        let plaintext = VigenereScheme::decrypt(ciphertext, &key);
+        candidates.push(KeyCandidate {
+            key,
+            score: chi_squared(&plaintext, language),
+            plaintext,
+        });
+    }
+    Ok(rank(candidates, max_candidates))
+}
+
+/// Teilt die Buchstaben in `key_length` Spalten auf: Spalte i enthält jeden Buchstaben, dessen
+/// Position modulo `key_length` gleich i ist.
+fn columns(letters: &[u8], key_length: usize) -> Vec<Vec<u8>> {
+    (0..key_length)
+        .map(|column| {
+            letters
+                .iter()
+                .skip(column)
+                .step_by(key_length)
+                .copied()
+                .collect()
+        })
+        .collect()
+}
+
+/// Die Verschiebung, nach deren Rückgängigmachen die Spalte am besten zur Sprache passt.
+fn best_shift(column: &[u8], language: Language) -> u8 {
+    let counts = counts_of_indices(column);
+    // Das unwrap() schlägt niemals fehl, weil der Bereich nicht leer ist.
+    (0..ALPHABET_SIZE)
+        .map(|shift| {
+            let mut shifted = counts;
+            shifted.rotate_left(shift as usize);
+            (shift, chi_squared_of_counts(&shifted, language))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+        .0
+}
+
+/// Der kürzeste Anfang der Folge, dessen Wiederholung die ganze Folge ergibt.
+fn shortest_period(shifts: &[u8]) -> &[u8] {
+    // Das unwrap() schlägt niemals fehl, weil die volle Länge immer eine Periode ist.
+    let period = (1..=shifts.len())
+        .find(|period| {
+            shifts.len().is_multiple_of(*period)
+                && shifts
+                    .iter()
+                    .enumerate()
+                    .all(|(i, shift)| *shift == shifts[i % period])
+        })
+        .unwrap();
+    &shifts[..period]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricEncryptor;
+
+    const ENGLISH_TEXT: &str = "Cryptanalysis is the study of analyzing information systems in \
+        order to understand hidden aspects of the systems. It is used to breach cryptographic \
+        security systems and gain access to the contents of encrypted messages, even if the \
+        cryptographic key is unknown. In addition to mathematical analysis of cryptographic \
+        algorithms, cryptanalysis includes the study of side channel attacks that do not target \
+        weaknesses in the cryptographic algorithms themselves, but instead exploit weaknesses in \
+        their implementation. Even though the goal has been the same, the methods and techniques \
+        of cryptanalysis have changed drastically through the history of cryptography, adapting \
+        to increasing cryptographic complexity, ranging from the pen and paper methods of the \
+        past, through machines like the British Bombes and Colossus computers at Bletchley Park \
+        in World War II, to the mathematically advanced computerized schemes of the present.";
+
+    fn encrypt(keyword: &str) -> String {
+        VigenereScheme::encrypt(ENGLISH_TEXT, &keyword.parse().unwrap())
+    }
+
+    #[test]
+    fn test_break_vigenere() {
+        let ciphertext = encrypt("LEMON");
+        let candidates = break_vigenere(&ciphertext, Language::English, 12, 3).unwrap();
+        assert_eq!(candidates[0].key.to_string(), "LEMON");
+        assert_eq!(candidates[0].plaintext, ENGLISH_TEXT);
+    }
+
+    #[test]
+    fn test_estimate_key_lengths() {
+        let ciphertext = encrypt("CIPHER");
+        let lengths = estimate_key_lengths(&ciphertext, Language::English, 20);
+        // Die richtige Länge oder eines ihrer Vielfachen steht vorne.
+        assert!(lengths[0].key.is_multiple_of(6), "{:?}", lengths[0]);
+    }
+
+    #[test]
+    fn test_kasiski_examination() {
+        let ciphertext = encrypt("CIPHER");
+        let results = kasiski_examination(&ciphertext, 10);
+        assert_eq!(results.len(), 9);
+        let best = results[0].key_length;
+        assert!(best == 2 || best == 3 || best == 6, "{:?}", results);
+        let six = results
+            .iter()
+            .find(|result| result.key_length == 6)
+            .unwrap();
+        assert!(six.matching_distances > 0);
+    }
+
+    #[test]
+    fn test_key_length_bounded_by_text() {
+        let ciphertext = encrypt("CIPHER");
+        let letters = letter_indices(&ciphertext).len();
+        assert_eq!(
+            kasiski_examination(&ciphertext, usize::MAX).len(),
+            letters - 1
+        );
+        assert_eq!(
+            estimate_key_lengths(&ciphertext, Language::English, usize::MAX).len(),
+            letters / 2
+        );
+    }
+
+    #[test]
+    fn test_friedman_estimate() {
+        let estimate = friedman_estimate(&encrypt("CIPHER"), Language::English);
+        assert!((3.0..12.0).contains(&estimate), "{}", estimate);
+        assert!(friedman_estimate(ENGLISH_TEXT, Language::English) < 1.5);
+        assert_eq!(
+            friedman_estimate("ABCDEF", Language::English),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_shortest_period() {
+        assert_eq!(shortest_period(&[1, 2, 1, 2, 1, 2]), &[1, 2]);
+        assert_eq!(shortest_period(&[1, 2, 1]), &[1, 2, 1]);
+    }
+
+    #[test]
+    fn test_too_short() {
+        assert!(break_vigenere("a", Language::German, 10, 3).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::ToPrimitive;
+
+use crate::encryption::classic::alphabet::{letter, letter_indices, KeyRandomness, ALPHABET_SIZE};
+use crate::encryption::classic::keys::{ClassicKeyPair, HillKey};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+use crate::math_core::modular_matrix::ModularMatrix;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::shared::errors::ClassicCipherError::InvalidCiphertextLengthError;
+
+/// Der Füllbuchstabe X, mit dem der Klartext auf ein Vielfaches der Blocklänge aufgefüllt wird.
+const FILLER: u8 = 23;
+
+/// Die Hill-Chiffre, die Blöcke von n Buchstaben als Vektoren auffasst und mit einer invertierbaren
+/// n x n-Matrix modulo 26 multipliziert. Zum Entschlüsseln wird mit der inversen Matrix
+/// multipliziert, daher unterscheiden sich hier Schlüssel zum Ver- und Entschlüsseln.
+/// Vor der Verschlüsselung werden alle Zeichen außer Buchstaben entfernt.
+pub struct HillScheme {}
+
+impl EncryptionScheme for HillScheme {}
+
+impl SymmetricEncryptionScheme for HillScheme {}
+
+impl Encryptor<HillScheme> for HillScheme {
+    type Input = str;
+    type Output = String;
+    type Key = HillKey;
+}
+
+impl SymmetricEncryptor<HillScheme> for HillScheme {
+    /// Verschlüsselt den Klartext, der mit X auf ein Vielfaches der Blocklänge aufgefüllt wird.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let dimension = key.matrix().dimension();
+        let mut letters = letter_indices(plaintext);
+        let padding = (dimension - letters.len() % dimension) % dimension;
+        letters.extend(std::iter::repeat_n(FILLER, padding));
+        // Das unwrap() schlägt niemals fehl, weil die Blöcke genau die Dimension der Matrix haben.
+        multiply_blocks(key.matrix(), &letters).unwrap()
+    }
+}
+
+impl Decryptor<HillScheme> for HillScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = HillKey;
+}
+
+impl SymmetricDecryptor<HillScheme> for HillScheme {
+    /// Entschlüsselt den Geheimtext mit dem Schlüssel zum Entschlüsseln, d.h. der inversen Matrix.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidCiphertextLengthError` - Falls die Anzahl der Buchstaben kein
+    ///   Vielfaches der Blocklänge ist.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let dimension = key.matrix().dimension();
+        let letters = letter_indices(ciphertext);
+        ensure!(
+            letters.len().is_multiple_of(dimension),
+            InvalidCiphertextLengthError(dimension, letters.len())
+        );
+        multiply_blocks(key.matrix(), &letters)
+    }
+}
+
+fn multiply_blocks(matrix: &ModularMatrix, letters: &[u8]) -> Result<String> {
+    let mut text = String::with_capacity(letters.len());
+    for block in letters.chunks(matrix.dimension()) {
+        let vector: Vec<BigInt> = block.iter().map(|index| BigInt::from(*index)).collect();
+        for entry in matrix.multiply_vector(&vector)? {
+            // Das unwrap() schlägt niemals fehl, weil die Einträge kleiner als 26 sind.
+            text.push(letter(entry.to_u8().unwrap()));
+        }
+    }
+    Ok(text)
+}
+
+/// Die Konfiguration für die Erzeugung einer zufälligen invertierbaren Schlüsselmatrix.
+///
+/// # Felder
+/// * `dimension` - Die Blocklänge n, mindestens 1.
+/// * `random_seed` - Der Seed für den Zufallszahlengenerator.
+pub struct HillKeyGenConfig {
+    pub dimension: usize,
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<HillKey, HillKey, HillScheme> for HillKeyGenConfig {
+    type KeyPair = ClassicKeyPair<HillKey>;
+
+    /// Zieht so lange zufällige Matrizen, bis eine modulo 26 invertierbar ist.
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let service = NumberTheoryService::new(Fast);
+        let random = KeyRandomness::new(self.random_seed);
+        let dimension = self.dimension.max(1);
+        loop {
+            let rows = (0..dimension)
+                .map(|_| {
+                    (0..dimension)
+                        .map(|_| BigInt::from(random.below(ALPHABET_SIZE as usize)))
+                        .collect()
+                })
+                .collect();
+            // Das unwrap() schlägt niemals fehl, weil die Matrix quadratisch und nicht leer ist.
+            let matrix = ModularMatrix::new(rows, ALPHABET_SIZE.into()).unwrap();
+            if let Ok(key) = HillKey::new(matrix, service) {
+                return ClassicKeyPair {
+                    decryption_key: key.inverse(service),
+                    encryption_key: key,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let service = NumberTheoryService::new(Fast);
+        let key: HillKey = "6,24,1,13,16,10,20,17,15".parse().unwrap();
+        assert_eq!(HillScheme::encrypt("act", &key), "POH");
+        assert_eq!(
+            HillScheme::decrypt("POH", &key.inverse(service)).unwrap(),
+            "ACT"
+        );
+
+        let key: HillKey = "3,3,2,5".parse().unwrap();
+        let ciphertext = HillScheme::encrypt("Hello!", &key);
+        assert_eq!(ciphertext, "HIOZHN");
+        assert_eq!(
+            HillScheme::decrypt(&ciphertext, &key.inverse(service)).unwrap(),
+            "HELLOX"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_invalid_length() {
+        let key: HillKey = "3,3,2,5".parse().unwrap();
+        assert!(HillScheme::decrypt("ABC", &key).is_err());
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        for dimension in [2, 3, 4] {
+            let key_pair = HillKeyGenConfig {
+                dimension,
+                random_seed: 9,
+            }
+            .generate_keypair();
+            let encryption_key = key_pair.encryption();
+            assert_eq!(encryption_key.matrix().dimension(), dimension);
+
+            let ciphertext = HillScheme::encrypt("MATRIXCIPHER", &encryption_key);
+            assert_eq!(
+                HillScheme::decrypt(&ciphertext, &key_pair.decryption()).unwrap(),
+                "MATRIXCIPHER"
+            );
+        }
+    }
+}
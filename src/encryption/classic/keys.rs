@@ -0,0 +1,468 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::ToPrimitive;
+
+use crate::encryption::classic::affine_scheme::AffineScheme;
+use crate::encryption::classic::alphabet::{letter, letter_index, ALPHABET_SIZE};
+use crate::encryption::classic::caesar_scheme::CaesarScheme;
+use crate::encryption::classic::columnar_transposition_scheme::ColumnarTranspositionScheme;
+use crate::encryption::classic::hill_scheme::HillScheme;
+use crate::encryption::classic::playfair_scheme::PlayfairScheme;
+use crate::encryption::classic::vigenere_scheme::VigenereScheme;
+use crate::encryption::encryption_types::Key;
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptionKey, SymmetricEncryptionKey, SymmetricEncryptionScheme, SymmetricKey,
+    SymmetricKeyPair,
+};
+use crate::math_core::modular_matrix::ModularMatrix;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::ClassicCipherError::InvalidKeyError;
+
+/// Implementiert die Schlüssel-Traits für einen Schlüssel, der zum Ver- und Entschlüsseln dient.
+macro_rules! symmetric_key {
+    ($key:ty, $scheme:ty) => {
+        impl Key<$scheme> for $key {}
+
+        impl SymmetricKey<$scheme> for $key {}
+
+        impl SymmetricEncryptionKey<$scheme> for $key {}
+
+        impl SymmetricDecryptionKey<$scheme> for $key {}
+    };
+}
+
+/// Ein Schlüsselpaar einer klassischen Chiffre.
+/// Bis auf die Hill-Chiffre sind beide Schlüssel identisch, da die Schemata selbst wissen,
+/// wie sie die Verschiebung bzw. Permutation umkehren.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassicKeyPair<K> {
+    pub encryption_key: K,
+    pub decryption_key: K,
+}
+
+impl<K: Clone> ClassicKeyPair<K> {
+    /// Erstellt ein Schlüsselpaar, bei dem beide Schlüssel identisch sind.
+    pub fn symmetric(key: K) -> Self {
+        ClassicKeyPair {
+            encryption_key: key.clone(),
+            decryption_key: key,
+        }
+    }
+}
+
+impl<K, S> SymmetricKeyPair<K, K, S> for ClassicKeyPair<K>
+where
+    K: SymmetricEncryptionKey<S> + SymmetricDecryptionKey<S> + Clone,
+    S: SymmetricEncryptionScheme,
+{
+    fn encryption(&self) -> K {
+        self.encryption_key.clone()
+    }
+
+    fn decryption(&self) -> K {
+        self.decryption_key.clone()
+    }
+}
+
+/// Liest ein Schlüsselwort, das nur aus ASCII-Buchstaben besteht.
+fn parse_keyword(keyword: &str) -> Result<Vec<u8>> {
+    keyword
+        .chars()
+        .map(|character| {
+            letter_index(character).ok_or_else(|| {
+                InvalidKeyError(format!("keyword contains non-letter '{}'", character)).into()
+            })
+        })
+        .collect()
+}
+
+/// Der Schlüssel der Caesar-Chiffre: die Verschiebung im Alphabet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CaesarKey {
+    pub shift: u8,
+}
+
+symmetric_key!(CaesarKey, CaesarScheme);
+
+impl FromStr for CaesarKey {
+    type Err = anyhow::Error;
+
+    /// Liest die Verschiebung, z.B. "3". Negative Werte verschieben nach links.
+    fn from_str(value: &str) -> Result<Self> {
+        let shift: i64 = value
+            .trim()
+            .parse()
+            .map_err(|_| InvalidKeyError(format!("shift {} is not a number", value)))?;
+        Ok(CaesarKey {
+            shift: shift.rem_euclid(ALPHABET_SIZE as i64) as u8,
+        })
+    }
+}
+
+impl Display for CaesarKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.shift)
+    }
+}
+
+/// Der Schlüssel der affinen Chiffre E(x) = a * x + b mod 26.
+/// Der Faktor a muss teilerfremd zu 26 sein, sein Inverses wird beim Erstellen berechnet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AffineKey {
+    a: u8,
+    b: u8,
+    a_inverse: u8,
+}
+
+symmetric_key!(AffineKey, AffineScheme);
+
+impl AffineKey {
+    /// Erstellt den Schlüssel.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidKeyError` - Falls a nicht teilerfremd zu 26 ist.
+    pub fn new(a: i64, b: i64, service: NumberTheoryService) -> Result<Self> {
+        let modulus = ALPHABET_SIZE as i64;
+        let a = a.rem_euclid(modulus);
+        let a_inverse = service
+            .modulo_inverse(&BigInt::from(a), &BigInt::from(modulus))
+            .map_err(|_| InvalidKeyError(format!("a = {} is not coprime to 26", a)))?;
+        Ok(AffineKey {
+            a: a as u8,
+            b: b.rem_euclid(modulus) as u8,
+            // Das unwrap() schlägt niemals fehl, weil das Inverse kleiner als 26 ist.
+            a_inverse: a_inverse.to_u8().unwrap(),
+        })
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+
+    pub fn b(&self) -> u8 {
+        self.b
+    }
+
+    /// Das Inverse von a modulo 26.
+    pub fn a_inverse(&self) -> u8 {
+        self.a_inverse
+    }
+}
+
+impl FromStr for AffineKey {
+    type Err = anyhow::Error;
+
+    /// Liest den Schlüssel in der Form "a,b", z.B. "5,8".
+    fn from_str(value: &str) -> Result<Self> {
+        let parts: Vec<i64> = value
+            .split(',')
+            .map(|part| part.trim().parse())
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| InvalidKeyError(format!("{} is not of the form a,b", value)))?;
+        ensure!(
+            parts.len() == 2,
+            InvalidKeyError(format!("{} is not of the form a,b", value))
+        );
+        AffineKey::new(parts[0], parts[1], NumberTheoryService::new(Fast))
+    }
+}
+
+impl Display for AffineKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.a, self.b)
+    }
+}
+
+/// Der Schlüssel der Vigenère-Chiffre: ein nicht leeres Schlüsselwort.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VigenereKey {
+    shifts: Vec<u8>,
+}
+
+symmetric_key!(VigenereKey, VigenereScheme);
+
+impl VigenereKey {
+    /// Die Verschiebungen, die sich aus den Buchstaben des Schlüsselworts ergeben.
+    pub fn shifts(&self) -> &[u8] {
+        &self.shifts
+    }
+}
+
+impl FromStr for VigenereKey {
+    type Err = anyhow::Error;
+
+    /// Liest das Schlüsselwort, z.B. "LEMON".
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidKeyError` - Falls das Wort leer ist oder andere Zeichen als
+    ///   Buchstaben enthält.
+    fn from_str(keyword: &str) -> Result<Self> {
+        let shifts = parse_keyword(keyword)?;
+        ensure!(
+            !shifts.is_empty(),
+            InvalidKeyError("keyword must not be empty".to_string())
+        );
+        Ok(VigenereKey { shifts })
+    }
+}
+
+impl Display for VigenereKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let keyword: String = self.shifts.iter().map(|shift| letter(*shift)).collect();
+        write!(f, "{}", keyword)
+    }
+}
+
+/// Der Schlüssel der Playfair-Chiffre: ein 5x5-Quadrat aus allen Buchstaben außer J.
+/// Das Quadrat wird zeilenweise aus dem Schlüsselwort ohne Wiederholungen und den übrigen
+/// Buchstaben des Alphabets gefüllt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayfairKey {
+    keyword: String,
+    square: [u8; 25],
+}
+
+symmetric_key!(PlayfairKey, PlayfairScheme);
+
+/// Der Index des Buchstabens J, der in der Playfair-Chiffre durch I ersetzt wird.
+pub(crate) const PLAYFAIR_J: u8 = 9;
+
+impl PlayfairKey {
+    /// Zeile und Spalte eines Buchstabens im Quadrat. J wird wie I behandelt.
+    pub fn position(&self, index: u8) -> (usize, usize) {
+        let index = if index == PLAYFAIR_J {
+            PLAYFAIR_J - 1
+        } else {
+            index
+        };
+        // Das unwrap() schlägt niemals fehl, weil das Quadrat alle Buchstaben außer J enthält.
+        let position = self
+            .square
+            .iter()
+            .position(|entry| *entry == index)
+            .unwrap();
+        (position / 5, position % 5)
+    }
+
+    /// Der Buchstabe an der gegebenen Stelle des Quadrats.
+    pub fn at(&self, row: usize, column: usize) -> u8 {
+        self.square[5 * (row % 5) + column % 5]
+    }
+}
+
+impl FromStr for PlayfairKey {
+    type Err = anyhow::Error;
+
+    /// Erstellt das Quadrat aus dem Schlüsselwort, z.B. "PLAYFAIR".
+    fn from_str(keyword: &str) -> Result<Self> {
+        let letters = parse_keyword(keyword)?;
+        let mut square = Vec::with_capacity(25);
+        for index in letters.into_iter().chain(0..ALPHABET_SIZE) {
+            let index = if index == PLAYFAIR_J {
+                PLAYFAIR_J - 1
+            } else {
+                index
+            };
+            if !square.contains(&index) {
+                square.push(index);
+            }
+        }
+        Ok(PlayfairKey {
+            keyword: keyword.to_uppercase(),
+            // Das unwrap() schlägt niemals fehl, weil genau 25 verschiedene Buchstaben übrig bleiben.
+            square: square.try_into().unwrap(),
+        })
+    }
+}
+
+impl Display for PlayfairKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.keyword)
+    }
+}
+
+/// Der Schlüssel der Hill-Chiffre: eine über Z/26Z invertierbare quadratische Matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HillKey {
+    matrix: ModularMatrix,
+}
+
+symmetric_key!(HillKey, HillScheme);
+
+/// Die größte Dimension einer Hill-Matrix, die als Schlüssel gelesen oder erzeugt wird.
+pub const MAXIMUM_HILL_DIMENSION: usize = 16;
+
+impl HillKey {
+    /// Erstellt den Schlüssel.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidKeyError` - Falls die Matrix nicht modulo 26 rechnet oder
+    ///   nicht invertierbar ist.
+    pub fn new(matrix: ModularMatrix, service: NumberTheoryService) -> Result<Self> {
+        ensure!(
+            matrix.modulus() == &BigInt::from(ALPHABET_SIZE),
+            InvalidKeyError("matrix must be defined modulo 26".to_string())
+        );
+        let determinant = matrix.determinant();
+        ensure!(
+            service
+                .modulo_inverse(&determinant, matrix.modulus())
+                .is_ok(),
+            InvalidKeyError(format!(
+                "determinant {} is not coprime to 26, the matrix is not invertible",
+                determinant
+            ))
+        );
+        Ok(HillKey { matrix })
+    }
+
+    pub fn matrix(&self) -> &ModularMatrix {
+        &self.matrix
+    }
+
+    /// Der Schlüssel zum Entschlüsseln mit der inversen Matrix.
+    pub fn inverse(&self, service: NumberTheoryService) -> HillKey {
+        HillKey {
+            // Das unwrap() schlägt niemals fehl, weil die Invertierbarkeit in new geprüft wird.
+            matrix: self.matrix.inverse(service).unwrap(),
+        }
+    }
+}
+
+impl FromStr for HillKey {
+    type Err = anyhow::Error;
+
+    /// Liest die Matrix zeilenweise als kommagetrennte Liste, z.B. "3,3,2,5" für eine 2x2-Matrix.
+    fn from_str(value: &str) -> Result<Self> {
+        let entries: Vec<BigInt> = value
+            .split(',')
+            .map(|entry| entry.trim().parse())
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| InvalidKeyError(format!("{} is not a list of numbers", value)))?;
+        let dimension = (entries.len() as f64).sqrt() as usize;
+        ensure!(
+            dimension > 0 && dimension * dimension == entries.len(),
+            InvalidKeyError(format!(
+                "{} entries do not form a square matrix",
+                entries.len()
+            ))
+        );
+        ensure!(
+            dimension <= MAXIMUM_HILL_DIMENSION,
+            InvalidKeyError(format!(
+                "dimension {} exceeds the maximum of {}",
+                dimension, MAXIMUM_HILL_DIMENSION
+            ))
+        );
+        let rows = entries.chunks(dimension).map(|row| row.to_vec()).collect();
+        HillKey::new(
+            ModularMatrix::new(rows, ALPHABET_SIZE.into())?,
+            NumberTheoryService::new(Fast),
+        )
+    }
+}
+
+impl Display for HillKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self
+            .matrix
+            .rows()
+            .iter()
+            .flatten()
+            .map(|entry| entry.to_string())
+            .collect();
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+/// Der Schlüssel der Spaltentransposition: die Reihenfolge, in der die Spalten ausgelesen werden.
+/// Sie ergibt sich aus der alphabetischen Ordnung der Buchstaben des Schlüsselworts, bei gleichen
+/// Buchstaben von links nach rechts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnarTranspositionKey {
+    keyword: String,
+    column_order: Vec<usize>,
+}
+
+symmetric_key!(ColumnarTranspositionKey, ColumnarTranspositionScheme);
+
+impl ColumnarTranspositionKey {
+    /// Die Indizes der Spalten in der Reihenfolge, in der sie ausgelesen werden.
+    pub fn column_order(&self) -> &[usize] {
+        &self.column_order
+    }
+}
+
+impl FromStr for ColumnarTranspositionKey {
+    type Err = anyhow::Error;
+
+    /// Liest das Schlüsselwort, z.B. "ZEBRAS".
+    fn from_str(keyword: &str) -> Result<Self> {
+        let letters = parse_keyword(keyword)?;
+        ensure!(
+            !letters.is_empty(),
+            InvalidKeyError("keyword must not be empty".to_string())
+        );
+        let mut column_order: Vec<usize> = (0..letters.len()).collect();
+        column_order.sort_by_key(|column| letters[*column]);
+        Ok(ColumnarTranspositionKey {
+            keyword: keyword.to_uppercase(),
+            column_order,
+        })
+    }
+}
+
+impl Display for ColumnarTranspositionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.keyword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("-3".parse::<CaesarKey>().unwrap().shift, 23);
+        assert_eq!("5, 8".parse::<AffineKey>().unwrap().to_string(), "5,8");
+        assert_eq!("5,8".parse::<AffineKey>().unwrap().a_inverse(), 21);
+        assert_eq!("lemon".parse::<VigenereKey>().unwrap().to_string(), "LEMON");
+        assert_eq!("3,3,2,5".parse::<HillKey>().unwrap().to_string(), "3,3,2,5");
+        assert_eq!(
+            "ZEBRAS"
+                .parse::<ColumnarTranspositionKey>()
+                .unwrap()
+                .column_order(),
+            &[4, 2, 1, 3, 5, 0]
+        );
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        assert!("x".parse::<CaesarKey>().is_err());
+        assert!("13,1".parse::<AffineKey>().is_err());
+        assert!("5".parse::<AffineKey>().is_err());
+        assert!("".parse::<VigenereKey>().is_err());
+        assert!("KEY WORD".parse::<VigenereKey>().is_err());
+        assert!("2,0,0,1".parse::<HillKey>().is_err());
+        assert!("1,2,3".parse::<HillKey>().is_err());
+        let too_large = vec!["1"; (MAXIMUM_HILL_DIMENSION + 1).pow(2)].join(",");
+        assert!(too_large.parse::<HillKey>().is_err());
+        assert!("".parse::<ColumnarTranspositionKey>().is_err());
+    }
+
+    #[test]
+    fn test_playfair_square() {
+        let key: PlayfairKey = "playfair example".replace(' ', "").parse().unwrap();
+        let square: String = key.square.iter().map(|index| letter(*index)).collect();
+        assert_eq!(square, "PLAYFIREXMBCDGHKNOQSTUVWZ");
+        assert_eq!(key.position(PLAYFAIR_J), key.position(PLAYFAIR_J - 1));
+    }
+}
@@ -0,0 +1,10 @@
+pub mod affine_scheme;
+pub mod alphabet;
+pub mod caesar_scheme;
+pub mod classic_cipher;
+pub mod columnar_transposition_scheme;
+pub mod cryptanalysis;
+pub mod hill_scheme;
+pub mod keys;
+pub mod playfair_scheme;
+pub mod vigenere_scheme;
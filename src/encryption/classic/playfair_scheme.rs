@@ -0,0 +1,203 @@
+use anyhow::{ensure, Result};
+
+use crate::encryption::classic::alphabet::{letter, letter_indices, KeyRandomness};
+use crate::encryption::classic::keys::{ClassicKeyPair, PlayfairKey, PLAYFAIR_J};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+use crate::shared::errors::ClassicCipherError::{
+    InvalidCharacterError, InvalidCiphertextLengthError,
+};
+
+/// Der Füllbuchstabe X, der doppelte Buchstaben in einem Paar trennt und ungerade Längen auffüllt.
+const FILLER: u8 = 23;
+
+/// Der Füllbuchstabe Q, der verwendet wird, wenn der zu trennende Buchstabe selbst ein X ist.
+const ALTERNATIVE_FILLER: u8 = 16;
+
+/// Die Playfair-Chiffre, die Buchstabenpaare über ein 5x5-Quadrat verschlüsselt.
+/// Vor der Verschlüsselung werden alle Zeichen außer Buchstaben entfernt, J durch I ersetzt und
+/// doppelte Buchstaben innerhalb eines Paars durch ein X getrennt. Der Geheimtext besteht aus
+/// Großbuchstaben, die Füllbuchstaben bleiben bei der Entschlüsselung erhalten.
+pub struct PlayfairScheme {}
+
+impl EncryptionScheme for PlayfairScheme {}
+
+impl SymmetricEncryptionScheme for PlayfairScheme {}
+
+impl Encryptor<PlayfairScheme> for PlayfairScheme {
+    type Input = str;
+    type Output = String;
+    type Key = PlayfairKey;
+}
+
+impl SymmetricEncryptor<PlayfairScheme> for PlayfairScheme {
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let letters: Vec<u8> = letter_indices(plaintext)
+            .into_iter()
+            .map(|index| {
+                if index == PLAYFAIR_J {
+                    PLAYFAIR_J - 1
+                } else {
+                    index
+                }
+            })
+            .collect();
+
+        let mut pairs = Vec::with_capacity(letters.len() / 2 + 1);
+        let mut i = 0;
+        while i < letters.len() {
+            let first = letters[i];
+            match letters.get(i + 1) {
+                Some(second) if *second != first => {
+                    pairs.push((first, *second));
+                    i += 2;
+                }
+                _ => {
+                    let filler = if first == FILLER {
+                        ALTERNATIVE_FILLER
+                    } else {
+                        FILLER
+                    };
+                    pairs.push((first, filler));
+                    i += 1;
+                }
+            }
+        }
+
+        pairs
+            .into_iter()
+            .flat_map(|(first, second)| transform_pair(key, first, second, 1))
+            .map(letter)
+            .collect()
+    }
+}
+
+impl Decryptor<PlayfairScheme> for PlayfairScheme {
+    type Input = str;
+    type Output = Result<String>;
+    type Key = PlayfairKey;
+}
+
+impl SymmetricDecryptor<PlayfairScheme> for PlayfairScheme {
+    /// Entschlüsselt den Geheimtext. Leerzeichen und Satzzeichen werden ignoriert.
+    ///
+    /// # Fehler
+    /// * `ClassicCipherError::InvalidCharacterError` - Falls der Geheimtext ein J enthält.
+    /// * `ClassicCipherError::InvalidCiphertextLengthError` - Falls die Anzahl der Buchstaben
+    ///   ungerade ist.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let letters = letter_indices(ciphertext);
+        ensure!(
+            !letters.contains(&PLAYFAIR_J),
+            InvalidCharacterError(letter(PLAYFAIR_J))
+        );
+        ensure!(
+            letters.len().is_multiple_of(2),
+            InvalidCiphertextLengthError(2, letters.len())
+        );
+
+        Ok(letters
+            .chunks(2)
+            .flat_map(|pair| transform_pair(key, pair[0], pair[1], 4))
+            .map(letter)
+            .collect())
+    }
+}
+
+/// Ver- oder entschlüsselt ein Paar. Liegen beide Buchstaben in derselben Zeile oder Spalte, wird
+/// um `step` Positionen weitergegangen (1 zum Verschlüsseln, 4 zum Entschlüsseln), andernfalls
+/// werden die Spalten der Ecken des aufgespannten Rechtecks getauscht.
+fn transform_pair(key: &PlayfairKey, first: u8, second: u8, step: usize) -> [u8; 2] {
+    let (row_1, column_1) = key.position(first);
+    let (row_2, column_2) = key.position(second);
+    if row_1 == row_2 {
+        [
+            key.at(row_1, column_1 + step),
+            key.at(row_2, column_2 + step),
+        ]
+    } else if column_1 == column_2 {
+        [
+            key.at(row_1 + step, column_1),
+            key.at(row_2 + step, column_2),
+        ]
+    } else {
+        [key.at(row_1, column_2), key.at(row_2, column_1)]
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen Schlüsselworts für das Quadrat.
+///
+/// # Felder
+/// * `key_length` - Die Länge des Schlüsselworts.
+/// * `random_seed` - Der Seed für den Zufallszahlengenerator.
+pub struct PlayfairKeyGenConfig {
+    pub key_length: usize,
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<PlayfairKey, PlayfairKey, PlayfairScheme> for PlayfairKeyGenConfig {
+    type KeyPair = ClassicKeyPair<PlayfairKey>;
+
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let random = KeyRandomness::new(self.random_seed);
+        // Das unwrap() schlägt niemals fehl, weil das Wort nur aus Buchstaben besteht.
+        let key = random.word(self.key_length).parse().unwrap();
+        ClassicKeyPair::symmetric(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+    use crate::shared::errors::ClassicCipherError;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key: PlayfairKey = "PLAYFAIREXAMPLE".parse().unwrap();
+        let ciphertext = PlayfairScheme::encrypt("Hide the gold in the tree stump", &key);
+        assert_eq!(ciphertext, "BMODZBXDNABEKUDMUIXMMOUVIF");
+        assert_eq!(
+            PlayfairScheme::decrypt(&ciphertext, &key).unwrap(),
+            "HIDETHEGOLDINTHETREXESTUMP"
+        );
+    }
+
+    #[test]
+    fn test_fillers() {
+        let key: PlayfairKey = "KEYWORD".parse().unwrap();
+        // XX wird durch Q getrennt, die ungerade Länge mit X aufgefüllt und J durch I ersetzt.
+        let ciphertext = PlayfairScheme::encrypt("xxjj", &key);
+        assert_eq!(
+            PlayfairScheme::decrypt(&ciphertext, &key).unwrap(),
+            "XQXIIX"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let key: PlayfairKey = "KEYWORD".parse().unwrap();
+        let error = PlayfairScheme::decrypt("ABC", &key).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ClassicCipherError>(),
+            Some(ClassicCipherError::InvalidCiphertextLengthError(2, 3))
+        ));
+        assert!(PlayfairScheme::decrypt("AJ", &key).is_err());
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        let key_pair = PlayfairKeyGenConfig {
+            key_length: 10,
+            random_seed: 5,
+        }
+        .generate_keypair();
+        let ciphertext = PlayfairScheme::encrypt("WIKIPEDIA", &key_pair.encryption());
+        assert_eq!(
+            PlayfairScheme::decrypt(&ciphertext, &key_pair.decryption()).unwrap(),
+            "WIKIPEDIAX"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::encryption::classic::alphabet::{substitute_letters, KeyRandomness, ALPHABET_SIZE};
+use crate::encryption::classic::keys::{ClassicKeyPair, VigenereKey};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+
+/// Die Vigenère-Chiffre, die den i-ten Buchstaben um den (i mod k)-ten Buchstaben des
+/// Schlüsselworts der Länge k verschiebt. Zeichen, die keine Buchstaben sind, werden übernommen
+/// und verbrauchen keinen Buchstaben des Schlüsselworts.
+pub struct VigenereScheme {}
+
+impl EncryptionScheme for VigenereScheme {}
+
+impl SymmetricEncryptionScheme for VigenereScheme {}
+
+impl Encryptor<VigenereScheme> for VigenereScheme {
+    type Input = str;
+    type Output = String;
+    type Key = VigenereKey;
+}
+
+impl SymmetricEncryptor<VigenereScheme> for VigenereScheme {
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let shifts = key.shifts();
+        substitute_letters(plaintext, |index, position| {
+            index + shifts[position % shifts.len()]
+        })
+    }
+}
+
+impl Decryptor<VigenereScheme> for VigenereScheme {
+    type Input = str;
+    type Output = String;
+    type Key = VigenereKey;
+}
+
+impl SymmetricDecryptor<VigenereScheme> for VigenereScheme {
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let shifts = key.shifts();
+        substitute_letters(ciphertext, |index, position| {
+            index + ALPHABET_SIZE - shifts[position % shifts.len()]
+        })
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen Schlüsselworts.
+///
+/// # Felder
+/// * `key_length` - Die Länge des Schlüsselworts, mindestens 1.
+/// * `random_seed` - Der Seed für den Zufallszahlengenerator.
+pub struct VigenereKeyGenConfig {
+    pub key_length: usize,
+    pub random_seed: u32,
+}
+
+impl KeyGenerator<VigenereKey, VigenereKey, VigenereScheme> for VigenereKeyGenConfig {
+    type KeyPair = ClassicKeyPair<VigenereKey>;
+
+    fn generate_keypair(&self) -> Self::KeyPair {
+        let random = KeyRandomness::new(self.random_seed);
+        // Das unwrap() schlägt niemals fehl, weil das Wort aus mindestens einem Buchstaben besteht.
+        let key = random.word(self.key_length.max(1)).parse().unwrap();
+        ClassicKeyPair::symmetric(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::symmetric_encryption_types::SymmetricKeyPair;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key: VigenereKey = "LEMON".parse().unwrap();
+        assert_eq!(
+            VigenereScheme::encrypt("Attack at dawn", &key),
+            "Lxfopv ef rnhr"
+        );
+        assert_eq!(
+            VigenereScheme::decrypt("Lxfopv ef rnhr", &key),
+            "Attack at dawn"
+        );
+    }
+
+    #[test]
+    fn test_generate_keypair() {
+        let key_pair = VigenereKeyGenConfig {
+            key_length: 7,
+            random_seed: 42,
+        }
+        .generate_keypair();
+        assert_eq!(key_pair.encryption().shifts().len(), 7);
+
+        let message = "Le chiffre indéchiffrable";
+        let ciphertext = VigenereScheme::encrypt(message, &key_pair.encryption());
+        assert_eq!(
+            VigenereScheme::decrypt(&ciphertext, &key_pair.decryption()),
+            message
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use anyhow::{ensure, Result};
+
+use crate::shared::errors::AesError::InvalidKeyLengthError;
+
+/// Blockgröße von AES in Bytes.
+pub const BLOCK_SIZE: usize = 16;
+
+/// Ein AES-Block.
+pub type Block = [u8; BLOCK_SIZE];
+
+/// Die S-Box von AES, berechnet aus dem multiplikativen Inversen in GF(2^8) und der affinen
+/// Abbildung aus FIPS-197, Abschnitt 5.1.1.
+const S_BOX: [u8; 256] = build_s_box();
+
+/// Die inverse S-Box von AES.
+const INVERSE_S_BOX: [u8; 256] = build_inverse_s_box();
+
+/// Die Rundenkonstanten Rcon[i] = x^(i-1) in GF(2^8) für die Schlüsselexpansion.
+const ROUND_CONSTANTS: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Die Blockchiffre AES (FIPS-197) für Schlüssel mit 128, 192 oder 256 Bit.
+/// Der Zustand wird wie im Standard spaltenweise abgelegt, d.h. Byte `i` der Eingabe liegt in
+/// Zeile `i % 4` und Spalte `i / 4`.
+#[derive(Clone, Debug)]
+pub struct AesBlockCipher {
+    round_keys: Vec<Block>,
+}
+
+impl AesBlockCipher {
+    /// Erstellt die Blockchiffre und berechnet die Rundenschlüssel.
+    ///
+    /// # Argumente
+    /// * `key` - Der Schlüssel mit 16, 24 oder 32 Bytes.
+    ///
+    /// # Fehler
+    /// * `AesError::InvalidKeyLengthError` - Falls der Schlüssel eine andere Länge hat.
+    pub fn new(key: &[u8]) -> Result<Self> {
+        ensure!(
+            matches!(key.len(), 16 | 24 | 32),
+            InvalidKeyLengthError(key.len())
+        );
+        Ok(AesBlockCipher {
+            round_keys: expand_key(key),
+        })
+    }
+
+    /// Die Anzahl der Runden Nr (10, 12 oder 14).
+    pub fn rounds(&self) -> usize {
+        self.round_keys.len() - 1
+    }
+
+    /// Verschlüsselt einen einzelnen Block (FIPS-197, Abschnitt 5.1).
+    pub fn encrypt_block(&self, block: &Block) -> Block {
+        let mut state = *block;
+        add_round_key(&mut state, &self.round_keys[0]);
+        for round in 1..self.rounds() {
+            sub_bytes(&mut state, &S_BOX);
+            shift_rows(&mut state);
+            mix_columns(&mut state);
+            add_round_key(&mut state, &self.round_keys[round]);
+        }
+        sub_bytes(&mut state, &S_BOX);
+        shift_rows(&mut state);
+        add_round_key(&mut state, &self.round_keys[self.rounds()]);
+        state
+    }
+
+    /// Entschlüsselt einen einzelnen Block mit der inversen Chiffre (FIPS-197, Abschnitt 5.3).
+    pub fn decrypt_block(&self, block: &Block) -> Block {
+        let mut state = *block;
+        add_round_key(&mut state, &self.round_keys[self.rounds()]);
+        for round in (1..self.rounds()).rev() {
+            inverse_shift_rows(&mut state);
+            sub_bytes(&mut state, &INVERSE_S_BOX);
+            add_round_key(&mut state, &self.round_keys[round]);
+            inverse_mix_columns(&mut state);
+        }
+        inverse_shift_rows(&mut state);
+        sub_bytes(&mut state, &INVERSE_S_BOX);
+        add_round_key(&mut state, &self.round_keys[0]);
+        state
+    }
+}
+
+/// Berechnet die Rundenschlüssel aus dem Schlüssel (FIPS-197, Abschnitt 5.2).
+fn expand_key(key: &[u8]) -> Vec<Block> {
+    let key_words = key.len() / 4;
+    let rounds = key_words + 6;
+    let total_words = 4 * (rounds + 1);
+
+    let mut words: Vec<[u8; 4]> = key
+        .chunks(4)
+        .map(|word| [word[0], word[1], word[2], word[3]])
+        .collect();
+    for i in key_words..total_words {
+        let mut temp = words[i - 1];
+        if i % key_words == 0 {
+            // RotWord, SubWord und Rundenkonstante
+            temp = [
+                S_BOX[temp[1] as usize] ^ ROUND_CONSTANTS[i / key_words - 1],
+                S_BOX[temp[2] as usize],
+                S_BOX[temp[3] as usize],
+                S_BOX[temp[0] as usize],
+            ];
+        } else if key_words > 6 && i % key_words == 4 {
+            temp = temp.map(|byte| S_BOX[byte as usize]);
+        }
+        let previous = words[i - key_words];
+        words.push([
+            previous[0] ^ temp[0],
+            previous[1] ^ temp[1],
+            previous[2] ^ temp[2],
+            previous[3] ^ temp[3],
+        ]);
+    }
+
+    words
+        .chunks(4)
+        .map(|round_words| {
+            let mut round_key = [0u8; BLOCK_SIZE];
+            for (i, word) in round_words.iter().enumerate() {
+                round_key[4 * i..4 * i + 4].copy_from_slice(word);
+            }
+            round_key
+        })
+        .collect()
+}
+
+fn add_round_key(state: &mut Block, round_key: &Block) {
+    for (byte, key_byte) in state.iter_mut().zip(round_key) {
+        *byte ^= key_byte;
+    }
+}
+
+fn sub_bytes(state: &mut Block, s_box: &[u8; 256]) {
+    for byte in state.iter_mut() {
+        *byte = s_box[*byte as usize];
+    }
+}
+
+/// Rotiert Zeile r um r Positionen nach links.
+fn shift_rows(state: &mut Block) {
+    let old = *state;
+    for row in 1..4 {
+        for column in 0..4 {
+            state[row + 4 * column] = old[row + 4 * ((column + row) % 4)];
+        }
+    }
+}
+
+/// Rotiert Zeile r um r Positionen nach rechts.
+fn inverse_shift_rows(state: &mut Block) {
+    let old = *state;
+    for row in 1..4 {
+        for column in 0..4 {
+            state[row + 4 * ((column + row) % 4)] = old[row + 4 * column];
+        }
+    }
+}
+
+/// Multipliziert jede Spalte mit dem Polynom 3x^3 + x^2 + x + 2 modulo x^4 + 1.
+fn mix_columns(state: &mut Block) {
+    for column in state.chunks_mut(4) {
+        let [a0, a1, a2, a3] = [column[0], column[1], column[2], column[3]];
+        column[0] = multiply(a0, 2) ^ multiply(a1, 3) ^ a2 ^ a3;
+        column[1] = a0 ^ multiply(a1, 2) ^ multiply(a2, 3) ^ a3;
+        column[2] = a0 ^ a1 ^ multiply(a2, 2) ^ multiply(a3, 3);
+        column[3] = multiply(a0, 3) ^ a1 ^ a2 ^ multiply(a3, 2);
+    }
+}
+
+/// Multipliziert jede Spalte mit dem Polynom 11x^3 + 13x^2 + 9x + 14 modulo x^4 + 1.
+fn inverse_mix_columns(state: &mut Block) {
+    for column in state.chunks_mut(4) {
+        let [a0, a1, a2, a3] = [column[0], column[1], column[2], column[3]];
+        column[0] = multiply(a0, 14) ^ multiply(a1, 11) ^ multiply(a2, 13) ^ multiply(a3, 9);
+        column[1] = multiply(a0, 9) ^ multiply(a1, 14) ^ multiply(a2, 11) ^ multiply(a3, 13);
+        column[2] = multiply(a0, 13) ^ multiply(a1, 9) ^ multiply(a2, 14) ^ multiply(a3, 11);
+        column[3] = multiply(a0, 11) ^ multiply(a1, 13) ^ multiply(a2, 9) ^ multiply(a3, 14);
+    }
+}
+
+/// Multipliziert zwei Elemente in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1.
+const fn multiply(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        // xtime: Multiplikation mit x und Reduktion um 0x1b bei Überlauf
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+        b >>= 1;
+    }
+    product
+}
+
+/// Berechnet das multiplikative Inverse in GF(2^8) als a^254; die 0 wird auf sich selbst abgebildet.
+const fn inverse(a: u8) -> u8 {
+    let mut result = 1;
+    let mut i = 0;
+    while i < 254 {
+        result = multiply(result, a);
+        i += 1;
+    }
+    if a == 0 {
+        0
+    } else {
+        result
+    }
+}
+
+const fn build_s_box() -> [u8; 256] {
+    let mut s_box = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        let b = inverse(i as u8);
+        s_box[i] =
+            b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63;
+        i += 1;
+    }
+    s_box
+}
+
+const fn build_inverse_s_box() -> [u8; 256] {
+    let s_box = build_s_box();
+    let mut inverse_s_box = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        inverse_s_box[s_box[i] as usize] = i as u8;
+        i += 1;
+    }
+    inverse_s_box
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::shared::errors::AesError;
+
+    /// Wandelt eine Hex-Zeichenkette in Bytes um.
+    pub(crate) fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn block(value: &str) -> Block {
+        hex(value).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_s_box() {
+        // FIPS-197, Abbildung 7 und 14
+        assert_eq!(S_BOX[0x00], 0x63);
+        assert_eq!(S_BOX[0x53], 0xed);
+        assert_eq!(S_BOX[0xff], 0x16);
+        assert_eq!(INVERSE_S_BOX[0xed], 0x53);
+    }
+
+    #[test]
+    fn test_key_expansion_fips_197_appendix_a1() {
+        let cipher = AesBlockCipher::new(&hex("2b7e151628aed2a6abf7158809cf4f3c")).unwrap();
+        assert_eq!(cipher.rounds(), 10);
+        assert_eq!(
+            cipher.round_keys[10],
+            block("d014f9a8c9ee2589e13f0cc8b6630ca6")
+        );
+    }
+
+    #[test]
+    fn test_fips_197_appendix_c() {
+        let plaintext = block("00112233445566778899aabbccddeeff");
+        let vectors = [
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "69c4e0d86a7b0430d8cdb78070b4c55a",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f1011121314151617",
+                "dda97ca4864cdfe06eaf70a0ec0d7191",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+                "8ea2b7ca516745bfeafc49904b496089",
+            ),
+        ];
+
+        for (key, ciphertext) in vectors {
+            let cipher = AesBlockCipher::new(&hex(key)).unwrap();
+            assert_eq!(cipher.encrypt_block(&plaintext), block(ciphertext));
+            assert_eq!(cipher.decrypt_block(&block(ciphertext)), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        let error = AesBlockCipher::new(&[0u8; 20]).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::InvalidKeyLengthError(20))
+        ));
+    }
+}
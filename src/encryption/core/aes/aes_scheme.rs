@@ -0,0 +1,233 @@
+use anyhow::{ensure, Result};
+
+use crate::encryption::core::aes::aes_block_cipher::{AesBlockCipher, Block};
+use crate::encryption::core::aes::block_modes::{
+    cbc_decrypt, cbc_encrypt, ctr_apply, ecb_decrypt, ecb_encrypt, pkcs7_pad, pkcs7_unpad,
+};
+use crate::encryption::core::aes::galois_counter_mode::{
+    gcm_decrypt_with_appended_tag, gcm_encrypt,
+};
+use crate::encryption::core::aes::keys::{AesKey, AesMode};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+use crate::shared::errors::AesError::{InvalidCiphertextLengthError, InvalidIvLengthError};
+
+/// Das symmetrische Verschlüsselungsschema AES (FIPS-197) in einem der Betriebsmodi ECB, CBC,
+/// CTR oder GCM (SP 800-38A und SP 800-38D).
+/// In den Modi ECB und CBC wird der Klartext mit PKCS#7 aufgefüllt, in GCM wird das
+/// Authentifizierungs-Tag an den Geheimtext angehängt.
+pub struct AesScheme {}
+
+impl EncryptionScheme for AesScheme {}
+
+impl SymmetricEncryptionScheme for AesScheme {}
+
+impl Encryptor<AesScheme> for AesScheme {
+    type Input = [u8];
+    type Output = Result<Vec<u8>>;
+    type Key = AesKey;
+}
+
+impl SymmetricEncryptor<AesScheme> for AesScheme {
+    /// Verschlüsselt eine beliebige Bytefolge. Der Initialisierungsvektor bzw. Zählerstartwert
+    /// wird für jede Nachricht neu gewählt, siehe `encrypt_with_iv`.
+    ///
+    /// # Argumente
+    /// * `plaintext` - Der Klartext.
+    /// * `key` - Der Schlüssel mit dem Betriebsmodus.
+    ///
+    /// # Rückgabe
+    /// Der Initialisierungsvektor gefolgt vom Geheimtext, im Modus GCM zusätzlich gefolgt von dem
+    /// 16 Bytes langen Tag.
+    ///
+    /// # Fehler
+    /// * `AesError::InvalidKeyLengthError` - Falls der Schlüssel nicht 16, 24 oder 32 Bytes lang ist.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        AesScheme::encrypt_with_iv(plaintext, key, &key.mode.generate_iv())
+    }
+}
+
+impl AesScheme {
+    /// Verschlüsselt eine Bytefolge mit dem gegebenen Initialisierungsvektor bzw. Zählerstartwert
+    /// und stellt diesen dem Geheimtext voran. Ein Initialisierungsvektor darf mit demselben
+    /// Schlüssel nicht wiederverwendet werden.
+    ///
+    /// # Argumente
+    /// * `plaintext` - Der Klartext.
+    /// * `key` - Der Schlüssel mit dem Betriebsmodus.
+    /// * `iv` - Der Initialisierungsvektor. Leer im Modus ECB.
+    ///
+    /// # Fehler
+    /// * `AesError::InvalidKeyLengthError` - Falls der Schlüssel nicht 16, 24 oder 32 Bytes lang ist.
+    /// * `AesError::InvalidIvLengthError` - Falls der Initialisierungsvektor nicht zum Modus passt.
+    pub fn encrypt_with_iv(plaintext: &[u8], key: &AesKey, iv: &[u8]) -> Result<Vec<u8>> {
+        let cipher = AesBlockCipher::new(&key.key)?;
+        ensure!(
+            iv.len() == key.mode.iv_size(),
+            InvalidIvLengthError(key.mode.iv_size(), iv.len())
+        );
+
+        let mut ciphertext = iv.to_vec();
+        match &key.mode {
+            AesMode::Ecb => ciphertext.extend(ecb_encrypt(&cipher, &pkcs7_pad(plaintext))?),
+            AesMode::Cbc => {
+                ciphertext.extend(cbc_encrypt(&cipher, &to_block(iv), &pkcs7_pad(plaintext))?)
+            }
+            AesMode::Ctr => ciphertext.extend(ctr_apply(&cipher, &to_block(iv), plaintext)),
+            AesMode::Gcm { aad } => {
+                let (encrypted, tag) = gcm_encrypt(&cipher, iv, aad, plaintext)?;
+                ciphertext.extend(encrypted);
+                ciphertext.extend_from_slice(&tag);
+            }
+        }
+        Ok(ciphertext)
+    }
+}
+
+/// Wandelt einen Initialisierungsvektor, dessen Länge bereits geprüft wurde, in einen Block um.
+fn to_block(iv: &[u8]) -> Block {
+    iv.try_into().unwrap()
+}
+
+impl Decryptor<AesScheme> for AesScheme {
+    type Input = [u8];
+    type Output = Result<Vec<u8>>;
+    type Key = AesKey;
+}
+
+impl SymmetricDecryptor<AesScheme> for AesScheme {
+    /// Entschlüsselt eine Bytefolge.
+    ///
+    /// # Argumente
+    /// * `ciphertext` - Der Initialisierungsvektor gefolgt vom Geheimtext, im Modus GCM inklusive Tag.
+    /// * `key` - Der Schlüssel mit dem Betriebsmodus.
+    ///
+    /// # Rückgabe
+    /// Der Klartext.
+    ///
+    /// # Fehler
+    /// * `AesError` - Falls Schlüssel, Länge oder Padding des Geheimtexts ungültig sind oder die
+    ///   Authentifizierung in GCM fehlschlägt.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        let cipher = AesBlockCipher::new(&key.key)?;
+        ensure!(
+            ciphertext.len() >= key.mode.iv_size(),
+            InvalidCiphertextLengthError(ciphertext.len())
+        );
+        let (iv, ciphertext) = ciphertext.split_at(key.mode.iv_size());
+
+        match &key.mode {
+            AesMode::Ecb => pkcs7_unpad(&ecb_decrypt(&cipher, ciphertext)?),
+            AesMode::Cbc => pkcs7_unpad(&cbc_decrypt(&cipher, &to_block(iv), ciphertext)?),
+            AesMode::Ctr => Ok(ctr_apply(&cipher, &to_block(iv), ciphertext)),
+            AesMode::Gcm { aad } => gcm_decrypt_with_appended_tag(&cipher, iv, aad, ciphertext),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::keys::{AesKeyGenConfig, AesModeName};
+    use crate::encryption::symmetric_encryption_types::{KeyGenerator, SymmetricKeyPair};
+    use crate::shared::errors::AesError;
+
+    #[test]
+    fn test_encrypt_decrypt_all_modes() {
+        let message = "Nachrichten beliebiger Länge, auch über mehrere Blöcke hinweg.".as_bytes();
+        for mode in [
+            AesModeName::Ecb,
+            AesModeName::Cbc,
+            AesModeName::Ctr,
+            AesModeName::Gcm,
+        ] {
+            let key_pair = AesKeyGenConfig::new(256, mode).unwrap().generate_keypair();
+            let ciphertext = AesScheme::encrypt(message, &key_pair.encryption()).unwrap();
+            assert_ne!(ciphertext, message);
+            let plaintext = AesScheme::decrypt(&ciphertext, &key_pair.decryption()).unwrap();
+            assert_eq!(plaintext, message);
+        }
+    }
+
+    #[test]
+    fn test_fresh_iv_per_message() {
+        let message = b"Dieselbe Nachricht";
+        for mode in [AesModeName::Cbc, AesModeName::Ctr, AesModeName::Gcm] {
+            let key = AesKeyGenConfig::new(128, mode)
+                .unwrap()
+                .generate_keypair()
+                .encryption();
+            let first = AesScheme::encrypt(message, &key).unwrap();
+            let second = AesScheme::encrypt(message, &key).unwrap();
+            assert_ne!(first[..key.mode.iv_size()], second[..key.mode.iv_size()]);
+            assert_ne!(first, second);
+            assert_eq!(AesScheme::decrypt(&second, &key).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_ciphertext_lengths() {
+        let key = vec![0u8; 16];
+        let with_mode = |mode| AesKey {
+            key: key.clone(),
+            mode,
+        };
+        let message = [1u8; 20];
+
+        let ecb = AesScheme::encrypt(&message, &with_mode(AesMode::Ecb)).unwrap();
+        assert_eq!(ecb.len(), 32);
+        let ctr = AesScheme::encrypt(&message, &with_mode(AesMode::Ctr)).unwrap();
+        assert_eq!(ctr.len(), 16 + 20);
+        let gcm = AesScheme::encrypt(&message, &with_mode(AesMode::Gcm { aad: vec![] })).unwrap();
+        assert_eq!(gcm.len(), 12 + 20 + 16);
+    }
+
+    #[test]
+    fn test_encrypt_with_iv() {
+        let key = AesKey {
+            key: vec![0u8; 16],
+            mode: AesMode::Cbc,
+        };
+        let ciphertext = AesScheme::encrypt_with_iv(b"Nachricht", &key, &[7u8; 16]).unwrap();
+        assert_eq!(ciphertext[..16], [7u8; 16]);
+        assert_eq!(AesScheme::decrypt(&ciphertext, &key).unwrap(), b"Nachricht");
+
+        let error = AesScheme::encrypt_with_iv(b"Nachricht", &key, &[7u8; 12]).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::InvalidIvLengthError(16, 12))
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let key = AesKey {
+            key: vec![0u8; 16],
+            mode: AesMode::Cbc,
+        };
+        let error = AesScheme::decrypt(&[0u8; 31], &key).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::InvalidCiphertextLengthError(15))
+        ));
+        let error = AesScheme::decrypt(&[0u8; 10], &key).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::InvalidCiphertextLengthError(10))
+        ));
+
+        let gcm_key = AesKey {
+            key: vec![0u8; 16],
+            mode: AesMode::Gcm { aad: vec![] },
+        };
+        assert!(AesScheme::decrypt(&[0u8; 20], &gcm_key).is_err());
+
+        let invalid_key = AesKey {
+            key: vec![0u8; 17],
+            mode: AesMode::Ecb,
+        };
+        assert!(AesScheme::encrypt(b"", &invalid_key).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use anyhow::{ensure, Result};
+
+use crate::encryption::core::aes::aes_block_cipher::{AesBlockCipher, Block, BLOCK_SIZE};
+use crate::shared::errors::AesError::{InvalidCiphertextLengthError, InvalidPaddingError};
+
+/// Füllt die Daten nach PKCS#7 (RFC 5652, Abschnitt 6.3) auf ein Vielfaches der Blockgröße auf.
+/// Es wird immer mindestens ein Byte angehängt, damit das Padding eindeutig entfernt werden kann.
+pub fn pkcs7_pad(data: &[u8]) -> Vec<u8> {
+    let padding = BLOCK_SIZE - data.len() % BLOCK_SIZE;
+    let mut padded = data.to_vec();
+    padded.resize(data.len() + padding, padding as u8);
+    padded
+}
+
+/// Entfernt das PKCS#7-Padding.
+///
+/// # Fehler
+/// * `AesError::InvalidPaddingError` - Falls das Padding ungültig ist.
+pub fn pkcs7_unpad(data: &[u8]) -> Result<Vec<u8>> {
+    let padding = *data.last().ok_or(InvalidPaddingError)? as usize;
+    ensure!(
+        (1..=BLOCK_SIZE).contains(&padding)
+            && padding <= data.len()
+            && data[data.len() - padding..]
+                .iter()
+                .all(|byte| *byte as usize == padding),
+        InvalidPaddingError
+    );
+    Ok(data[..data.len() - padding].to_vec())
+}
+
+/// Verschlüsselt vollständige Blöcke im Electronic-Codebook-Modus (SP 800-38A, Abschnitt 6.1).
+///
+/// # Fehler
+/// * `AesError::InvalidCiphertextLengthError` - Falls die Länge kein Vielfaches der Blockgröße ist.
+pub fn ecb_encrypt(cipher: &AesBlockCipher, data: &[u8]) -> Result<Vec<u8>> {
+    map_blocks(data, |block| cipher.encrypt_block(block))
+}
+
+/// Entschlüsselt vollständige Blöcke im Electronic-Codebook-Modus.
+pub fn ecb_decrypt(cipher: &AesBlockCipher, data: &[u8]) -> Result<Vec<u8>> {
+    map_blocks(data, |block| cipher.decrypt_block(block))
+}
+
+/// Verschlüsselt vollständige Blöcke im Cipher-Block-Chaining-Modus (SP 800-38A, Abschnitt 6.2).
+/// Jeder Klartextblock wird vor dem Verschlüsseln mit dem vorherigen Geheimtextblock verknüpft.
+pub fn cbc_encrypt(cipher: &AesBlockCipher, iv: &Block, data: &[u8]) -> Result<Vec<u8>> {
+    let mut previous = *iv;
+    map_blocks(data, |block| {
+        previous = cipher.encrypt_block(&xor_blocks(block, &previous));
+        previous
+    })
+}
+
+/// Entschlüsselt vollständige Blöcke im Cipher-Block-Chaining-Modus.
+pub fn cbc_decrypt(cipher: &AesBlockCipher, iv: &Block, data: &[u8]) -> Result<Vec<u8>> {
+    let mut previous = *iv;
+    map_blocks(data, |block| {
+        let plaintext = xor_blocks(&cipher.decrypt_block(block), &previous);
+        previous = *block;
+        plaintext
+    })
+}
+
+/// Ver- oder entschlüsselt beliebig lange Daten im Counter-Modus (SP 800-38A, Abschnitt 6.5).
+/// Der Zählerblock wird als 128-Bit-Zahl in Big-Endian-Darstellung inkrementiert.
+pub fn ctr_apply(cipher: &AesBlockCipher, initial_counter: &Block, data: &[u8]) -> Vec<u8> {
+    let mut counter = u128::from_be_bytes(*initial_counter);
+    let mut output = Vec::with_capacity(data.len());
+    for chunk in data.chunks(BLOCK_SIZE) {
+        let key_stream = cipher.encrypt_block(&counter.to_be_bytes());
+        output.extend(chunk.iter().zip(key_stream).map(|(a, b)| a ^ b));
+        counter = counter.wrapping_add(1);
+    }
+    output
+}
+
+/// Verknüpft zwei Blöcke byteweise mit XOR.
+pub(crate) fn xor_blocks(a: &Block, b: &Block) -> Block {
+    let mut result = *a;
+    for (byte, other) in result.iter_mut().zip(b) {
+        *byte ^= other;
+    }
+    result
+}
+
+fn map_blocks(data: &[u8], mut transform: impl FnMut(&Block) -> Block) -> Result<Vec<u8>> {
+    ensure!(
+        data.len().is_multiple_of(BLOCK_SIZE),
+        InvalidCiphertextLengthError(data.len())
+    );
+    Ok(data
+        .chunks(BLOCK_SIZE)
+        .flat_map(|chunk| transform(&chunk.try_into().unwrap()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    /// Klartext und Schlüssel aus SP 800-38A, Anhang F
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const KEY_128: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const KEY_256: &str = "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4";
+
+    #[test]
+    fn test_ecb_sp_800_38a_f_1() {
+        let cipher = AesBlockCipher::new(&hex(KEY_128)).unwrap();
+        let ciphertext = hex("3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4");
+        assert_eq!(ecb_encrypt(&cipher, &hex(PLAINTEXT)).unwrap(), ciphertext);
+        assert_eq!(ecb_decrypt(&cipher, &ciphertext).unwrap(), hex(PLAINTEXT));
+    }
+
+    #[test]
+    fn test_cbc_sp_800_38a_f_2() {
+        let iv: Block = hex("000102030405060708090a0b0c0d0e0f").try_into().unwrap();
+        let vectors = [
+            (KEY_128, "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b273bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7"),
+            (KEY_256, "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b"),
+        ];
+        for (key, ciphertext) in vectors {
+            let cipher = AesBlockCipher::new(&hex(key)).unwrap();
+            assert_eq!(
+                cbc_encrypt(&cipher, &iv, &hex(PLAINTEXT)).unwrap(),
+                hex(ciphertext)
+            );
+            assert_eq!(
+                cbc_decrypt(&cipher, &iv, &hex(ciphertext)).unwrap(),
+                hex(PLAINTEXT)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ctr_sp_800_38a_f_5() {
+        let counter: Block = hex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff").try_into().unwrap();
+        let vectors = [
+            (KEY_128, "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee"),
+            (KEY_256, "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c52b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6"),
+        ];
+        for (key, ciphertext) in vectors {
+            let cipher = AesBlockCipher::new(&hex(key)).unwrap();
+            assert_eq!(
+                ctr_apply(&cipher, &counter, &hex(PLAINTEXT)),
+                hex(ciphertext)
+            );
+            // Im Counter-Modus sind Ver- und Entschlüsselung identisch und die Länge ist beliebig.
+            assert_eq!(
+                ctr_apply(&cipher, &counter, &hex(&ciphertext[..42])),
+                hex(&PLAINTEXT[..42])
+            );
+        }
+    }
+
+    #[test]
+    fn test_pkcs7() {
+        assert_eq!(pkcs7_pad(&[]), vec![16u8; 16]);
+        assert_eq!(pkcs7_pad(&[1, 2, 3]).len(), 16);
+        assert_eq!(pkcs7_unpad(&pkcs7_pad(&[1, 2, 3])).unwrap(), vec![1, 2, 3]);
+
+        let mut invalid = pkcs7_pad(&[1, 2, 3]);
+        invalid[14] = 0;
+        assert!(pkcs7_unpad(&invalid).is_err());
+        assert!(pkcs7_unpad(&[0u8; 16]).is_err());
+        assert!(pkcs7_unpad(&[]).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{ensure, Result};
+
+use crate::encryption::core::aes::aes_block_cipher::{AesBlockCipher, Block, BLOCK_SIZE};
+use crate::encryption::core::aes::keys::GCM_IV_SIZE;
+use crate::shared::errors::AesError::{
+    AuthenticationFailedError, InvalidCiphertextLengthError, InvalidIvLengthError,
+};
+
+/// Länge des Authentifizierungs-Tags in Bytes.
+pub const TAG_SIZE: usize = 16;
+
+/// Das Reduktionspolynom x^128 + x^7 + x^2 + x + 1 in der bitgespiegelten Darstellung von GCM.
+const R: u128 = 0xe1 << 120;
+
+/// Verschlüsselt und authentifiziert die Daten im Galois/Counter-Modus (SP 800-38D).
+///
+/// # Argumente
+/// * `cipher` - Die Blockchiffre.
+/// * `iv` - Der Initialisierungsvektor. Empfohlen sind 12 Bytes, andere Längen werden über GHASH
+///   auf einen Zählerblock abgebildet.
+/// * `aad` - Zusätzliche Daten, die authentifiziert, aber nicht verschlüsselt werden.
+/// * `plaintext` - Der Klartext.
+///
+/// # Rückgabe
+/// Der Geheimtext und das Authentifizierungs-Tag.
+///
+/// # Fehler
+/// * `AesError::InvalidIvLengthError` - Falls der Initialisierungsvektor leer ist.
+pub fn gcm_encrypt(
+    cipher: &AesBlockCipher,
+    iv: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Block)> {
+    ensure!(!iv.is_empty(), InvalidIvLengthError(GCM_IV_SIZE, 0));
+    let h = u128::from_be_bytes(cipher.encrypt_block(&[0u8; BLOCK_SIZE]));
+    let j0 = pre_counter_block(h, iv);
+    let ciphertext = gctr(cipher, inc32(j0), plaintext);
+    let tag = compute_tag(cipher, h, j0, aad, &ciphertext);
+    Ok((ciphertext, tag))
+}
+
+/// Prüft das Authentifizierungs-Tag und entschlüsselt die Daten im Galois/Counter-Modus.
+///
+/// # Fehler
+/// * `AesError::InvalidIvLengthError` - Falls der Initialisierungsvektor leer ist.
+/// * `AesError::AuthenticationFailedError` - Falls das Tag nicht zu Geheimtext und AAD passt.
+pub fn gcm_decrypt(
+    cipher: &AesBlockCipher,
+    iv: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &Block,
+) -> Result<Vec<u8>> {
+    ensure!(!iv.is_empty(), InvalidIvLengthError(GCM_IV_SIZE, 0));
+    let h = u128::from_be_bytes(cipher.encrypt_block(&[0u8; BLOCK_SIZE]));
+    let j0 = pre_counter_block(h, iv);
+    let expected_tag = compute_tag(cipher, h, j0, aad, ciphertext);
+    // Vergleich ohne vorzeitigen Abbruch, damit die Laufzeit nichts über das Tag verrät.
+    let difference = expected_tag
+        .iter()
+        .zip(tag)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    ensure!(difference == 0, AuthenticationFailedError);
+    Ok(gctr(cipher, inc32(j0), ciphertext))
+}
+
+/// Trennt Geheimtext und angehängtes Tag und entschlüsselt anschließend.
+///
+/// # Fehler
+/// * `AesError::InvalidCiphertextLengthError` - Falls die Daten kürzer als ein Tag sind.
+/// * `AesError::AuthenticationFailedError` - Falls die Authentifizierung fehlschlägt.
+pub fn gcm_decrypt_with_appended_tag(
+    cipher: &AesBlockCipher,
+    iv: &[u8],
+    aad: &[u8],
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    ensure!(
+        data.len() >= TAG_SIZE,
+        InvalidCiphertextLengthError(data.len())
+    );
+    let (ciphertext, tag) = data.split_at(data.len() - TAG_SIZE);
+    gcm_decrypt(cipher, iv, aad, ciphertext, &tag.try_into().unwrap())
+}
+
+/// Multipliziert zwei Elemente in GF(2^128) nach SP 800-38D, Algorithmus 1.
+/// Das höchstwertige Bit des u128 entspricht dem Koeffizienten von x^0.
+pub fn gf_multiply(x: u128, y: u128) -> u128 {
+    let mut z = 0u128;
+    let mut v = y;
+    for i in 0..128 {
+        if (x >> (127 - i)) & 1 == 1 {
+            z ^= v;
+        }
+        v = if v & 1 == 1 { (v >> 1) ^ R } else { v >> 1 };
+    }
+    z
+}
+
+/// Berechnet GHASH über die Daten, die mit Nullen auf volle Blöcke aufgefüllt werden.
+fn ghash(h: u128, mut state: u128, data: &[u8]) -> u128 {
+    for chunk in data.chunks(BLOCK_SIZE) {
+        let mut block = [0u8; BLOCK_SIZE];
+        block[..chunk.len()].copy_from_slice(chunk);
+        state = gf_multiply(state ^ u128::from_be_bytes(block), h);
+    }
+    state
+}
+
+/// Berechnet den Zählerblock J0 aus dem Initialisierungsvektor.
+fn pre_counter_block(h: u128, iv: &[u8]) -> u128 {
+    if iv.len() == 12 {
+        let mut block = [0u8; BLOCK_SIZE];
+        block[..12].copy_from_slice(iv);
+        block[15] = 1;
+        u128::from_be_bytes(block)
+    } else {
+        let state = ghash(h, 0, iv);
+        gf_multiply(state ^ (iv.len() as u128 * 8), h)
+    }
+}
+
+/// Inkrementiert die niederwertigsten 32 Bit des Zählerblocks modulo 2^32.
+fn inc32(counter: u128) -> u128 {
+    let low = (counter as u32).wrapping_add(1);
+    (counter & !(u32::MAX as u128)) | low as u128
+}
+
+/// Verschlüsselt die Daten im Counter-Modus mit inc32 als Inkrementfunktion.
+fn gctr(cipher: &AesBlockCipher, mut counter: u128, data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(data.len());
+    for chunk in data.chunks(BLOCK_SIZE) {
+        let key_stream = cipher.encrypt_block(&counter.to_be_bytes());
+        output.extend(chunk.iter().zip(key_stream).map(|(a, b)| a ^ b));
+        counter = inc32(counter);
+    }
+    output
+}
+
+/// Berechnet das Tag T = E(K, J0) xor GHASH(A || C || len(A) || len(C)).
+fn compute_tag(cipher: &AesBlockCipher, h: u128, j0: u128, aad: &[u8], ciphertext: &[u8]) -> Block {
+    let mut state = ghash(h, 0, aad);
+    state = ghash(h, state, ciphertext);
+    let lengths = ((aad.len() as u128 * 8) << 64) | (ciphertext.len() as u128 * 8);
+    state = gf_multiply(state ^ lengths, h);
+    let mask = u128::from_be_bytes(cipher.encrypt_block(&j0.to_be_bytes()));
+    (state ^ mask).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+    use crate::shared::errors::AesError;
+
+    /// Testfälle 2 und 4 aus der GCM-Spezifikation von McGrew und Viega.
+    #[test]
+    fn test_gcm_specification_vectors() {
+        let cipher = AesBlockCipher::new(&[0u8; 16]).unwrap();
+        let (ciphertext, tag) = gcm_encrypt(&cipher, &[0u8; 12], &[], &[0u8; 16]).unwrap();
+        assert_eq!(ciphertext, hex("0388dace60b6a392f328c2b971b2fe78"));
+        assert_eq!(tag.to_vec(), hex("ab6e47d42cec13bdf53a67b21257bddf"));
+
+        let cipher = AesBlockCipher::new(&hex("feffe9928665731c6d6a8f9467308308")).unwrap();
+        let iv = hex("cafebabefacedbaddecaf888");
+        let aad = hex("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+        let plaintext = hex("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39");
+        let expected = hex("42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091");
+
+        let (ciphertext, tag) = gcm_encrypt(&cipher, &iv, &aad, &plaintext).unwrap();
+        assert_eq!(ciphertext, expected);
+        assert_eq!(tag.to_vec(), hex("5bc94fbc3221a5db94fae95ae7121a47"));
+        assert_eq!(
+            gcm_decrypt(&cipher, &iv, &aad, &ciphertext, &tag).unwrap(),
+            plaintext
+        );
+    }
+
+    /// Testfall 6 aus der GCM-Spezifikation mit einem 60 Bytes langen Initialisierungsvektor.
+    #[test]
+    fn test_gcm_long_iv() {
+        let cipher = AesBlockCipher::new(&hex("feffe9928665731c6d6a8f9467308308")).unwrap();
+        let iv = hex("9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b");
+        let aad = hex("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+        let plaintext = hex("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39");
+
+        let (ciphertext, tag) = gcm_encrypt(&cipher, &iv, &aad, &plaintext).unwrap();
+        assert_eq!(ciphertext, hex("8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca701e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5"));
+        assert_eq!(tag.to_vec(), hex("619cc5aefffe0bfa462af43c1699d050"));
+    }
+
+    #[test]
+    fn test_gcm_rejects_tampering() {
+        let cipher = AesBlockCipher::new(&[7u8; 32]).unwrap();
+        let iv = [1u8; 12];
+        let (mut ciphertext, tag) =
+            gcm_encrypt(&cipher, &iv, b"Kopf", b"Geheime Nachricht").unwrap();
+
+        assert!(gcm_decrypt(&cipher, &iv, b"Kopf!", &ciphertext, &tag).is_err());
+        ciphertext[0] ^= 1;
+        let error = gcm_decrypt(&cipher, &iv, b"Kopf", &ciphertext, &tag).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::AuthenticationFailedError)
+        ));
+    }
+
+    #[test]
+    fn test_gcm_rejects_empty_iv() {
+        let cipher = AesBlockCipher::new(&[7u8; 16]).unwrap();
+        let error = gcm_encrypt(&cipher, &[], b"", b"Nachricht").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AesError>(),
+            Some(AesError::InvalidIvLengthError(GCM_IV_SIZE, 0))
+        ));
+        assert!(gcm_decrypt(&cipher, &[], b"", b"", &[0u8; 16]).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{ensure, Result};
+use rand::RngCore;
+
+use crate::encryption::core::aes::aes_block_cipher::BLOCK_SIZE;
+use crate::encryption::core::aes::aes_scheme::AesScheme;
+use crate::encryption::encryption_types::Key;
+use crate::encryption::symmetric_encryption_types::{
+    KeyGenerator, SymmetricDecryptionKey, SymmetricEncryptionKey, SymmetricKey, SymmetricKeyPair,
+};
+use crate::shared::errors::AesError::{InvalidKeyLengthError, UnknownModeError};
+
+/// Länge des Initialisierungsvektors in Bytes, der für GCM erzeugt wird.
+pub const GCM_IV_SIZE: usize = 12;
+
+/// Der Betriebsmodus der Blockchiffre samt seiner Parameter.
+/// Initialisierungsvektor bzw. Zählerstartwert gehören nicht zum Schlüssel, sondern werden bei
+/// jeder Verschlüsselung neu gewählt und dem Geheimtext vorangestellt.
+///
+/// # Varianten
+/// * `Ecb` - Electronic Codebook, jeder Block wird unabhängig verschlüsselt.
+/// * `Cbc` - Cipher Block Chaining mit Initialisierungsvektor.
+/// * `Ctr` - Counter-Modus mit zufälligem Startwert des Zählers.
+/// * `Gcm` - Galois/Counter-Modus mit zusätzlichen authentifizierten Daten.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AesMode {
+    Ecb,
+    Cbc,
+    Ctr,
+    Gcm { aad: Vec<u8> },
+}
+
+impl AesMode {
+    /// Der Name des Modus, z.B. "CBC".
+    pub fn name(&self) -> AesModeName {
+        match self {
+            AesMode::Ecb => AesModeName::Ecb,
+            AesMode::Cbc => AesModeName::Cbc,
+            AesMode::Ctr => AesModeName::Ctr,
+            AesMode::Gcm { .. } => AesModeName::Gcm,
+        }
+    }
+
+    /// Die Länge des Initialisierungsvektors bzw. Zählerstartwerts, der dem Geheimtext vorangestellt wird.
+    pub fn iv_size(&self) -> usize {
+        match self {
+            AesMode::Ecb => 0,
+            AesMode::Cbc | AesMode::Ctr => BLOCK_SIZE,
+            AesMode::Gcm { .. } => GCM_IV_SIZE,
+        }
+    }
+
+    /// Erzeugt einen zufälligen Initialisierungsvektor bzw. Zählerstartwert für eine Nachricht.
+    /// Im Modus CTR bleiben die hinteren 8 Bytes 0, damit der Zähler nicht überläuft.
+    pub fn generate_iv(&self) -> Vec<u8> {
+        let mut iv = vec![0u8; self.iv_size()];
+        match self {
+            AesMode::Ctr => rand::thread_rng().fill_bytes(&mut iv[..BLOCK_SIZE - 8]),
+            _ => rand::thread_rng().fill_bytes(&mut iv),
+        }
+        iv
+    }
+}
+
+/// Der Name eines Betriebsmodus ohne dessen Parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AesModeName {
+    Ecb,
+    Cbc,
+    Ctr,
+    Gcm,
+}
+
+impl Display for AesModeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AesModeName::Ecb => write!(f, "ECB"),
+            AesModeName::Cbc => write!(f, "CBC"),
+            AesModeName::Ctr => write!(f, "CTR"),
+            AesModeName::Gcm => write!(f, "GCM"),
+        }
+    }
+}
+
+impl FromStr for AesModeName {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_uppercase().as_str() {
+            "ECB" => Ok(AesModeName::Ecb),
+            "CBC" => Ok(AesModeName::Cbc),
+            "CTR" => Ok(AesModeName::Ctr),
+            "GCM" => Ok(AesModeName::Gcm),
+            _ => Err(UnknownModeError(name.to_string()).into()),
+        }
+    }
+}
+
+/// Ein AES-Schlüssel zusammen mit dem Betriebsmodus, in dem er verwendet wird.
+/// Bei symmetrischen Verfahren ist der Schlüssel zum Ver- und Entschlüsseln identisch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AesKey {
+    pub key: Vec<u8>,
+    pub mode: AesMode,
+}
+
+impl Key<AesScheme> for AesKey {}
+
+impl SymmetricKey<AesScheme> for AesKey {}
+
+impl SymmetricEncryptionKey<AesScheme> for AesKey {}
+
+impl SymmetricDecryptionKey<AesScheme> for AesKey {}
+
+#[derive(Clone, Debug)]
+pub struct AesKeyPair {
+    pub key: AesKey,
+}
+
+impl SymmetricKeyPair<AesKey, AesKey, AesScheme> for AesKeyPair {
+    fn encryption(&self) -> AesKey {
+        self.key.clone()
+    }
+
+    fn decryption(&self) -> AesKey {
+        self.key.clone()
+    }
+}
+
+/// Die Konfiguration für die Erzeugung eines zufälligen AES-Schlüssels.
+///
+/// # Felder
+/// * `key_size` - Die Schlüssellänge in Bit (128, 192 oder 256).
+/// * `mode` - Der Betriebsmodus.
+#[derive(Clone, Copy, Debug)]
+pub struct AesKeyGenConfig {
+    pub key_size: usize,
+    pub mode: AesModeName,
+}
+
+impl AesKeyGenConfig {
+    /// Prüft die Konfiguration und erstellt sie.
+    ///
+    /// # Fehler
+    /// * `AesError::InvalidKeyLengthError` - Falls die Schlüssellänge nicht 128, 192 oder 256 Bit ist.
+    pub fn new(key_size: usize, mode: AesModeName) -> Result<Self> {
+        ensure!(
+            matches!(key_size, 128 | 192 | 256),
+            InvalidKeyLengthError(key_size / 8)
+        );
+        Ok(AesKeyGenConfig { key_size, mode })
+    }
+}
+
+impl KeyGenerator<AesKey, AesKey, AesScheme> for AesKeyGenConfig {
+    type KeyPair = AesKeyPair;
+
+    /// Erzeugt einen zufälligen Schlüssel.
+    fn generate_keypair(&self) -> AesKeyPair {
+        let mut key = vec![0u8; self.key_size / 8];
+        rand::thread_rng().fill_bytes(&mut key);
+
+        let mode = match self.mode {
+            AesModeName::Ecb => AesMode::Ecb,
+            AesModeName::Cbc => AesMode::Cbc,
+            AesModeName::Ctr => AesMode::Ctr,
+            AesModeName::Gcm => AesMode::Gcm { aad: vec![] },
+        };
+
+        AesKeyPair {
+            key: AesKey { key, mode },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_keypair() {
+        for (key_size, mode) in [
+            (128, AesModeName::Ecb),
+            (192, AesModeName::Cbc),
+            (256, AesModeName::Ctr),
+            (256, AesModeName::Gcm),
+        ] {
+            let key_pair = AesKeyGenConfig::new(key_size, mode)
+                .unwrap()
+                .generate_keypair();
+            assert_eq!(key_pair.encryption(), key_pair.decryption());
+            assert_eq!(key_pair.key.key.len(), key_size / 8);
+            assert_eq!(key_pair.key.mode.name(), mode);
+        }
+        assert!(AesKeyGenConfig::new(512, AesModeName::Ecb).is_err());
+    }
+
+    #[test]
+    fn test_generate_iv() {
+        assert!(AesMode::Ecb.generate_iv().is_empty());
+        assert_eq!(AesMode::Cbc.generate_iv().len(), BLOCK_SIZE);
+        assert_eq!(
+            AesMode::Gcm { aad: vec![] }.generate_iv().len(),
+            GCM_IV_SIZE
+        );
+
+        let counter = AesMode::Ctr.generate_iv();
+        assert_eq!(counter.len(), BLOCK_SIZE);
+        assert_eq!(counter[8..], [0u8; 8]);
+        assert_ne!(AesMode::Cbc.generate_iv(), AesMode::Cbc.generate_iv());
+    }
+
+    #[test]
+    fn test_mode_name() {
+        assert_eq!("gcm".parse::<AesModeName>().unwrap(), AesModeName::Gcm);
+        assert_eq!(AesModeName::Cbc.to_string(), "CBC");
+        assert!("OFB".parse::<AesModeName>().is_err());
+    }
+}
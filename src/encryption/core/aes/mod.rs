@@ -0,0 +1,5 @@
+pub mod aes_block_cipher;
+pub mod aes_scheme;
+pub mod block_modes;
+pub mod galois_counter_mode;
+pub mod keys;
@@ -0,0 +1,197 @@
+use anyhow::{bail, ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+
+use crate::shared::errors::EcdsaError::InvalidDerSignatureError;
+
+const SEQUENCE_TAG: u8 = 0x30;
+const INTEGER_TAG: u8 = 0x02;
+
+/// Kodiert eine Signatur als DER-Folge `SEQUENCE { INTEGER r, INTEGER s }` nach RFC 3279.
+///
+/// # Argumente
+/// * `r` - Die nicht negative Komponente r.
+/// * `s` - Die nicht negative Komponente s.
+pub fn encode_signature(r: &BigInt, s: &BigInt) -> Vec<u8> {
+    let mut content = encode_integer(r);
+    content.extend(encode_integer(s));
+
+    let mut encoded = vec![SEQUENCE_TAG];
+    encoded.extend(encode_length(content.len()));
+    encoded.extend(content);
+    encoded
+}
+
+/// Dekodiert eine DER-kodierte Signatur. Es wird nur die eindeutige Kodierung akzeptiert, also
+/// minimale Längenangaben, Ganzzahlen ohne überflüssige führende Null und keine Bytes nach der
+/// Folge. So hat jede Signatur genau eine gültige Darstellung.
+///
+/// # Rückgabe
+/// * Das Paar (r, s).
+///
+/// # Fehler
+/// * `EcdsaError::InvalidDerSignatureError` - Falls die Kodierung nicht strikt gültig ist oder
+///   r oder s negativ ist.
+pub fn decode_signature(bytes: &[u8]) -> Result<(BigInt, BigInt)> {
+    let mut reader = DerReader { bytes };
+    let content = reader.read_element(SEQUENCE_TAG)?;
+    ensure!(
+        reader.bytes.is_empty(),
+        InvalidDerSignatureError("trailing bytes after the sequence".to_string())
+    );
+
+    let mut reader = DerReader { bytes: content };
+    let r = decode_integer(reader.read_element(INTEGER_TAG)?)?;
+    let s = decode_integer(reader.read_element(INTEGER_TAG)?)?;
+    ensure!(
+        reader.bytes.is_empty(),
+        InvalidDerSignatureError("trailing bytes inside the sequence".to_string())
+    );
+    Ok((r, s))
+}
+
+fn encode_integer(value: &BigInt) -> Vec<u8> {
+    // Das höchste Bit kennzeichnet negative Zahlen, daher wird gegebenenfalls eine Null
+    // vorangestellt.
+    let (_, mut bytes) = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    let mut encoded = vec![INTEGER_TAG];
+    encoded.extend(encode_length(bytes.len()));
+    encoded.extend(bytes);
+    encoded
+}
+
+fn encode_length(length: usize) -> Vec<u8> {
+    if length < 0x80 {
+        return vec![length as u8];
+    }
+    let bytes: Vec<u8> = length
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|byte| *byte == 0)
+        .collect();
+    let mut encoded = vec![0x80 | bytes.len() as u8];
+    encoded.extend(bytes);
+    encoded
+}
+
+fn decode_integer(content: &[u8]) -> Result<BigInt> {
+    ensure!(
+        !content.is_empty(),
+        InvalidDerSignatureError("empty integer".to_string())
+    );
+    ensure!(
+        content.len() == 1 || content[0] != 0 || content[1] & 0x80 != 0,
+        InvalidDerSignatureError("integer has a redundant leading zero".to_string())
+    );
+    ensure!(
+        content[0] & 0x80 == 0,
+        InvalidDerSignatureError("integer is negative".to_string())
+    );
+    Ok(BigInt::from_bytes_be(Sign::Plus, content))
+}
+
+/// Liest Elemente der Form Tag, Länge, Inhalt vom Anfang einer Bytefolge.
+struct DerReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    fn read_element(&mut self, tag: u8) -> Result<&'a [u8]> {
+        let (&actual_tag, rest) = self
+            .bytes
+            .split_first()
+            .ok_or_else(|| InvalidDerSignatureError("unexpected end of input".to_string()))?;
+        ensure!(
+            actual_tag == tag,
+            InvalidDerSignatureError(format!(
+                "expected tag {:#04x}, found {:#04x}",
+                tag, actual_tag
+            ))
+        );
+        let (length, rest) = Self::read_length(rest)?;
+        ensure!(
+            rest.len() >= length,
+            InvalidDerSignatureError("length exceeds the input".to_string())
+        );
+        let (content, rest) = rest.split_at(length);
+        self.bytes = rest;
+        Ok(content)
+    }
+
+    fn read_length(bytes: &[u8]) -> Result<(usize, &[u8])> {
+        let Some((&first, rest)) = bytes.split_first() else {
+            bail!(InvalidDerSignatureError("missing length".to_string()));
+        };
+        if first < 0x80 {
+            return Ok((first as usize, rest));
+        }
+        let count = (first & 0x7f) as usize;
+        ensure!(
+            (1..=size_of::<usize>()).contains(&count) && rest.len() >= count,
+            InvalidDerSignatureError("invalid long form length".to_string())
+        );
+        let (length_bytes, rest) = rest.split_at(count);
+        let length = length_bytes
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+        ensure!(
+            length_bytes[0] != 0 && length >= 0x80,
+            InvalidDerSignatureError("length is not minimally encoded".to_string())
+        );
+        Ok((length, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode() {
+        for (r, s) in [
+            (1, 1),
+            (127, 128),
+            (255, 65535),
+            (0x7fffffff, 0x80000000u32 as i64),
+        ] {
+            let (r, s) = (BigInt::from(r), BigInt::from(s));
+            assert_eq!(decode_signature(&encode_signature(&r, &s)).unwrap(), (r, s));
+        }
+        // 128 und 255 benötigen eine führende Null.
+        assert_eq!(
+            encode_signature(&127.into(), &128.into()),
+            vec![0x30, 0x07, 0x02, 0x01, 0x7f, 0x02, 0x02, 0x00, 0x80]
+        );
+
+        // Eine Folge über 127 Bytes benötigt die lange Form der Länge.
+        let large = BigInt::from(1) << 520;
+        let encoded = encode_signature(&large, &large);
+        assert_eq!(encoded[..3], [0x30, 0x81, 0x88]);
+        assert_eq!(decode_signature(&encoded).unwrap(), (large.clone(), large));
+    }
+
+    #[test]
+    fn test_decode_rejects_non_strict_encodings() {
+        let invalid: [&[u8]; 9] = [
+            &[],
+            &[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            // Zu kurz und überzählige Bytes
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00],
+            // Lange Form für eine kurze Länge
+            &[0x30, 0x81, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            // Führende Null ohne gesetztes höchstes Bit
+            &[0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01],
+            // Negative Zahl
+            &[0x30, 0x06, 0x02, 0x01, 0x81, 0x02, 0x01, 0x01],
+            // Leere Zahl und fehlendes s
+            &[0x30, 0x05, 0x02, 0x00, 0x02, 0x01, 0x01],
+            &[0x30, 0x03, 0x02, 0x01, 0x01],
+        ];
+        for bytes in invalid {
+            assert!(decode_signature(bytes).is_err(), "{:02x?}", bytes);
+        }
+    }
+}
@@ -0,0 +1,604 @@
+use anyhow::{ensure, Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::Zero;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricEncryptionScheme, Signer, Verifier,
+};
+use crate::encryption::core::ecdsa::der::{decode_signature, encode_signature};
+use crate::encryption::core::ecdsa::keys::{EcdsaPrivateKey, EcdsaPublicKey};
+use crate::encryption::encryption_types::EncryptionScheme;
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{i2osp, os2ip};
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EcdsaError::{InvalidRecoveryIdError, PublicKeyRecoveryError};
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use crate::shared::hashing::hmac::Hmac;
+
+/// Eine ECDSA-Signatur (r, s) zusammen mit dem Hashverfahren, mit dem die Nachricht gehasht
+/// wurde.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EcdsaSignature {
+    pub r: BigInt,
+    pub s: BigInt,
+    /// Die Recovery-ID v. Bit 0 ist die Parität der y-Koordinate von k · G, die übrigen Bits
+    /// geben j = x(k · G) div q an. Bei Kofaktor 1 liegt v also zwischen 0 und 3. `None`, falls
+    /// die Signatur aus einer Kodierung ohne v stammt.
+    pub recovery_id: Option<u8>,
+    pub hash_algorithm: HashAlgorithm,
+}
+
+impl EcdsaSignature {
+    /// Die DER-Kodierung `SEQUENCE { INTEGER r, INTEGER s }`. Die Recovery-ID und das
+    /// Hashverfahren sind nicht enthalten.
+    pub fn to_der(&self) -> Vec<u8> {
+        encode_signature(&self.r, &self.s)
+    }
+
+    /// Liest eine DER-kodierte Signatur.
+    ///
+    /// # Fehler
+    /// * `EcdsaError::InvalidDerSignatureError` - Falls die Kodierung nicht strikt gültig ist.
+    pub fn from_der(bytes: &[u8], hash_algorithm: HashAlgorithm) -> Result<Self> {
+        let (r, s) = decode_signature(bytes)?;
+        Ok(EcdsaSignature {
+            r,
+            s,
+            recovery_id: None,
+            hash_algorithm,
+        })
+    }
+
+    /// Ob s <= q / 2 gilt. Mit (r, s) ist auch (r, q - s) gültig, die Beschränkung auf das
+    /// kleinere s macht Signaturen eindeutig.
+    pub fn is_low_s(&self, order_of_subgroup: &BigInt) -> bool {
+        self.s <= order_of_subgroup >> 1
+    }
+
+    /// Ersetzt s durch q - s, falls s > q / 2 ist. Da -k · G an der x-Achse gespiegelt ist,
+    /// wechselt dabei die Parität in der Recovery-ID.
+    pub fn normalize_s(self, order_of_subgroup: &BigInt) -> Self {
+        if self.is_low_s(order_of_subgroup) {
+            return self;
+        }
+        EcdsaSignature {
+            s: order_of_subgroup - &self.s,
+            recovery_id: self.recovery_id.map(|id| id ^ 1),
+            ..self
+        }
+    }
+}
+
+/// Signaturen nach ECDSA (FIPS 186-4) über den Kurven des MenezesVanstone-Schemas, also den
+/// erzeugten Kurven y^2 = x^3 - n^2 · x ebenso wie den benannten Kurven. Die Nonce k wird nach
+/// RFC 6979 deterministisch aus privatem Schlüssel und Hashwert abgeleitet.
+pub struct EcdsaScheme {}
+
+impl EncryptionScheme for EcdsaScheme {}
+
+impl AsymmetricEncryptionScheme for EcdsaScheme {}
+
+impl EcdsaScheme {
+    /// Signiert die Nachricht mit dem gegebenen Hashverfahren, siehe `sign_digest`.
+    pub fn sign_with_hash(
+        key: &EcdsaPrivateKey,
+        message: &[u8],
+        hash_algorithm: HashAlgorithm,
+        service: NumberTheoryService,
+    ) -> Result<EcdsaSignature> {
+        Self::sign_digest(
+            key,
+            &hash_algorithm.digest(message),
+            hash_algorithm,
+            service,
+        )
+    }
+
+    /// Signiert einen Hashwert. Es ist r = x(k · G) mod q und s = k^(-1) · (e + r · d) mod q,
+    /// wobei e der auf die Bitlänge von q gekürzte Hashwert ist. Die Signatur wird auf ein
+    /// kleines s normiert.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel d.
+    /// * `digest` - Der Hashwert der Nachricht.
+    /// * `hash_algorithm` - Das Hashverfahren, das den Hashwert erzeugt hat. Es wird auch für
+    ///   den HMAC nach RFC 6979 verwendet.
+    /// * `service` - Der Service für das modulare Inverse.
+    ///
+    /// # Fehler
+    /// * `HashError::UnsupportedHmacAlgorithmError` - Falls das Hashverfahren SHAKE ist.
+    pub fn sign_digest(
+        key: &EcdsaPrivateKey,
+        digest: &[u8],
+        hash_algorithm: HashAlgorithm,
+        service: NumberTheoryService,
+    ) -> Result<EcdsaSignature> {
+        let curve = &key.mv_key.curve;
+        let q = &curve.order_of_subgroup;
+        let d = &key.mv_key.x;
+        let e = hash_to_integer(digest, q);
+
+        let mut nonces = DeterministicNonces::new(d, digest, q, hash_algorithm)?;
+        loop {
+            let k = nonces.next()?;
+            let point = curve
+                .generator
+                .multiply_with(&k, &curve.curve, ScalarKind::Secret)
+                .context("Failed to calculate k * G")?;
+
+            let r = point.x.rem_euclid(q);
+            if r.is_zero() {
+                continue;
+            }
+            let inverse_k = service.modulo_inverse(&k, q)?;
+            let s = (inverse_k * (&e + d * &r)).rem_euclid(q);
+            if s.is_zero() {
+                continue;
+            }
+
+            let multiple = u8::try_from(&point.x / q)
+                .ok()
+                .filter(|multiple| *multiple < 0x80)
+                .context("x(k * G) / q does not fit into a recovery id")?;
+            let recovery_id = point.y.bit(0) as u8 | multiple << 1;
+            let signature = EcdsaSignature {
+                r,
+                s,
+                recovery_id: Some(recovery_id),
+                hash_algorithm,
+            };
+            return Ok(signature.normalize_s(q));
+        }
+    }
+
+    /// Prüft eine Signatur zu einem Hashwert. Wie in FIPS 186-4 werden auch Signaturen mit
+    /// großem s akzeptiert.
+    ///
+    /// # Rückgabe
+    /// * `true`, falls 1 <= r, s < q gilt und x(u1 · G + u2 · Q) ≡ r mod q ist.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der öffentliche Schlüssel nicht auf
+    ///   der Kurve liegt.
+    pub fn verify_digest(
+        key: &EcdsaPublicKey,
+        signature: &EcdsaSignature,
+        digest: &[u8],
+        service: NumberTheoryService,
+    ) -> Result<bool> {
+        let curve = &key.mv_key.curve;
+        let q = &curve.order_of_subgroup;
+        if !is_in_range(&signature.r, q) || !is_in_range(&signature.s, q) {
+            return Ok(false);
+        }
+
+        let e = hash_to_integer(digest, q);
+        let w = service.modulo_inverse(&signature.s, q)?;
+        let u1 = (e * &w).rem_euclid(q);
+        let u2 = (&signature.r * w).rem_euclid(q);
+        let point = multiply_and_add(curve, &u1, &key.mv_key.y, &u2)?;
+
+        Ok(!point.is_infinite && point.x.rem_euclid(q) == signature.r)
+    }
+
+    /// Berechnet den öffentlichen Schlüssel, der zu einer Signatur mit Recovery-ID passt. Aus
+    /// r und v folgt der Punkt R = k · G, aus s · R = e · G + r · Q dann Q = r^(-1) · (s · R - e · G).
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve, auf der signiert wurde.
+    /// * `signature` - Die Signatur mit Recovery-ID.
+    /// * `message` - Die signierte Nachricht.
+    /// * `service` - Der Service für das modulare Inverse.
+    ///
+    /// # Fehler
+    /// * `EcdsaError::InvalidRecoveryIdError` - Falls r + j · q kein Körperelement ist.
+    /// * `EcdsaError::PublicKeyRecoveryError` - Falls die Signatur keine Recovery-ID hat, r
+    ///   oder s außerhalb von 1 bis q - 1 liegt oder kein passender Punkt R existiert.
+    pub fn recover_public_key(
+        curve: &SecureFiniteFieldEllipticCurve,
+        signature: &EcdsaSignature,
+        message: &[u8],
+        service: NumberTheoryService,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        let q = &curve.order_of_subgroup;
+        let recovery_id = signature.recovery_id.ok_or_else(|| {
+            PublicKeyRecoveryError("the signature has no recovery id".to_string())
+        })?;
+        ensure!(
+            is_in_range(&signature.r, q) && is_in_range(&signature.s, q),
+            PublicKeyRecoveryError("r or s is out of range".to_string())
+        );
+
+        let x = &signature.r + q * (recovery_id >> 1);
+        ensure!(x < curve.curve.prime, InvalidRecoveryIdError(recovery_id));
+        let mut encoded = vec![0x02 | (recovery_id & 1)];
+        encoded.extend(i2osp(&x, curve.curve.field_byte_length())?);
+        let r_point = FiniteFieldEllipticCurvePoint::from_sec1(&encoded, &curve.curve)
+            .map_err(|_| PublicKeyRecoveryError(format!("there is no point with x = {}", x)))?;
+        ensure!(
+            r_point
+                .multiply_with(q, &curve.curve, ScalarKind::Public)?
+                .is_infinite,
+            PublicKeyRecoveryError("R is not in the subgroup".to_string())
+        );
+
+        let e = hash_to_integer(&signature.hash_algorithm.digest(message), q);
+        let inverse_r = service.modulo_inverse(&signature.r, q)?;
+        let u1 = (-e * &inverse_r).rem_euclid(q);
+        let u2 = (&signature.s * inverse_r).rem_euclid(q);
+        let public_key = multiply_and_add(curve, &u1, &r_point, &u2)?;
+        ensure!(
+            !public_key.is_infinite,
+            PublicKeyRecoveryError("the recovered point is infinite".to_string())
+        );
+        Ok(public_key)
+    }
+}
+
+impl Signer<EcdsaScheme> for EcdsaScheme {
+    type Input = [u8];
+    type Output = Result<EcdsaSignature>;
+    type Key = EcdsaPrivateKey;
+
+    /// Signiert die Nachricht mit dem Standard-Hashverfahren SHA-256, siehe `sign_digest`.
+    fn sign(key: &Self::Key, message: &Self::Input, service: NumberTheoryService) -> Self::Output {
+        Self::sign_with_hash(key, message, HashAlgorithm::default(), service)
+    }
+}
+
+impl Verifier<EcdsaScheme> for EcdsaScheme {
+    type Signature = EcdsaSignature;
+    type Message = [u8];
+    type Output = Result<bool>;
+    type Key = EcdsaPublicKey;
+
+    /// Hasht die Nachricht mit dem Hashverfahren der Signatur, siehe `verify_digest`.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        service: NumberTheoryService,
+    ) -> Self::Output {
+        let digest = signature.hash_algorithm.digest(message);
+        Self::verify_digest(key, signature, &digest, service)
+    }
+}
+
+/// Wandelt einen Hashwert in eine Zahl um und behält nach FIPS 186-4 nur die linken Bits, wenn
+/// der Hashwert länger als q ist. Das Ergebnis wird nicht modulo q reduziert.
+pub fn hash_to_integer(digest: &[u8], order_of_subgroup: &BigInt) -> BigInt {
+    let digest_bits = digest.len() as u64 * 8;
+    os2ip(digest) >> digest_bits.saturating_sub(order_of_subgroup.bits())
+}
+
+fn is_in_range(value: &BigInt, order_of_subgroup: &BigInt) -> bool {
+    value.is_positive() && value < order_of_subgroup
+}
+
+/// Berechnet u1 · G + u2 · P für öffentliche Skalare.
+fn multiply_and_add(
+    curve: &SecureFiniteFieldEllipticCurve,
+    u1: &BigInt,
+    point: &FiniteFieldEllipticCurvePoint,
+    u2: &BigInt,
+) -> Result<FiniteFieldEllipticCurvePoint> {
+    let first = curve
+        .generator
+        .multiply_with(u1, &curve.curve, ScalarKind::Public)?;
+    let second = point.multiply_with(u2, &curve.curve, ScalarKind::Public)?;
+    first.add(&second, &curve.curve)
+}
+
+/// Erzeugt die Nonces k nach RFC 6979, Abschnitt 3.2, mit HMAC-DRBG. Weitere Aufrufe von
+/// `next` liefern die Kandidaten, die verwendet werden, falls r oder s null wird. Auch
+/// Schnorr-Signaturen leiten ihre Nonces so ab.
+pub(crate) struct DeterministicNonces {
+    hash_algorithm: HashAlgorithm,
+    order_of_subgroup: BigInt,
+    key: Vec<u8>,
+    v: Vec<u8>,
+    started: bool,
+}
+
+impl DeterministicNonces {
+    pub(crate) fn new(
+        private_key: &BigInt,
+        digest: &[u8],
+        order_of_subgroup: &BigInt,
+        hash_algorithm: HashAlgorithm,
+    ) -> Result<Self> {
+        let length = (order_of_subgroup.bits() as usize).div_ceil(8);
+        let private_octets = i2osp(private_key, length)?;
+        let digest_octets = i2osp(
+            &hash_to_integer(digest, order_of_subgroup).rem_euclid(order_of_subgroup),
+            length,
+        )?;
+
+        let mut nonces = DeterministicNonces {
+            hash_algorithm,
+            order_of_subgroup: order_of_subgroup.clone(),
+            key: vec![0; hash_algorithm.output_length()],
+            v: vec![1; hash_algorithm.output_length()],
+            started: false,
+        };
+        for separator in [0x00, 0x01] {
+            nonces.key = nonces.mac(&[&nonces.v, &[separator], &private_octets, &digest_octets])?;
+            nonces.v = nonces.mac(&[&nonces.v])?;
+        }
+        Ok(nonces)
+    }
+
+    pub(crate) fn next(&mut self) -> Result<BigInt> {
+        if self.started {
+            self.reseed()?;
+        }
+        self.started = true;
+        loop {
+            let mut t = Vec::new();
+            while t.len() * 8 < self.order_of_subgroup.bits() as usize {
+                self.v = self.mac(&[&self.v])?;
+                t.extend(&self.v);
+            }
+            let k = hash_to_integer(&t, &self.order_of_subgroup);
+            if is_in_range(&k, &self.order_of_subgroup) {
+                return Ok(k);
+            }
+            self.reseed()?;
+        }
+    }
+
+    fn reseed(&mut self) -> Result<()> {
+        self.key = self.mac(&[&self.v, &[0x00]])?;
+        self.v = self.mac(&[&self.v])?;
+        Ok(())
+    }
+
+    fn mac(&self, parts: &[&[u8]]) -> Result<Vec<u8>> {
+        let mut mac = Hmac::new(self.hash_algorithm, &self.key)?;
+        for part in parts {
+            mac.update(part);
+        }
+        Ok(mac.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::menezes_vanstone::keys::{
+        MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+    };
+    use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
+        MenezesVanstoneCurveConfig, MenezesVanstoneScheme,
+    };
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use bigdecimal::One;
+
+    fn hex(value: &str) -> BigInt {
+        BigInt::parse_bytes(value.as_bytes(), 16).unwrap()
+    }
+
+    /// Der Schlüssel aus RFC 6979, Anhang A.2.5, für P-256.
+    fn rfc6979_keys() -> (EcdsaPrivateKey, EcdsaPublicKey) {
+        let curve = NamedCurve::P256.domain_parameters();
+        let x = hex("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        let y = FiniteFieldEllipticCurvePoint::new(
+            hex("60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6"),
+            hex("7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"),
+        );
+        (
+            EcdsaPrivateKey {
+                mv_key: MenezesVanstonePrivateKey {
+                    curve: curve.clone(),
+                    x,
+                },
+            },
+            EcdsaPublicKey {
+                mv_key: MenezesVanstonePublicKey { curve, y },
+            },
+        )
+    }
+
+    /// Nachricht, Hashverfahren, k, r und s aus RFC 6979, Anhang A.2.5.
+    fn rfc6979_vectors() -> [(&'static str, HashAlgorithm, BigInt, BigInt, BigInt); 3] {
+        [
+            (
+                "sample",
+                HashAlgorithm::Sha256,
+                hex("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"),
+                hex("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"),
+                hex("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"),
+            ),
+            (
+                "test",
+                HashAlgorithm::Sha256,
+                hex("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0"),
+                hex("F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367"),
+                hex("019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083"),
+            ),
+            (
+                // SHA-512 ist länger als q und wird auf 256 Bit gekürzt.
+                "sample",
+                HashAlgorithm::Sha512,
+                hex("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5"),
+                hex("8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00"),
+                hex("2362AB1ADBE2B8ADF9CB9EDAB740EA6049C028114F2460F96554F61FAE3302FE"),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_rfc6979_nonces() {
+        let (private_key, _) = rfc6979_keys();
+        let q = &private_key.mv_key.curve.order_of_subgroup;
+        for (message, hash_algorithm, k, _, _) in rfc6979_vectors() {
+            let digest = hash_algorithm.digest(message.as_bytes());
+            let mut nonces =
+                DeterministicNonces::new(&private_key.mv_key.x, &digest, q, hash_algorithm)
+                    .unwrap();
+            assert_eq!(nonces.next().unwrap(), k);
+            assert_ne!(nonces.next().unwrap(), k);
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify_rfc6979_vectors() {
+        let service = NumberTheoryService::new(Fast);
+        let (private_key, public_key) = rfc6979_keys();
+        let q = &private_key.mv_key.curve.order_of_subgroup;
+        for (message, hash_algorithm, _, r, s) in rfc6979_vectors() {
+            let signature = EcdsaScheme::sign_with_hash(
+                &private_key,
+                message.as_bytes(),
+                hash_algorithm,
+                service,
+            )
+            .unwrap();
+            let expected = EcdsaSignature {
+                r,
+                s,
+                recovery_id: None,
+                hash_algorithm,
+            };
+            assert_eq!(signature.r, expected.r);
+            assert_eq!(signature.s, expected.clone().normalize_s(q).s);
+            assert!(signature.is_low_s(q));
+
+            // Die veröffentlichten Signaturen sind nicht normiert und trotzdem gültig.
+            for signature in [signature, expected] {
+                assert!(
+                    EcdsaScheme::verify(&public_key, &signature, message.as_bytes(), service)
+                        .unwrap()
+                );
+                assert!(
+                    !EcdsaScheme::verify(&public_key, &signature, b"other message", service)
+                        .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_der_encoding_of_rfc6979_vector() {
+        let (_, public_key) = rfc6979_keys();
+        let [_, (message, hash_algorithm, _, r, s), _] = rfc6979_vectors();
+        let der = EcdsaSignature {
+            r,
+            s,
+            recovery_id: None,
+            hash_algorithm,
+        }
+        .to_der();
+        assert_eq!(der.len(), 71);
+        assert_eq!(der[..5], [0x30, 0x45, 0x02, 0x21, 0x00]);
+
+        let signature = EcdsaSignature::from_der(&der, hash_algorithm).unwrap();
+        assert!(EcdsaScheme::verify(
+            &public_key,
+            &signature,
+            message.as_bytes(),
+            NumberTheoryService::new(Fast)
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        let service = NumberTheoryService::new(Fast);
+        let (private_key, public_key) = rfc6979_keys();
+        let curve = &private_key.mv_key.curve;
+        for message in ["sample", "test", "recovery"] {
+            let signature = EcdsaScheme::sign(&private_key, message.as_bytes(), service).unwrap();
+            let recovered =
+                EcdsaScheme::recover_public_key(curve, &signature, message.as_bytes(), service)
+                    .unwrap();
+            assert_eq!(recovered, public_key.mv_key.y);
+
+            let flipped = EcdsaSignature {
+                recovery_id: signature.recovery_id.map(|id| id ^ 1),
+                ..signature.clone()
+            };
+            let other =
+                EcdsaScheme::recover_public_key(curve, &flipped, message.as_bytes(), service)
+                    .unwrap();
+            assert_ne!(other, public_key.mv_key.y);
+
+            let invalid = EcdsaSignature {
+                recovery_id: Some(4),
+                ..signature
+            };
+            assert!(
+                EcdsaScheme::recover_public_key(curve, &invalid, message.as_bytes(), service)
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_curve_with_cofactor() {
+        // q hat hier etwa 29 Bit, SHA-256 wird also stark gekürzt.
+        let service = NumberTheoryService::new(Fast);
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(3, 32, 40),
+            11,
+        )
+        .unwrap();
+        let private_key = EcdsaPrivateKey {
+            mv_key: key_pair.private_key,
+        };
+        let public_key = EcdsaPublicKey {
+            mv_key: key_pair.public_key,
+        };
+        let curve = &public_key.mv_key.curve;
+
+        for message in ["Hallo Welt", "ECDSA über y^2 = x^3 - 9x"] {
+            let signature = EcdsaScheme::sign(&private_key, message.as_bytes(), service).unwrap();
+            assert!(
+                signature.r < curve.order_of_subgroup
+                    && signature.is_low_s(&curve.order_of_subgroup)
+            );
+            assert!(
+                EcdsaScheme::verify(&public_key, &signature, message.as_bytes(), service).unwrap()
+            );
+            assert_eq!(
+                EcdsaScheme::recover_public_key(curve, &signature, message.as_bytes(), service)
+                    .unwrap(),
+                public_key.mv_key.y
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_truncation_and_range_checks() {
+        let q = BigInt::from(1_000_003);
+        // q hat 20 Bit, von 0xffff... bleiben also 20 Einsen.
+        assert_eq!(
+            hash_to_integer(&[0xff; 32], &q),
+            BigInt::from((1 << 20) - 1)
+        );
+        assert_eq!(hash_to_integer(&[0x01, 0x02], &q), BigInt::from(0x0102));
+
+        let service = NumberTheoryService::new(Fast);
+        let (_, public_key) = rfc6979_keys();
+        let q = public_key.mv_key.curve.order_of_subgroup.clone();
+        for (r, s) in [
+            (BigInt::zero(), BigInt::one()),
+            (BigInt::one(), q.clone()),
+            (-BigInt::one(), BigInt::one()),
+        ] {
+            let signature = EcdsaSignature {
+                r,
+                s,
+                recovery_id: None,
+                hash_algorithm: HashAlgorithm::Sha256,
+            };
+            assert!(!EcdsaScheme::verify(&public_key, &signature, b"sample", service).unwrap());
+        }
+    }
+}
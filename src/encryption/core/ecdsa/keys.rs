@@ -0,0 +1,30 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricKey, PrivateKey, PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::ecdsa::ecdsa_scheme::EcdsaScheme;
+use crate::encryption::core::menezes_vanstone::keys::{
+    MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+};
+use crate::encryption::encryption_types::Key;
+
+/// Öffentlicher Schlüssel für ECDSA. Kurve und Punkt werden aus dem MenezesVanstone-Schema
+/// übernommen, sodass dieselben Schlüssel für beide Verfahren verwendet werden können.
+#[derive(Clone, Debug)]
+pub struct EcdsaPublicKey {
+    pub mv_key: MenezesVanstonePublicKey,
+}
+
+impl Key<EcdsaScheme> for EcdsaPublicKey {}
+impl AsymmetricKey<EcdsaScheme> for EcdsaPublicKey {}
+impl PublicKey<EcdsaScheme> for EcdsaPublicKey {}
+impl VerificationKey<EcdsaScheme> for EcdsaPublicKey {}
+
+#[derive(Clone, Debug)]
+pub struct EcdsaPrivateKey {
+    pub mv_key: MenezesVanstonePrivateKey,
+}
+
+impl Key<EcdsaScheme> for EcdsaPrivateKey {}
+impl AsymmetricKey<EcdsaScheme> for EcdsaPrivateKey {}
+impl PrivateKey<EcdsaScheme> for EcdsaPrivateKey {}
+impl SignatureKey<EcdsaScheme> for EcdsaPrivateKey {}
@@ -0,0 +1,3 @@
+pub mod der;
+pub mod ecdsa_scheme;
+pub mod keys;
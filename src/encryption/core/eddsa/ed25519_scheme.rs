@@ -0,0 +1,293 @@
+use anyhow::{Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::One;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricEncryptionScheme, Signer, Verifier,
+};
+use crate::encryption::core::eddsa::keys::{
+    Ed25519PrivateKey, Ed25519PublicKey, ED25519_KEY_LENGTH,
+};
+use crate::encryption::encryption_types::EncryptionScheme;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::ecc::twisted_edwards_curve::{
+    from_little_endian, to_little_endian, EdwardsPoint, TwistedEdwardsCurve,
+};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::shared::errors::EddsaError::SignatureLengthError;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+
+/// Die Länge einer Ed25519-Signatur in Bytes.
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// Die Kodierung des Basispunkts B aus RFC 8032, Abschnitt 5.1. Es ist y = 4/5 und x gerade.
+const BASE_POINT_ENCODING: [u8; ED25519_KEY_LENGTH] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+
+/// Der Kofaktor von edwards25519.
+const COFACTOR: u32 = 8;
+
+/// Eine Ed25519-Signatur R || S aus dem kodierten Punkt R und dem Skalar S in Little-Endian.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ed25519Signature {
+    pub bytes: [u8; ED25519_SIGNATURE_LENGTH],
+}
+
+impl Ed25519Signature {
+    /// Übernimmt eine kodierte Signatur.
+    ///
+    /// # Fehler
+    /// * `EddsaError::SignatureLengthError` - Falls die Signatur nicht 64 Bytes lang ist.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| SignatureLengthError(bytes.len()))?;
+        Ok(Ed25519Signature { bytes })
+    }
+}
+
+/// Ed25519 nach RFC 8032, also EdDSA über edwards25519 mit SHA-512. Signaturen sind
+/// deterministisch, die Nonce r wird aus dem Präfix des Schlüssels und der Nachricht gehasht.
+pub struct Ed25519Scheme {}
+
+impl EncryptionScheme for Ed25519Scheme {}
+
+impl AsymmetricEncryptionScheme for Ed25519Scheme {}
+
+impl Ed25519Scheme {
+    /// Der Basispunkt B.
+    pub fn base_point() -> EdwardsPoint {
+        TwistedEdwardsCurve::ed25519()
+            .decode_point(&BASE_POINT_ENCODING)
+            .unwrap()
+    }
+
+    /// Die Primzahlordnung L = 2^252 + 27742317777372353535851937790883648493 von B.
+    pub fn group_order() -> BigInt {
+        (BigInt::one() << 252)
+            + "27742317777372353535851937790883648493"
+                .parse::<BigInt>()
+                .unwrap()
+    }
+
+    /// Berechnet den öffentlichen Schlüssel A = s · B zu einem privaten Schlüssel.
+    pub fn public_key(key: &Ed25519PrivateKey) -> Result<Ed25519PublicKey> {
+        let curve = TwistedEdwardsCurve::ed25519();
+        let (scalar, _) = expand(key);
+        let point = curve.multiply(&Self::base_point(), &scalar, ScalarKind::Secret)?;
+        Ed25519PublicKey::from_bytes(&curve.encode_point(&point)?)
+    }
+}
+
+impl Signer<Ed25519Scheme> for Ed25519Scheme {
+    type Input = [u8];
+    type Output = Result<Ed25519Signature>;
+    type Key = Ed25519PrivateKey;
+
+    /// Signiert die Nachricht nach RFC 8032, Abschnitt 5.1.6. Mit r = SHA-512(Präfix || M) ist
+    /// R = r · B und S = r + SHA-512(R || A || M) · s mod L.
+    fn sign(key: &Self::Key, message: &Self::Input, _service: NumberTheoryService) -> Self::Output {
+        let curve = TwistedEdwardsCurve::ed25519();
+        let order = Self::group_order();
+        let base_point = Self::base_point();
+        let (scalar, prefix) = expand(key);
+        let public_key =
+            curve.encode_point(&curve.multiply(&base_point, &scalar, ScalarKind::Secret)?)?;
+
+        let r = hash_to_scalar(&[&prefix, message], &order);
+        let commitment = curve.encode_point(
+            &curve
+                .multiply(&base_point, &r, ScalarKind::Secret)
+                .context("Failed to calculate r * B")?,
+        )?;
+        let k = hash_to_scalar(&[&commitment, &public_key, message], &order);
+        let s = (r + k * scalar).rem_euclid(&order);
+
+        let mut bytes = commitment;
+        bytes.extend(to_little_endian(&s, ED25519_KEY_LENGTH));
+        Ed25519Signature::from_bytes(&bytes)
+    }
+}
+
+impl Verifier<Ed25519Scheme> for Ed25519Scheme {
+    type Signature = Ed25519Signature;
+    type Message = [u8];
+    type Output = bool;
+    type Key = Ed25519PublicKey;
+
+    /// Prüft eine Signatur nach RFC 8032, Abschnitt 5.1.7, mit der Gleichung
+    /// [8][S]B = [8]R + [8][k]A, die auch Anteile kleiner Ordnung in R und A toleriert.
+    ///
+    /// # Rückgabe
+    /// * `false`, falls A oder R keine gültigen Punkte kodieren, S >= L ist oder die Gleichung
+    ///   nicht erfüllt ist.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        _service: NumberTheoryService,
+    ) -> bool {
+        let curve = TwistedEdwardsCurve::ed25519();
+        let order = Self::group_order();
+        let (commitment_bytes, s_bytes) = signature.bytes.split_at(ED25519_KEY_LENGTH);
+        let s = from_little_endian(s_bytes);
+        if s >= order {
+            return false;
+        }
+        let (Ok(public_key), Ok(commitment)) = (
+            curve.decode_point(&key.encoded),
+            curve.decode_point(commitment_bytes),
+        ) else {
+            return false;
+        };
+
+        let k = hash_to_scalar(&[commitment_bytes, &key.encoded, message], &order);
+        let cofactor = BigInt::from(COFACTOR);
+        let left = curve
+            .multiply(&Self::base_point(), &(s * &cofactor), ScalarKind::Public)
+            .ok();
+        let right = curve
+            .multiply(&public_key, &k, ScalarKind::Public)
+            .and_then(|point| curve.add(&commitment, &point))
+            .and_then(|point| curve.multiply(&point, &cofactor, ScalarKind::Public))
+            .ok();
+        left.is_some() && left == right
+    }
+}
+
+/// Leitet aus dem Seed den geklemmten Skalar s und das Präfix ab. Die unteren drei Bits von s
+/// werden gelöscht, damit s ein Vielfaches des Kofaktors ist, Bit 254 wird gesetzt.
+fn expand(key: &Ed25519PrivateKey) -> (BigInt, Vec<u8>) {
+    let hash = HashAlgorithm::Sha512.digest(&key.seed);
+    let (scalar_bytes, prefix) = hash.split_at(ED25519_KEY_LENGTH);
+    let mut scalar_bytes = scalar_bytes.to_vec();
+    scalar_bytes[0] &= 0xf8;
+    scalar_bytes[ED25519_KEY_LENGTH - 1] &= 0x7f;
+    scalar_bytes[ED25519_KEY_LENGTH - 1] |= 0x40;
+    (from_little_endian(&scalar_bytes), prefix.to_vec())
+}
+
+/// SHA-512 der aneinandergehängten Teile als Little-Endian-Zahl modulo L.
+fn hash_to_scalar(parts: &[&[u8]], order: &BigInt) -> BigInt {
+    let mut hasher = HashAlgorithm::Sha512.hasher();
+    for part in parts {
+        hasher.update(part);
+    }
+    from_little_endian(&hasher.finalize()).rem_euclid(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Seed, öffentlicher Schlüssel, Nachricht und Signatur der Tests 1 bis 3 aus RFC 8032,
+    /// Abschnitt 7.1.
+    fn rfc8032_vectors() -> [(&'static str, &'static str, &'static str, &'static str); 3] {
+        [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                 5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                 085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+                 18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_rfc8032_vectors() {
+        let service = NumberTheoryService::new(Fast);
+        for (seed, public_key, message, signature) in rfc8032_vectors() {
+            let private_key = Ed25519PrivateKey::from_bytes(&hex(seed)).unwrap();
+            let public_key = Ed25519PublicKey::from_bytes(&hex(public_key)).unwrap();
+            let message = hex(message);
+            let signature = Ed25519Signature::from_bytes(&hex(signature)).unwrap();
+
+            assert_eq!(Ed25519Scheme::public_key(&private_key).unwrap(), public_key);
+            assert_eq!(
+                Ed25519Scheme::sign(&private_key, &message, service).unwrap(),
+                signature
+            );
+            assert!(Ed25519Scheme::verify(
+                &public_key,
+                &signature,
+                &message,
+                service
+            ));
+            assert!(!Ed25519Scheme::verify(
+                &public_key,
+                &signature,
+                b"other message",
+                service
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reject_invalid_signatures() {
+        let service = NumberTheoryService::new(Fast);
+        let (seed, public_key, _, signature) = &rfc8032_vectors()[0];
+        let public_key = Ed25519PublicKey::from_bytes(&hex(public_key)).unwrap();
+        let signature = Ed25519Signature::from_bytes(&hex(signature)).unwrap();
+
+        // S + L beschreibt denselben Skalar, wird aber als nicht reduziert abgelehnt.
+        let s = from_little_endian(&signature.bytes[32..]) + Ed25519Scheme::group_order();
+        let mut malleable = signature.clone();
+        malleable.bytes[32..].copy_from_slice(&to_little_endian(&s, 32));
+        assert!(!Ed25519Scheme::verify(
+            &public_key,
+            &malleable,
+            b"",
+            service
+        ));
+
+        let mut tampered = signature.clone();
+        tampered.bytes[0] ^= 1;
+        assert!(!Ed25519Scheme::verify(&public_key, &tampered, b"", service));
+
+        let other_key = Ed25519PublicKey::from_bytes(&hex(rfc8032_vectors()[1].1)).unwrap();
+        assert!(!Ed25519Scheme::verify(&other_key, &signature, b"", service));
+
+        assert!(Ed25519Signature::from_bytes(&signature.bytes[..63]).is_err());
+        assert!(Ed25519PrivateKey::from_bytes(&hex(seed)[1..]).is_err());
+    }
+
+    #[test]
+    fn test_generated_key() {
+        let service = NumberTheoryService::new(Fast);
+        let private_key = Ed25519PrivateKey::generate();
+        let public_key = Ed25519Scheme::public_key(&private_key).unwrap();
+        let signature = Ed25519Scheme::sign(&private_key, b"Hallo Welt", service).unwrap();
+        assert!(Ed25519Scheme::verify(
+            &public_key,
+            &signature,
+            b"Hallo Welt",
+            service
+        ));
+    }
+}
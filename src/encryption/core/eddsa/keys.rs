@@ -0,0 +1,65 @@
+use anyhow::Result;
+use rand::RngCore;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricKey, PrivateKey, PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::eddsa::ed25519_scheme::Ed25519Scheme;
+use crate::encryption::encryption_types::Key;
+use crate::shared::errors::EddsaError::KeyLengthError;
+
+/// Die Länge von privaten und öffentlichen Ed25519-Schlüsseln in Bytes.
+pub const ED25519_KEY_LENGTH: usize = 32;
+
+/// Öffentlicher Ed25519-Schlüssel, der nach RFC 8032 kodierte Punkt A = s · B.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ed25519PublicKey {
+    pub encoded: [u8; ED25519_KEY_LENGTH],
+}
+
+impl Ed25519PublicKey {
+    /// Übernimmt einen kodierten öffentlichen Schlüssel. Ob er einen Punkt der Kurve
+    /// beschreibt, wird erst beim Verifizieren geprüft.
+    ///
+    /// # Fehler
+    /// * `EddsaError::KeyLengthError` - Falls der Schlüssel nicht 32 Bytes lang ist.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let encoded = bytes.try_into().map_err(|_| KeyLengthError(bytes.len()))?;
+        Ok(Ed25519PublicKey { encoded })
+    }
+}
+
+impl Key<Ed25519Scheme> for Ed25519PublicKey {}
+impl AsymmetricKey<Ed25519Scheme> for Ed25519PublicKey {}
+impl PublicKey<Ed25519Scheme> for Ed25519PublicKey {}
+impl VerificationKey<Ed25519Scheme> for Ed25519PublicKey {}
+
+/// Privater Ed25519-Schlüssel. Nach RFC 8032 ist das ein zufälliger Seed, aus dessen
+/// SHA-512-Hashwert der Skalar s und das Präfix für die Nonces abgeleitet werden.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ed25519PrivateKey {
+    pub seed: [u8; ED25519_KEY_LENGTH],
+}
+
+impl Ed25519PrivateKey {
+    /// Erzeugt einen zufälligen privaten Schlüssel.
+    pub fn generate() -> Self {
+        let mut seed = [0u8; ED25519_KEY_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed);
+        Ed25519PrivateKey { seed }
+    }
+
+    /// Übernimmt einen Seed als privaten Schlüssel.
+    ///
+    /// # Fehler
+    /// * `EddsaError::KeyLengthError` - Falls der Seed nicht 32 Bytes lang ist.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let seed = bytes.try_into().map_err(|_| KeyLengthError(bytes.len()))?;
+        Ok(Ed25519PrivateKey { seed })
+    }
+}
+
+impl Key<Ed25519Scheme> for Ed25519PrivateKey {}
+impl AsymmetricKey<Ed25519Scheme> for Ed25519PrivateKey {}
+impl PrivateKey<Ed25519Scheme> for Ed25519PrivateKey {}
+impl SignatureKey<Ed25519Scheme> for Ed25519PrivateKey {}
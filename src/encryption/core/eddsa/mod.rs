@@ -0,0 +1,2 @@
+pub mod ed25519_scheme;
+pub mod keys;
@@ -10,6 +10,7 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::increment::Increment;
 use atomic_counter::RelaxedCounter;
 use bigdecimal::num_bigint::BigInt;
@@ -63,16 +64,18 @@ impl KeyGenerator<ElGamalPublicKey, ElGamalPrivateKey, ElGamalScheme> for ElGama
     ///
     /// # Argumente
     /// * `config` - Die Konfiguration für den Schlüsselgenerierungsvorgang.
+    /// * `random_generator` - Der Zufallszahlengenerator, aus dem die Schlüssel erzeugt werden.
     ///
     /// # Rückgabe
     /// Ein Tupel aus dem öffentlichen und privaten Schlüssel.
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair {
+    fn generate_keypair_with_generator(
+        config: &impl KeyGenWithPrimeConfig,
+        random_generator: &impl RandomNumberGenerator,
+    ) -> Self::KeyPair {
         debug!(
             "Generieren eines neuen ElGamal-Schlüsselpaares mit Konfiguration: {:?}",
             config
         );
-        let random_generator =
-            PseudoRandomNumberGenerator::new(config.random_seed(), config.number_theory_service());
         let counter = RelaxedCounter::new(1);
 
         // Generieren der sicheren Primzahl p und der Primitivwurzel g
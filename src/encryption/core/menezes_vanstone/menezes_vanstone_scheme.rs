@@ -1,7 +1,7 @@
 use anyhow::Context;
 use anyhow::{ensure, Result};
 
-use crate::api::endpoints::mv::MvSignatureBean;
+use crate::api::endpoints::mv::{MvCreateKeyPairRequestBean, MvSignatureBean};
 use atomic_counter::RelaxedCounter;
 use bigdecimal::num_bigint::BigInt;
 use bigdecimal::num_traits::Euclid;
@@ -19,15 +19,18 @@ use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_blo
 use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::symmetric_encryption_types::SymmetricDecryptor;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::named_curve::NamedCurve;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
 use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::increment::Increment;
 use crate::shared::errors::MenezesVanstoneError;
-use crate::shared::hashing::sha256;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MenezesVanstonePlaintext {
@@ -42,32 +45,46 @@ pub struct MenezesVanstoneCiphertext {
     pub second: BigInt,
 }
 
+/// Eine Signatur (r, s) zusammen mit dem Hashverfahren, mit dem die Nachricht gehasht wurde.
 #[derive(Clone, Debug, PartialEq)]
 pub struct MenezesVanstoneSignature {
     pub r: BigInt,
     pub s: BigInt,
+    pub hash_algorithm: HashAlgorithm,
 }
 
-impl From<MvSignatureBean> for MenezesVanstoneSignature {
+impl TryFrom<MvSignatureBean> for MenezesVanstoneSignature {
+    type Error = anyhow::Error;
+
     /// Mapped die Bean in das Domain-Modell
-    fn from(signature: MvSignatureBean) -> Self {
+    ///
+    /// # Fehler
+    /// * Falls r, s oder das Hashverfahren nicht gelesen werden können oder die Textdarstellung
+    ///   nicht zu r und s passt.
+    fn try_from(signature: MvSignatureBean) -> Result<Self> {
         // TODO: Sauber ausarbeiten!
         let key = DecimalUnicodeConversionSchemeKey {
             block_size: 4,
             radix: 55296,
         };
         let blocks = FromDecimalBlockScheme::decrypt(&signature.string_representation, &key);
-        assert_eq!(blocks.len(), 2);
-        let r_from_string = blocks[0].clone();
-        let s_from_string = blocks[1].clone();
-
-        let r = signature.r.parse().unwrap();
-        let s = signature.s.parse().unwrap();
+        ensure!(
+            blocks.len() == 2,
+            "String representation must contain exactly two blocks"
+        );
 
-        assert_eq!(r_from_string, r);
-        assert_eq!(s_from_string, s);
+        let r: BigInt = signature.r.parse()?;
+        let s: BigInt = signature.s.parse()?;
+        ensure!(
+            blocks[0] == r && blocks[1] == s,
+            "String representation does not match r and s"
+        );
 
-        MenezesVanstoneSignature { r, s }
+        Ok(MenezesVanstoneSignature {
+            r,
+            s,
+            hash_algorithm: signature.hash_algorithm.parse()?,
+        })
     }
 }
 
@@ -77,32 +94,103 @@ impl EncryptionScheme for MenezesVanstoneScheme {}
 
 impl AsymmetricEncryptionScheme for MenezesVanstoneScheme {}
 
-impl MenezesVanstoneScheme {
-    pub fn generate_keypair(
+/// Die Kurve, auf der ein Schlüsselpaar erzeugt wird.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MenezesVanstoneCurveConfig {
+    /// Eine standardisierte Kurve. Die Schlüsselerzeugung ist sofort fertig und die Schlüssel
+    /// sind mit anderen Implementierungen austauschbar.
+    Named(NamedCurve),
+    /// Eine neu erzeugte Kurve y^2 = x^3 - n^2 * x, deren Modulus p `modul_width` Bit breit
+    /// ist. Die Suche nach p und dem Generator dauert bei großen Breiten lange.
+    Generated {
         n: i32,
         modul_width: u32,
         miller_rabin_iterations: u32,
+    },
+}
+
+impl MenezesVanstoneCurveConfig {
+    pub fn generated(n: i32, modul_width: u32, miller_rabin_iterations: u32) -> Self {
+        MenezesVanstoneCurveConfig::Generated {
+            n,
+            modul_width,
+            miller_rabin_iterations,
+        }
+    }
+
+    /// Erstellt die Kurve mit Generator und Ordnung der Untergruppe.
+    ///
+    /// # Fehler
+    /// * `MenezesVanstoneError::InvalidNValueError` - Falls n = 0 ist.
+    /// * `MenezesVanstoneError::InvalidModulusWidthError` - Falls p höchstens 3 Bit breit ist.
+    pub fn create_curve(&self) -> Result<SecureFiniteFieldEllipticCurve> {
+        match *self {
+            MenezesVanstoneCurveConfig::Named(named_curve) => Ok(named_curve.domain_parameters()),
+            MenezesVanstoneCurveConfig::Generated {
+                n,
+                modul_width,
+                miller_rabin_iterations,
+            } => {
+                ensure!(n != 0, MenezesVanstoneError::InvalidNValueError(n));
+                ensure!(
+                    modul_width > 3,
+                    MenezesVanstoneError::InvalidModulusWidthError(modul_width)
+                );
+                SecureFiniteFieldEllipticCurve::new(n.into(), modul_width, miller_rabin_iterations)
+                    .context("Failed to create secure elliptic curve")
+            }
+        }
+    }
+}
+
+impl TryFrom<&MvCreateKeyPairRequestBean> for MenezesVanstoneCurveConfig {
+    type Error = anyhow::Error;
+
+    /// Mapped die Bean in das Domain-Modell
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::UnknownCurveError` - Falls der Name der Kurve unbekannt ist.
+    fn try_from(request: &MvCreateKeyPairRequestBean) -> Result<Self> {
+        Ok(match &request.curve_name {
+            Some(curve_name) => MenezesVanstoneCurveConfig::Named(curve_name.parse()?),
+            None => MenezesVanstoneCurveConfig::generated(
+                request.coef_a,
+                request.modulus_width,
+                request.miller_rabin_rounds,
+            ),
+        })
+    }
+}
+
+impl MenezesVanstoneScheme {
+    pub fn generate_keypair(
+        curve_config: &MenezesVanstoneCurveConfig,
         random_seed: u32,
     ) -> Result<MenezesVanstoneKeyPair> {
-        ensure!(n != 0, MenezesVanstoneError::InvalidNValueError(n));
-        ensure!(
-            modul_width > 3,
-            MenezesVanstoneError::InvalidModulusWidthError(modul_width)
-        );
+        let prng = PseudoRandomNumberGenerator::new(random_seed, NumberTheoryService::new(Fast)); // TODO übergeben
+        Self::generate_keypair_with_generator(curve_config, &prng)
+    }
 
-        let curve =
-            SecureFiniteFieldEllipticCurve::new(n.into(), modul_width, miller_rabin_iterations)
-                .context("Failed to create secure elliptic curve")?;
+    /// Generiert ein Schlüsselpaar mit einem beliebigen Zufallszahlengenerator.
+    /// Der Generator bestimmt den privaten Schlüssel, die Kurve wird unabhängig davon erzeugt.
+    ///
+    /// # Argumente
+    /// * `curve_config` - Die benannte oder zu erzeugende Kurve.
+    /// * `random_generator` - Der Zufallszahlengenerator für den privaten Schlüssel.
+    pub fn generate_keypair_with_generator(
+        curve_config: &MenezesVanstoneCurveConfig,
+        random_generator: &impl RandomNumberGenerator,
+    ) -> Result<MenezesVanstoneKeyPair> {
+        let curve = curve_config.create_curve()?;
 
-        let prng = PseudoRandomNumberGenerator::new(random_seed, NumberTheoryService::new(Fast)); // TODO übergeben
         let counter = RelaxedCounter::new(1);
         let order_of_subgroup = &curve.order_of_subgroup;
         let (mut x, mut y);
         loop {
-            x = prng.take(&1.into(), &order_of_subgroup.decrement(), &counter);
+            x = random_generator.take(&1.into(), &order_of_subgroup.decrement(), &counter);
             y = curve
                 .generator
-                .multiply(&x, &curve)
+                .multiply(&x, &curve.curve)
                 .context("Failed to calculate key-component y")?;
             if !y.x.is_zero() && !y.y.is_zero() {
                 break;
@@ -121,6 +209,58 @@ impl MenezesVanstoneScheme {
             private_key,
         })
     }
+
+    /// Signiert die Nachricht nach dem Verfahren von ECDSA. Die Nachricht wird mit dem gegebenen
+    /// Hashverfahren gehasht, das in der Signatur vermerkt wird.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `hash_algorithm` - Das Hashverfahren.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    pub fn sign_with_hash(
+        key: &MenezesVanstonePrivateKey,
+        message: &str,
+        hash_algorithm: HashAlgorithm,
+        service: NumberTheoryService,
+    ) -> Result<MenezesVanstoneSignature> {
+        let prng = PseudoRandomNumberGenerator::new(17, service); // TODO übergeben
+        let counter = RelaxedCounter::new(1);
+        let curve = &key.curve;
+        let q = &curve.order_of_subgroup;
+
+        let hashed_message = hash_algorithm.digest_to_big_int(message.as_bytes());
+
+        // Schleife, bis r und s jeweils ungleich 0 sind.
+        loop {
+            let k = &prng.take(&1.into(), &q.decrement(), &counter);
+            let point = curve
+                .generator
+                .multiply(k, &curve.curve)
+                .context("Failed to calculate Point (c1, c2)")?;
+
+            ensure!(
+                !point.is_infinite,
+                "Calculated point is infinite, but cannot be since k < |H|. With k = {}",
+                k
+            );
+
+            let r = point.x.rem_euclid(q);
+            if r.is_zero() {
+                continue;
+            }
+            let inverse_k = service.modulo_inverse(k, q).unwrap();
+            let s = (inverse_k * (&hashed_message + &key.x * &r)).rem_euclid(q);
+            if s.is_zero() {
+                continue;
+            }
+            return Ok(MenezesVanstoneSignature {
+                r,
+                s,
+                hash_algorithm,
+            });
+        }
+    }
 }
 
 impl Encryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
@@ -137,7 +277,7 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
     ) -> Self::Output {
         let m1 = &plaintext.first;
         let m2 = &plaintext.second;
-        let prime = &key.curve.prime;
+        let prime = &key.curve.curve.prime;
 
         let mut rng = rand::thread_rng();
         let random_seed: u16 = rng.next_u32() as u16;
@@ -154,7 +294,7 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
             k = random_generator.take(&1.into(), &curve.order_of_subgroup.decrement(), &counter);
             let point = key
                 .y
-                .multiply(&k, curve)
+                .multiply(&k, &curve.curve)
                 .context("Failed to calculate Point (c1, c2)")?;
 
             ensure!(
@@ -172,7 +312,7 @@ impl AsymmetricEncryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let a = key
             .curve
             .generator
-            .multiply(&k, curve)
+            .multiply(&k, &curve.curve)
             .context("Failed to calculate Point a")?;
         let b1 = (c1 * m1) % prime;
         let b2 = (c2 * m2) % prime;
@@ -200,10 +340,10 @@ impl AsymmetricDecryptor<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let a = &ciphertext.point;
         let b1 = &ciphertext.first;
         let b2 = &ciphertext.second;
-        let prime = &key.curve.prime;
+        let prime = &key.curve.curve.prime;
 
         let point = a
-            .multiply(&key.x, &key.curve)
+            .multiply(&key.x, &key.curve.curve)
             .context("Failed to calculate Point (c1, c2)")?;
         let (c1, c2) = (point.x, point.y);
         let c1_inverse = service
@@ -228,39 +368,9 @@ impl<'a> Signer<MenezesVanstoneScheme> for MenezesVanstoneScheme {
     type Output = Result<MenezesVanstoneSignature>;
     type Key = MenezesVanstonePrivateKey;
 
+    /// Signiert die Nachricht mit dem Standard-Hashverfahren SHA-256, siehe `sign_with_hash`.
     fn sign(key: &Self::Key, message: &Self::Input, service: NumberTheoryService) -> Self::Output {
-        let prng = PseudoRandomNumberGenerator::new(17, service); // TODO übergeben
-        let counter = RelaxedCounter::new(1);
-        let curve = &key.curve;
-        let q = &curve.order_of_subgroup;
-
-        let hashed_message = sha256(message);
-
-        // Schleife, bis r und s jeweils ungleich 0 sind.
-        loop {
-            let k = &prng.take(&1.into(), &q.decrement(), &counter);
-            let point = curve
-                .generator
-                .multiply(k, curve)
-                .context("Failed to calculate Point (c1, c2)")?;
-
-            ensure!(
-                !point.is_infinite,
-                "Calculated point is infinite, but cannot be since k < |H|. With k = {}",
-                k
-            );
-
-            let r = point.x.rem_euclid(q);
-            if r.is_zero() {
-                continue;
-            }
-            let inverse_k = service.modulo_inverse(k, q).unwrap();
-            let s = (inverse_k * (&hashed_message + &key.x * &r)).rem_euclid(q);
-            if s.is_zero() {
-                continue;
-            }
-            return Ok(MenezesVanstoneSignature { r, s });
-        }
+        Self::sign_with_hash(key, message, HashAlgorithm::default(), service)
     }
 }
 
@@ -281,21 +391,24 @@ impl<'a> Verifier<MenezesVanstoneScheme> for MenezesVanstoneScheme {
         let s = &signature.s;
         let q = &curve.order_of_subgroup;
 
-        let hashed_message = sha256(message);
+        let hashed_message = signature
+            .hash_algorithm
+            .digest_to_big_int(message.as_bytes());
         let w = &service.modulo_inverse(s, q).unwrap();
         let u1 = (hashed_message * w).rem_euclid(q);
         let u2 = (r * w).rem_euclid(q);
 
+        // u1 und u2 folgen aus Nachricht und Signatur und sind daher öffentlich.
         let first_point = curve
             .generator
-            .multiply(&u1, curve)
+            .multiply_with(&u1, &curve.curve, ScalarKind::Public)
             .context("Failed to calculate first point")?;
         let second_point = key
             .y
-            .multiply(&u2, curve)
+            .multiply_with(&u2, &curve.curve, ScalarKind::Public)
             .context("Failed to calculate second point")?;
         let point = first_point
-            .add(&second_point, curve)
+            .add(&second_point, &curve.curve)
             .context("Failed to calculate verification point")?;
 
         let v = point.x.rem_euclid(q);
@@ -318,8 +431,11 @@ mod tests {
         let n = 7; //rand::thread_rng().gen_range(1..30);
         let modul_width = 128; //rand::thread_rng().gen_range(4..256);
         let random_seed = 300; //rand::thread_rng().gen_range(1..1000);
-        let key_pair =
-            MenezesVanstoneScheme::generate_keypair(n, modul_width, 40, random_seed).unwrap();
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+        )
+        .unwrap();
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
@@ -344,8 +460,11 @@ mod tests {
         let n = rand::thread_rng().gen_range(1..30);
         let modul_width = rand::thread_rng().gen_range(4..256);
         let random_seed = rand::thread_rng().gen_range(1..1000);
-        let key_pair =
-            MenezesVanstoneScheme::generate_keypair(n, modul_width, 40, random_seed).unwrap();
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+        )
+        .unwrap();
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
@@ -370,13 +489,19 @@ mod tests {
         let n = 5; //rand::thread_rng().gen_range(1..30);
         let modul_width = 16; //rand::thread_rng().gen_range(4..16);
         let random_seed = 73; //rand::thread_rng().gen_range(1..1000);
-        let key_pair =
-            MenezesVanstoneScheme::generate_keypair(n, modul_width, 40, random_seed).unwrap();
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+        )
+        .unwrap();
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
 
-        assert!(public_key.curve.has_point(&public_key.curve.generator));
+        assert!(public_key
+            .curve
+            .curve
+            .has_point(&public_key.curve.generator));
 
         let message = "Hello World!";
         let signature =
@@ -392,10 +517,85 @@ mod tests {
         assert!(is_verified);
     }
 
+    #[test]
+    fn test_encryption_and_signature_on_named_curve() {
+        let curve_config = MenezesVanstoneCurveConfig::Named(NamedCurve::P256);
+        let key_pair = MenezesVanstoneScheme::generate_keypair(&curve_config, 11).unwrap();
+        assert_eq!(
+            key_pair.public_key.curve,
+            NamedCurve::P256.domain_parameters()
+        );
+        assert!(key_pair
+            .public_key
+            .curve
+            .curve
+            .has_point(&key_pair.public_key.y));
+
+        let service = NumberTheoryService::new(Fast);
+        let plaintext = MenezesVanstonePlaintext {
+            first: BigInt::from(2).pow(200u32),
+            second: 42.into(),
+        };
+        let ciphertext =
+            MenezesVanstoneScheme::encrypt(&key_pair.public_key, &plaintext, service).unwrap();
+        assert_eq!(
+            MenezesVanstoneScheme::decrypt(&key_pair.private_key, &ciphertext, service).unwrap(),
+            plaintext
+        );
+
+        let message = "Hello World!";
+        let signature =
+            MenezesVanstoneScheme::sign(&key_pair.private_key, message, service).unwrap();
+        assert!(
+            MenezesVanstoneScheme::verify(&key_pair.public_key, &signature, message, service)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sign_verify_with_hash_algorithm() {
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 16, 40),
+            73,
+        )
+        .unwrap();
+        let service = NumberTheoryService::new(Fast);
+        let message = "Hello World!";
+
+        let signature = MenezesVanstoneScheme::sign_with_hash(
+            &key_pair.private_key,
+            message,
+            HashAlgorithm::Sha3_512,
+            service,
+        )
+        .unwrap();
+        assert_eq!(signature.hash_algorithm, HashAlgorithm::Sha3_512);
+        assert!(
+            MenezesVanstoneScheme::verify(&key_pair.public_key, &signature, message, service)
+                .unwrap()
+        );
+
+        // Wird ein anderes Hashverfahren angegeben, passt die Signatur nicht mehr zur Nachricht.
+        let wrong_algorithm = MenezesVanstoneSignature {
+            hash_algorithm: HashAlgorithm::Sha256,
+            ..signature
+        };
+        assert!(!MenezesVanstoneScheme::verify(
+            &key_pair.public_key,
+            &wrong_algorithm,
+            message,
+            service
+        )
+        .unwrap());
+    }
+
     #[test]
     fn test_invalid_n_value_error() {
         // Testet, ob ein Fehler zurückgegeben wird, wenn n = 0 ist
-        let result = MenezesVanstoneScheme::generate_keypair(0, 128, 40, 123);
+        let result = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(0, 128, 40),
+            123,
+        );
         match result {
             Err(err) => match err.downcast_ref::<MenezesVanstoneError>() {
                 Some(&MenezesVanstoneError::InvalidNValueError(_)) => assert!(true),
@@ -408,7 +608,10 @@ mod tests {
     #[test]
     fn test_invalid_modulus_width_error() {
         // Testet, ob ein Fehler zurückgegeben wird, wenn die Breite des Moduls <= 3 ist
-        let result = MenezesVanstoneScheme::generate_keypair(5, 3, 40, 123);
+        let result = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 3, 40),
+            123,
+        );
         match result {
             Err(err) => match err.downcast_ref::<MenezesVanstoneError>() {
                 Some(&MenezesVanstoneError::InvalidModulusWidthError(_)) => assert!(true),
@@ -424,8 +627,11 @@ mod tests {
         let n = 5;
         let modul_width = 16;
         let random_seed = 73;
-        let key_pair =
-            MenezesVanstoneScheme::generate_keypair(n, modul_width, 40, random_seed).unwrap();
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+        )
+        .unwrap();
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
         let message = "Hello My Friend!";
@@ -436,6 +642,7 @@ mod tests {
         let invalid_signature = MenezesVanstoneSignature {
             r: BigInt::from(12345),
             s: BigInt::from(67890),
+            hash_algorithm: HashAlgorithm::Sha256,
         };
         let is_verified = MenezesVanstoneScheme::verify(
             &public_key,
@@ -453,8 +660,11 @@ mod tests {
         let n = 5;
         let modul_width = 16;
         let random_seed = 73;
-        let key_pair =
-            MenezesVanstoneScheme::generate_keypair(n, modul_width, 40, random_seed).unwrap();
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+        )
+        .unwrap();
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
@@ -1,3 +1,7 @@
+pub mod aes;
+pub mod ecdsa;
+pub mod eddsa;
 pub mod el_gamal;
 pub mod menezes_vanstone;
 pub mod rsa;
+pub mod schnorr;
@@ -7,7 +7,7 @@ use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::increment::Increment;
 use anyhow::Result;
 use atomic_counter::RelaxedCounter;
@@ -50,14 +50,15 @@ impl KeyGenWithPrimeConfig for RsaKeyGenConfig {
 impl KeyGenerator<RsaPublicKey, RsaPrivateKey, RsaScheme> for RsaScheme {
     type KeyPair = RsaKeyPair;
 
-    fn generate_keypair(config: &impl KeyGenWithPrimeConfig) -> Self::KeyPair {
+    fn generate_keypair_with_generator(
+        config: &impl KeyGenWithPrimeConfig,
+        random_generator: &impl RandomNumberGenerator,
+    ) -> Self::KeyPair {
         debug!(
             "Generiere Schlüsselpaar mit key_size {} und Miller-Rabin-Iterations {}",
             config.characteristic(),
             config.miller_rabin_iterations()
         );
-        let random_generator =
-            &PseudoRandomNumberGenerator::new(config.random_seed(), config.number_theory_service());
 
         let (prime_one, prime_two) = random_generator
             .get_distinct_primes(config.characteristic(), config.miller_rabin_iterations());
@@ -66,8 +67,15 @@ impl KeyGenerator<RsaPublicKey, RsaPrivateKey, RsaScheme> for RsaScheme {
         debug!("n ist {}", n);
 
         let phi = (&prime_one.decrement()) * (&prime_two.decrement());
+        // λ(n) = kgV(p - 1, q - 1) ist der Exponent der Einheitengruppe modulo n und damit der
+        // kleinste Modul, für den e * d ≡ 1 die Entschlüsselung garantiert.
+        let lambda = &phi
+            / config
+                .number_theory_service()
+                .extended_euclid(&prime_one.decrement(), &prime_two.decrement())
+                .ggt;
         let e = Self::generate_e(&phi, random_generator, config.number_theory_service());
-        let d = Self::generate_d(&e, &phi, config.number_theory_service()).unwrap(); // TODO: Abfangen später
+        let d = Self::generate_d(&e, &lambda, config.number_theory_service()).unwrap(); // TODO: Abfangen später
 
         RsaKeyPair {
             public_key: RsaPublicKey { e, n: n.clone() },
@@ -140,13 +148,13 @@ impl RsaScheme {
     ///
     /// # Argumente
     /// * `phi` - Die Zahl `phi`.
-    /// * `random_generator` - Der Pseudo-Zufallszahlengenerator.
+    /// * `random_generator` - Der Zufallszahlengenerator.
     ///
     /// # Rückgabe
     /// Die generierte Zahl `e`.
     fn generate_e(
         phi: &BigInt,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &impl RandomNumberGenerator,
         service: NumberTheoryService,
     ) -> BigInt {
         debug!("Generiere e mit phi {}", phi);
@@ -166,21 +174,22 @@ impl RsaScheme {
         panic!("Kein e gefunden, das relativ prim zu phi {} ist", phi);
     }
 
-    /// Generiert eine Zahl `d` mit `1 < d < phi` und `e * d = 1 mod phi`.
-    /// d ist damit das multiplikative Inverse von e mod phi.
+    /// Generiert eine Zahl `d` mit `1 < d < lambda` und `e * d = 1 mod lambda`.
+    /// d ist damit das multiplikative Inverse von e mod lambda.
     ///
     /// # Argumente
     /// * `e` - Die Zahl `e`.
-    /// * `phi` - Die Zahl `phi`.
+    /// * `lambda` - Die Carmichael-Funktion λ(n) = kgV(p - 1, q - 1). Da e zu φ(n) teilerfremd
+    ///   ist, ist es auch zu deren Teiler λ(n) teilerfremd.
     ///
     /// # Rückgabe
     /// Die generierte Zahl `d`.
     ///
     /// # Fehler
     /// * `ArithmeticError::NoInverseError` - Falls kein multiplikatives Inverses gefunden werden konnte.
-    fn generate_d(e: &BigInt, phi: &BigInt, service: NumberTheoryService) -> Result<BigInt> {
-        trace!("Generiere d mit e {} und phi {}", e, phi);
-        let d = service.modulo_inverse(e, phi)?;
+    fn generate_d(e: &BigInt, lambda: &BigInt, service: NumberTheoryService) -> Result<BigInt> {
+        trace!("Generiere d mit e {} und lambda {}", e, lambda);
+        let d = service.modulo_inverse(e, lambda)?;
         debug!("d ist {}", d);
         Ok(d)
     }
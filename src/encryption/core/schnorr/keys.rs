@@ -0,0 +1,56 @@
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricKey, PrivateKey, PublicKey, SignatureKey, VerificationKey,
+};
+use crate::encryption::core::menezes_vanstone::keys::{
+    MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+};
+use crate::encryption::core::schnorr::schnorr_scheme::SchnorrScheme;
+use crate::encryption::encryption_types::Key;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::prime_order_group::PrimeOrderGroup;
+
+/// Öffentlicher Schlüssel für Schnorr-Signaturen, das Gruppenelement y = g^x.
+#[derive(Clone, Debug)]
+pub struct SchnorrPublicKey<G: PrimeOrderGroup> {
+    pub group: G,
+    pub y: G::Element,
+}
+
+impl<G: PrimeOrderGroup> Key<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+impl<G: PrimeOrderGroup> AsymmetricKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+impl<G: PrimeOrderGroup> PublicKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+impl<G: PrimeOrderGroup> VerificationKey<SchnorrScheme<G>> for SchnorrPublicKey<G> {}
+
+/// Privater Schlüssel für Schnorr-Signaturen, der Exponent x mit 1 <= x < q.
+#[derive(Clone, Debug)]
+pub struct SchnorrPrivateKey<G: PrimeOrderGroup> {
+    pub group: G,
+    pub x: BigInt,
+}
+
+impl<G: PrimeOrderGroup> Key<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+impl<G: PrimeOrderGroup> AsymmetricKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+impl<G: PrimeOrderGroup> PrivateKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+impl<G: PrimeOrderGroup> SignatureKey<SchnorrScheme<G>> for SchnorrPrivateKey<G> {}
+
+impl From<MenezesVanstonePublicKey> for SchnorrPublicKey<SecureFiniteFieldEllipticCurve> {
+    /// Übernimmt Kurve und Punkt eines MenezesVanstone-Schlüssels.
+    fn from(key: MenezesVanstonePublicKey) -> Self {
+        SchnorrPublicKey {
+            group: key.curve,
+            y: key.y,
+        }
+    }
+}
+
+impl From<MenezesVanstonePrivateKey> for SchnorrPrivateKey<SecureFiniteFieldEllipticCurve> {
+    /// Übernimmt Kurve und Exponent eines MenezesVanstone-Schlüssels.
+    fn from(key: MenezesVanstonePrivateKey) -> Self {
+        SchnorrPrivateKey {
+            group: key.curve,
+            x: key.x,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::marker::PhantomData;
+
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::Zero;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricEncryptionScheme, Signer, Verifier,
+};
+use crate::encryption::core::ecdsa::ecdsa_scheme::{hash_to_integer, DeterministicNonces};
+use crate::encryption::core::schnorr::keys::{SchnorrPrivateKey, SchnorrPublicKey};
+use crate::encryption::encryption_types::EncryptionScheme;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::prime_order_group::PrimeOrderGroup;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+
+/// Eine Schnorr-Signatur (e, s) zusammen mit dem Hashverfahren der Challenge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchnorrSignature {
+    pub e: BigInt,
+    pub s: BigInt,
+    pub hash_algorithm: HashAlgorithm,
+}
+
+/// Schnorr-Signaturen über einer beliebigen Gruppe mit Primzahlordnung, also sowohl über den
+/// quadratischen Resten modulo einer sicheren Primzahl als auch über elliptischen Kurven.
+///
+/// Mit R = g^k ist e = H(R || y || m) mod q und s = k + e · x mod q. Zum Prüfen wird
+/// R' = g^s · y^(-e) berechnet und die Challenge verglichen. Die Nonce k wird wie bei ECDSA
+/// nach RFC 6979 deterministisch abgeleitet.
+pub struct SchnorrScheme<G: PrimeOrderGroup> {
+    group: PhantomData<G>,
+}
+
+impl<G: PrimeOrderGroup> EncryptionScheme for SchnorrScheme<G> {}
+
+impl<G: PrimeOrderGroup> AsymmetricEncryptionScheme for SchnorrScheme<G> {}
+
+impl<G: PrimeOrderGroup> SchnorrScheme<G> {
+    /// Signiert die Nachricht.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel x.
+    /// * `message` - Die Nachricht.
+    /// * `hash_algorithm` - Das Hashverfahren für die Challenge und die Nonces.
+    ///
+    /// # Fehler
+    /// * `HashError::UnsupportedHmacAlgorithmError` - Falls das Hashverfahren SHAKE ist.
+    pub fn sign_with_hash(
+        key: &SchnorrPrivateKey<G>,
+        message: &[u8],
+        hash_algorithm: HashAlgorithm,
+    ) -> Result<SchnorrSignature> {
+        let group = &key.group;
+        let q = group.order();
+        let x = key.x.rem_euclid(q);
+        let y = group.exponentiate(&group.generator(), &x, ScalarKind::Secret)?;
+
+        let digest = hash_algorithm.digest(message);
+        let mut nonces = DeterministicNonces::new(&x, &digest, q, hash_algorithm)?;
+        loop {
+            let k = nonces.next()?;
+            let commitment = group.exponentiate(&group.generator(), &k, ScalarKind::Secret)?;
+            let e = challenge(group, &commitment, &y, message, hash_algorithm)?;
+            if e.is_zero() {
+                continue;
+            }
+            return Ok(SchnorrSignature {
+                s: (k + &e * &x).rem_euclid(q),
+                e,
+                hash_algorithm,
+            });
+        }
+    }
+}
+
+impl<G: PrimeOrderGroup> Signer<SchnorrScheme<G>> for SchnorrScheme<G> {
+    type Input = [u8];
+    type Output = Result<SchnorrSignature>;
+    type Key = SchnorrPrivateKey<G>;
+
+    /// Signiert die Nachricht mit dem Standard-Hashverfahren SHA-256, siehe `sign_with_hash`.
+    fn sign(key: &Self::Key, message: &Self::Input, _service: NumberTheoryService) -> Self::Output {
+        Self::sign_with_hash(key, message, HashAlgorithm::default())
+    }
+}
+
+impl<G: PrimeOrderGroup> Verifier<SchnorrScheme<G>> for SchnorrScheme<G> {
+    type Signature = SchnorrSignature;
+    type Message = [u8];
+    type Output = Result<bool>;
+    type Key = SchnorrPublicKey<G>;
+
+    /// Prüft eine Signatur mit dem Hashverfahren der Signatur.
+    ///
+    /// # Rückgabe
+    /// * `true`, falls 1 <= e < q und 0 <= s < q gilt, y ein gültiges Gruppenelement ist und
+    ///   H(g^s · y^(-e) || y || m) mod q gleich e ist.
+    fn verify(
+        key: &Self::Key,
+        signature: &Self::Signature,
+        message: &Self::Message,
+        _service: NumberTheoryService,
+    ) -> Self::Output {
+        let group = &key.group;
+        let q = group.order();
+        if !signature.e.is_positive()
+            || &signature.e >= q
+            || signature.s.is_negative()
+            || &signature.s >= q
+            || !group.is_valid_element(&key.y)
+        {
+            return Ok(false);
+        }
+
+        let first = group.exponentiate(&group.generator(), &signature.s, ScalarKind::Public)?;
+        let second = group.exponentiate(&key.y, &(q - &signature.e), ScalarKind::Public)?;
+        let commitment = group.operate(&first, &second)?;
+        if commitment == group.identity() {
+            return Ok(false);
+        }
+
+        let e = challenge(
+            group,
+            &commitment,
+            &key.y,
+            message,
+            signature.hash_algorithm,
+        )?;
+        Ok(e == signature.e)
+    }
+}
+
+/// Die Challenge H(R || y || m), nach FIPS 186-4 gekürzt und modulo q reduziert.
+fn challenge<G: PrimeOrderGroup>(
+    group: &G,
+    commitment: &G::Element,
+    public_key: &G::Element,
+    message: &[u8],
+    hash_algorithm: HashAlgorithm,
+) -> Result<BigInt> {
+    let mut input = group.encode(commitment)?;
+    input.extend(group.encode(public_key)?);
+    input.extend(message);
+    let q = group.order();
+    Ok(hash_to_integer(&hash_algorithm.digest(&input), q).rem_euclid(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
+        MenezesVanstoneCurveConfig, MenezesVanstoneScheme,
+    };
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::prime_order_group::PrimeFieldSubgroup;
+    use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+    use crate::math_core::random_number_generator::RandomNumberGenerator;
+    use atomic_counter::RelaxedCounter;
+
+    /// Berechnet den zugehörigen öffentlichen Schlüssel y = g^x.
+    fn derive_public_key<G: PrimeOrderGroup + Clone>(
+        private_key: &SchnorrPrivateKey<G>,
+    ) -> SchnorrPublicKey<G> {
+        let group = &private_key.group;
+        SchnorrPublicKey {
+            group: group.clone(),
+            y: group
+                .exponentiate(&group.generator(), &private_key.x, ScalarKind::Secret)
+                .unwrap(),
+        }
+    }
+
+    /// Signiert mehrere Nachrichten und prüft die Signaturen gegen veränderte Nachrichten,
+    /// Signaturen und Schlüssel.
+    fn assert_sign_and_verify<G: PrimeOrderGroup + Clone>(private_key: SchnorrPrivateKey<G>) {
+        let service = NumberTheoryService::new(Fast);
+        let public_key = derive_public_key(&private_key);
+        let other_key = derive_public_key(&SchnorrPrivateKey {
+            x: &private_key.x + 1,
+            ..private_key.clone()
+        });
+        let q = private_key.group.order().clone();
+
+        for message in ["Hallo Welt", "Schnorr", ""] {
+            let signature = SchnorrScheme::sign(&private_key, message.as_bytes(), service).unwrap();
+            assert_eq!(
+                signature,
+                SchnorrScheme::sign(&private_key, message.as_bytes(), service).unwrap()
+            );
+            assert!(
+                SchnorrScheme::verify(&public_key, &signature, message.as_bytes(), service)
+                    .unwrap()
+            );
+            assert!(
+                !SchnorrScheme::verify(&public_key, &signature, b"other message", service).unwrap()
+            );
+            assert!(
+                !SchnorrScheme::verify(&other_key, &signature, message.as_bytes(), service)
+                    .unwrap()
+            );
+
+            let tampered = SchnorrSignature {
+                s: (&signature.s + 1) % &q,
+                ..signature.clone()
+            };
+            assert!(
+                !SchnorrScheme::verify(&public_key, &tampered, message.as_bytes(), service)
+                    .unwrap()
+            );
+            let out_of_range = SchnorrSignature {
+                s: &signature.s + &q,
+                ..signature.clone()
+            };
+            assert!(!SchnorrScheme::verify(
+                &public_key,
+                &out_of_range,
+                message.as_bytes(),
+                service
+            )
+            .unwrap());
+        }
+
+        let sha512 =
+            SchnorrScheme::sign_with_hash(&private_key, b"Hallo Welt", HashAlgorithm::Sha512)
+                .unwrap();
+        assert!(SchnorrScheme::verify(&public_key, &sha512, b"Hallo Welt", service).unwrap());
+    }
+
+    #[test]
+    fn test_prime_field_group() {
+        let service = NumberTheoryService::new(Fast);
+        let random_generator = PseudoRandomNumberGenerator::new(17, service);
+        let counter = RelaxedCounter::new(1);
+        let (p, g) = random_generator.generate_secure_prime_with_primitive_root(128, 20, &counter);
+        let group = PrimeFieldSubgroup::from_safe_prime(&p, &g).unwrap();
+        let x = random_generator.take(&1.into(), &(&group.order - 1), &counter);
+
+        let private_key = SchnorrPrivateKey { group, x };
+        assert_sign_and_verify(private_key.clone());
+
+        // Elemente außerhalb der Untergruppe werden als öffentlicher Schlüssel abgelehnt.
+        let signature = SchnorrScheme::sign(&private_key, b"Hallo Welt", service).unwrap();
+        let invalid_key = SchnorrPublicKey {
+            group: private_key.group.clone(),
+            y: &private_key.group.prime - 1,
+        };
+        assert!(!SchnorrScheme::verify(&invalid_key, &signature, b"Hallo Welt", service).unwrap());
+    }
+
+    #[test]
+    fn test_named_curve() {
+        assert_sign_and_verify(SchnorrPrivateKey {
+            group: NamedCurve::P256.domain_parameters(),
+            x: BigInt::parse_bytes(
+                b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+                16,
+            )
+            .unwrap(),
+        });
+    }
+
+    #[test]
+    fn test_generated_curve() {
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(3, 32, 40),
+            11,
+        )
+        .unwrap();
+        let private_key = SchnorrPrivateKey::from(key_pair.private_key);
+        assert_eq!(derive_public_key(&private_key).y, key_pair.public_key.y);
+        assert_sign_and_verify(private_key);
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+use chacha20poly1305::ChaCha20Poly1305;
+use rand::RngCore;
+
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::os2ip;
+use crate::shared::errors::HybridEncryptionError::{
+    AuthenticationFailedError, InvalidNonceLengthError, UnknownAeadAlgorithmError,
+};
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use crate::shared::hashing::kdf::hkdf;
+
+/// Länge des symmetrischen Schlüssels in Bytes, der aus dem gemeinsamen Geheimnis abgeleitet wird.
+pub const DEM_KEY_SIZE: usize = 32;
+
+/// Länge der Nonce in Bytes, die beide AEAD-Verfahren verwenden.
+pub const NONCE_SIZE: usize = 12;
+
+/// Das authentifizierte symmetrische Verfahren (AEAD), mit dem die Nutzdaten verschlüsselt werden.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AeadAlgorithm {
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+impl Display for AeadAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AeadAlgorithm::Aes256Gcm => write!(f, "AES-256-GCM"),
+            AeadAlgorithm::ChaCha20Poly1305 => write!(f, "ChaCha20-Poly1305"),
+        }
+    }
+}
+
+impl FromStr for AeadAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name {
+            "AES-256-GCM" => Ok(AeadAlgorithm::Aes256Gcm),
+            "ChaCha20-Poly1305" => Ok(AeadAlgorithm::ChaCha20Poly1305),
+            _ => Err(UnknownAeadAlgorithmError(name.to_string()).into()),
+        }
+    }
+}
+
+/// Der Chiffretext eines hybriden Verfahrens.
+///
+/// # Felder
+/// * `aead` - Das verwendete AEAD-Verfahren.
+/// * `encapsulated_key` - Der mit dem asymmetrischen Verfahren gekapselte Schlüssel.
+/// * `nonce` - Die Nonce des AEAD-Verfahrens.
+/// * `payload` - Die verschlüsselten Nutzdaten inklusive Authentifizierungs-Tag.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HybridCiphertext {
+    pub aead: AeadAlgorithm,
+    pub encapsulated_key: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+/// Leitet mit HKDF-SHA256 (RFC 5869) den symmetrischen Schlüssel aus dem gemeinsamen Geheimnis ab.
+///
+/// # Argumente
+/// * `shared_secret` - Das gemeinsame Geheimnis des KEM.
+/// * `info` - Kontextinformationen, an die der Schlüssel gebunden wird.
+pub fn derive_key(shared_secret: &[u8], info: &[u8]) -> [u8; DEM_KEY_SIZE] {
+    // Die unwrap() schlagen niemals fehl: SHA-256 ist für HMAC zulässig und es werden genau
+    // 32 Bytes angefordert, deutlich unter der Maximallänge von 255 * 32 Bytes.
+    hkdf(
+        HashAlgorithm::Sha256,
+        &[],
+        shared_secret,
+        info,
+        DEM_KEY_SIZE,
+    )
+    .unwrap()
+    .try_into()
+    .unwrap()
+}
+
+/// Verschlüsselt die Nutzdaten mit einer zufälligen Nonce und dem gegebenen AEAD-Verfahren.
+/// Der gekapselte Schlüssel wird als zugehörige Daten authentifiziert.
+///
+/// # Argumente
+/// * `aead` - Das AEAD-Verfahren.
+/// * `key` - Der abgeleitete symmetrische Schlüssel.
+/// * `encapsulated_key` - Der gekapselte Schlüssel.
+/// * `plaintext` - Die zu verschlüsselnden Nutzdaten.
+pub fn seal(
+    aead: AeadAlgorithm,
+    key: &[u8; DEM_KEY_SIZE],
+    encapsulated_key: Vec<u8>,
+    plaintext: &[u8],
+) -> HybridCiphertext {
+    let mut nonce = vec![0u8; NONCE_SIZE];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let payload = Payload {
+        msg: plaintext,
+        aad: &encapsulated_key,
+    };
+    // Das Verschlüsseln schlägt nur bei Nachrichten jenseits von 2^36 Bytes fehl.
+    let payload = match aead {
+        AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(key.into())
+            .encrypt(Nonce::from_slice(&nonce), payload)
+            .expect("Verschlüsselung mit AES-256-GCM fehlgeschlagen."),
+        AeadAlgorithm::ChaCha20Poly1305 => ChaCha20Poly1305::new(key.into())
+            .encrypt(Nonce::from_slice(&nonce), payload)
+            .expect("Verschlüsselung mit ChaCha20-Poly1305 fehlgeschlagen."),
+    };
+
+    HybridCiphertext {
+        aead,
+        encapsulated_key,
+        nonce,
+        payload,
+    }
+}
+
+/// Entschlüsselt die Nutzdaten und prüft dabei deren Authentizität.
+///
+/// # Argumente
+/// * `key` - Der abgeleitete symmetrische Schlüssel.
+/// * `ciphertext` - Der hybride Chiffretext.
+///
+/// # Fehler
+/// * `HybridEncryptionError::InvalidNonceLengthError` - Falls die Nonce nicht 12 Bytes lang ist.
+/// * `HybridEncryptionError::AuthenticationFailedError` - Falls der Chiffretext manipuliert wurde.
+pub fn open(key: &[u8; DEM_KEY_SIZE], ciphertext: &HybridCiphertext) -> Result<Vec<u8>> {
+    ensure!(
+        ciphertext.nonce.len() == NONCE_SIZE,
+        InvalidNonceLengthError(ciphertext.nonce.len())
+    );
+
+    let nonce = Nonce::from_slice(&ciphertext.nonce);
+    let payload = Payload {
+        msg: &ciphertext.payload,
+        aad: &ciphertext.encapsulated_key,
+    };
+    let plaintext = match ciphertext.aead {
+        AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(key.into()).decrypt(nonce, payload),
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            ChaCha20Poly1305::new(key.into()).decrypt(nonce, payload)
+        }
+    };
+    plaintext.map_err(|_| AuthenticationFailedError.into())
+}
+
+/// Erzeugt mit dem kryptographisch sicheren Zufallszahlengenerator des Betriebssystems eine
+/// gleichverteilte Zahl im Bereich von 1 bis `upper - 1`.
+/// Der deterministische PseudoRandomNumberGenerator eignet sich nicht für Sitzungsschlüssel, weil
+/// dessen Seed erraten werden kann.
+pub(crate) fn random_in_range(upper: &BigInt) -> BigInt {
+    // 64 zusätzliche Bits machen die Abweichung von der Gleichverteilung vernachlässigbar.
+    let mut bytes = vec![0u8; (upper.bits() as usize).div_ceil(8) + 8];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    os2ip(&bytes) % (upper - BigInt::one()) + BigInt::one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::HybridEncryptionError;
+
+    #[test]
+    fn test_derive_key_rfc_5869_test_case_3() {
+        // RFC 5869, Anhang A.3: ohne Salt und ohne Kontextinformationen
+        let okm = derive_key(&[0x0b; 22], &[]);
+        let expected = [
+            0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c,
+            0x5a, 0x31, 0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f,
+            0x3c, 0x73, 0x8d, 0x2d,
+        ];
+        assert_eq!(okm, expected);
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip_and_tampering() {
+        let key = derive_key(b"gemeinsames Geheimnis", b"test");
+        for aead in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            let ciphertext = seal(aead, &key, vec![1, 2, 3], b"Nutzdaten");
+            assert_eq!(open(&key, &ciphertext).unwrap(), b"Nutzdaten");
+
+            let mut tampered = ciphertext.clone();
+            tampered.encapsulated_key[0] ^= 1;
+            let error = open(&key, &tampered).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<HybridEncryptionError>(),
+                Some(HybridEncryptionError::AuthenticationFailedError)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_aead_algorithm_names() {
+        for aead in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            assert_eq!(aead.to_string().parse::<AeadAlgorithm>().unwrap(), aead);
+        }
+        assert!("DES".parse::<AeadAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_random_in_range() {
+        let upper = BigInt::from(5);
+        for _ in 0..100 {
+            let random = random_in_range(&upper);
+            assert!(random >= BigInt::one() && random < upper);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use anyhow::{ensure, Result};
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor,
+};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::hybrid::data_encapsulation::{
+    derive_key, open, random_in_range, seal, HybridCiphertext,
+};
+use crate::encryption::hybrid::keys::{EcKemPrivateKey, EcKemPublicKey};
+use crate::encryption::hybrid::rsa_kem_scheme::byte_length;
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::i2osp;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::shared::errors::HybridEncryptionError::{
+    InvalidEncapsulatedKeyLengthError, InvalidEncapsulatedPointError,
+};
+
+/// Kontextinformation für die Schlüsselableitung, damit die Schlüssel verschiedener KEMs getrennt sind.
+const KDF_LABEL: &[u8] = b"EC-KEM";
+
+/// Hybride Verschlüsselung mit einem KEM über den elliptischen Kurven des MenezesVanstone-Schemas.
+/// Gekapselt wird der Punkt R = r * g in komprimierter SEC1-Kodierung, das gemeinsame Geheimnis
+/// ist die x-Koordinate von r * y = x * R.
+pub struct EcKemScheme {}
+
+impl EncryptionScheme for EcKemScheme {}
+
+impl AsymmetricEncryptionScheme for EcKemScheme {}
+
+impl Encryptor<EcKemScheme> for EcKemScheme {
+    type Input = [u8];
+    type Output = Result<HybridCiphertext>;
+    type Key = EcKemPublicKey;
+}
+
+impl AsymmetricEncryptor<EcKemScheme> for EcKemScheme {
+    /// Verschlüsselt eine beliebige Bytefolge hybrid.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel mit dem AEAD-Verfahren für die Nutzdaten.
+    /// * `plaintext` - Die zu verschlüsselnde Bytefolge.
+    /// * `_service` - Der Service für die Zahlentheorie. Wird nicht benötigt.
+    ///
+    /// # Rückgabe
+    /// Der komprimiert gekapselte Punkt r * g und die verschlüsselten Nutzdaten.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        _service: NumberTheoryService,
+    ) -> Self::Output {
+        let curve = &key.mv_key.curve;
+        let coordinate_length = byte_length(&curve.curve.prime);
+
+        let (r_point, shared_point) = loop {
+            let r = random_in_range(&curve.order_of_subgroup);
+            let r_point = curve.generator.multiply(&r, &curve.curve)?;
+            let shared_point = key.mv_key.y.multiply(&r, &curve.curve)?;
+            if !shared_point.is_infinite {
+                break (r_point, shared_point);
+            }
+        };
+
+        let encapsulated_key = r_point.to_sec1(&curve.curve, true)?;
+        let dem_key = derive_key(
+            &i2osp(&shared_point.x, coordinate_length)?,
+            &[KDF_LABEL, &encapsulated_key].concat(),
+        );
+        Ok(seal(key.aead, &dem_key, encapsulated_key, plaintext))
+    }
+}
+
+impl Decryptor<EcKemScheme> for EcKemScheme {
+    type Input = HybridCiphertext;
+    type Output = Result<Vec<u8>>;
+    type Key = EcKemPrivateKey;
+}
+
+impl AsymmetricDecryptor<EcKemScheme> for EcKemScheme {
+    /// Entschlüsselt einen hybriden Chiffretext und prüft dabei dessen Authentizität.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der hybride Chiffretext.
+    /// * `_service` - Der Service für die Zahlentheorie. Wird nicht benötigt.
+    ///
+    /// # Rückgabe
+    /// Die entschlüsselte Bytefolge.
+    ///
+    /// # Fehler
+    /// * `HybridEncryptionError` - Falls der gekapselte Punkt ungültig ist oder die
+    ///   Authentifizierung fehlschlägt.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        _service: NumberTheoryService,
+    ) -> Self::Output {
+        let curve = &key.mv_key.curve;
+        let coordinate_length = byte_length(&curve.curve.prime);
+        let encapsulated_key = &ciphertext.encapsulated_key;
+        ensure!(
+            encapsulated_key.len() == 1 + coordinate_length,
+            InvalidEncapsulatedKeyLengthError(1 + coordinate_length, encapsulated_key.len())
+        );
+
+        // Der Punkt muss auf der Kurve und in der Untergruppe des Generators liegen, sonst
+        // könnte ein Angreifer mit Punkten kleiner Ordnung Bits von x erfahren.
+        let r_point = curve
+            .decode_point(encapsulated_key)
+            .map_err(|_| InvalidEncapsulatedPointError)?;
+
+        let shared_point = r_point.multiply(&key.mv_key.x, &curve.curve)?;
+        ensure!(!shared_point.is_infinite, InvalidEncapsulatedPointError);
+
+        let dem_key = derive_key(
+            &i2osp(&shared_point.x, coordinate_length)?,
+            &[KDF_LABEL, encapsulated_key].concat(),
+        );
+        open(&dem_key, ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
+        MenezesVanstoneCurveConfig, MenezesVanstoneScheme,
+    };
+    use crate::encryption::hybrid::data_encapsulation::AeadAlgorithm;
+    use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+    use crate::math_core::number_theory::modular_square_root::modular_square_root;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::shared::errors::HybridEncryptionError;
+    use bigdecimal::num_bigint::BigInt;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let service = NumberTheoryService::new(Fast);
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 64, 40),
+            11,
+        )
+        .unwrap();
+        let private_key = EcKemPrivateKey {
+            mv_key: key_pair.private_key,
+        };
+        let message = "Hybride Verschlüsselung über elliptischen Kurven".as_bytes();
+
+        for aead in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            let public_key = EcKemPublicKey {
+                mv_key: key_pair.public_key.clone(),
+                aead,
+            };
+            let ciphertext = EcKemScheme::encrypt(&public_key, message, service).unwrap();
+            let plaintext = EcKemScheme::decrypt(&private_key, &ciphertext, service).unwrap();
+            assert_eq!(plaintext, message);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_rejects_invalid_points() {
+        let service = NumberTheoryService::new(Fast);
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 64, 40),
+            11,
+        )
+        .unwrap();
+        let public_key = EcKemPublicKey {
+            mv_key: key_pair.public_key,
+            aead: AeadAlgorithm::Aes256Gcm,
+        };
+        let private_key = EcKemPrivateKey {
+            mv_key: key_pair.private_key,
+        };
+
+        let curve = &public_key.mv_key.curve.curve;
+        let length = byte_length(&curve.prime);
+        let ciphertext = EcKemScheme::encrypt(&public_key, b"Geheim", service).unwrap();
+        assert_eq!(ciphertext.encapsulated_key.len(), 1 + length);
+
+        // Eine x-Koordinate, zu der es keinen Punkt gibt, und der Punkt (0, 0) der Ordnung 2
+        let x_without_point = (0..)
+            .map(BigInt::from)
+            .find(|x| {
+                modular_square_root(&curve.right_hand_side(x), &curve.prime, service).is_err()
+            })
+            .unwrap();
+        let point_of_order_two = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
+        for encapsulated_key in [
+            [vec![0x02], i2osp(&x_without_point, length).unwrap()].concat(),
+            point_of_order_two.to_sec1(curve, true).unwrap(),
+        ] {
+            let manipulated = HybridCiphertext {
+                encapsulated_key,
+                ..ciphertext.clone()
+            };
+            let error = EcKemScheme::decrypt(&private_key, &manipulated, service).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<HybridEncryptionError>(),
+                Some(HybridEncryptionError::InvalidEncapsulatedPointError)
+            ));
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor,
+};
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::hybrid::data_encapsulation::{
+    derive_key, open, random_in_range, seal, HybridCiphertext,
+};
+use crate::encryption::hybrid::keys::{ElGamalKemPrivateKey, ElGamalKemPublicKey};
+use crate::encryption::hybrid::rsa_kem_scheme::byte_length;
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{i2osp, os2ip};
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::HybridEncryptionError::{
+    EncapsulatedKeyOutOfRangeError, InvalidEncapsulatedKeyLengthError,
+};
+
+/// Kontextinformation für die Schlüsselableitung, damit die Schlüssel verschiedener KEMs getrennt sind.
+const KDF_LABEL: &[u8] = b"ElGamal-KEM";
+
+/// Hybride Verschlüsselung mit einem ElGamal-KEM in primen Restklassengruppen.
+/// Gekapselt wird a = g^k mod p, das gemeinsame Geheimnis ist y^k = a^x mod p.
+pub struct ElGamalKemScheme {}
+
+impl EncryptionScheme for ElGamalKemScheme {}
+
+impl AsymmetricEncryptionScheme for ElGamalKemScheme {}
+
+impl Encryptor<ElGamalKemScheme> for ElGamalKemScheme {
+    type Input = [u8];
+    type Output = Result<HybridCiphertext>;
+    type Key = ElGamalKemPublicKey;
+}
+
+impl AsymmetricEncryptor<ElGamalKemScheme> for ElGamalKemScheme {
+    /// Verschlüsselt eine beliebige Bytefolge hybrid.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel mit dem AEAD-Verfahren für die Nutzdaten.
+    /// * `plaintext` - Die zu verschlüsselnde Bytefolge.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Der gekapselte Schlüssel g^k mod p und die verschlüsselten Nutzdaten.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: NumberTheoryService,
+    ) -> Self::Output {
+        let p = &key.el_gamal_key.p;
+        let modulus_length = byte_length(p);
+
+        // k aus dem Bereich von 1 bis p-2
+        let k = random_in_range(&p.decrement());
+        let a = service.fast_exponentiation(&key.el_gamal_key.g, &k, p);
+        let shared_secret = service.fast_exponentiation(&key.el_gamal_key.y, &k, p);
+
+        let encapsulated_key = i2osp(&a, modulus_length)?;
+        let dem_key = derive_key(
+            &i2osp(&shared_secret, modulus_length)?,
+            &[KDF_LABEL, &encapsulated_key].concat(),
+        );
+        Ok(seal(key.aead, &dem_key, encapsulated_key, plaintext))
+    }
+}
+
+impl Decryptor<ElGamalKemScheme> for ElGamalKemScheme {
+    type Input = HybridCiphertext;
+    type Output = Result<Vec<u8>>;
+    type Key = ElGamalKemPrivateKey;
+}
+
+impl AsymmetricDecryptor<ElGamalKemScheme> for ElGamalKemScheme {
+    /// Entschlüsselt einen hybriden Chiffretext und prüft dabei dessen Authentizität.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der hybride Chiffretext.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die entschlüsselte Bytefolge.
+    ///
+    /// # Fehler
+    /// * `HybridEncryptionError` - Falls der gekapselte Schlüssel ungültig ist oder die
+    ///   Authentifizierung fehlschlägt.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: NumberTheoryService,
+    ) -> Self::Output {
+        let p = &key.el_gamal_key.p;
+        let modulus_length = byte_length(p);
+        let encapsulated_key = &ciphertext.encapsulated_key;
+        ensure!(
+            encapsulated_key.len() == modulus_length,
+            InvalidEncapsulatedKeyLengthError(modulus_length, encapsulated_key.len())
+        );
+
+        // Die Werte 0, 1 und p-1 liegen in keiner großen Untergruppe und werden abgelehnt.
+        let a = os2ip(encapsulated_key);
+        ensure!(
+            a > BigInt::one() && a < p.decrement(),
+            EncapsulatedKeyOutOfRangeError
+        );
+        let shared_secret = service.fast_exponentiation(&a, &key.el_gamal_key.x, p);
+
+        let dem_key = derive_key(
+            &i2osp(&shared_secret, modulus_length)?,
+            &[KDF_LABEL, encapsulated_key].concat(),
+        );
+        open(&dem_key, ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::el_gamal::el_gamal_scheme::{ElGamalKeyGenConfig, ElGamalScheme};
+    use crate::encryption::hybrid::data_encapsulation::AeadAlgorithm;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let service = NumberTheoryService::new(Fast);
+        let config = ElGamalKeyGenConfig {
+            modulus_width: 64,
+            miller_rabin_iterations: 40,
+            random_seed: 7,
+            number_theory_service: service,
+        };
+        let key_pair = ElGamalScheme::generate_keypair(&config);
+        let public_key = ElGamalKemPublicKey {
+            el_gamal_key: key_pair.public_key,
+            aead: AeadAlgorithm::ChaCha20Poly1305,
+        };
+        let private_key = ElGamalKemPrivateKey {
+            el_gamal_key: key_pair.private_key,
+        };
+
+        let message = [0u8, 1, 2, 3, 255, 0];
+        let ciphertext = ElGamalKemScheme::encrypt(&public_key, &message, service).unwrap();
+        let plaintext = ElGamalKemScheme::decrypt(&private_key, &ciphertext, service).unwrap();
+        assert_eq!(plaintext, message);
+
+        let mut tampered = ciphertext;
+        tampered.nonce[0] ^= 1;
+        assert!(ElGamalKemScheme::decrypt(&private_key, &tampered, service).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptionKey, AsymmetricEncryptionKey, AsymmetricKey, PrivateKey, PublicKey,
+};
+use crate::encryption::core::el_gamal::keys::{ElGamalPrivateKey, ElGamalPublicKey};
+use crate::encryption::core::menezes_vanstone::keys::{
+    MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
+};
+use crate::encryption::core::rsa::keys::{RsaPrivateKey, RsaPublicKey};
+use crate::encryption::encryption_types::Key;
+use crate::encryption::hybrid::data_encapsulation::AeadAlgorithm;
+use crate::encryption::hybrid::ec_kem_scheme::EcKemScheme;
+use crate::encryption::hybrid::el_gamal_kem_scheme::ElGamalKemScheme;
+use crate::encryption::hybrid::rsa_kem_scheme::RsaKemScheme;
+
+/// Öffentlicher Schlüssel für RSA-KEM. Das AEAD-Verfahren bestimmt, wie die Nutzdaten verschlüsselt
+/// werden; beim Entschlüsseln wird es aus dem Chiffretext übernommen.
+#[derive(Clone, Debug)]
+pub struct RsaKemPublicKey {
+    pub rsa_key: RsaPublicKey,
+    pub aead: AeadAlgorithm,
+}
+
+impl Key<RsaKemScheme> for RsaKemPublicKey {}
+impl AsymmetricKey<RsaKemScheme> for RsaKemPublicKey {}
+impl PublicKey<RsaKemScheme> for RsaKemPublicKey {}
+impl AsymmetricEncryptionKey<RsaKemScheme> for RsaKemPublicKey {}
+
+#[derive(Clone, Debug)]
+pub struct RsaKemPrivateKey {
+    pub rsa_key: RsaPrivateKey,
+}
+
+impl Key<RsaKemScheme> for RsaKemPrivateKey {}
+impl AsymmetricKey<RsaKemScheme> for RsaKemPrivateKey {}
+impl PrivateKey<RsaKemScheme> for RsaKemPrivateKey {}
+impl AsymmetricDecryptionKey<RsaKemScheme> for RsaKemPrivateKey {}
+
+/// Öffentlicher Schlüssel für das ElGamal-KEM in primen Restklassengruppen.
+#[derive(Clone, Debug)]
+pub struct ElGamalKemPublicKey {
+    pub el_gamal_key: ElGamalPublicKey,
+    pub aead: AeadAlgorithm,
+}
+
+impl Key<ElGamalKemScheme> for ElGamalKemPublicKey {}
+impl AsymmetricKey<ElGamalKemScheme> for ElGamalKemPublicKey {}
+impl PublicKey<ElGamalKemScheme> for ElGamalKemPublicKey {}
+impl AsymmetricEncryptionKey<ElGamalKemScheme> for ElGamalKemPublicKey {}
+
+#[derive(Clone, Debug)]
+pub struct ElGamalKemPrivateKey {
+    pub el_gamal_key: ElGamalPrivateKey,
+}
+
+impl Key<ElGamalKemScheme> for ElGamalKemPrivateKey {}
+impl AsymmetricKey<ElGamalKemScheme> for ElGamalKemPrivateKey {}
+impl PrivateKey<ElGamalKemScheme> for ElGamalKemPrivateKey {}
+impl AsymmetricDecryptionKey<ElGamalKemScheme> for ElGamalKemPrivateKey {}
+
+/// Öffentlicher Schlüssel für das KEM über elliptischen Kurven. Es werden die Kurven und Schlüssel
+/// des MenezesVanstone-Schemas verwendet.
+#[derive(Clone, Debug)]
+pub struct EcKemPublicKey {
+    pub mv_key: MenezesVanstonePublicKey,
+    pub aead: AeadAlgorithm,
+}
+
+impl Key<EcKemScheme> for EcKemPublicKey {}
+impl AsymmetricKey<EcKemScheme> for EcKemPublicKey {}
+impl PublicKey<EcKemScheme> for EcKemPublicKey {}
+impl AsymmetricEncryptionKey<EcKemScheme> for EcKemPublicKey {}
+
+#[derive(Clone, Debug)]
+pub struct EcKemPrivateKey {
+    pub mv_key: MenezesVanstonePrivateKey,
+}
+
+impl Key<EcKemScheme> for EcKemPrivateKey {}
+impl AsymmetricKey<EcKemScheme> for EcKemPrivateKey {}
+impl PrivateKey<EcKemScheme> for EcKemPrivateKey {}
+impl AsymmetricDecryptionKey<EcKemScheme> for EcKemPrivateKey {}
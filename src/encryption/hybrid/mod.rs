@@ -0,0 +1,5 @@
+pub mod data_encapsulation;
+pub mod ec_kem_scheme;
+pub mod el_gamal_kem_scheme;
+pub mod keys;
+pub mod rsa_kem_scheme;
@@ -0,0 +1,192 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::asymmetric_encryption_types::{
+    AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor,
+};
+use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::hybrid::data_encapsulation::{
+    derive_key, open, random_in_range, seal, HybridCiphertext,
+};
+use crate::encryption::hybrid::keys::{RsaKemPrivateKey, RsaKemPublicKey};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{i2osp, os2ip};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::shared::errors::HybridEncryptionError::{
+    EncapsulatedKeyOutOfRangeError, InvalidEncapsulatedKeyLengthError,
+};
+
+/// Kontextinformation für die Schlüsselableitung, damit die Schlüssel verschiedener KEMs getrennt sind.
+const KDF_LABEL: &[u8] = b"RSA-KEM";
+
+/// Hybride Verschlüsselung mit RSA-KEM (ISO 18033-2) und einem AEAD-Verfahren für die Nutzdaten.
+/// Statt der Nachricht wird eine zufällige Zahl r mit RSA verschlüsselt. Aus r wird mit HKDF-SHA256
+/// der symmetrische Schlüssel abgeleitet, mit dem die beliebig lange Nachricht verschlüsselt wird.
+pub struct RsaKemScheme {}
+
+impl EncryptionScheme for RsaKemScheme {}
+
+impl AsymmetricEncryptionScheme for RsaKemScheme {}
+
+impl Encryptor<RsaKemScheme> for RsaKemScheme {
+    type Input = [u8];
+    type Output = Result<HybridCiphertext>;
+    type Key = RsaKemPublicKey;
+}
+
+impl AsymmetricEncryptor<RsaKemScheme> for RsaKemScheme {
+    /// Verschlüsselt eine beliebige Bytefolge hybrid.
+    ///
+    /// # Argumente
+    /// * `key` - Der öffentliche Schlüssel mit dem AEAD-Verfahren für die Nutzdaten.
+    /// * `plaintext` - Die zu verschlüsselnde Bytefolge.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Der gekapselte Schlüssel r^e mod n und die verschlüsselten Nutzdaten.
+    fn encrypt(
+        key: &Self::Key,
+        plaintext: &Self::Input,
+        service: NumberTheoryService,
+    ) -> Self::Output {
+        let n = &key.rsa_key.n;
+        let modulus_length = byte_length(n);
+
+        let r = random_in_range(n);
+        let c = RsaScheme::encrypt(&key.rsa_key, &r, service);
+
+        let encapsulated_key = i2osp(&c, modulus_length)?;
+        let dem_key = derive_key(
+            &i2osp(&r, modulus_length)?,
+            &[KDF_LABEL, &encapsulated_key].concat(),
+        );
+        Ok(seal(key.aead, &dem_key, encapsulated_key, plaintext))
+    }
+}
+
+impl Decryptor<RsaKemScheme> for RsaKemScheme {
+    type Input = HybridCiphertext;
+    type Output = Result<Vec<u8>>;
+    type Key = RsaKemPrivateKey;
+}
+
+impl AsymmetricDecryptor<RsaKemScheme> for RsaKemScheme {
+    /// Entschlüsselt einen hybriden Chiffretext und prüft dabei dessen Authentizität.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `ciphertext` - Der hybride Chiffretext.
+    /// * `service` - Der Service für die Zahlentheorie.
+    ///
+    /// # Rückgabe
+    /// Die entschlüsselte Bytefolge.
+    ///
+    /// # Fehler
+    /// * `HybridEncryptionError` - Falls der gekapselte Schlüssel ungültig ist oder die
+    ///   Authentifizierung fehlschlägt.
+    fn decrypt(
+        key: &Self::Key,
+        ciphertext: &Self::Input,
+        service: NumberTheoryService,
+    ) -> Self::Output {
+        let n = &key.rsa_key.n;
+        let modulus_length = byte_length(n);
+        let encapsulated_key = &ciphertext.encapsulated_key;
+        ensure!(
+            encapsulated_key.len() == modulus_length,
+            InvalidEncapsulatedKeyLengthError(modulus_length, encapsulated_key.len())
+        );
+
+        let c = os2ip(encapsulated_key);
+        ensure!(&c < n, EncapsulatedKeyOutOfRangeError);
+        let r = RsaScheme::decrypt(&key.rsa_key, &c, service);
+
+        let dem_key = derive_key(
+            &i2osp(&r, modulus_length)?,
+            &[KDF_LABEL, encapsulated_key].concat(),
+        );
+        open(&dem_key, ciphertext)
+    }
+}
+
+/// Die Anzahl der Bytes, die zur Darstellung des Modulus benötigt werden.
+pub(crate) fn byte_length(modulus: &BigInt) -> usize {
+    (modulus.bits() as usize).div_ceil(8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::KeyGenerator;
+    use crate::encryption::core::rsa::rsa_scheme::RsaKeyGenConfig;
+    use crate::encryption::hybrid::data_encapsulation::AeadAlgorithm;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::shared::errors::HybridEncryptionError;
+
+    fn key_pair(random_seed: u32) -> (RsaKemPublicKey, RsaKemPrivateKey) {
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 30,
+            random_seed,
+            number_theory_service: NumberTheoryService::new(Fast),
+        };
+        let key_pair = RsaScheme::generate_keypair(&config);
+        (
+            RsaKemPublicKey {
+                rsa_key: key_pair.public_key,
+                aead: AeadAlgorithm::Aes256Gcm,
+            },
+            RsaKemPrivateKey {
+                rsa_key: key_pair.private_key,
+            },
+        )
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let service = NumberTheoryService::new(Fast);
+        let (mut public_key, private_key) = key_pair(13);
+        let message = "Eine lange Nachricht, die nicht blockweise mit RSA verschlüsselt wird. "
+            .repeat(20)
+            .into_bytes();
+
+        for aead in [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305] {
+            public_key.aead = aead;
+            let ciphertext = RsaKemScheme::encrypt(&public_key, &message, service).unwrap();
+            assert_eq!(ciphertext.aead, aead);
+            assert_eq!(ciphertext.payload.len(), message.len() + 16);
+
+            let plaintext = RsaKemScheme::decrypt(&private_key, &ciphertext, service).unwrap();
+            assert_eq!(plaintext, message);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key_and_tampering() {
+        let service = NumberTheoryService::new(Fast);
+        let (public_key, private_key) = key_pair(13);
+        let (_, other_private_key) = key_pair(29);
+
+        let ciphertext = RsaKemScheme::encrypt(&public_key, b"Geheim", service).unwrap();
+        assert!(RsaKemScheme::decrypt(&other_private_key, &ciphertext, service).is_err());
+
+        let mut tampered = ciphertext.clone();
+        tampered.payload[0] ^= 1;
+        let error = RsaKemScheme::decrypt(&private_key, &tampered, service).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<HybridEncryptionError>(),
+            Some(HybridEncryptionError::AuthenticationFailedError)
+        ));
+
+        let mut truncated = ciphertext;
+        truncated.encapsulated_key.pop();
+        let error = RsaKemScheme::decrypt(&private_key, &truncated, service).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<HybridEncryptionError>(),
+            Some(HybridEncryptionError::InvalidEncapsulatedKeyLengthError(
+                _,
+                _
+            ))
+        ));
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{ensure, Result};
+use atomic_counter::RelaxedCounter;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::prime_order_group::PrimeOrderGroup;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
+use crate::shared::errors::KeyExchangeError::InvalidPublicValueError;
+
+/// Das Schlüsselpaar einer Partei, der geheime Exponent x und der öffentliche Wert g^x.
+#[derive(Clone, Debug)]
+pub struct DiffieHellmanKeyPair<G: PrimeOrderGroup> {
+    pub private_key: BigInt,
+    pub public_value: G::Element,
+}
+
+///
+/// Schlüsselvereinbarung nach Diffie-Hellman in einer Gruppe mit Primzahlordnung q. Über den
+/// quadratischen Resten einer sicheren Primzahl ist das das klassische Verfahren, über
+/// elliptischen Kurven ECDH.
+///
+/// Alice und Bob tauschen g^a und g^b aus und berechnen beide g^(ab). Der öffentliche Wert der
+/// Gegenseite wird vorher geprüft, sonst könnte ein Angreifer mit Elementen kleiner Ordnung
+/// Bits des geheimen Exponenten erfahren.
+///
+#[derive(Clone, Debug)]
+pub struct DiffieHellman<G: PrimeOrderGroup> {
+    pub group: G,
+}
+
+impl<G: PrimeOrderGroup> DiffieHellman<G> {
+    pub fn new(group: G) -> Self {
+        DiffieHellman { group }
+    }
+
+    /// Erzeugt ein Schlüsselpaar mit einem geheimen Exponenten 1 <= x < q.
+    ///
+    /// # Argumente
+    /// * `random_generator` - Der Zufallszahlengenerator für den geheimen Exponenten.
+    /// * `counter` - Der Zähler des Zufallszahlengenerators.
+    pub fn generate_key_pair(
+        &self,
+        random_generator: &impl RandomNumberGenerator,
+        counter: &RelaxedCounter,
+    ) -> Result<DiffieHellmanKeyPair<G>> {
+        let order = self.group.order();
+        let private_key = random_generator.take(&1.into(), &(order - 1), counter);
+        let public_value =
+            self.group
+                .exponentiate(&self.group.generator(), &private_key, ScalarKind::Secret)?;
+        Ok(DiffieHellmanKeyPair {
+            private_key,
+            public_value,
+        })
+    }
+
+    /// Prüft einen öffentlichen Wert der Gegenseite. Er muss in der Untergruppe der Ordnung q
+    /// liegen und darf nicht das neutrale Element sein. Damit sind auch alle Elemente kleiner
+    /// Ordnung ausgeschlossen, etwa p - 1 modulo p oder Punkte im Kofaktor-Anteil einer Kurve.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidPublicValueError` - Falls der Wert ungültig ist.
+    pub fn validate_public_value(&self, public_value: &G::Element) -> Result<()> {
+        ensure!(
+            self.group.is_valid_element(public_value),
+            InvalidPublicValueError(public_value.to_string())
+        );
+        Ok(())
+    }
+
+    /// Berechnet das gemeinsame Geheimnis, die Bytedarstellung von y^x. Bei ECDH ist das wie
+    /// in SEC 1 nur die x-Koordinate.
+    ///
+    /// # Argumente
+    /// * `private_key` - Der eigene geheime Exponent x.
+    /// * `peer_public_value` - Der öffentliche Wert y der Gegenseite.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::InvalidPublicValueError` - Falls der öffentliche Wert ungültig ist
+    ///   oder das Ergebnis das neutrale Element ist, weil x ein Vielfaches von q ist.
+    pub fn shared_secret(
+        &self,
+        private_key: &BigInt,
+        peer_public_value: &G::Element,
+    ) -> Result<Vec<u8>> {
+        self.validate_public_value(peer_public_value)?;
+        let shared_element = self.group.exponentiate(
+            peer_public_value,
+            &private_key.rem_euclid(self.group.order()),
+            ScalarKind::Secret,
+        )?;
+        ensure!(
+            shared_element != self.group.identity(),
+            InvalidPublicValueError(peer_public_value.to_string())
+        );
+        self.group.encode_shared_secret(&shared_element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
+        MenezesVanstoneCurveConfig, MenezesVanstoneScheme,
+    };
+    use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::prime_order_group::PrimeFieldSubgroup;
+    use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+    use crate::shared::errors::KeyExchangeError;
+
+    fn hex(value: &str) -> BigInt {
+        BigInt::parse_bytes(value.as_bytes(), 16).unwrap()
+    }
+
+    fn assert_invalid<G: PrimeOrderGroup>(
+        key_exchange: &DiffieHellman<G>,
+        private_key: &BigInt,
+        public_value: &G::Element,
+    ) {
+        let error = key_exchange
+            .shared_secret(private_key, public_value)
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<KeyExchangeError>(),
+            Some(KeyExchangeError::InvalidPublicValueError(_))
+        ));
+    }
+
+    #[test]
+    fn test_finite_field_diffie_hellman() {
+        let random_generator = PseudoRandomNumberGenerator::new(23, NumberTheoryService::new(Fast));
+        let counter = RelaxedCounter::new(1);
+        let (p, g) = random_generator.generate_secure_prime_with_primitive_root(128, 20, &counter);
+        let key_exchange = DiffieHellman::new(PrimeFieldSubgroup::from_safe_prime(&p, &g).unwrap());
+
+        let alice = key_exchange
+            .generate_key_pair(&random_generator, &counter)
+            .unwrap();
+        let bob = key_exchange
+            .generate_key_pair(&random_generator, &counter)
+            .unwrap();
+        let alice_secret = key_exchange
+            .shared_secret(&alice.private_key, &bob.public_value)
+            .unwrap();
+        let bob_secret = key_exchange
+            .shared_secret(&bob.private_key, &alice.public_value)
+            .unwrap();
+        assert_eq!(alice_secret, bob_secret);
+        assert_eq!(alice_secret.len(), 16);
+
+        // 1 und p - 1 haben die Ordnung 1 bzw. 2, die Primitivwurzel g die Ordnung 2q.
+        for invalid in [BigInt::from(1), &p - 1, g, p.clone(), BigInt::from(0)] {
+            assert_invalid(&key_exchange, &alice.private_key, &invalid);
+        }
+    }
+
+    #[test]
+    fn test_ecdh_on_p256() {
+        let key_exchange = DiffieHellman::new(NamedCurve::P256.domain_parameters());
+        let curve = &key_exchange.group;
+        let alice = hex("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        let bob = hex("7D7DC5F71EB29DDAF80D6214632EEAE03D9058AF1FB6D22ED80BADB62BC1A534");
+        let bob_public = FiniteFieldEllipticCurvePoint::new(
+            hex("EAD218590119E8876B29146FF89CA61770C4EDBBF97D38CE385ED281D8A6B230"),
+            hex("28AF61281FD35E2FA7002523ACC85A429CB06EE6648325389F59EDFCE1405141"),
+        );
+        assert_eq!(
+            curve
+                .exponentiate(&curve.generator(), &bob, ScalarKind::Secret)
+                .unwrap(),
+            bob_public
+        );
+
+        // Das gemeinsame Geheimnis ist die x-Koordinate, wie bei anderen ECDH-Implementierungen.
+        let secret = key_exchange.shared_secret(&alice, &bob_public).unwrap();
+        assert_eq!(
+            BigInt::from_bytes_be(bigdecimal::num_bigint::Sign::Plus, &secret),
+            hex("61E109425A7ADBB9D0137091CFF10A55550B708D14AD0137B80FA0EC1328394F")
+        );
+
+        let off_curve = FiniteFieldEllipticCurvePoint::new(bob_public.x.clone(), &bob_public.y + 1);
+        assert_invalid(&key_exchange, &alice, &off_curve);
+        assert_invalid(
+            &key_exchange,
+            &alice,
+            &FiniteFieldEllipticCurvePoint::infinite(),
+        );
+    }
+
+    #[test]
+    fn test_ecdh_rejects_small_subgroup_points() {
+        // Die erzeugten Kurven y^2 = x^3 - n^2 · x haben den Kofaktor 8, (0, 0) hat Ordnung 2.
+        let key_pair = MenezesVanstoneScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(3, 32, 40),
+            11,
+        )
+        .unwrap();
+        let key_exchange = DiffieHellman::new(key_pair.private_key.curve.clone());
+        let random_generator = PseudoRandomNumberGenerator::new(5, NumberTheoryService::new(Fast));
+        let bob = key_exchange
+            .generate_key_pair(&random_generator, &RelaxedCounter::new(1))
+            .unwrap();
+        assert_eq!(
+            key_exchange
+                .shared_secret(&key_pair.private_key.x, &bob.public_value)
+                .unwrap(),
+            key_exchange
+                .shared_secret(&bob.private_key, &key_pair.public_key.y)
+                .unwrap()
+        );
+
+        let point_of_order_two = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
+        assert!(key_exchange.group.curve.has_point(&point_of_order_two));
+        assert_invalid(&key_exchange, &key_pair.private_key.x, &point_of_order_two);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod diffie_hellman;
+pub mod x25519;
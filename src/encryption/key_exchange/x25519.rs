@@ -0,0 +1,155 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+
+use crate::math_core::ecc::montgomery_curve::MontgomeryCurve;
+use crate::math_core::ecc::twisted_edwards_curve::{from_little_endian, to_little_endian};
+use crate::shared::errors::KeyExchangeError::AllZeroSharedSecretError;
+
+/// Die Länge von Schlüsseln, u-Koordinaten und gemeinsamen Geheimnissen bei X25519 in Bytes.
+pub const X25519_KEY_LENGTH: usize = 32;
+
+/// Die u-Koordinate 9 des Basispunkts von Curve25519.
+pub const X25519_BASE_POINT: [u8; X25519_KEY_LENGTH] = {
+    let mut u = [0u8; X25519_KEY_LENGTH];
+    u[0] = 9;
+    u
+};
+
+/// Die Anzahl der Bits, über die die Montgomery-Leiter läuft.
+const LADDER_BITS: u64 = 255;
+
+/// Schlüsselvereinbarung mit X25519 nach RFC 7748. Gerechnet wird nur mit u-Koordinaten auf
+/// Curve25519, jede Bytefolge ist ein zulässiger öffentlicher Wert. Punkte kleiner Ordnung
+/// werden daran erkannt, dass das gemeinsame Geheimnis 0 ist.
+pub struct X25519 {}
+
+impl X25519 {
+    /// Die Funktion X25519(k, u) aus RFC 7748, Abschnitt 5. Der Skalar wird geklemmt, d.h. die
+    /// unteren drei Bits und Bit 255 gelöscht und Bit 254 gesetzt, von u wird Bit 255 ignoriert.
+    pub fn scalar_multiply(
+        scalar: &[u8; X25519_KEY_LENGTH],
+        u_coordinate: &[u8; X25519_KEY_LENGTH],
+    ) -> [u8; X25519_KEY_LENGTH] {
+        let mut u_bytes = *u_coordinate;
+        u_bytes[X25519_KEY_LENGTH - 1] &= 0x7f;
+        // Das unwrap() schlägt niemals fehl: Der geklemmte Skalar hat genau 255 Bits und 4 ist
+        // modulo p invertierbar.
+        let u = MontgomeryCurve::curve25519()
+            .ladder(&from_little_endian(&u_bytes), &clamp(scalar), LADDER_BITS)
+            .unwrap();
+        to_little_endian(&u, X25519_KEY_LENGTH).try_into().unwrap()
+    }
+
+    /// Der öffentliche Schlüssel X25519(k, 9).
+    pub fn public_key(private_key: &[u8; X25519_KEY_LENGTH]) -> [u8; X25519_KEY_LENGTH] {
+        Self::scalar_multiply(private_key, &X25519_BASE_POINT)
+    }
+
+    /// Das gemeinsame Geheimnis X25519(k, u) mit dem öffentlichen Schlüssel u der Gegenseite.
+    ///
+    /// # Fehler
+    /// * `KeyExchangeError::AllZeroSharedSecretError` - Falls das Ergebnis 0 ist, u also zu
+    ///   einem Punkt kleiner Ordnung gehört (RFC 7748, Abschnitt 6.1).
+    pub fn shared_secret(
+        private_key: &[u8; X25519_KEY_LENGTH],
+        peer_public_key: &[u8; X25519_KEY_LENGTH],
+    ) -> Result<[u8; X25519_KEY_LENGTH]> {
+        let secret = Self::scalar_multiply(private_key, peer_public_key);
+        ensure!(
+            secret.iter().any(|byte| *byte != 0),
+            AllZeroSharedSecretError
+        );
+        Ok(secret)
+    }
+}
+
+fn clamp(scalar: &[u8; X25519_KEY_LENGTH]) -> BigInt {
+    let mut bytes = *scalar;
+    bytes[0] &= 0xf8;
+    bytes[X25519_KEY_LENGTH - 1] &= 0x7f;
+    bytes[X25519_KEY_LENGTH - 1] |= 0x40;
+    from_little_endian(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::KeyExchangeError;
+
+    fn hex(value: &str) -> [u8; X25519_KEY_LENGTH] {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect::<Vec<u8>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rfc7748_vectors() {
+        // RFC 7748, Abschnitt 5.2. Im zweiten Vektor ist Bit 255 von u gesetzt.
+        for (scalar, u, expected) in [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ] {
+            assert_eq!(
+                X25519::scalar_multiply(&hex(scalar), &hex(u)),
+                hex(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rfc7748_diffie_hellman() {
+        // RFC 7748, Abschnitt 6.1
+        let alice = hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = X25519::public_key(&alice);
+        let bob_public = X25519::public_key(&bob);
+        assert_eq!(
+            alice_public,
+            hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared_secret = hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(
+            X25519::shared_secret(&alice, &bob_public).unwrap(),
+            shared_secret
+        );
+        assert_eq!(
+            X25519::shared_secret(&bob, &alice_public).unwrap(),
+            shared_secret
+        );
+    }
+
+    #[test]
+    fn test_rejects_small_order_points() {
+        let alice = hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        // u = 0 und u = 1 haben die Ordnung 2 bzw. 4, der dritte Wert die Ordnung 8.
+        let mut one = [0u8; X25519_KEY_LENGTH];
+        one[0] = 1;
+        for small_order in [
+            [0u8; X25519_KEY_LENGTH],
+            one,
+            hex("e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800"),
+        ] {
+            let error = X25519::shared_secret(&alice, &small_order).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<KeyExchangeError>(),
+                Some(KeyExchangeError::AllZeroSharedSecretError)
+            ));
+        }
+    }
+}
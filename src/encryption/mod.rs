@@ -1,5 +1,8 @@
 pub mod asymmetric_encryption_types;
+pub mod classic;
 pub mod core;
 pub mod encryption_types;
+pub mod hybrid;
+pub mod key_exchange;
 pub mod string_schemes;
 pub mod symmetric_encryption_types;
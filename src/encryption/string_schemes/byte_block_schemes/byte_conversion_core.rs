@@ -0,0 +1,181 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::Signed;
+
+use crate::shared::errors::ByteEncodingError::{
+    IntegerTooLargeError, InvalidBlockSizeError, InvalidLengthPrefixError, InvalidPaddingError,
+    ModulusTooSmallError, NegativeIntegerError,
+};
+
+/// Anzahl der Bytes, mit der die Länge einer Nachricht vor die Nachricht geschrieben wird.
+pub const LENGTH_PREFIX_SIZE: usize = 8;
+
+/// Integer-to-Octet-String-Primitive (I2OSP) aus RFC 8017.
+/// Wandelt eine nicht-negative Zahl in eine Big-Endian-Bytefolge der festen Länge `length` um.
+///
+/// # Argumente
+/// * `number` - Die umzuwandelnde Zahl.
+/// * `length` - Die Länge der Bytefolge.
+///
+/// # Rückgabe
+/// * Die Bytefolge, mit führenden Nullen aufgefüllt.
+///
+/// # Fehler
+/// * `ByteEncodingError::NegativeIntegerError` - Falls die Zahl negativ ist.
+/// * `ByteEncodingError::IntegerTooLargeError` - Falls die Zahl nicht in `length` Bytes passt.
+pub fn i2osp(number: &BigInt, length: usize) -> Result<Vec<u8>> {
+    ensure!(
+        !number.is_negative(),
+        NegativeIntegerError(number.to_string())
+    );
+    let (_, bytes) = number.to_bytes_be();
+    // Die Null wird von to_bytes_be als [0] dargestellt und zählt daher nicht als signifikantes Byte.
+    let significant_bytes: &[u8] = if bytes == [0] { &[] } else { &bytes };
+    ensure!(
+        significant_bytes.len() <= length,
+        IntegerTooLargeError(number.to_string(), length)
+    );
+
+    let mut result = vec![0u8; length - significant_bytes.len()];
+    result.extend_from_slice(significant_bytes);
+    Ok(result)
+}
+
+/// Octet-String-to-Integer-Primitive (OS2IP) aus RFC 8017.
+/// Interpretiert eine Bytefolge als nicht-negative Big-Endian-Zahl.
+pub fn os2ip(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, bytes)
+}
+
+/// Teilt eine Bytefolge in Blöcke der Größe `block_size` und wandelt jeden Block mit OS2IP um.
+///
+/// # Fehler
+/// * `ByteEncodingError::InvalidBlockSizeError` - Falls die Länge kein Vielfaches der Blockgröße ist.
+pub fn bytes_to_blocks(bytes: &[u8], block_size: usize) -> Result<Vec<BigInt>> {
+    ensure!(
+        block_size > 0 && bytes.len().is_multiple_of(block_size),
+        InvalidBlockSizeError(bytes.len(), block_size)
+    );
+    Ok(bytes.chunks(block_size).map(os2ip).collect())
+}
+
+/// Wandelt jeden Block mit I2OSP in `block_size` Bytes um und fügt die Ergebnisse aneinander.
+///
+/// # Fehler
+/// * `ByteEncodingError::IntegerTooLargeError` - Falls ein Block nicht in `block_size` Bytes passt.
+pub fn blocks_to_bytes(blocks: &[BigInt], block_size: usize) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(blocks.len() * block_size);
+    for block in blocks {
+        result.extend(i2osp(block, block_size)?);
+    }
+    Ok(result)
+}
+
+/// Stellt der Nachricht ihre Länge als 8-Byte-Big-Endian-Zahl voran und füllt das Ergebnis mit
+/// Nullen auf ein Vielfaches der Blockgröße auf. Durch die Längenangabe ist die Auffüllung
+/// eindeutig umkehrbar, auch wenn die Nachricht selbst auf Nullbytes endet.
+pub fn frame(message: &[u8], block_size: usize) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(LENGTH_PREFIX_SIZE + message.len() + block_size);
+    framed.extend_from_slice(&(message.len() as u64).to_be_bytes());
+    framed.extend_from_slice(message);
+    let padding = (block_size - framed.len() % block_size) % block_size;
+    framed.resize(framed.len() + padding, 0);
+    framed
+}
+
+/// Kehrt `frame` um, indem die Längenangabe gelesen und die Auffüllung entfernt wird.
+///
+/// # Fehler
+/// * `ByteEncodingError::InvalidLengthPrefixError` - Falls die Längenangabe fehlt oder zu groß ist.
+/// * `ByteEncodingError::InvalidPaddingError` - Falls die Auffüllung nicht nur aus Nullbytes besteht.
+pub fn unframe(framed: &[u8]) -> Result<Vec<u8>> {
+    ensure!(
+        framed.len() >= LENGTH_PREFIX_SIZE,
+        InvalidLengthPrefixError(framed.len() as u64)
+    );
+    let (prefix, rest) = framed.split_at(LENGTH_PREFIX_SIZE);
+    let length = u64::from_be_bytes(prefix.try_into()?);
+    ensure!(
+        length <= rest.len() as u64,
+        InvalidLengthPrefixError(length)
+    );
+
+    let (message, padding) = rest.split_at(length as usize);
+    ensure!(padding.iter().all(|byte| *byte == 0), InvalidPaddingError);
+    Ok(message.to_vec())
+}
+
+/// Bestimmt die Blockgrößen in Bytes für Verfahren, die modulo `modulus` rechnen.
+/// Klartextblöcke sind so groß, dass jeder Block echt kleiner als der Modulus ist.
+/// Chiffretextblöcke sind so groß, dass jede Zahl kleiner als der Modulus hineinpasst.
+///
+/// # Rückgabe
+/// * Ein Tupel aus der Klartext- und der Chiffretextblockgröße.
+///
+/// # Fehler
+/// * `ByteEncodingError::ModulusTooSmallError` - Falls der Modulus kleiner als 2^8 ist.
+pub fn block_sizes_for_modulus(modulus: &BigInt) -> Result<(usize, usize)> {
+    let bits = modulus.bits() as usize;
+    let plaintext_block_size = bits.saturating_sub(1) / 8;
+    ensure!(
+        plaintext_block_size > 0,
+        ModulusTooSmallError(modulus.to_string())
+    );
+    Ok((plaintext_block_size, bits.div_ceil(8)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i2osp_os2ip() {
+        let number = BigInt::from(0x010203);
+        assert_eq!(i2osp(&number, 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(i2osp(&number, 5).unwrap(), vec![0, 0, 1, 2, 3]);
+        assert_eq!(os2ip(&[0, 0, 1, 2, 3]), number);
+        assert_eq!(i2osp(&BigInt::from(0), 2).unwrap(), vec![0, 0]);
+        assert!(i2osp(&number, 2).is_err());
+        assert!(i2osp(&BigInt::from(-1), 2).is_err());
+    }
+
+    #[test]
+    fn test_frame_unframe() {
+        let message = [0u8, 1, 0, 0];
+        let framed = frame(&message, 5);
+        assert_eq!(framed.len(), 15);
+        assert_eq!(&framed[..8], &[0, 0, 0, 0, 0, 0, 0, 4]);
+        assert_eq!(unframe(&framed).unwrap(), message);
+
+        assert_eq!(unframe(&frame(&[], 3)).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_unframe_rejects_invalid_input() {
+        assert!(unframe(&[0, 0, 0]).is_err());
+        assert!(unframe(&[0, 0, 0, 0, 0, 0, 0, 9, 1]).is_err());
+        assert!(unframe(&[0, 0, 0, 0, 0, 0, 0, 1, 1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_block_sizes_for_modulus() {
+        assert_eq!(block_sizes_for_modulus(&BigInt::from(256)).unwrap(), (1, 2));
+        assert_eq!(
+            block_sizes_for_modulus(&BigInt::from(65535)).unwrap(),
+            (1, 2)
+        );
+        assert_eq!(
+            block_sizes_for_modulus(&BigInt::from(65536)).unwrap(),
+            (2, 3)
+        );
+        assert!(block_sizes_for_modulus(&BigInt::from(255)).is_err());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = bytes_to_blocks(&[1, 2, 3, 4], 2).unwrap();
+        assert_eq!(blocks, vec![BigInt::from(0x0102), BigInt::from(0x0304)]);
+        assert_eq!(blocks_to_bytes(&blocks, 2).unwrap(), vec![1, 2, 3, 4]);
+        assert!(bytes_to_blocks(&[1, 2, 3], 2).is_err());
+    }
+}
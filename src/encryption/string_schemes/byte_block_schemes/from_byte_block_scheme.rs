@@ -0,0 +1,89 @@
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{
+    blocks_to_bytes, bytes_to_blocks,
+};
+use crate::encryption::string_schemes::byte_block_schemes::keys::ByteBlockConversionSchemeKey;
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+
+/// Ein Verschlüsselungsschema, das eine Menge von Zahlen in eine Bytefolge umwandelt, indem jede
+/// Zahl mit I2OSP in einen Block fester Größe geschrieben wird.
+/// Es wird für Chiffretexte verwendet, deren Blöcke durch den Modulus begrenzt sind und daher keine
+/// Längenangabe benötigen.
+pub struct FromByteBlockScheme {}
+
+impl EncryptionScheme for FromByteBlockScheme {}
+
+impl SymmetricEncryptionScheme for FromByteBlockScheme {}
+
+impl Encryptor<FromByteBlockScheme> for FromByteBlockScheme {
+    type Input = Vec<BigInt>;
+    type Output = Result<Vec<u8>>;
+    type Key = ByteBlockConversionSchemeKey;
+}
+
+impl SymmetricEncryptor<FromByteBlockScheme> for FromByteBlockScheme {
+    /// Schreibt jede Zahl als Big-Endian-Block der gegebenen Größe in die Bytefolge.
+    ///
+    /// # Arguments
+    /// * `plaintext` - Die Zahlen, die umgewandelt werden sollen.
+    /// * `key` - Der Schlüssel, der die Blockgröße in Bytes enthält.
+    ///
+    /// # Returns
+    /// Die Bytefolge oder ein `ByteEncodingError`, falls eine Zahl nicht in einen Block passt.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        assert!(key.block_size > 0, "Die Blockgröße muss größer als 0 sein.");
+
+        blocks_to_bytes(plaintext, key.block_size)
+    }
+}
+
+impl Decryptor<FromByteBlockScheme> for FromByteBlockScheme {
+    type Input = [u8];
+    type Output = Result<Vec<BigInt>>;
+    type Key = ByteBlockConversionSchemeKey;
+}
+
+impl SymmetricDecryptor<FromByteBlockScheme> for FromByteBlockScheme {
+    /// Teilt die Bytefolge in Blöcke der gegebenen Größe und wandelt diese in Zahlen um.
+    ///
+    /// # Arguments
+    /// * `ciphertext` - Die Bytefolge.
+    /// * `key` - Der Schlüssel, der die Blockgröße in Bytes enthält.
+    ///
+    /// # Returns
+    /// Die Zahlen oder ein `ByteEncodingError`, falls die Länge kein Vielfaches der Blockgröße ist.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        assert!(key.block_size > 0, "Die Blockgröße muss größer als 0 sein.");
+
+        bytes_to_blocks(ciphertext, key.block_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_happy_flow() {
+        let key = ByteBlockConversionSchemeKey { block_size: 3 };
+        let blocks = vec![BigInt::from(1), BigInt::from(0x010203), BigInt::from(0)];
+
+        let bytes = FromByteBlockScheme::encrypt(&blocks, &key).unwrap();
+        assert_eq!(bytes, vec![0, 0, 1, 1, 2, 3, 0, 0, 0]);
+
+        let decoded = FromByteBlockScheme::decrypt(&bytes, &key).unwrap();
+        assert_eq!(decoded, blocks);
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let key = ByteBlockConversionSchemeKey { block_size: 3 };
+        assert!(FromByteBlockScheme::decrypt(&[1, 2, 3, 4], &key).is_err());
+        assert!(FromByteBlockScheme::encrypt(&vec![BigInt::from(1 << 24)], &key).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::encryption::encryption_types::Key;
+use crate::encryption::string_schemes::byte_block_schemes::from_byte_block_scheme::FromByteBlockScheme;
+use crate::encryption::string_schemes::byte_block_schemes::to_byte_block_scheme::ToByteBlockScheme;
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptionKey, SymmetricEncryptionKey, SymmetricKey,
+};
+
+// Ein Schlüssel, welcher für Verschlüsselungsschemata verwendet wird,
+// die zwischen Bytefolgen und Blöcken in Dezimalform mit einer festen Anzahl an Bytes konvertieren.
+#[derive(Debug)]
+pub struct ByteBlockConversionSchemeKey {
+    pub block_size: usize,
+}
+
+// Typisierung für ToByteBlockScheme
+impl Key<ToByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricKey<ToByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricEncryptionKey<ToByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricDecryptionKey<ToByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+// Typisierung für FromByteBlockScheme
+impl Key<FromByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricKey<FromByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricEncryptionKey<FromByteBlockScheme> for ByteBlockConversionSchemeKey {}
+
+impl SymmetricDecryptionKey<FromByteBlockScheme> for ByteBlockConversionSchemeKey {}
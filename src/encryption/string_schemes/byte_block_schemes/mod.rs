@@ -0,0 +1,4 @@
+pub mod byte_conversion_core;
+pub mod from_byte_block_scheme;
+pub mod keys;
+pub mod to_byte_block_scheme;
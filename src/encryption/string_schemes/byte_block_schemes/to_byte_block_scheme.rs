@@ -0,0 +1,130 @@
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+
+use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{
+    blocks_to_bytes, bytes_to_blocks, frame, unframe,
+};
+use crate::encryption::string_schemes::byte_block_schemes::keys::ByteBlockConversionSchemeKey;
+use crate::encryption::symmetric_encryption_types::{
+    SymmetricDecryptor, SymmetricEncryptionScheme, SymmetricEncryptor,
+};
+
+/// Ein Verschlüsselungsschema, das beliebige Bytefolgen in Blöcke aufteilt und diese Blöcke als
+/// Big-Endian-Zahlen (OS2IP) interpretiert.
+/// Vor der Aufteilung wird der Nachricht ihre Länge vorangestellt und das Ergebnis mit Nullbytes auf
+/// ein Vielfaches der Blockgröße aufgefüllt. Dadurch ist die Umwandlung auch für Binärdaten mit
+/// Nullbytes eindeutig umkehrbar.
+pub struct ToByteBlockScheme {}
+
+impl EncryptionScheme for ToByteBlockScheme {}
+
+impl SymmetricEncryptionScheme for ToByteBlockScheme {}
+
+impl Encryptor<ToByteBlockScheme> for ToByteBlockScheme {
+    type Input = [u8];
+    type Output = Vec<BigInt>;
+    type Key = ByteBlockConversionSchemeKey;
+}
+
+impl SymmetricEncryptor<ToByteBlockScheme> for ToByteBlockScheme {
+    /// Stellt der Bytefolge ihre Länge voran, teilt sie in Blöcke der gegebenen Größe auf und
+    /// wandelt diese Blöcke in Zahlen um.
+    ///
+    /// # Arguments
+    /// * `plaintext` - Die Bytefolge, die umgewandelt werden soll.
+    /// * `key` - Der Schlüssel, der die Blockgröße in Bytes enthält.
+    ///
+    /// # Returns
+    /// Ein Vektor von Zahlen, die jeweils kleiner als 256^block_size sind.
+    fn encrypt(plaintext: &Self::Input, key: &Self::Key) -> Self::Output {
+        assert!(key.block_size > 0, "Die Blockgröße muss größer als 0 sein.");
+
+        let framed = frame(plaintext, key.block_size);
+        // Die Länge ist durch frame immer ein Vielfaches der Blockgröße.
+        bytes_to_blocks(&framed, key.block_size).unwrap()
+    }
+}
+
+impl Decryptor<ToByteBlockScheme> for ToByteBlockScheme {
+    type Input = Vec<BigInt>;
+    type Output = Result<Vec<u8>>;
+    type Key = ByteBlockConversionSchemeKey;
+}
+
+impl SymmetricDecryptor<ToByteBlockScheme> for ToByteBlockScheme {
+    /// Wandelt die Zahlen in Blöcke der gegebenen Größe um, fügt diese zusammen und entfernt
+    /// Längenangabe und Auffüllung.
+    ///
+    /// # Arguments
+    /// * `ciphertext` - Die Zahlen, die die Blöcke repräsentieren.
+    /// * `key` - Der Schlüssel, der die Blockgröße in Bytes enthält.
+    ///
+    /// # Returns
+    /// Die ursprüngliche Bytefolge oder ein `ByteEncodingError`, falls die Blöcke nicht zu einer
+    /// gültigen Nachricht gehören.
+    fn decrypt(ciphertext: &Self::Input, key: &Self::Key) -> Self::Output {
+        assert!(key.block_size > 0, "Die Blockgröße muss größer als 0 sein.");
+
+        unframe(&blocks_to_bytes(ciphertext, key.block_size)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_happy_flow() {
+        let key = ByteBlockConversionSchemeKey { block_size: 4 };
+        let message = b"Hi\x00";
+
+        let blocks = ToByteBlockScheme::encrypt(message, &key);
+        // Längenangabe 3 in 8 Bytes, dann "Hi\0" und ein Byte Auffüllung
+        assert_eq!(
+            blocks,
+            vec![
+                BigInt::from(0),
+                BigInt::from(3),
+                BigInt::from(0x48690000u32)
+            ]
+        );
+
+        let plaintext = ToByteBlockScheme::decrypt(&blocks, &key).unwrap();
+        assert_eq!(plaintext, message);
+    }
+
+    #[test]
+    fn test_binary_data_with_trailing_zeros() {
+        let key = ByteBlockConversionSchemeKey { block_size: 7 };
+        let message: Vec<u8> = (0..=255u8).chain([0, 0, 0]).collect();
+
+        let blocks = ToByteBlockScheme::encrypt(&message, &key);
+        let plaintext = ToByteBlockScheme::decrypt(&blocks, &key).unwrap();
+        assert_eq!(plaintext, message);
+    }
+
+    #[test]
+    fn test_empty_message() {
+        let key = ByteBlockConversionSchemeKey { block_size: 3 };
+
+        let blocks = ToByteBlockScheme::encrypt(&[], &key);
+        assert_eq!(blocks.len(), 3);
+        assert!(ToByteBlockScheme::decrypt(&blocks, &key)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_block_too_large() {
+        let key = ByteBlockConversionSchemeKey { block_size: 1 };
+        assert!(ToByteBlockScheme::decrypt(&vec![BigInt::from(256)], &key).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_block_size() {
+        let key = ByteBlockConversionSchemeKey { block_size: 0 };
+        ToByteBlockScheme::encrypt(&[], &key);
+    }
+}
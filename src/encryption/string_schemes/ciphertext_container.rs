@@ -0,0 +1,452 @@
+use anyhow::{ensure, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bigdecimal::num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha256};
+
+use crate::encryption::string_schemes::decimal_unicode_schemes::decimal_unicode_conversion_core::ToRadixString;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::shared::errors::ContainerError::{
+    InvalidBase64Error, InvalidBlockError, InvalidEncodingParametersError, InvalidMagicError,
+    KeyFingerprintMismatchError, LengthMismatchError, SchemeMismatchError, TrailingBytesError,
+    TruncatedError, UnknownSchemeError, UnsupportedVersionError,
+};
+
+/// Kennung, mit der jeder Container beginnt.
+pub const CONTAINER_MAGIC: [u8; 4] = *b"KCCT";
+
+/// Aktuelle Version des Containerformats.
+pub const CONTAINER_VERSION: u8 = 1;
+
+/// Länge des Fingerabdrucks eines Schlüssels in Bytes (SHA-256).
+pub const FINGERPRINT_SIZE: usize = 32;
+
+/// Das Verschlüsselungsschema, mit dem der Inhalt eines Containers erstellt wurde.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemeIdentifier {
+    RsaWithString = 1,
+    MenezesVanstoneString = 2,
+}
+
+impl TryFrom<u8> for SchemeIdentifier {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(SchemeIdentifier::RsaWithString),
+            2 => Ok(SchemeIdentifier::MenezesVanstoneString),
+            _ => Err(UnknownSchemeError(value).into()),
+        }
+    }
+}
+
+/// Die Parameter der Decimal-Unicode-Abbildung, mit der der Klartext in Blöcke zerlegt wurde.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncodingParameters {
+    pub radix: u32,
+    pub block_size: u32,
+}
+
+/// Ein selbstbeschreibender Container für Chiffretexte der String-Schemata.
+/// Neben den verschlüsselten Blöcken enthält er alle Parameter, die zum Entschlüsseln benötigt
+/// werden, damit gespeicherte Chiffretexte auch nach Änderungen der Standardwerte lesbar bleiben.
+///
+/// Binäres Format (alle Zahlen Big-Endian):
+/// `Kennung (4) | Version (1) | Schema (1) | Fingerabdruck (32) | Radix (4) | Blockgröße (4) |
+/// Ursprüngliche Länge (8) | Anzahl Blöcke (4) | Blöcke | Anzahl Punkte (4) | Punkte`
+///
+/// Jeder Block ist als Länge (4) und Betrag kodiert, jeder Punkt als Unendlichkeits-Flag (1)
+/// gefolgt von den beiden Koordinaten im Format der Blöcke.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CiphertextContainer {
+    pub scheme: SchemeIdentifier,
+    pub key_fingerprint: [u8; FINGERPRINT_SIZE],
+    pub encoding: EncodingParameters,
+    /// Die Anzahl der Unicode-Zeichen des Klartextes.
+    pub original_length: u64,
+    pub blocks: Vec<BigInt>,
+    pub points: Vec<FiniteFieldEllipticCurvePoint>,
+}
+
+impl CiphertextContainer {
+    /// Serialisiert den Container in das binäre Format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&CONTAINER_MAGIC);
+        bytes.push(CONTAINER_VERSION);
+        bytes.push(self.scheme as u8);
+        bytes.extend_from_slice(&self.key_fingerprint);
+        bytes.extend_from_slice(&self.encoding.radix.to_be_bytes());
+        bytes.extend_from_slice(&self.encoding.block_size.to_be_bytes());
+        bytes.extend_from_slice(&self.original_length.to_be_bytes());
+
+        bytes.extend_from_slice(&(self.blocks.len() as u32).to_be_bytes());
+        for block in &self.blocks {
+            write_big_int(&mut bytes, block);
+        }
+
+        bytes.extend_from_slice(&(self.points.len() as u32).to_be_bytes());
+        for point in &self.points {
+            bytes.push(point.is_infinite as u8);
+            write_big_int(&mut bytes, &point.x);
+            write_big_int(&mut bytes, &point.y);
+        }
+        bytes
+    }
+
+    /// Liest einen Container aus dem binären Format.
+    ///
+    /// # Fehler
+    /// * `ContainerError` - Falls Kennung, Version oder Schema ungültig sind oder die Bytefolge
+    ///   nicht genau einen Container enthält.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader { bytes, position: 0 };
+
+        ensure!(reader.take(4)? == CONTAINER_MAGIC, InvalidMagicError);
+        let version = reader.read_u8()?;
+        ensure!(
+            version == CONTAINER_VERSION,
+            UnsupportedVersionError(version)
+        );
+        let scheme = SchemeIdentifier::try_from(reader.read_u8()?)?;
+
+        let mut key_fingerprint = [0u8; FINGERPRINT_SIZE];
+        key_fingerprint.copy_from_slice(reader.take(FINGERPRINT_SIZE)?);
+        let encoding = EncodingParameters {
+            radix: reader.read_u32()?,
+            block_size: reader.read_u32()?,
+        };
+        let original_length = reader.read_u64()?;
+
+        let block_count = reader.read_u32()?;
+        let blocks = (0..block_count)
+            .map(|_| reader.read_big_int())
+            .collect::<Result<Vec<BigInt>>>()?;
+
+        let point_count = reader.read_u32()?;
+        let points = (0..point_count)
+            .map(|_| {
+                let is_infinite = reader.read_u8()? != 0;
+                let x = reader.read_big_int()?;
+                let y = reader.read_big_int()?;
+                Ok(FiniteFieldEllipticCurvePoint { x, y, is_infinite })
+            })
+            .collect::<Result<Vec<FiniteFieldEllipticCurvePoint>>>()?;
+
+        let remaining = bytes.len() - reader.position;
+        ensure!(remaining == 0, TrailingBytesError(remaining));
+
+        Ok(CiphertextContainer {
+            scheme,
+            key_fingerprint,
+            encoding,
+            original_length,
+            blocks,
+            points,
+        })
+    }
+
+    /// Serialisiert den Container in das binäre Format und kodiert dieses mit Base64.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.to_bytes())
+    }
+
+    /// Liest einen Container aus seiner Base64-Darstellung.
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = STANDARD
+            .decode(encoded.trim())
+            .map_err(|error| InvalidBase64Error(error.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Prüft, ob der Container mit dem gegebenen Schema und einem Schlüssel mit dem gegebenen
+    /// Fingerabdruck erstellt wurde.
+    ///
+    /// # Fehler
+    /// * `ContainerError::SchemeMismatchError` - Falls das Schema nicht übereinstimmt.
+    /// * `ContainerError::KeyFingerprintMismatchError` - Falls der Schlüssel nicht übereinstimmt.
+    pub fn check_key(
+        &self,
+        scheme: SchemeIdentifier,
+        key_fingerprint: &[u8; FINGERPRINT_SIZE],
+    ) -> Result<()> {
+        ensure!(
+            self.scheme == scheme,
+            SchemeMismatchError(format!("{:?}", scheme), format!("{:?}", self.scheme))
+        );
+        ensure!(
+            &self.key_fingerprint == key_fingerprint,
+            KeyFingerprintMismatchError
+        );
+        Ok(())
+    }
+
+    /// Die Anzahl der Klartextblöcke, die sich aus der ursprünglichen Länge und der Blockgröße ergibt.
+    pub fn plaintext_block_count(&self) -> usize {
+        let block_size = self.encoding.block_size.max(1) as u64;
+        self.original_length.div_ceil(block_size) as usize
+    }
+}
+
+/// Prüft, ob jeder Block der Decimal-Unicode-Abbildung mit den gegebenen Parametern kleiner als
+/// der Modulus ist und damit eindeutig verschlüsselt werden kann.
+///
+/// # Fehler
+/// * `ContainerError::InvalidEncodingParametersError` - Falls die Parameter nicht zum Modulus passen.
+pub fn check_encoding(encoding: &EncodingParameters, modulus: &BigInt) -> Result<()> {
+    let invalid = || InvalidEncodingParametersError(encoding.radix, encoding.block_size);
+    ensure!(encoding.radix > 1 && encoding.block_size > 0, invalid());
+
+    // Mit b Bits ist radix >= 2^(b - 1), die Potenz hat also mindestens block_size · (b - 1) + 1
+    // Bits. Zu große Parameter aus einem Container werden so abgelehnt, bevor die Potenz
+    // berechnet wird, die sonst beliebig viel Speicher belegen könnte.
+    let radix_bits = u64::from(u32::BITS - encoding.radix.leading_zeros());
+    ensure!(
+        u64::from(encoding.block_size) * (radix_bits - 1) < modulus.bits(),
+        invalid()
+    );
+    ensure!(
+        &BigInt::from(encoding.radix).pow(encoding.block_size) <= modulus,
+        invalid()
+    );
+    Ok(())
+}
+
+/// Berechnet den Fingerabdruck eines Schlüssels als SHA-256-Hash über das Schema und die
+/// längenpräfixierten Zweierkomplement-Darstellungen der gegebenen Schlüsselparameter.
+///
+/// # Argumente
+/// * `scheme` - Das Schema, zu dem der Schlüssel gehört.
+/// * `parameters` - Die Parameter, die den Schlüssel eindeutig bestimmen.
+pub fn key_fingerprint(scheme: SchemeIdentifier, parameters: &[&BigInt]) -> [u8; FINGERPRINT_SIZE] {
+    let mut encoded = vec![scheme as u8];
+    for parameter in parameters {
+        let bytes = parameter.to_signed_bytes_be();
+        encoded.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        encoded.extend_from_slice(&bytes);
+    }
+    Sha256::digest(&encoded).into()
+}
+
+/// Wandelt entschlüsselte Blöcke in eine Zeichenkette um.
+/// Im Gegensatz zu `ToDecimalBlockScheme::decrypt` werden führende Nullzeichen eines Blocks anhand
+/// der Blockgröße und der ursprünglichen Länge wiederhergestellt.
+///
+/// # Argumente
+/// * `blocks` - Die entschlüsselten Blöcke.
+/// * `encoding` - Die Parameter der Decimal-Unicode-Abbildung.
+/// * `original_length` - Die Anzahl der Zeichen des Klartextes.
+///
+/// # Fehler
+/// * `ContainerError::LengthMismatchError` - Falls die Anzahl der Blöcke nicht zur Länge passt.
+/// * `ContainerError::InvalidBlockError` - Falls ein Block nicht in die erwartete Anzahl an
+///   Zeichen umgewandelt werden kann.
+pub fn decode_text(
+    blocks: &[BigInt],
+    encoding: &EncodingParameters,
+    original_length: u64,
+) -> Result<String> {
+    let block_size = encoding.block_size as u64;
+    ensure!(
+        block_size > 0 && original_length.div_ceil(block_size) == blocks.len() as u64,
+        LengthMismatchError(original_length, blocks.len())
+    );
+
+    let mut text = String::new();
+    for (index, block) in blocks.iter().enumerate() {
+        let expected_chars = block_size.min(original_length - index as u64 * block_size) as usize;
+        let chars = block
+            .to_radix_string(&encoding.radix)
+            .ok_or(InvalidBlockError(index))?;
+        let char_count = chars.chars().count();
+        ensure!(char_count <= expected_chars, InvalidBlockError(index));
+
+        text.extend(std::iter::repeat_n('\0', expected_chars - char_count));
+        text.push_str(&chars);
+    }
+    Ok(text)
+}
+
+/// Schreibt den Betrag einer Zahl längenpräfixiert in die Bytefolge.
+fn write_big_int(bytes: &mut Vec<u8>, number: &BigInt) {
+    let (_, magnitude) = number.to_bytes_be();
+    bytes.extend_from_slice(&(magnitude.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&magnitude);
+}
+
+/// Liest die Felder eines Containers nacheinander aus einer Bytefolge.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(TruncatedError(self.position))?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into()?))
+    }
+
+    fn read_big_int(&mut self) -> Result<BigInt> {
+        let length = self.read_u32()? as usize;
+        Ok(BigInt::from_bytes_be(Sign::Plus, self.take(length)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::ContainerError;
+
+    fn example_container() -> CiphertextContainer {
+        CiphertextContainer {
+            scheme: SchemeIdentifier::MenezesVanstoneString,
+            key_fingerprint: key_fingerprint(
+                SchemeIdentifier::MenezesVanstoneString,
+                &[&BigInt::from(65537)],
+            ),
+            encoding: EncodingParameters {
+                radix: 55296,
+                block_size: 4,
+            },
+            original_length: 7,
+            blocks: vec![
+                BigInt::from(0),
+                BigInt::from(123456789u64),
+                BigInt::from(42),
+            ],
+            points: vec![
+                FiniteFieldEllipticCurvePoint::new(BigInt::from(17), BigInt::from(4711)),
+                FiniteFieldEllipticCurvePoint::infinite(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let container = example_container();
+        let bytes = container.to_bytes();
+        assert_eq!(&bytes[..4], b"KCCT");
+        assert_eq!(CiphertextContainer::from_bytes(&bytes).unwrap(), container);
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let container = example_container();
+        let encoded = container.to_base64();
+        assert_eq!(
+            CiphertextContainer::from_base64(&encoded).unwrap(),
+            container
+        );
+    }
+
+    #[test]
+    fn test_invalid_containers_are_rejected() {
+        let bytes = example_container().to_bytes();
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'X';
+        let error = CiphertextContainer::from_bytes(&wrong_magic).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::InvalidMagicError)
+        ));
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = 2;
+        let error = CiphertextContainer::from_bytes(&wrong_version).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::UnsupportedVersionError(2))
+        ));
+
+        let mut unknown_scheme = bytes.clone();
+        unknown_scheme[5] = 9;
+        let error = CiphertextContainer::from_bytes(&unknown_scheme).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::UnknownSchemeError(9))
+        ));
+
+        let error = CiphertextContainer::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::TruncatedError(_))
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        let error = CiphertextContainer::from_bytes(&trailing).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::TrailingBytesError(1))
+        ));
+
+        let error = CiphertextContainer::from_base64("kein base64!").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::InvalidBase64Error(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_key() {
+        let container = example_container();
+        let fingerprint = container.key_fingerprint;
+        assert!(container
+            .check_key(SchemeIdentifier::MenezesVanstoneString, &fingerprint)
+            .is_ok());
+
+        let error = container
+            .check_key(SchemeIdentifier::RsaWithString, &fingerprint)
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::SchemeMismatchError(_, _))
+        ));
+
+        let other_fingerprint =
+            key_fingerprint(SchemeIdentifier::MenezesVanstoneString, &[&BigInt::from(3)]);
+        let error = container
+            .check_key(SchemeIdentifier::MenezesVanstoneString, &other_fingerprint)
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::KeyFingerprintMismatchError)
+        ));
+    }
+
+    #[test]
+    fn test_decode_text_restores_leading_null_chars() {
+        let encoding = EncodingParameters {
+            radix: 55296,
+            block_size: 3,
+        };
+        // "\0\0a" und "b" als g-adische Entwicklung
+        let blocks = vec![BigInt::from('a' as u32), BigInt::from('b' as u32)];
+        assert_eq!(decode_text(&blocks, &encoding, 4).unwrap(), "\0\0ab");
+
+        let error = decode_text(&blocks, &encoding, 7).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::LengthMismatchError(7, 2))
+        ));
+    }
+}
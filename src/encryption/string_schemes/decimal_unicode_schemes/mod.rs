@@ -1,4 +1,4 @@
-mod decimal_unicode_conversion_core;
+pub(crate) mod decimal_unicode_conversion_core;
 pub mod from_decimal_block_scheme;
 pub mod keys;
 pub mod to_decimal_block_scheme;
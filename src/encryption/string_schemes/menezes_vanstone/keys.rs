@@ -6,7 +6,13 @@ use crate::encryption::core::menezes_vanstone::keys::{
     MenezesVanstonePrivateKey, MenezesVanstonePublicKey,
 };
 use crate::encryption::encryption_types::Key;
+use crate::encryption::string_schemes::ciphertext_container::{
+    key_fingerprint, SchemeIdentifier, FINGERPRINT_SIZE,
+};
 use crate::encryption::string_schemes::menezes_vanstone::menezes_vanstone_string_scheme::MenezesVanstoneStringScheme;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use anyhow::Result;
 
 #[derive(Clone, Debug)]
 pub struct MenezesVanstoneStringPublicKey {
@@ -14,6 +20,13 @@ pub struct MenezesVanstoneStringPublicKey {
     pub radix: u32,
 }
 
+impl MenezesVanstoneStringPublicKey {
+    /// Der Fingerabdruck des Schlüssels über die Kurvenparameter und den öffentlichen Punkt y.
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        mv_fingerprint(&self.mv_key.curve, &self.mv_key.y)
+    }
+}
+
 impl Key<MenezesVanstoneStringScheme> for MenezesVanstoneStringPublicKey {}
 impl AsymmetricKey<MenezesVanstoneStringScheme> for MenezesVanstoneStringPublicKey {}
 impl PublicKey<MenezesVanstoneStringScheme> for MenezesVanstoneStringPublicKey {}
@@ -27,6 +40,16 @@ pub struct MenezesVanstoneStringPrivateKey {
     pub radix: u32,
 }
 
+impl MenezesVanstoneStringPrivateKey {
+    /// Der Fingerabdruck des Schlüssels, der mit dem des zugehörigen öffentlichen Schlüssels übereinstimmt.
+    /// Dafür wird der öffentliche Punkt y = x * g aus dem privaten Schlüssel berechnet.
+    pub fn fingerprint(&self) -> Result<[u8; FINGERPRINT_SIZE]> {
+        let curve = &self.mv_key.curve;
+        let y = curve.generator.multiply(&self.mv_key.x, &curve.curve)?;
+        Ok(mv_fingerprint(curve, &y))
+    }
+}
+
 impl Key<MenezesVanstoneStringScheme> for MenezesVanstoneStringPrivateKey {}
 
 impl AsymmetricKey<MenezesVanstoneStringScheme> for MenezesVanstoneStringPrivateKey {}
@@ -58,3 +81,22 @@ impl
         self.private_key.clone()
     }
 }
+
+fn mv_fingerprint(
+    curve: &SecureFiniteFieldEllipticCurve,
+    y: &FiniteFieldEllipticCurvePoint,
+) -> [u8; FINGERPRINT_SIZE] {
+    key_fingerprint(
+        SchemeIdentifier::MenezesVanstoneString,
+        &[
+            &curve.curve.a,
+            &curve.curve.b,
+            &curve.curve.prime,
+            &curve.order_of_subgroup,
+            &curve.generator.x,
+            &curve.generator.y,
+            &y.x,
+            &y.y,
+        ],
+    )
+}
@@ -3,9 +3,17 @@ use crate::encryption::asymmetric_encryption_types::{
     AsymmetricDecryptor, AsymmetricEncryptionScheme, AsymmetricEncryptor, Signer, Verifier,
 };
 use crate::encryption::core::menezes_vanstone::menezes_vanstone_scheme::{
-    MenezesVanstoneCiphertext, MenezesVanstonePlaintext, MenezesVanstoneScheme,
+    MenezesVanstoneCiphertext, MenezesVanstoneCurveConfig, MenezesVanstonePlaintext,
+    MenezesVanstoneScheme,
 };
 use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::block_sizes_for_modulus;
+use crate::encryption::string_schemes::byte_block_schemes::from_byte_block_scheme::FromByteBlockScheme;
+use crate::encryption::string_schemes::byte_block_schemes::keys::ByteBlockConversionSchemeKey;
+use crate::encryption::string_schemes::byte_block_schemes::to_byte_block_scheme::ToByteBlockScheme;
+use crate::encryption::string_schemes::ciphertext_container::{
+    check_encoding, decode_text, CiphertextContainer, EncodingParameters, SchemeIdentifier,
+};
 use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
 use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::string_schemes::decimal_unicode_schemes::to_decimal_block_scheme::ToDecimalBlockScheme;
@@ -42,31 +50,26 @@ impl From<MvCipherTextBean> for MvStringCiphertext {
     }
 }
 
+/// Chiffretext einer mit `encrypt_bytes` verschlüsselten Bytefolge.
+#[derive(Clone, Debug)]
+pub struct MvByteCiphertext {
+    pub ciphertext: Vec<u8>,
+    pub points: Vec<FiniteFieldEllipticCurvePoint>,
+}
+
 impl MenezesVanstoneStringScheme {
     pub fn generate_keypair(
-        n: i32,
-        modul_width: u32,
-        miller_rabin_iterations: u32,
+        curve_config: &MenezesVanstoneCurveConfig,
         random_seed: u32,
         radix: u32,
     ) -> Result<MenezesVanstoneStringKeyPair> {
-        ensure!(n != 0, MenezesVanstoneError::InvalidNValueError(n));
-        ensure!(
-            modul_width > 3,
-            MenezesVanstoneError::InvalidModulusWidthError(modul_width)
-        );
         ensure!(
             radix != 0,
             MenezesVanstoneError::InvalidNumberSystemBaseError(radix)
         );
 
-        let key_pair = MenezesVanstoneScheme::generate_keypair(
-            n,
-            modul_width,
-            miller_rabin_iterations,
-            random_seed,
-        )
-        .context("Error while creating keypair for MenezesVanstone-Core. Error: {:#?}")?;
+        let key_pair = MenezesVanstoneScheme::generate_keypair(curve_config, random_seed)
+            .context("Error while creating keypair for MenezesVanstone-Core. Error: {:#?}")?;
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
@@ -86,6 +89,204 @@ impl MenezesVanstoneStringScheme {
             private_key,
         })
     }
+
+    /// Verschlüsselt eine beliebige Bytefolge, z.B. den Inhalt einer Datei.
+    /// Die Bytefolge wird mit vorangestellter Länge in Blöcke aufgeteilt, die echt kleiner als
+    /// der Modulus der Kurve sind. Je zwei Blöcke werden gemeinsam verschlüsselt, bei einer
+    /// ungeraden Anzahl wird ein Nullblock ergänzt, der durch die Längenangabe eindeutig ist.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Die zu verschlüsselnde Bytefolge.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `MvByteCiphertext` - Die verschlüsselte Bytefolge und die verwendeten Punkte.
+    pub fn encrypt_bytes(
+        key: &MenezesVanstoneStringPublicKey,
+        plaintext: &[u8],
+        service: NumberTheoryService,
+    ) -> Result<MvByteCiphertext> {
+        let (plaintext_block_size, ciphertext_block_size) =
+            block_sizes_for_modulus(&key.mv_key.curve.curve.prime)?;
+
+        let message = ToByteBlockScheme::encrypt(
+            plaintext,
+            &ByteBlockConversionSchemeKey {
+                block_size: plaintext_block_size,
+            },
+        );
+
+        let mut encrypted_blocks: Vec<BigInt> = Vec::new();
+        let mut points = Vec::new();
+        for chunk in message.chunks(2) {
+            let plaintext_chunk = MenezesVanstonePlaintext {
+                first: chunk[0].clone(),
+                second: chunk.get(1).cloned().unwrap_or_else(BigInt::zero),
+            };
+            let ciphertext = MenezesVanstoneScheme::encrypt(&key.mv_key, &plaintext_chunk, service)
+                .context("Verschlüsselung im MenezesVanstone-Kern fehlgeschlagen.")?;
+            encrypted_blocks.push(ciphertext.first);
+            encrypted_blocks.push(ciphertext.second);
+            points.push(ciphertext.point);
+        }
+
+        let ciphertext = FromByteBlockScheme::encrypt(
+            &encrypted_blocks,
+            &ByteBlockConversionSchemeKey {
+                block_size: ciphertext_block_size,
+            },
+        )?;
+        Ok(MvByteCiphertext { ciphertext, points })
+    }
+
+    /// Entschlüsselt eine mit `encrypt_bytes` verschlüsselte Bytefolge.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `ciphertext` - Die verschlüsselte Bytefolge und die verwendeten Punkte.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Die entschlüsselte Bytefolge.
+    pub fn decrypt_bytes(
+        key: &MenezesVanstoneStringPrivateKey,
+        ciphertext: &MvByteCiphertext,
+        service: NumberTheoryService,
+    ) -> Result<Vec<u8>> {
+        let (plaintext_block_size, ciphertext_block_size) =
+            block_sizes_for_modulus(&key.mv_key.curve.curve.prime)?;
+
+        let encrypted_blocks = FromByteBlockScheme::decrypt(
+            &ciphertext.ciphertext,
+            &ByteBlockConversionSchemeKey {
+                block_size: ciphertext_block_size,
+            },
+        )?;
+        ensure!(
+            ciphertext.points.len() * 2 == encrypted_blocks.len(),
+            "Die Anzahl der Punkte und Tupel stimmen nicht überein."
+        );
+
+        let mut blocks: Vec<BigInt> = Vec::new();
+        for (pair, point) in encrypted_blocks.chunks(2).zip(&ciphertext.points) {
+            let ciphertext = MenezesVanstoneCiphertext {
+                point: point.clone(),
+                first: pair[0].clone(),
+                second: pair[1].clone(),
+            };
+            let plaintext = MenezesVanstoneScheme::decrypt(&key.mv_key, &ciphertext, service)
+                .context("Entschlüsselung im MenezesVanstone-Kern fehlgeschlagen.")?;
+            blocks.push(plaintext.first);
+            blocks.push(plaintext.second);
+        }
+
+        ToByteBlockScheme::decrypt(
+            &blocks,
+            &ByteBlockConversionSchemeKey {
+                block_size: plaintext_block_size,
+            },
+        )
+    }
+
+    /// Verschlüsselt eine Zeichenkette wie `encrypt`, gibt das Ergebnis aber als selbstbeschreibenden
+    /// Container zurück. Da die Länge des Klartextes im Container steht, kann ein zum Auffüllen
+    /// eingefügter Nullblock beim Entschlüsseln eindeutig verworfen werden.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Der zu verschlüsselnde Klartext.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `CiphertextContainer` - Der Container mit den verschlüsselten Blöcken und Punkten.
+    pub fn encrypt_to_container(
+        key: &MenezesVanstoneStringPublicKey,
+        plaintext: &str,
+        service: NumberTheoryService,
+    ) -> Result<CiphertextContainer> {
+        let prime = &key.mv_key.curve.curve.prime;
+        let encoding = EncodingParameters {
+            radix: key.radix,
+            block_size: prime.log(&key.radix.into()) as u32,
+        };
+        check_encoding(&encoding, prime)?;
+
+        let message = ToDecimalBlockScheme::encrypt(
+            plaintext,
+            &DecimalUnicodeConversionSchemeKey {
+                radix: encoding.radix,
+                block_size: encoding.block_size as usize,
+            },
+        );
+
+        let mut blocks: Vec<BigInt> = Vec::new();
+        let mut points = Vec::new();
+        for chunk in message.chunks(2) {
+            let plaintext_chunk = MenezesVanstonePlaintext {
+                first: chunk[0].clone(),
+                second: chunk.get(1).cloned().unwrap_or_else(BigInt::zero),
+            };
+            let ciphertext = MenezesVanstoneScheme::encrypt(&key.mv_key, &plaintext_chunk, service)
+                .context("Verschlüsselung im MenezesVanstone-Kern fehlgeschlagen.")?;
+            blocks.push(ciphertext.first);
+            blocks.push(ciphertext.second);
+            points.push(ciphertext.point);
+        }
+
+        Ok(CiphertextContainer {
+            scheme: SchemeIdentifier::MenezesVanstoneString,
+            key_fingerprint: key.fingerprint(),
+            encoding,
+            original_length: plaintext.chars().count() as u64,
+            blocks,
+            points,
+        })
+    }
+
+    /// Entschlüsselt einen mit `encrypt_to_container` erstellten Container.
+    /// Die Parameter der Decimal-Unicode-Abbildung werden dabei aus dem Container und nicht aus
+    /// dem Schlüssel übernommen.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `container` - Der Container mit den verschlüsselten Blöcken und Punkten.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die entschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `ContainerError` - Falls der Container nicht zum Schema oder Schlüssel passt.
+    pub fn decrypt_container(
+        key: &MenezesVanstoneStringPrivateKey,
+        container: &CiphertextContainer,
+        service: NumberTheoryService,
+    ) -> Result<String> {
+        container.check_key(SchemeIdentifier::MenezesVanstoneString, &key.fingerprint()?)?;
+        check_encoding(&container.encoding, &key.mv_key.curve.curve.prime)?;
+        ensure!(
+            container.points.len() * 2 == container.blocks.len(),
+            "Die Anzahl der Punkte und Tupel stimmen nicht überein."
+        );
+
+        let mut blocks: Vec<BigInt> = Vec::new();
+        for (pair, point) in container.blocks.chunks(2).zip(&container.points) {
+            let ciphertext = MenezesVanstoneCiphertext {
+                point: point.clone(),
+                first: pair[0].clone(),
+                second: pair[1].clone(),
+            };
+            let plaintext = MenezesVanstoneScheme::decrypt(&key.mv_key, &ciphertext, service)
+                .context("Entschlüsselung im MenezesVanstone-Kern fehlgeschlagen.")?;
+            blocks.push(plaintext.first);
+            blocks.push(plaintext.second);
+        }
+
+        // Ein zum Auffüllen eingefügter Nullblock gehört nicht zur Nachricht.
+        blocks.truncate(container.plaintext_block_count());
+        decode_text(&blocks, &container.encoding, container.original_length)
+    }
 }
 
 impl<'a> Encryptor<MenezesVanstoneStringScheme> for MenezesVanstoneStringScheme {
@@ -101,7 +302,7 @@ impl AsymmetricEncryptor<MenezesVanstoneStringScheme> for MenezesVanstoneStringS
         service: NumberTheoryService,
     ) -> Self::Output {
         let radix = key.radix;
-        let block_size = key.mv_key.curve.prime.log(&radix.into());
+        let block_size = key.mv_key.curve.curve.prime.log(&radix.into());
 
         ensure!(
             block_size > 0,
@@ -184,7 +385,7 @@ impl AsymmetricDecryptor<MenezesVanstoneStringScheme> for MenezesVanstoneStringS
         let ciphertext_string = &ciphertext.ciphertext;
         let points = &ciphertext.points;
         let radix = key.radix;
-        let block_size = key.mv_key.curve.prime.log(&radix.into()) + 1;
+        let block_size = key.mv_key.curve.curve.prime.log(&radix.into()) + 1;
 
         // Blockchiffre anwenden
         let decimal_unicode_key = DecimalUnicodeConversionSchemeKey { radix, block_size };
@@ -276,6 +477,7 @@ mod tests {
     use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
 
     use super::*;
+    use crate::shared::errors::ContainerError;
 
     #[test]
     #[ignore] // TODO Fix me: Dieser Test rennt in manchen Fällen in eine Endlosschleife.
@@ -286,9 +488,12 @@ mod tests {
         let n = rand::thread_rng().gen_range(1..30);
         let modul_width = rand::thread_rng().gen_range(4..256);
         let random_seed = rand::thread_rng().gen_range(1..1000);
-        let key_pair =
-            MenezesVanstoneStringScheme::generate_keypair(n, modul_width, 40, random_seed, radix)
-                .unwrap();
+        let key_pair = MenezesVanstoneStringScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(n, modul_width, 40),
+            random_seed,
+            radix,
+        )
+        .unwrap();
 
         let public_key = key_pair.public_key;
         let private_key = key_pair.private_key;
@@ -308,4 +513,98 @@ mod tests {
             MenezesVanstoneStringScheme::decrypt(&private_key, &ciphertext, service).unwrap();
         assert_eq!(plaintext, decrypted_plaintext);
     }
+
+    #[test]
+    fn test_encrypt_decrypt_bytes() {
+        let key_pair = MenezesVanstoneStringScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 32, 40),
+            17,
+            55296,
+        )
+        .unwrap();
+        let service = NumberTheoryService::new(Fast);
+
+        for message in [
+            vec![],
+            vec![0u8],
+            (0..=255u8).chain([0, 0, 0]).collect::<Vec<u8>>(),
+        ] {
+            let ciphertext =
+                MenezesVanstoneStringScheme::encrypt_bytes(&key_pair.public_key, &message, service)
+                    .unwrap();
+            let plaintext = MenezesVanstoneStringScheme::decrypt_bytes(
+                &key_pair.private_key,
+                &ciphertext,
+                service,
+            )
+            .unwrap();
+            assert_eq!(plaintext, message);
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_container() {
+        let key_pair = MenezesVanstoneStringScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 32, 40),
+            17,
+            55296,
+        )
+        .unwrap();
+        let service = NumberTheoryService::new(Fast);
+
+        for message in [
+            "",
+            "\u{0}",
+            "Das ist eine\u{0}Testnachricht mit Nullzeichen\u{0}",
+        ] {
+            let container = MenezesVanstoneStringScheme::encrypt_to_container(
+                &key_pair.public_key,
+                message,
+                service,
+            )
+            .unwrap();
+            let parsed = CiphertextContainer::from_base64(&container.to_base64()).unwrap();
+            let plaintext = MenezesVanstoneStringScheme::decrypt_container(
+                &key_pair.private_key,
+                &parsed,
+                service,
+            )
+            .unwrap();
+            assert_eq!(plaintext, message);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_container_rejects_other_key() {
+        let key_pair = MenezesVanstoneStringScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 32, 40),
+            17,
+            55296,
+        )
+        .unwrap();
+        let other_key_pair = MenezesVanstoneStringScheme::generate_keypair(
+            &MenezesVanstoneCurveConfig::generated(5, 32, 40),
+            23,
+            55296,
+        )
+        .unwrap();
+        let service = NumberTheoryService::new(Fast);
+
+        let container = MenezesVanstoneStringScheme::encrypt_to_container(
+            &key_pair.public_key,
+            "Geheim",
+            service,
+        )
+        .unwrap();
+        let error = MenezesVanstoneStringScheme::decrypt_container(
+            &other_key_pair.private_key,
+            &container,
+            service,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::KeyFingerprintMismatchError)
+        ));
+    }
 }
@@ -1,3 +1,5 @@
+pub mod byte_block_schemes;
+pub mod ciphertext_container;
 pub mod decimal_unicode_schemes;
 pub mod menezes_vanstone;
 pub mod rsa;
@@ -4,6 +4,9 @@ use crate::encryption::asymmetric_encryption_types::{
 };
 use crate::encryption::core::rsa::keys;
 use crate::encryption::encryption_types::Key;
+use crate::encryption::string_schemes::ciphertext_container::{
+    key_fingerprint, SchemeIdentifier, FINGERPRINT_SIZE,
+};
 use crate::encryption::string_schemes::rsa::rsa_with_string_scheme::RsaWithStringScheme;
 use keys::{RsaPrivateKey, RsaPublicKey};
 
@@ -13,6 +16,13 @@ pub struct RsaWithStringPublicKey {
     pub radix: u32,
 }
 
+impl RsaWithStringPublicKey {
+    /// Der Fingerabdruck des Schlüssels, der mit dem des zugehörigen privaten Schlüssels übereinstimmt.
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        key_fingerprint(SchemeIdentifier::RsaWithString, &[&self.rsa_public_key.n])
+    }
+}
+
 impl Key<RsaWithStringScheme> for RsaWithStringPublicKey {}
 
 impl AsymmetricKey<RsaWithStringScheme> for RsaWithStringPublicKey {}
@@ -29,6 +39,13 @@ pub struct RsaWithStringPrivateKey {
     pub radix: u32,
 }
 
+impl RsaWithStringPrivateKey {
+    /// Der Fingerabdruck des Schlüssels, der mit dem des zugehörigen öffentlichen Schlüssels übereinstimmt.
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        key_fingerprint(SchemeIdentifier::RsaWithString, &[&self.rsa_private_key.n])
+    }
+}
+
 impl Key<RsaWithStringScheme> for RsaWithStringPrivateKey {}
 
 impl AsymmetricKey<RsaWithStringScheme> for RsaWithStringPrivateKey {}
@@ -5,6 +5,13 @@ use crate::encryption::asymmetric_encryption_types::{
 
 use crate::encryption::core::rsa::rsa_scheme::RsaScheme;
 use crate::encryption::encryption_types::{Decryptor, EncryptionScheme, Encryptor};
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::block_sizes_for_modulus;
+use crate::encryption::string_schemes::byte_block_schemes::from_byte_block_scheme::FromByteBlockScheme;
+use crate::encryption::string_schemes::byte_block_schemes::keys::ByteBlockConversionSchemeKey;
+use crate::encryption::string_schemes::byte_block_schemes::to_byte_block_scheme::ToByteBlockScheme;
+use crate::encryption::string_schemes::ciphertext_container::{
+    check_encoding, decode_text, CiphertextContainer, EncodingParameters, SchemeIdentifier,
+};
 use crate::encryption::string_schemes::decimal_unicode_schemes::from_decimal_block_scheme::FromDecimalBlockScheme;
 use crate::encryption::string_schemes::decimal_unicode_schemes::keys::DecimalUnicodeConversionSchemeKey;
 use crate::encryption::string_schemes::decimal_unicode_schemes::to_decimal_block_scheme::ToDecimalBlockScheme;
@@ -14,10 +21,19 @@ use crate::encryption::string_schemes::rsa::keys::{
 use crate::encryption::symmetric_encryption_types::{SymmetricDecryptor, SymmetricEncryptor};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::traits::logarithm::Logarithm;
-use crate::shared::hashing::sha256;
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use anyhow::{ensure, Result};
 
 pub struct RsaWithStringScheme {}
 
+/// Eine RSA-Signatur als Zeichenkette zusammen mit dem Hashverfahren, mit dem die Nachricht vor
+/// dem Signieren gehasht wurde. Der Verifizierer liest das Verfahren aus der Signatur.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RsaWithStringSignature {
+    pub hash_algorithm: HashAlgorithm,
+    pub signature: String,
+}
+
 impl EncryptionScheme for RsaWithStringScheme {}
 
 impl AsymmetricEncryptionScheme for RsaWithStringScheme {}
@@ -106,11 +122,10 @@ impl AsymmetricDecryptor<RsaWithStringScheme> for RsaWithStringScheme {
 
 impl<'a> Signer<RsaWithStringScheme> for RsaWithStringScheme {
     type Input = str;
-    type Output = String;
+    type Output = RsaWithStringSignature;
     type Key = RsaWithStringPrivateKey;
 
-    /// Signiert eine Nachricht. Dafür wird die Nachricht gehasht und dann unter einer
-    /// Decimal-Unicode-Abbildung mittels RSA signiert.
+    /// Signiert eine Nachricht mit dem Standard-Hashverfahren SHA-256, siehe `sign_with_hash`.
     ///
     /// # Argumente
     /// * `key` - Der private Schlüssel.
@@ -118,37 +133,21 @@ impl<'a> Signer<RsaWithStringScheme> for RsaWithStringScheme {
     /// * `service` - Der zu verwendende NumberTheoryService.
     ///
     /// # Rückgabe
-    /// * `String` - Die Signatur.
+    /// * `RsaWithStringSignature` - Die Signatur.
     ///
     fn sign(key: &Self::Key, message: &Self::Input, service: NumberTheoryService) -> Self::Output {
-        let radix = key.radix;
-        let rsa_key = &key.rsa_private_key;
-        let block_size = rsa_key.n.log(&radix.into());
-        let hashed_message = sha256(message).to_str_radix(10);
-
-        let pre_key = DecimalUnicodeConversionSchemeKey { radix, block_size };
-        let chunks = ToDecimalBlockScheme::encrypt(&hashed_message, &pre_key);
-        let encrypted_chunks = chunks
-            .iter()
-            .map(|chunk| RsaScheme::sign(rsa_key, chunk, service))
-            .collect();
-
-        // Die Größe der verschlüsselten Blöcke ist immer um 1 größer als die Klartextgröße.
-        let post_key = DecimalUnicodeConversionSchemeKey {
-            radix,
-            block_size: block_size + 1,
-        };
-        FromDecimalBlockScheme::encrypt(&encrypted_chunks, &post_key)
+        Self::sign_with_hash(key, message, HashAlgorithm::default(), service)
     }
 }
 
 impl<'a> Verifier<RsaWithStringScheme> for RsaWithStringScheme {
-    type Signature = str;
+    type Signature = RsaWithStringSignature;
     type Message = str;
     type Output = bool;
     type Key = RsaWithStringPublicKey;
 
-    /// Verifiert eine Nachricht gegen eine Signatur.
+    /// Verifiert eine Nachricht gegen eine Signatur. Die Nachricht wird mit dem Hashverfahren
+    /// gehasht, das in der Signatur vermerkt ist.
     ///
     /// # Argumente
     /// * `key` - Der öffentliche Schlüssel.
@@ -175,24 +174,229 @@ impl<'a> Verifier<RsaWithStringScheme> for RsaWithStringScheme {
             block_size: block_size + 1,
         };
 
-        let hashed_message = sha256(message).to_str_radix(10);
+        let hashed_message = signature
+            .hash_algorithm
+            .digest_to_big_int(message.as_bytes())
+            .to_str_radix(10);
         // Die g-adisch entwickelten Werte der gehashten Nachricht
         let message_chunks =
             ToDecimalBlockScheme::encrypt(&hashed_message, &message_unicode_conversion_key);
         // Die verschlüsselten Werte der Signatur
-        let encrypted_signature_chunks =
-            FromDecimalBlockScheme::decrypt(signature, &signature_unicode_conversion_key);
-
-        message_chunks
-            .iter()
-            .zip(encrypted_signature_chunks.iter())
-            .all(|(message_chunk, encrypted_signature_chunk)| {
-                RsaScheme::verify(rsa_key, message_chunk, encrypted_signature_chunk, service)
-            })
+        let encrypted_signature_chunks = FromDecimalBlockScheme::decrypt(
+            &signature.signature,
+            &signature_unicode_conversion_key,
+        );
+
+        message_chunks.len() == encrypted_signature_chunks.len()
+            && message_chunks
+                .iter()
+                .zip(encrypted_signature_chunks.iter())
+                .all(|(message_chunk, encrypted_signature_chunk)| {
+                    RsaScheme::verify(rsa_key, message_chunk, encrypted_signature_chunk, service)
+                })
     }
 }
 
 impl RsaWithStringScheme {
+    /// Signiert eine Nachricht. Dafür wird die Nachricht mit dem gegebenen Hashverfahren gehasht
+    /// und der Hashwert dann unter einer Decimal-Unicode-Abbildung mittels RSA signiert.
+    ///
+    /// # Argumente
+    /// * `key` - Der private Schlüssel.
+    /// * `message` - Die zu signierende Nachricht.
+    /// * `hash_algorithm` - Das Hashverfahren, das in der Signatur vermerkt wird.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `RsaWithStringSignature` - Die Signatur.
+    pub fn sign_with_hash(
+        key: &RsaWithStringPrivateKey,
+        message: &str,
+        hash_algorithm: HashAlgorithm,
+        service: NumberTheoryService,
+    ) -> RsaWithStringSignature {
+        let radix = key.radix;
+        let rsa_key = &key.rsa_private_key;
+        let block_size = rsa_key.n.log(&radix.into());
+        let hashed_message = hash_algorithm
+            .digest_to_big_int(message.as_bytes())
+            .to_str_radix(10);
+
+        let pre_key = DecimalUnicodeConversionSchemeKey { radix, block_size };
+        let chunks = ToDecimalBlockScheme::encrypt(&hashed_message, &pre_key);
+        let encrypted_chunks = chunks
+            .iter()
+            .map(|chunk| RsaScheme::sign(rsa_key, chunk, service))
+            .collect();
+
+        // Die Größe der verschlüsselten Blöcke ist immer um 1 größer als die Klartextgröße.
+        let post_key = DecimalUnicodeConversionSchemeKey {
+            radix,
+            block_size: block_size + 1,
+        };
+        RsaWithStringSignature {
+            hash_algorithm,
+            signature: FromDecimalBlockScheme::encrypt(&encrypted_chunks, &post_key),
+        }
+    }
+
+    /// Verschlüsselt eine Zeichenkette wie `encrypt`, gibt das Ergebnis aber als selbstbeschreibenden
+    /// Container zurück. Dieser enthält neben den verschlüsselten Blöcken den Fingerabdruck des
+    /// Schlüssels, die Parameter der Decimal-Unicode-Abbildung und die Länge des Klartextes.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Der zu verschlüsselnde Klartext.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `CiphertextContainer` - Der Container mit der verschlüsselten Nachricht.
+    pub fn encrypt_to_container(
+        key: &RsaWithStringPublicKey,
+        plaintext: &str,
+        service: NumberTheoryService,
+    ) -> Result<CiphertextContainer> {
+        let rsa_key = &key.rsa_public_key;
+        let encoding = EncodingParameters {
+            radix: key.radix,
+            block_size: rsa_key.n.log(&key.radix.into()) as u32,
+        };
+        check_encoding(&encoding, &rsa_key.n)?;
+
+        let pre_key = DecimalUnicodeConversionSchemeKey {
+            radix: encoding.radix,
+            block_size: encoding.block_size as usize,
+        };
+        let blocks = ToDecimalBlockScheme::encrypt(plaintext, &pre_key)
+            .iter()
+            .map(|chunk| RsaScheme::encrypt(rsa_key, chunk, service))
+            .collect();
+
+        Ok(CiphertextContainer {
+            scheme: SchemeIdentifier::RsaWithString,
+            key_fingerprint: key.fingerprint(),
+            encoding,
+            original_length: plaintext.chars().count() as u64,
+            blocks,
+            points: vec![],
+        })
+    }
+
+    /// Entschlüsselt einen mit `encrypt_to_container` erstellten Container.
+    /// Die Parameter der Decimal-Unicode-Abbildung werden dabei aus dem Container und nicht aus
+    /// dem Schlüssel übernommen.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `container` - Der Container mit der verschlüsselten Nachricht.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `String` - Die entschlüsselte Nachricht.
+    ///
+    /// # Fehler
+    /// * `ContainerError` - Falls der Container nicht zum Schema oder Schlüssel passt.
+    pub fn decrypt_container(
+        key: &RsaWithStringPrivateKey,
+        container: &CiphertextContainer,
+        service: NumberTheoryService,
+    ) -> Result<String> {
+        let rsa_key = &key.rsa_private_key;
+        container.check_key(SchemeIdentifier::RsaWithString, &key.fingerprint())?;
+        check_encoding(&container.encoding, &rsa_key.n)?;
+        ensure!(
+            container.points.is_empty(),
+            "Ein RSA-Container darf keine Punkte enthalten."
+        );
+
+        let blocks: Vec<_> = container
+            .blocks
+            .iter()
+            .map(|block| RsaScheme::decrypt(rsa_key, block, service))
+            .collect();
+        decode_text(&blocks, &container.encoding, container.original_length)
+    }
+
+    /// Verschlüsselt eine beliebige Bytefolge, z.B. den Inhalt einer Datei.
+    /// Die Bytefolge wird mit vorangestellter Länge in Blöcke aufgeteilt, die echt kleiner als
+    /// der Modulus sind. Jeder verschlüsselte Block wird mit der Bytelänge des Modulus abgelegt.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `plaintext` - Die zu verschlüsselnde Bytefolge.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Die verschlüsselte Bytefolge.
+    ///
+    /// # Fehler
+    /// * `ByteEncodingError::ModulusTooSmallError` - Falls der Modulus kleiner als 2^8 ist.
+    pub fn encrypt_bytes(
+        key: &RsaWithStringPublicKey,
+        plaintext: &[u8],
+        service: NumberTheoryService,
+    ) -> Result<Vec<u8>> {
+        let rsa_key = &key.rsa_public_key;
+        let (plaintext_block_size, ciphertext_block_size) = block_sizes_for_modulus(&rsa_key.n)?;
+
+        let chunks = ToByteBlockScheme::encrypt(
+            plaintext,
+            &ByteBlockConversionSchemeKey {
+                block_size: plaintext_block_size,
+            },
+        );
+        let encrypted_chunks = chunks
+            .iter()
+            .map(|chunk| RsaScheme::encrypt(rsa_key, chunk, service))
+            .collect();
+
+        FromByteBlockScheme::encrypt(
+            &encrypted_chunks,
+            &ByteBlockConversionSchemeKey {
+                block_size: ciphertext_block_size,
+            },
+        )
+    }
+
+    /// Entschlüsselt eine mit `encrypt_bytes` verschlüsselte Bytefolge.
+    ///
+    /// # Argumente
+    /// * `key` - Der zu verwendende Schlüssel.
+    /// * `ciphertext` - Die zu entschlüsselnde Bytefolge.
+    /// * `service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * `Vec<u8>` - Die entschlüsselte Bytefolge.
+    ///
+    /// # Fehler
+    /// * `ByteEncodingError` - Falls der Chiffretext nicht zu einer gültigen Nachricht gehört.
+    pub fn decrypt_bytes(
+        key: &RsaWithStringPrivateKey,
+        ciphertext: &[u8],
+        service: NumberTheoryService,
+    ) -> Result<Vec<u8>> {
+        let rsa_key = &key.rsa_private_key;
+        let (plaintext_block_size, ciphertext_block_size) = block_sizes_for_modulus(&rsa_key.n)?;
+
+        let chunks = FromByteBlockScheme::decrypt(
+            ciphertext,
+            &ByteBlockConversionSchemeKey {
+                block_size: ciphertext_block_size,
+            },
+        )?;
+        let decrypted_chunks = chunks
+            .iter()
+            .map(|chunk| RsaScheme::decrypt(rsa_key, chunk, service))
+            .collect();
+
+        ToByteBlockScheme::decrypt(
+            &decrypted_chunks,
+            &ByteBlockConversionSchemeKey {
+                block_size: plaintext_block_size,
+            },
+        )
+    }
+
     // TODO KeyGenConfig anpassen? Das hier passt nicht mehr ins Muster
 
     fn generate_keypair(config: &impl KeyGenWithPrimeConfig, radix: u32) -> RsaWithStringKeyPair {
@@ -222,6 +426,7 @@ mod tests {
     };
 
     use super::*;
+    use crate::shared::errors::ContainerError;
 
     fn run_test_for_all_services(test: impl Fn(NumberTheoryService)) {
         test(NumberTheoryService::new(Slow)); // Langsame, eigene Implementierung
@@ -363,6 +568,92 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_sign_verify_with_hash_algorithms() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 512,
+            miller_rabin_iterations: 30,
+            random_seed: 23,
+            number_theory_service: service,
+        };
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+        let (public_key, private_key) = (&key_pair.public_key, &key_pair.private_key);
+        let message = "Signiert mit verschiedenen Hashverfahren";
+
+        for hash_algorithm in [
+            HashAlgorithm::Sha224,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Sha3_256,
+            HashAlgorithm::Shake256(40),
+        ] {
+            let signature =
+                RsaWithStringScheme::sign_with_hash(private_key, message, hash_algorithm, service);
+            assert_eq!(signature.hash_algorithm, hash_algorithm);
+            assert!(RsaWithStringScheme::verify(
+                public_key, &signature, message, service
+            ));
+
+            // Mit einem anderen Hashverfahren passt die Signatur nicht mehr zur Nachricht.
+            let mut changed = signature.clone();
+            changed.hash_algorithm = HashAlgorithm::Sha384;
+            assert!(!RsaWithStringScheme::verify(
+                public_key, &changed, message, service
+            ));
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_bytes() {
+        run_test_for_all_services(|service| {
+            let config = RsaKeyGenConfig {
+                key_size: 256,
+                miller_rabin_iterations: 30,
+                random_seed: 31,
+                number_theory_service: service,
+            };
+            let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+            let (public_key, private_key) = (&key_pair.public_key, &key_pair.private_key);
+
+            // Binärdaten mit Nullbytes am Anfang und Ende, die als String nicht darstellbar wären
+            let message: Vec<u8> = [0u8, 0, 0xff, 0xfe]
+                .into_iter()
+                .chain(0..=255u8)
+                .chain([0, 0])
+                .collect();
+
+            let ciphertext =
+                RsaWithStringScheme::encrypt_bytes(public_key, &message, service).unwrap();
+            let n_length = (public_key.rsa_public_key.n.bits() as usize).div_ceil(8);
+            assert_eq!(ciphertext.len() % n_length, 0);
+
+            let plaintext =
+                RsaWithStringScheme::decrypt_bytes(private_key, &ciphertext, service).unwrap();
+            assert_eq!(plaintext, message);
+
+            let empty = RsaWithStringScheme::encrypt_bytes(public_key, &[], service).unwrap();
+            assert!(
+                RsaWithStringScheme::decrypt_bytes(private_key, &empty, service)
+                    .unwrap()
+                    .is_empty()
+            );
+        });
+    }
+
+    #[test]
+    fn test_decrypt_bytes_with_invalid_length() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 30,
+            random_seed: 31,
+            number_theory_service: service,
+        };
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+        let result = RsaWithStringScheme::decrypt_bytes(&key_pair.private_key, &[1, 2, 3], service);
+        assert!(result.is_err());
+    }
+
     fn sign_verify_assert(
         config: RsaKeyGenConfig,
         radix: u32,
@@ -379,4 +670,101 @@ mod tests {
             RsaWithStringScheme::verify(public_key, &signature, message, service.clone());
         assert_eq!(expected, is_valid);
     }
+
+    #[test]
+    fn test_encrypt_decrypt_container() {
+        run_test_for_all_services(|service| {
+            let config = RsaKeyGenConfig {
+                key_size: 256,
+                miller_rabin_iterations: 30,
+                random_seed: 31,
+                number_theory_service: service,
+            };
+            let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+            let message = "\u{0}Nachricht mit führendem Nullzeichen 苉";
+
+            let container =
+                RsaWithStringScheme::encrypt_to_container(&key_pair.public_key, message, service)
+                    .unwrap();
+            let parsed = CiphertextContainer::from_bytes(&container.to_bytes()).unwrap();
+            assert_eq!(parsed, container);
+
+            let plaintext =
+                RsaWithStringScheme::decrypt_container(&key_pair.private_key, &parsed, service)
+                    .unwrap();
+            assert_eq!(plaintext, message);
+        });
+    }
+
+    #[test]
+    fn test_decrypt_container_uses_stored_encoding() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 30,
+            random_seed: 17,
+            number_theory_service: service,
+        };
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+        let message = "Radix im Container";
+        let container =
+            RsaWithStringScheme::encrypt_to_container(&key_pair.public_key, message, service)
+                .unwrap();
+
+        // Ein Schlüssel mit geändertem Standard-Radix kann den Container weiterhin entschlüsseln.
+        let mut private_key = key_pair.private_key.clone();
+        private_key.radix = 1024;
+        let plaintext =
+            RsaWithStringScheme::decrypt_container(&private_key, &container, service).unwrap();
+        assert_eq!(plaintext, message);
+
+        let mut mv_container = container.clone();
+        mv_container.scheme = SchemeIdentifier::MenezesVanstoneString;
+        let error = RsaWithStringScheme::decrypt_container(&private_key, &mv_container, service)
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::SchemeMismatchError(_, _))
+        ));
+
+        let mut oversized_container = container.clone();
+        oversized_container.encoding.block_size = 64;
+        let error =
+            RsaWithStringScheme::decrypt_container(&private_key, &oversized_container, service)
+                .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::InvalidEncodingParametersError(55296, 64))
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_container_rejects_huge_encoding() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 30,
+            random_seed: 17,
+            number_theory_service: service,
+        };
+        let key_pair = RsaWithStringScheme::generate_keypair(&config, 55296);
+        let mut container =
+            RsaWithStringScheme::encrypt_to_container(&key_pair.public_key, "Hallo", service)
+                .unwrap();
+        container.encoding = EncodingParameters {
+            radix: u32::MAX,
+            block_size: u32::MAX,
+        };
+
+        let error =
+            RsaWithStringScheme::decrypt_container(&key_pair.private_key, &container, service)
+                .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ContainerError>(),
+            Some(ContainerError::InvalidEncodingParametersError(
+                u32::MAX,
+                u32::MAX
+            ))
+        ));
+    }
 }
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use bigdecimal::num_bigint::BigInt;
 use bigdecimal::{One, Zero};
 
+use crate::math_core::number_theory::computation_trace::{ShanksStep, Traced};
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
@@ -53,6 +54,33 @@ impl Shanks {
         base: &BigInt,
         element: &BigInt,
         modul: &BigInt,
+    ) -> Result<BigInt, ArithmeticError> {
+        self.run(base, element, modul, None)
+    }
+
+    /// Berechnet wie `calculate` den diskreten Logarithmus und protokolliert dabei die Tabelle
+    /// der Giantsteps sowie alle Babysteps bis zum ersten Treffer.
+    ///
+    /// # Fehler
+    ///
+    /// * `ArithmeticError::NoDiscreteLogarithmError` - Wenn der Logarithmus nicht existiert.
+    pub fn calculate_traced(
+        self,
+        base: &BigInt,
+        element: &BigInt,
+        modul: &BigInt,
+    ) -> Result<Traced<BigInt, ShanksStep>, ArithmeticError> {
+        let mut steps = Vec::new();
+        let result = self.run(base, element, modul, Some(&mut steps))?;
+        Ok(Traced::new(result, steps))
+    }
+
+    fn run(
+        self,
+        base: &BigInt,
+        element: &BigInt,
+        modul: &BigInt,
+        mut steps: Option<&mut Vec<ShanksStep>>,
     ) -> Result<BigInt, ArithmeticError> {
         //aufrundung: nachkommateil abschneiden (to_bigint) +1
         let mut m = (modul - BigInt::one()).sqrt();
@@ -70,6 +98,12 @@ impl Shanks {
             let giantstep = self
                 .number_theory_service
                 .fast_exponentiation(&g_ex_m, &j, modul);
+            if let Some(steps) = steps.as_mut() {
+                steps.push(ShanksStep::GiantStep {
+                    j: j.clone(),
+                    value: giantstep.clone(),
+                });
+            }
             map.insert(giantstep, j.clone());
             j.increment_assign();
         }
@@ -85,8 +119,15 @@ impl Shanks {
                 ))
                 % modul;
             let pair = map.get(&babystep);
-            if pair.is_some() {
-                return Ok((&m * pair.unwrap() + &i) % (modul - BigInt::one()));
+            if let Some(steps) = steps.as_mut() {
+                steps.push(ShanksStep::BabyStep {
+                    i: i.clone(),
+                    value: babystep.clone(),
+                    matching_giant_step: pair.cloned(),
+                });
+            }
+            if let Some(j) = pair {
+                return Ok((&m * j + &i) % (modul - BigInt::one()));
             }
             i.increment_assign();
         }
@@ -135,4 +176,41 @@ mod tests {
             //Da Base nicht primitive Wurzel!
         });
     }
+
+    #[test]
+    fn shanks_traced_test() {
+        run_test_for_all_services(|service| {
+            let traced = Shanks::new(service)
+                .calculate_traced(&3.into(), &4.into(), &7.into())
+                .unwrap();
+            assert_eq!(traced.result, 4.into());
+            assert_eq!(
+                traced.steps,
+                vec![
+                    ShanksStep::GiantStep {
+                        j: 0.into(),
+                        value: 1.into()
+                    },
+                    ShanksStep::GiantStep {
+                        j: 1.into(),
+                        value: 6.into()
+                    },
+                    ShanksStep::GiantStep {
+                        j: 2.into(),
+                        value: 1.into()
+                    },
+                    ShanksStep::BabyStep {
+                        i: 0.into(),
+                        value: 4.into(),
+                        matching_giant_step: None
+                    },
+                    ShanksStep::BabyStep {
+                        i: 1.into(),
+                        value: 6.into(),
+                        matching_giant_step: Some(1.into())
+                    },
+                ]
+            );
+        });
+    }
 }
@@ -0,0 +1,192 @@
+use anyhow::{ensure, Result};
+use atomic_counter::RelaxedCounter;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::One;
+use log::debug;
+use num::Integer;
+
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::{
+    take_from_bits, RandomNumberGenerator, SequentialBitCache,
+};
+
+/// Blum-Blum-Shub-Generator.
+/// Die Folge entsteht durch wiederholtes Quadrieren x_{i+1} = x_i^2 mod n, wobei n das Produkt
+/// zweier Blum-Primzahlen (p ≡ 3 mod 4) ist. Ausgegeben wird jeweils das niederwertigste Bit.
+/// Die Vorhersage des nächsten Bits ist so schwer wie die Faktorisierung von n.
+pub struct BlumBlumShubGenerator {
+    modulus: BigInt,
+    bits: SequentialBitCache,
+    number_theory_service: NumberTheoryService,
+}
+
+impl BlumBlumShubGenerator {
+    /// Erstellt einen neuen Blum-Blum-Shub-Generator.
+    /// Die Blum-Primzahlen und der Startwert werden mit dem PseudoRandomNumberGenerator erzeugt.
+    ///
+    /// # Argumente
+    /// * `random_seed` - Seed für die Erzeugung der Primzahlen und des Startwerts.
+    /// * `modulus_width` - Die Bitbreite des Modulus n.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `number_theory_service` - Der zu verwendende NumberTheoryService.
+    ///
+    /// # Rückgabe
+    /// * Der Generator oder ein Fehler, falls der Modulus zu klein gewählt wurde.
+    pub fn new(
+        random_seed: u32,
+        modulus_width: u32,
+        miller_rabin_iterations: u32,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<Self> {
+        ensure!(
+            modulus_width >= 16,
+            "Der Modulus muss mindestens 16 Bit breit sein!"
+        );
+
+        let prng = PseudoRandomNumberGenerator::new(random_seed, number_theory_service);
+        let counter = RelaxedCounter::new(1);
+
+        // Das Produkt zweier Primzahlen mit zusammen modulus_width Bits kann ein Bit kürzer sein,
+        // dann werden beide neu gezogen.
+        let prime_width = modulus_width / 2;
+        let modulus = loop {
+            let p =
+                Self::generate_blum_prime(&prng, prime_width, miller_rabin_iterations, &counter);
+            let q = Self::generate_blum_prime(
+                &prng,
+                modulus_width - prime_width,
+                miller_rabin_iterations,
+                &counter,
+            );
+            let modulus = &p * &q;
+            if p != q && modulus.bits() == u64::from(modulus_width) {
+                break modulus;
+            }
+        };
+        debug!("Blum-Blum-Shub-Modulus ist {}", modulus);
+
+        // Der Startwert s muss teilerfremd zu n sein, x_0 ist dann s^2 mod n.
+        let upper_bound = &modulus - BigInt::one();
+        let mut s = prng.take(&2.into(), &upper_bound, &counter);
+        while !s.gcd(&modulus).is_one() {
+            s = prng.take(&2.into(), &upper_bound, &counter);
+        }
+        let initial_state = (&s * &s) % &modulus;
+
+        Ok(Self {
+            modulus,
+            bits: SequentialBitCache::new(initial_state),
+            number_theory_service,
+        })
+    }
+
+    /// Der Modulus n = p * q des Generators.
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Generiert eine Blum-Primzahl, also eine Primzahl p mit p ≡ 3 mod 4.
+    fn generate_blum_prime(
+        prng: &PseudoRandomNumberGenerator,
+        size: u32,
+        miller_rabin_iterations: u32,
+        counter: &RelaxedCounter,
+    ) -> BigInt {
+        loop {
+            let prime = prng.generate_prime(size, miller_rabin_iterations, counter);
+            if prime.mod_floor(&4.into()) == 3.into() {
+                return prime;
+            }
+        }
+    }
+}
+
+impl RandomNumberGenerator for BlumBlumShubGenerator {
+    /// Setzt die Zufallszahl aus den Bits der Folge zusammen. Der Zähler wird pro Bit
+    /// inkrementiert.
+    fn take(&self, a: &BigInt, b: &BigInt, n_counter: &RelaxedCounter) -> BigInt {
+        take_from_bits(a, b, n_counter, |index| {
+            self.bits.bit_at(index, |state| {
+                let next_state = (state * state) % &self.modulus;
+                let bit = next_state.is_odd();
+                (next_state, bit)
+            })
+        })
+    }
+
+    fn number_theory_service(&self) -> NumberTheoryService {
+        self.number_theory_service
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::{
+        AsymmetricDecryptor, AsymmetricEncryptor, KeyGenerator,
+    };
+    use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn get_generator(random_seed: u32) -> BlumBlumShubGenerator {
+        BlumBlumShubGenerator::new(random_seed, 64, 20, NumberTheoryService::new(Fast)).unwrap()
+    }
+
+    #[test]
+    fn test_modulus_is_product_of_blum_primes() {
+        let generator = get_generator(13);
+        // Das Produkt zweier Blum-Primzahlen ist kongruent 1 mod 4.
+        assert_eq!(generator.modulus().mod_floor(&4.into()), BigInt::one());
+        assert_eq!(generator.modulus().bits(), 64);
+    }
+
+    #[test]
+    fn test_modulus_has_exact_width() {
+        for random_seed in 0..20 {
+            let generator =
+                BlumBlumShubGenerator::new(random_seed, 33, 20, NumberTheoryService::new(Fast))
+                    .unwrap();
+            assert_eq!(generator.modulus().bits(), 33);
+        }
+    }
+
+    #[test]
+    fn test_take_is_in_range_and_deterministic() {
+        let first = get_generator(17);
+        let second = get_generator(17);
+        let a: BigInt = 500.into();
+        let b: BigInt = 6000.into();
+        let first_counter = RelaxedCounter::new(1);
+        let second_counter = RelaxedCounter::new(1);
+
+        for _ in 0..100 {
+            let random = first.take(&a, &b, &first_counter);
+            assert!(random >= a && random <= b);
+            assert_eq!(random, second.take(&a, &b, &second_counter));
+        }
+    }
+
+    #[test]
+    fn test_too_small_modulus() {
+        assert!(BlumBlumShubGenerator::new(1, 8, 20, NumberTheoryService::new(Fast)).is_err());
+    }
+
+    #[test]
+    fn test_rsa_key_generation() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 20,
+            random_seed: 0,
+            number_theory_service: service,
+        };
+        let generator = get_generator(23);
+        let key_pair = RsaScheme::generate_keypair_with_generator(&config, &generator);
+
+        let message = BigInt::from(123456789);
+        let ciphertext = RsaScheme::encrypt(&key_pair.public_key, &message, service);
+        let plaintext = RsaScheme::decrypt(&key_pair.private_key, &ciphertext, service);
+        assert_eq!(message, plaintext);
+    }
+}
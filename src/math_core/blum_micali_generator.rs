@@ -0,0 +1,162 @@
+use atomic_counter::RelaxedCounter;
+use bigdecimal::num_bigint::BigInt;
+use log::debug;
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::{
+    take_from_bits, RandomNumberGenerator, SequentialBitCache,
+};
+use crate::math_core::traits::divisible::Divisible;
+use crate::math_core::traits::increment::Increment;
+
+/// Blum-Micali-Generator.
+/// Die Folge entsteht durch diskrete Exponentiation x_{i+1} = g^{x_i} mod p mit einer
+/// Primitivwurzel g der sicheren Primzahl p. Ausgegeben wird das höchstwertige Bit als
+/// Hard-Core-Prädikat: 1, falls x_i < (p - 1) / 2, sonst 0.
+/// Die Vorhersage des nächsten Bits ist so schwer wie die Berechnung diskreter Logarithmen.
+pub struct BlumMicaliGenerator {
+    prime: BigInt,
+    primitive_root: BigInt,
+    half_order: BigInt,
+    bits: SequentialBitCache,
+    number_theory_service: NumberTheoryService,
+}
+
+impl BlumMicaliGenerator {
+    /// Erstellt einen neuen Blum-Micali-Generator.
+    /// Primzahl, Primitivwurzel und Startwert werden mit dem PseudoRandomNumberGenerator erzeugt.
+    ///
+    /// # Argumente
+    /// * `random_seed` - Seed für die Erzeugung der Parameter und des Startwerts.
+    /// * `prime_width` - Die Bitbreite der sicheren Primzahl p.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `number_theory_service` - Der zu verwendende NumberTheoryService.
+    pub fn new(
+        random_seed: u32,
+        prime_width: u32,
+        miller_rabin_iterations: u32,
+        number_theory_service: NumberTheoryService,
+    ) -> Self {
+        let prng = PseudoRandomNumberGenerator::new(random_seed, number_theory_service);
+        let counter = RelaxedCounter::new(1);
+
+        let (prime, primitive_root) = prng.generate_secure_prime_with_primitive_root(
+            prime_width,
+            miller_rabin_iterations,
+            &counter,
+        );
+        debug!(
+            "Blum-Micali-Parameter sind p = {} und g = {}",
+            prime, primitive_root
+        );
+
+        let initial_state = prng.take(&1.into(), &prime.decrement(), &counter);
+        let half_order = prime.decrement().half();
+
+        Self {
+            prime,
+            primitive_root,
+            half_order,
+            bits: SequentialBitCache::new(initial_state),
+            number_theory_service,
+        }
+    }
+
+    /// Die sichere Primzahl p des Generators.
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    /// Die Primitivwurzel g modulo p.
+    pub fn primitive_root(&self) -> &BigInt {
+        &self.primitive_root
+    }
+}
+
+impl RandomNumberGenerator for BlumMicaliGenerator {
+    /// Setzt die Zufallszahl aus den Bits der Folge zusammen. Der Zähler wird pro Bit
+    /// inkrementiert.
+    fn take(&self, a: &BigInt, b: &BigInt, n_counter: &RelaxedCounter) -> BigInt {
+        take_from_bits(a, b, n_counter, |index| {
+            self.bits.bit_at(index, |state| {
+                let bit = state < &self.half_order;
+                let next_state = self.number_theory_service.fast_exponentiation(
+                    &self.primitive_root,
+                    state,
+                    &self.prime,
+                );
+                (next_state, bit)
+            })
+        })
+    }
+
+    fn number_theory_service(&self) -> NumberTheoryService {
+        self.number_theory_service
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::asymmetric_encryption_types::{
+        AsymmetricDecryptor, AsymmetricEncryptor, KeyGenerator,
+    };
+    use crate::encryption::core::rsa::rsa_scheme::{RsaKeyGenConfig, RsaScheme};
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn get_generator(random_seed: u32, service: NumberTheoryService) -> BlumMicaliGenerator {
+        BlumMicaliGenerator::new(random_seed, 32, 20, service)
+    }
+
+    #[test]
+    fn test_parameters() {
+        let service = NumberTheoryService::new(Fast);
+        let generator = get_generator(13, service);
+        let prime = generator.prime();
+        let source_prime = prime.decrement().half();
+        // g ist Primitivwurzel der sicheren Primzahl p, wenn g^((p-1)/2) ≡ -1 mod p.
+        assert_eq!(
+            service.fast_exponentiation(generator.primitive_root(), &source_prime, prime),
+            prime.decrement()
+        );
+    }
+
+    #[test]
+    fn test_take_is_in_range_and_independent_of_service() {
+        let fast = get_generator(17, NumberTheoryService::new(Fast));
+        let slow = get_generator(17, NumberTheoryService::new(Slow));
+        let a: BigInt = 500.into();
+        let b: BigInt = 6000.into();
+        let fast_counter = RelaxedCounter::new(1);
+        let slow_counter = RelaxedCounter::new(1);
+
+        for _ in 0..50 {
+            let random = fast.take(&a, &b, &fast_counter);
+            assert!(random >= a && random <= b);
+            assert_eq!(random, slow.take(&a, &b, &slow_counter));
+        }
+    }
+
+    #[test]
+    fn test_rsa_key_generation() {
+        let service = NumberTheoryService::new(Fast);
+        let config = RsaKeyGenConfig {
+            key_size: 256,
+            miller_rabin_iterations: 20,
+            random_seed: 0,
+            number_theory_service: service,
+        };
+        let generator = get_generator(23, service);
+        let key_pair = RsaScheme::generate_keypair_with_generator(&config, &generator);
+
+        let message = BigInt::from(123456789);
+        let ciphertext = RsaScheme::encrypt(&key_pair.public_key, &message, service);
+        let plaintext = RsaScheme::decrypt(&key_pair.private_key, &ciphertext, service);
+        assert_eq!(message, plaintext);
+    }
+}
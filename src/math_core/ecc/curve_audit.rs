@@ -0,0 +1,377 @@
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, ToPrimitive};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::point_counting::PointCountingMethod;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::factorization::{is_prime, Factorization};
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::shared::errors::EllipticCurveError::{
+    PointNotInSubgroupError, PointNotOnCurveError, SubgroupOrderMismatchError,
+};
+
+/// Die Sicherheit in Bit, die die Rho-Methode von Pollard auf der Untergruppe und auf dem Twist
+/// mindestens erfordern muss.
+pub const MINIMUM_RHO_SECURITY_BITS: f64 = 100.0;
+
+/// Bis zu diesem Grad wird nach dem Einbettungsgrad gesucht. Größere Grade machen den
+/// MOV-Angriff für kryptografische Größen ohnehin undurchführbar.
+const MAXIMUM_EMBEDDING_DEGREE: u64 = 1000;
+
+/// Die Anzahl der Schritte der Rho-Methode bei der Zerlegung der Ordnung des Twists.
+const TWIST_FACTORIZATION_STEPS: u64 = 1 << 12;
+
+/// Eine Schwäche, die eine Kurve für kryptografische Zwecke ungeeignet macht.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CurveWeakness {
+    /// Die Ordnung der Untergruppe ist keine Primzahl, sodass Pohlig-Hellman anwendbar ist.
+    CompositeSubgroupOrder,
+    /// Die Rho-Methode benötigt auf der Untergruppe nur etwa 2^bits Schritte.
+    SmallSubgroup(f64),
+    /// Der Einbettungsgrad k ist so klein, dass die Weil- oder Tate-Paarung den diskreten
+    /// Logarithmus in die multiplikative Gruppe von F_(p^k) überführt.
+    SmallEmbeddingDegree(u64),
+    /// Die Untergruppe hat die Ordnung p, der Angriff von Smart löst den diskreten Logarithmus
+    /// in linearer Zeit.
+    Anomalous,
+    /// Die Spur ist durch p teilbar. Solche Kurven haben einen Einbettungsgrad von höchstens 6.
+    Supersingular,
+    /// Der größte Primfaktor der Ordnung des Twists ist so klein, dass ein Angriff mit Punkten
+    /// auf dem Twist bei einer Multiplikation ohne Prüfung der y-Koordinate etwa 2^bits Schritte
+    /// benötigt.
+    WeakTwist(f64),
+    /// Die Ordnung des Twists konnte nicht vollständig zerlegt werden, seine Sicherheit ist
+    /// daher nicht belegt.
+    UnknownTwistSecurity,
+}
+
+impl Display for CurveWeakness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurveWeakness::CompositeSubgroupOrder => write!(f, "subgroup order is not prime"),
+            CurveWeakness::SmallSubgroup(bits) => {
+                write!(f, "rho security of {:.1} bits is too small", bits)
+            }
+            CurveWeakness::SmallEmbeddingDegree(degree) => {
+                write!(f, "embedding degree {} allows the MOV attack", degree)
+            }
+            CurveWeakness::Anomalous => write!(f, "curve is anomalous"),
+            CurveWeakness::Supersingular => write!(f, "curve is supersingular"),
+            CurveWeakness::WeakTwist(bits) => {
+                write!(f, "twist rho security of {:.1} bits is too small", bits)
+            }
+            CurveWeakness::UnknownTwistSecurity => {
+                write!(f, "twist order could not be factored")
+            }
+        }
+    }
+}
+
+///
+/// Bericht über die Sicherheit einer Kurve mit Untergruppe. `SecureFiniteFieldEllipticCurve::new`
+/// stellt nur sicher, dass q = N / 8 prim ist. Der Bericht prüft zusätzlich die Kriterien von
+/// SafeCurves zu Einbettungsgrad, anomalen und supersingulären Kurven sowie zum Twist.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveAudit {
+    /// Die Anzahl N der Punkte der Kurve
+    pub curve_order: BigInt,
+    /// Die Spur t = p + 1 - N des Frobenius
+    pub trace: BigInt,
+    /// Der Kofaktor h = N / q
+    pub cofactor: BigInt,
+    /// Ob die Ordnung q der Untergruppe prim ist
+    pub subgroup_order_is_prime: bool,
+    /// Der kleinste Grad k mit q | p^k - 1, oder `None`, falls k größer als 1000 ist
+    pub embedding_degree: Option<u64>,
+    /// Ob N = p oder q = p gilt
+    pub is_anomalous: bool,
+    /// Ob p die Spur teilt
+    pub is_supersingular: bool,
+    /// log2 der erwarteten Schritte √(π · q / 4) der Rho-Methode auf der Untergruppe
+    pub rho_security_bits: f64,
+    /// Die Anzahl 2p + 2 - N der Punkte des quadratischen Twists
+    pub twist_order: BigInt,
+    /// Der größte Primfaktor der Ordnung des Twists, oder `None`, falls sie nicht vollständig
+    /// zerlegt werden konnte
+    pub twist_largest_prime_factor: Option<BigInt>,
+    /// Die Sicherheit der Rho-Methode im größten Primfaktor des Twists, oder `None`, falls
+    /// dieser unbekannt ist
+    pub twist_rho_security_bits: Option<f64>,
+}
+
+impl CurveAudit {
+    /// Prüft die Kurve und ihre Untergruppe.
+    ///
+    /// Ist q prim und größer als 4√p, liegt genau ein Vielfaches von q im Hasse-Intervall, das
+    /// dann die Ordnung der Kurve ist. Andernfalls werden die Punkte mit dem für p passenden
+    /// Verfahren gezählt.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve mit Generator und Ordnung der Untergruppe.
+    /// * `number_theory_service` - Der Service für Exponentiation und Faktorisierung.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der Generator nicht auf der Kurve
+    ///   liegt.
+    /// * `EllipticCurveError::PointNotInSubgroupError` - Falls der Generator der Punkt im
+    ///   Unendlichen ist oder q · G nicht der Punkt im Unendlichen ist.
+    /// * `EllipticCurveError::SubgroupOrderMismatchError` - Falls q die Ordnung der Kurve nicht
+    ///   teilt.
+    /// * Fehler der Punktzählung, falls p für das gewählte Verfahren zu groß ist.
+    pub fn new(
+        curve: &SecureFiniteFieldEllipticCurve,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<Self> {
+        let prime = &curve.curve.prime;
+        let q = &curve.order_of_subgroup;
+        let generator = &curve.generator;
+        ensure!(
+            curve.curve.has_point(generator),
+            PointNotOnCurveError(generator.clone(), curve.curve.clone())
+        );
+        ensure!(
+            !generator.is_infinite
+                && generator
+                    .multiply_with(q, &curve.curve, ScalarKind::Public)?
+                    .is_infinite,
+            PointNotInSubgroupError(generator.clone())
+        );
+
+        let subgroup_order_is_prime = is_prime(q, number_theory_service);
+        let p_plus_one: BigInt = prime + 1;
+        let curve_order = match subgroup_order_is_prime && q * q > prime * 16 {
+            true => {
+                // Das nächste Vielfache von q an p + 1, gerundet als (2(p + 1) + q) / 2q.
+                let cofactor = (&p_plus_one * 2 + q) / (q * 2);
+                cofactor * q
+            }
+            false => PointCountingMethod::for_prime(prime)?
+                .count_points(&curve.curve, number_theory_service)?,
+        };
+        ensure!(
+            (&curve_order % q).is_zero(),
+            SubgroupOrderMismatchError(q.to_string(), curve_order.to_string())
+        );
+
+        let trace = &p_plus_one - &curve_order;
+        let twist_order = &p_plus_one * 2 - &curve_order;
+        let twist_largest_prime_factor =
+            Self::largest_prime_factor(&twist_order, number_theory_service)?;
+
+        Ok(CurveAudit {
+            cofactor: &curve_order / q,
+            subgroup_order_is_prime,
+            embedding_degree: Self::embedding_degree(prime, q),
+            is_anomalous: &curve_order == prime || q == prime,
+            is_supersingular: trace.rem_euclid(prime).is_zero(),
+            rho_security_bits: rho_security_bits(log2(q)),
+            twist_rho_security_bits: twist_largest_prime_factor
+                .as_ref()
+                .map(|factor| rho_security_bits(log2(factor))),
+            twist_largest_prime_factor,
+            curve_order,
+            trace,
+            twist_order,
+        })
+    }
+
+    /// Alle gefundenen Schwächen. Der Einbettungsgrad gilt nach SafeCurves als zu klein, wenn
+    /// k < (q - 1) / 100 ist.
+    pub fn weaknesses(&self) -> Vec<CurveWeakness> {
+        let subgroup_order = &self.curve_order / &self.cofactor;
+        let mut weaknesses = Vec::new();
+        if !self.subgroup_order_is_prime {
+            weaknesses.push(CurveWeakness::CompositeSubgroupOrder);
+        }
+        if self.rho_security_bits < MINIMUM_RHO_SECURITY_BITS {
+            weaknesses.push(CurveWeakness::SmallSubgroup(self.rho_security_bits));
+        }
+        if let Some(degree) = self.embedding_degree {
+            if BigInt::from(degree) * 100 < subgroup_order - 1 {
+                weaknesses.push(CurveWeakness::SmallEmbeddingDegree(degree));
+            }
+        }
+        if self.is_anomalous {
+            weaknesses.push(CurveWeakness::Anomalous);
+        }
+        if self.is_supersingular {
+            weaknesses.push(CurveWeakness::Supersingular);
+        }
+        match self.twist_rho_security_bits {
+            Some(bits) if bits < MINIMUM_RHO_SECURITY_BITS => {
+                weaknesses.push(CurveWeakness::WeakTwist(bits))
+            }
+            Some(_) => {}
+            None => weaknesses.push(CurveWeakness::UnknownTwistSecurity),
+        }
+        weaknesses
+    }
+
+    /// Ob die Kurve keine der geprüften Schwächen hat.
+    pub fn is_secure(&self) -> bool {
+        self.weaknesses().is_empty()
+    }
+
+    /// Der kleinste Grad k <= 1000 mit p^k ≡ 1 mod q. Für q = p gibt es keinen solchen Grad.
+    fn embedding_degree(prime: &BigInt, q: &BigInt) -> Option<u64> {
+        let base = prime.rem_euclid(q);
+        if base.is_zero() {
+            return None;
+        }
+        let mut power = base.clone();
+        for degree in 1..=MAXIMUM_EMBEDDING_DEGREE {
+            if power.is_one() {
+                return Some(degree);
+            }
+            power = (power * &base).rem_euclid(q);
+        }
+        None
+    }
+
+    /// Bestimmt den größten Primfaktor von `n`. Bleibt nach einer beschränkten Anzahl von
+    /// Schritten der Rho-Methode ein zusammengesetzter Rest, ist er unbekannt.
+    fn largest_prime_factor(
+        n: &BigInt,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<Option<BigInt>> {
+        let (factorization, unfactored) = Factorization::factorize_partially(
+            n,
+            TWIST_FACTORIZATION_STEPS,
+            number_theory_service,
+        )?;
+        match unfactored.is_one() {
+            true => Ok(factorization.primes().max().cloned()),
+            false => Ok(None),
+        }
+    }
+}
+
+/// Der Logarithmus zur Basis 2 einer positiven Zahl. Große Zahlen werden vorher auf 64 Bit
+/// verkürzt, damit die Umwandlung in f64 nicht überläuft.
+fn log2(n: &BigInt) -> f64 {
+    let shift = n.bits().saturating_sub(64);
+    (n >> shift).to_f64().unwrap_or(0.0).log2() + shift as f64
+}
+
+/// Die Sicherheit in Bit für eine Gruppe der Primordnung q mit log2 q = `order_log2`, also
+/// log2 √(π · q / 4).
+fn rho_security_bits(order_log2: f64) -> f64 {
+    ((order_log2 + (PI / 4.0).log2()) / 2.0).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+    use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    fn secure_curve(
+        a: i64,
+        b: i64,
+        prime: i64,
+        order_of_subgroup: i64,
+        generator: (i64, i64),
+    ) -> SecureFiniteFieldEllipticCurve {
+        SecureFiniteFieldEllipticCurve {
+            curve: FiniteFieldEllipticCurve::new(a.into(), b.into(), prime.into()).unwrap(),
+            order_of_subgroup: order_of_subgroup.into(),
+            generator: FiniteFieldEllipticCurvePoint::new(generator.0.into(), generator.1.into()),
+        }
+    }
+
+    #[test]
+    fn test_named_curves() {
+        // Die Sicherheit der Twists nach SafeCurves: P-256 120.3 und secp256k1 109.5 Bit.
+        let service = NumberTheoryService::new(Fast);
+        for (curve, twist_bits) in [(NamedCurve::P256, 120.28), (NamedCurve::Secp256k1, 109.46)] {
+            let parameters = curve.domain_parameters();
+            let audit = CurveAudit::new(&parameters, service).unwrap();
+            assert_eq!(audit.curve_order, parameters.order_of_subgroup);
+            assert!(audit.cofactor.is_one());
+            assert_eq!(audit.embedding_degree, None);
+            assert!(!audit.is_anomalous && !audit.is_supersingular);
+            assert!((audit.rho_security_bits - 127.8).abs() < 0.1);
+            assert!((audit.twist_rho_security_bits.unwrap() - twist_bits).abs() < 0.01);
+            assert!(audit.is_secure(), "{:?}", audit.weaknesses());
+        }
+
+        // Der Twist von brainpoolP256r1 hat nur 44 Bit Sicherheit. Nach den kleinen Faktoren
+        // bleibt ein Produkt aus vier Primfaktoren von 33 bis 89 Bit, das die Rho-Methode nicht
+        // in der vorgegebenen Zahl an Schritten zerlegt.
+        let audit =
+            CurveAudit::new(&NamedCurve::BrainpoolP256r1.domain_parameters(), service).unwrap();
+        assert!(audit.cofactor.is_one());
+        assert_eq!(audit.twist_largest_prime_factor, None);
+        assert_eq!(
+            audit.weaknesses(),
+            vec![CurveWeakness::UnknownTwistSecurity]
+        );
+    }
+
+    #[test]
+    fn test_generated_curve() {
+        let service = NumberTheoryService::new(Fast);
+        let curve = SecureFiniteFieldEllipticCurve::new(3, 32, 40).unwrap();
+        let audit = CurveAudit::new(&curve, service).unwrap();
+        assert_eq!(
+            audit.curve_order,
+            PointCountingMethod::BabyStepGiantStep
+                .count_points(&curve.curve, service)
+                .unwrap()
+        );
+        assert_eq!(audit.cofactor, BigInt::from(8));
+        assert_eq!(
+            &audit.twist_order + &audit.curve_order,
+            (&curve.curve.prime + 1) * 2
+        );
+        assert!(audit.subgroup_order_is_prime);
+        assert!(!audit.is_supersingular && !audit.is_anomalous);
+        assert!(audit.twist_largest_prime_factor.is_some());
+        assert!(!audit.is_secure());
+        assert!(audit
+            .weaknesses()
+            .contains(&CurveWeakness::SmallSubgroup(audit.rho_security_bits)));
+    }
+
+    #[test]
+    fn test_anomalous_curve() {
+        // y^2 = x^3 + 10x + 25 (mod 1009) hat genau 1009 Punkte.
+        let curve = secure_curve(10, 25, 1009, 1009, (0, 5));
+        let audit = CurveAudit::new(&curve, NumberTheoryService::new(Fast)).unwrap();
+        assert_eq!(audit.curve_order, BigInt::from(1009));
+        assert!(audit.trace.is_one());
+        assert!(audit.is_anomalous);
+        assert_eq!(audit.embedding_degree, None);
+        assert!(audit.weaknesses().contains(&CurveWeakness::Anomalous));
+    }
+
+    #[test]
+    fn test_supersingular_curve() {
+        // y^2 = x^3 + x (mod 1531) hat 1532 = 4 · 383 Punkte, (125, 1496) hat die Ordnung 383.
+        let curve = secure_curve(1, 0, 1531, 383, (125, 1496));
+        let audit = CurveAudit::new(&curve, NumberTheoryService::new(Fast)).unwrap();
+        assert_eq!(audit.curve_order, BigInt::from(1532));
+        assert!(audit.trace.is_zero());
+        assert!(audit.is_supersingular);
+        assert_eq!(audit.embedding_degree, Some(2));
+        let weaknesses = audit.weaknesses();
+        assert!(weaknesses.contains(&CurveWeakness::Supersingular));
+        assert!(weaknesses.contains(&CurveWeakness::SmallEmbeddingDegree(2)));
+    }
+
+    #[test]
+    fn test_invalid_subgroup() {
+        let service = NumberTheoryService::new(Fast);
+        // (125, 1496) hat die Ordnung 383, nicht 4.
+        assert!(CurveAudit::new(&secure_curve(1, 0, 1531, 4, (125, 1496)), service).is_err());
+        assert!(CurveAudit::new(&secure_curve(1, 0, 1531, 383, (125, 1)), service).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::Zero;
+
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::number_theory::factorization::is_prime;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EllipticCurveError::{InvalidFieldPrimeError, SingularCurveError};
+
+///
+/// Repräsentiert eine elliptische Kurve in kurzer Weierstraß-Form y^2 = x^3 + a · x + b über
+/// dem endlichen Körper F_p mit einer Primzahl p > 3.
+///
+/// Die Koeffizienten werden nicht modulo p reduziert, damit z.B. a = -3 auch so angezeigt wird.
+/// Alle Berechnungen finden aber modulo p statt.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct FiniteFieldEllipticCurve {
+    /// Der Koeffizient a der elliptischen Kurve
+    pub a: BigInt,
+    /// Der Koeffizient b der elliptischen Kurve
+    pub b: BigInt,
+    /// Der Modulus p, über dessen Restklassenkörper die Kurve definiert ist
+    pub prime: BigInt,
+}
+
+impl Display for FiniteFieldEllipticCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "y^2 = x^3 + {} * x + {} (mod {})",
+            self.a, self.b, self.prime
+        )
+    }
+}
+
+impl FiniteFieldEllipticCurve {
+    /// Erstellt eine neue elliptische Kurve y^2 = x^3 + a · x + b (mod p).
+    ///
+    /// # Argumente
+    /// * `a` - Der Koeffizient a.
+    /// * `b` - Der Koeffizient b.
+    /// * `prime` - Der Modulus p.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidFieldPrimeError` - Falls p keine Primzahl größer 3 ist.
+    /// * `EllipticCurveError::SingularCurveError` - Falls die Diskriminante modulo p
+    ///   verschwindet, die Kurve also einen Knoten oder eine Spitze hat.
+    pub fn new(a: BigInt, b: BigInt, prime: BigInt) -> Result<Self> {
+        ensure!(
+            prime > BigInt::from(3) && is_prime(&prime, NumberTheoryService::new(Fast)),
+            InvalidFieldPrimeError(prime.to_string())
+        );
+        let curve = FiniteFieldEllipticCurve { a, b, prime };
+        ensure!(!curve.is_singular(), SingularCurveError(curve.clone()));
+        Ok(curve)
+    }
+
+    /// Erstellt die Kurve y^2 = x^3 - n^2 · x (mod p) zur Kongruenzzahl n. Sie hat b = 0 und ist
+    /// genau dann nicht singulär, wenn p kein Teiler von n ist.
+    pub fn congruent_number_curve(n: &BigInt, prime: &BigInt) -> Result<Self> {
+        Self::new(-(n * n), BigInt::zero(), prime.clone())
+    }
+
+    /// Die Diskriminante ohne den konstanten Faktor -16, also 4a^3 + 27b^2 (mod p).
+    pub fn discriminant(&self) -> BigInt {
+        (BigInt::from(4) * self.a.pow(3) + BigInt::from(27) * self.b.pow(2)).rem_euclid(&self.prime)
+    }
+
+    /// Eine Kurve ist singulär, wenn die Diskriminante modulo p verschwindet.
+    pub fn is_singular(&self) -> bool {
+        self.discriminant().is_zero()
+    }
+
+    /// Die Anzahl der Bytes, die ein Körperelement in der SEC1-Kodierung belegt.
+    pub fn field_byte_length(&self) -> usize {
+        self.prime.bits().div_ceil(8) as usize
+    }
+
+    /// Berechnet die rechte Seite der Kurvengleichung x^3 + a · x + b (mod p).
+    pub fn right_hand_side(&self, x: &BigInt) -> BigInt {
+        (x.pow(3) + &self.a * x + &self.b).rem_euclid(&self.prime)
+    }
+
+    /// Der quadratische Twist y^2 = x^3 + a · d^2 · x + b · d^3 mit dem kleinsten quadratischen
+    /// Nichtrest d. Er ist über F_p nicht isomorph zur Kurve, und für die Ordnungen gilt
+    /// #E + #E' = 2p + 2.
+    pub fn quadratic_twist(&self, number_theory_service: NumberTheoryService) -> Self {
+        let p_minus_one: BigInt = &self.prime - 1;
+        let exponent = &p_minus_one >> 1;
+        let mut d = BigInt::from(2);
+        while number_theory_service.fast_exponentiation(&d, &exponent, &self.prime) != p_minus_one {
+            d += 1;
+        }
+        let d_squared = (&d * &d).rem_euclid(&self.prime);
+        FiniteFieldEllipticCurve {
+            a: (&self.a * &d_squared).rem_euclid(&self.prime),
+            b: (&self.b * d_squared * d).rem_euclid(&self.prime),
+            prime: self.prime.clone(),
+        }
+    }
+
+    /// Überprüft, ob ein Punkt auf der elliptischen Kurve liegt. Der Punkt im Unendlichen liegt
+    /// auf jeder Kurve.
+    pub fn has_point(&self, point: &FiniteFieldEllipticCurvePoint) -> bool {
+        if point.is_infinite {
+            return true;
+        }
+        // y^2 = x^3 + ax + b (mod p) ist äquivalent zu (x^3 + ax + b - y^2) % p == 0
+        (self.right_hand_side(&point.x) - point.y.pow(2))
+            .rem_euclid(&self.prime)
+            .is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_curve() -> FiniteFieldEllipticCurve {
+        // Lehrbuchbeispiel y^2 = x^3 + 2x + 2 (mod 17) mit 19 Punkten
+        FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_singular_curves() {
+        // 4 * (-3)^3 + 27 * 2^2 = 0
+        let result = FiniteFieldEllipticCurve::new((-3).into(), 2.into(), 17.into());
+        assert!(result.is_err());
+        // 4 * 1 + 27 * 1 = 31 ≡ 0 (mod 31)
+        let result = FiniteFieldEllipticCurve::new(1.into(), 1.into(), 31.into());
+        assert!(result.is_err());
+        assert!(FiniteFieldEllipticCurve::new(1.into(), 1.into(), 29.into()).is_ok());
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_primes() {
+        for prime in [2, 3, 15, -17] {
+            assert!(FiniteFieldEllipticCurve::new(2.into(), 2.into(), prime.into()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_congruent_number_curve() {
+        let curve =
+            FiniteFieldEllipticCurve::congruent_number_curve(&5.into(), &13.into()).unwrap();
+        assert_eq!(curve.a, BigInt::from(-25));
+        assert_eq!(curve.b, BigInt::zero());
+        assert!(FiniteFieldEllipticCurve::congruent_number_curve(&13.into(), &13.into()).is_err());
+    }
+
+    #[test]
+    fn test_quadratic_twist() {
+        // 3 ist der kleinste Nichtrest modulo 17, der Twist hat 2 · 18 - 19 = 17 Punkte.
+        let twist = get_curve().quadratic_twist(NumberTheoryService::new(Fast));
+        assert_eq!(twist.a, BigInt::from(1));
+        assert_eq!(twist.b, BigInt::from(3));
+        let points = (0..17)
+            .flat_map(|x| (0..17).map(move |y| (x, y)))
+            .filter(|(x, y)| {
+                twist.has_point(&FiniteFieldEllipticCurvePoint::new(
+                    BigInt::from(*x),
+                    BigInt::from(*y),
+                ))
+            })
+            .count();
+        assert_eq!(points + 1, 17);
+    }
+
+    #[test]
+    fn test_has_point() {
+        let curve = get_curve();
+        assert!(curve.has_point(&FiniteFieldEllipticCurvePoint::new(5.into(), 1.into())));
+        assert!(curve.has_point(&FiniteFieldEllipticCurvePoint::new(0.into(), 6.into())));
+        assert!(curve.has_point(&FiniteFieldEllipticCurvePoint::infinite()));
+        assert!(!curve.has_point(&FiniteFieldEllipticCurvePoint::new(5.into(), 2.into())));
+        assert_eq!(curve.to_string(), "y^2 = x^3 + 2 * x + 2 (mod 17)");
+    }
+}
@@ -1,22 +1,26 @@
 use crate::api::endpoints::mv::EcPointBean;
-use anyhow::{ensure, Context, Result};
+use anyhow::{bail, ensure, Context, Result};
 use bigdecimal::num_bigint::BigInt;
-use bigdecimal::num_traits::Euclid;
-use bigdecimal::{One, Zero};
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::Zero;
 use std::fmt::Display;
 use std::ops::Add;
 
-use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::{i2osp, os2ip};
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::jacobian_point::JacobianPoint;
+use crate::math_core::ecc::scalar_multiplication::{montgomery_ladder, multiply_wnaf, ScalarKind};
+use crate::math_core::number_theory::modular_square_root::modular_square_root;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::traits::parity::Parity;
-use crate::shared::errors::EllipticCurveError::PointNotOnCurveError;
+use crate::shared::errors::EllipticCurveError::{InvalidPointEncodingError, PointNotOnCurveError};
 
 /// Repräsentiert einen Punkt auf einer elliptischen Kurve.
 /// Die Koordinaten des Punktes sind Elemente eines endlichen Körpers.
-#[derive(Clone, PartialEq, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub struct FiniteFieldEllipticCurvePoint {
     // Die Koordinaten des Punktes
     pub x: BigInt,
@@ -64,11 +68,11 @@ impl FiniteFieldEllipticCurvePoint {
 
     /// Addiert zwei Punkte auf einer elliptischen Kurve.
     /// Die Punkte müssen auf der gleichen elliptischen Kurve liegen.
-    pub fn add(&self, other: &Self, curve: &SecureFiniteFieldEllipticCurve) -> Result<Self> {
+    pub fn add(&self, other: &Self, curve: &FiniteFieldEllipticCurve) -> Result<Self> {
         // Liegen die Punkte nicht auf der gleichen Kurve, ist das Ergebnis undefiniert.
         ensure!(
             curve.has_point(self),
-            PointNotOnCurveError(other.clone(), curve.clone())
+            PointNotOnCurveError(self.clone(), curve.clone())
         );
         ensure!(
             curve.has_point(other),
@@ -116,7 +120,7 @@ impl FiniteFieldEllipticCurvePoint {
     }
 
     /// Verdoppelt einen Punkt auf einer elliptischen Kurve.
-    pub fn double(&self, curve: &SecureFiniteFieldEllipticCurve) -> Self {
+    pub fn double(&self, curve: &FiniteFieldEllipticCurve) -> Self {
         if self.is_infinite {
             return self.clone();
         }
@@ -141,44 +145,180 @@ impl FiniteFieldEllipticCurvePoint {
         FiniteFieldEllipticCurvePoint::new(x_sum, y_sum).normalize(p)
     }
 
-    /// Multipliziert einen Punkt mit einem Skalar.
-    /// Dabei wird die optimierte Berechnung in Form des Double-and-add Algorithmus verwendet.
-    /// Bei Multiplikation mit 0 wird der Punkt im Ursprung mit Bezug auf die ursprüngliche Kurve
-    /// zurückgegeben.
-    pub fn multiply(
+    /// Multipliziert einen Punkt mit einem geheimen Skalar, siehe `multiply_with`.
+    pub fn multiply(&self, scalar: &BigInt, curve: &FiniteFieldEllipticCurve) -> Result<Self> {
+        self.multiply_with(scalar, curve, ScalarKind::Secret)
+    }
+
+    /// Multipliziert einen Punkt mit einem Skalar. Gerechnet wird in jacobischen Koordinaten,
+    /// sodass nur am Ende eine modulare Inversion anfällt. Geheime Skalare werden mit der
+    /// Montgomery-Leiter multipliziert, öffentliche mit dem schnelleren w-NAF.
+    /// Bei Multiplikation mit 0 wird der Punkt im Unendlichen zurückgegeben, ein negativer
+    /// Skalar liefert das Inverse von |k| · P.
+    ///
+    /// # Argumente
+    /// * `scalar` - Der Skalar k.
+    /// * `curve` - Die Kurve, auf der der Punkt liegt.
+    /// * `scalar_kind` - Ob der Skalar geheim ist.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der Punkt nicht auf der Kurve liegt.
+    pub fn multiply_with(
         &self,
         scalar: &BigInt,
-        curve: &SecureFiniteFieldEllipticCurve,
+        curve: &FiniteFieldEllipticCurve,
+        scalar_kind: ScalarKind,
     ) -> Result<Self> {
-        // Bei einer 1 passiert nichts
-        if scalar.is_one() {
-            return Ok(self.clone());
-        }
-        // Bei einer 2 wird verdoppelt
-        if scalar == &BigInt::from(2) {
-            return Ok(self.double(curve));
-        }
-        // Ist der Punkt der Generator und der Skalar die Ordnung des Generators, wird der Punkt
-        // im Unendlichen zurückgegeben.
-        if self == &curve.generator && scalar == &curve.order_of_subgroup {
-            return Ok(FiniteFieldEllipticCurvePoint::infinite());
-        }
+        ensure!(
+            curve.has_point(self),
+            PointNotOnCurveError(self.clone(), curve.clone())
+        );
+
+        let point = JacobianPoint::from_affine(self, curve);
+        let magnitude = scalar.abs();
+        let result = match scalar_kind {
+            ScalarKind::Secret => montgomery_ladder(&point, &magnitude, curve),
+            ScalarKind::Public => multiply_wnaf(&point, &magnitude, curve),
+        };
+        let result = match scalar.is_negative() {
+            true => result.negate(curve),
+            false => result,
+        };
+        Ok(result.to_affine(curve))
+    }
 
+    /// Multipliziert einen Punkt mit einem Skalar nach Double-and-add in affinen Koordinaten.
+    /// Jede Addition und Verdopplung benötigt eine modulare Inversion. Die Methode dient als
+    /// Referenz für `multiply`.
+    pub fn multiply_affine(
+        &self,
+        scalar: &BigInt,
+        curve: &FiniteFieldEllipticCurve,
+    ) -> Result<Self> {
         let mut result = FiniteFieldEllipticCurvePoint::infinite();
         let mut addend = self.clone();
         let mut n = scalar.clone();
         while n > BigInt::zero() {
             if n.is_odd() {
                 result = result
-                    .add(&addend, &curve)
+                    .add(&addend, curve)
                     .context("Error while adding point in multiply operation")?;
             }
             addend = addend.double(curve);
-            n = n >> 1;
+            n >>= 1;
         }
         Ok(result)
     }
 
+    /// Kodiert den Punkt nach SEC1 (Abschnitt 2.3.3). Der Punkt im Unendlichen wird als ein
+    /// einzelnes Nullbyte kodiert, andere Punkte unkomprimiert als 0x04 || x || y oder
+    /// komprimiert als 0x02 bzw. 0x03 || x, wobei das Präfix die Parität von y angibt.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve, die die Länge der Koordinaten festlegt.
+    /// * `compressed` - Ob nur die x-Koordinate und die Parität von y kodiert werden.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der Punkt nicht auf der Kurve liegt.
+    pub fn to_sec1(&self, curve: &FiniteFieldEllipticCurve, compressed: bool) -> Result<Vec<u8>> {
+        ensure!(
+            curve.has_point(self),
+            PointNotOnCurveError(self.clone(), curve.clone())
+        );
+        if self.is_infinite {
+            return Ok(vec![0x00]);
+        }
+        let point = self.normalize(&curve.prime);
+        let length = curve.field_byte_length();
+        let mut bytes = Vec::with_capacity(1 + 2 * length);
+        match compressed {
+            true => bytes.push(if point.y.is_odd() { 0x03 } else { 0x02 }),
+            false => bytes.push(0x04),
+        }
+        bytes.extend(i2osp(&point.x, length)?);
+        if !compressed {
+            bytes.extend(i2osp(&point.y, length)?);
+        }
+        Ok(bytes)
+    }
+
+    /// Dekodiert einen Punkt aus der SEC1-Kodierung. Bei komprimierten Punkten wird y als
+    /// Quadratwurzel von x^3 + ax + b bestimmt und anhand der Parität ausgewählt.
+    ///
+    /// Geprüft wird, dass die Koordinaten kleiner als p sind und der Punkt auf der Kurve liegt.
+    /// Ob er in der Untergruppe mit Primordnung liegt, prüft erst
+    /// `SecureFiniteFieldEllipticCurve::decode_point`.
+    ///
+    /// # Argumente
+    /// * `bytes` - Die Kodierung.
+    /// * `curve` - Die Kurve, auf der der Punkt liegen soll.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidPointEncodingError` - Falls Präfix, Länge oder Koordinaten
+    ///   ungültig sind oder es zu x keinen Punkt gibt.
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls ein unkomprimierter Punkt nicht auf
+    ///   der Kurve liegt.
+    pub fn from_sec1(bytes: &[u8], curve: &FiniteFieldEllipticCurve) -> Result<Self> {
+        let length = curve.field_byte_length();
+        let (prefix, coordinates) = bytes
+            .split_first()
+            .ok_or_else(|| InvalidPointEncodingError("empty encoding".to_string()))?;
+        let expected_length = match prefix {
+            0x00 => 0,
+            0x02 | 0x03 => length,
+            0x04 => 2 * length,
+            _ => bail!(InvalidPointEncodingError(format!(
+                "unknown prefix {:#04x}",
+                prefix
+            ))),
+        };
+        ensure!(
+            coordinates.len() == expected_length,
+            InvalidPointEncodingError(format!(
+                "expected {} bytes after prefix {:#04x}, got {}",
+                expected_length,
+                prefix,
+                coordinates.len()
+            ))
+        );
+        if *prefix == 0x00 {
+            return Ok(Self::infinite());
+        }
+
+        let x = os2ip(&coordinates[..length]);
+        ensure!(
+            x < curve.prime,
+            InvalidPointEncodingError(format!("x coordinate {} is not below p", x))
+        );
+        if *prefix == 0x04 {
+            let y = os2ip(&coordinates[length..]);
+            ensure!(
+                y < curve.prime,
+                InvalidPointEncodingError(format!("y coordinate {} is not below p", y))
+            );
+            let point = Self::new(x, y);
+            ensure!(
+                curve.has_point(&point),
+                PointNotOnCurveError(point.clone(), curve.clone())
+            );
+            return Ok(point);
+        }
+
+        let service = NumberTheoryService::new(Fast); // TODO X: Später korrigieren
+        let y = modular_square_root(&curve.right_hand_side(&x), &curve.prime, service)
+            .map_err(|_| InvalidPointEncodingError(format!("no point with x coordinate {}", x)))?;
+        let y = match y.is_odd() == (*prefix == 0x03) {
+            true => y,
+            false => (&curve.prime - y).rem_euclid(&curve.prime),
+        };
+        // Für y = 0 gibt es nur eine Wurzel, deren Parität nicht zum Präfix 0x03 passen muss.
+        ensure!(
+            y.is_odd() == (*prefix == 0x03),
+            InvalidPointEncodingError(format!("no odd y coordinate for x coordinate {}", x))
+        );
+        Ok(Self::new(x, y))
+    }
+
     ///
     /// Normalisiert den Punkt, indem negative Koordinaten in positive Koordinaten umgewandelt werden.
     /// Anschließend wird der Punkt wieder in den Körper der elliptischen Kurve zurückgeführt.
@@ -198,21 +338,144 @@ impl FiniteFieldEllipticCurvePoint {
 mod tests {
 
     use super::*;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 
     fn get_curve() -> SecureFiniteFieldEllipticCurve {
         SecureFiniteFieldEllipticCurve::new(5, 16, 40).unwrap()
     }
 
+    #[test]
+    fn test_arithmetic_on_curve_with_nonzero_b() {
+        // y^2 = x^3 + 2x + 2 (mod 17), der Punkt (5, 1) erzeugt alle 19 Punkte
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        let generator = FiniteFieldEllipticCurvePoint::new(5.into(), 1.into());
+
+        assert_eq!(
+            generator.double(&curve),
+            FiniteFieldEllipticCurvePoint::new(6.into(), 3.into())
+        );
+        assert_eq!(
+            generator.add(&generator.double(&curve), &curve).unwrap(),
+            FiniteFieldEllipticCurvePoint::new(10.into(), 6.into())
+        );
+        assert_eq!(
+            generator.multiply(&9.into(), &curve).unwrap(),
+            FiniteFieldEllipticCurvePoint::new(7.into(), 6.into())
+        );
+        assert!(generator.multiply(&19.into(), &curve).unwrap().is_infinite);
+
+        let not_on_curve = FiniteFieldEllipticCurvePoint::new(5.into(), 2.into());
+        assert!(generator.add(&not_on_curve, &curve).is_err());
+    }
+
+    #[test]
+    fn test_sec1_round_trip() {
+        // y^2 = x^3 + 2x + 2 (mod 17) mit einem Byte pro Koordinate
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        let generator = FiniteFieldEllipticCurvePoint::new(5.into(), 1.into());
+        assert_eq!(generator.to_sec1(&curve, false).unwrap(), vec![0x04, 5, 1]);
+        assert_eq!(generator.to_sec1(&curve, true).unwrap(), vec![0x03, 5]);
+        assert_eq!(
+            FiniteFieldEllipticCurvePoint::infinite()
+                .to_sec1(&curve, true)
+                .unwrap(),
+            vec![0x00]
+        );
+
+        for k in 0..19 {
+            let point = generator.multiply(&k.into(), &curve).unwrap();
+            for compressed in [true, false] {
+                let bytes = point.to_sec1(&curve, compressed).unwrap();
+                assert_eq!(
+                    FiniteFieldEllipticCurvePoint::from_sec1(&bytes, &curve).unwrap(),
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_sec1_rejects_invalid_encodings() {
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        for bytes in [
+            vec![],
+            vec![0x05, 5],
+            vec![0x02, 5, 1],
+            vec![0x04, 5],
+            vec![0x00, 0],
+            // x = 17 ist kein Körperelement
+            vec![0x02, 17],
+            // (5, 2) liegt nicht auf der Kurve
+            vec![0x04, 5, 2],
+            // x^3 + 2x + 2 = 7 ist für x = 2 kein Quadrat modulo 17
+            vec![0x02, 2],
+        ] {
+            assert!(
+                FiniteFieldEllipticCurvePoint::from_sec1(&bytes, &curve).is_err(),
+                "{:?}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_sec1_on_named_curves() {
+        // Die komprimierten Generatoren aus SEC 2
+        let parameters = NamedCurve::P256.domain_parameters();
+        let compressed = parameters
+            .generator
+            .to_sec1(&parameters.curve, true)
+            .unwrap();
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(
+            os2ip(&compressed),
+            BigInt::parse_bytes(
+                b"036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                16
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            FiniteFieldEllipticCurvePoint::from_sec1(&compressed, &parameters.curve).unwrap(),
+            parameters.generator
+        );
+
+        let parameters = NamedCurve::Secp256k1.domain_parameters();
+        let compressed = parameters
+            .generator
+            .to_sec1(&parameters.curve, true)
+            .unwrap();
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(
+            os2ip(&compressed),
+            BigInt::parse_bytes(
+                b"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                16
+            )
+            .unwrap()
+        );
+        let uncompressed = parameters
+            .generator
+            .to_sec1(&parameters.curve, false)
+            .unwrap();
+        assert_eq!(uncompressed.len(), 65);
+        assert_eq!(
+            FiniteFieldEllipticCurvePoint::from_sec1(&uncompressed, &parameters.curve).unwrap(),
+            parameters.generator
+        );
+    }
+
     #[test]
     fn test_add_trivial() {
         let curve = get_curve();
         assert!(curve.order_of_subgroup > 8.into());
         let p1 = curve.generator.clone();
-        let p2 = curve.generator.multiply(&4.into(), &curve).unwrap();
-        let p3 = p1.add(&p2, &curve).unwrap();
-        let expected = p1.multiply(&5.into(), &curve).unwrap();
+        let p2 = curve.generator.multiply(&4.into(), &curve.curve).unwrap();
+        let p3 = p1.add(&p2, &curve.curve).unwrap();
+        let expected = p1.multiply(&5.into(), &curve.curve).unwrap();
         assert_eq!(p3, expected);
-        let has_point = curve.has_point(&p3);
+        let has_point = curve.curve.has_point(&p3);
         assert!(has_point, "{:?}, {:?}", p3, curve);
     }
 
@@ -220,15 +483,15 @@ mod tests {
     fn test_add_identical_points_doubles() {
         let curve = get_curve();
         let p1 = curve.generator.clone();
-        let result = p1.add(&p1, &curve).unwrap();
-        assert_eq!(result, p1.double(&curve));
+        let result = p1.add(&p1, &curve.curve).unwrap();
+        assert_eq!(result, p1.double(&curve.curve));
     }
 
     #[test]
     fn test_add_two_points_at_infinity() {
         let curve = get_curve();
         let infinity = FiniteFieldEllipticCurvePoint::infinite();
-        let result = infinity.add(&infinity, &curve).unwrap();
+        let result = infinity.add(&infinity, &curve.curve).unwrap();
         let expected = FiniteFieldEllipticCurvePoint::infinite();
         assert_eq!(result, expected);
     }
@@ -237,15 +500,15 @@ mod tests {
     fn test_multiply_trivial() {
         let curve = get_curve();
         let p1 = curve.generator.clone();
-        let identical = p1.multiply(&1.into(), &curve).unwrap();
+        let identical = p1.multiply(&1.into(), &curve.curve).unwrap();
         assert_eq!(p1, identical);
 
-        let doubled = p1.multiply(&2.into(), &curve).unwrap();
-        let expected = curve.generator.double(&curve);
+        let doubled = p1.multiply(&2.into(), &curve.curve).unwrap();
+        let expected = curve.generator.double(&curve.curve);
         assert_eq!(doubled, expected);
 
-        let p2 = doubled.multiply(&8.into(), &curve).unwrap();
-        let expected = curve.generator.multiply(&16.into(), &curve).unwrap();
+        let p2 = doubled.multiply(&8.into(), &curve.curve).unwrap();
+        let expected = curve.generator.multiply(&16.into(), &curve.curve).unwrap();
         assert_eq!(p2, expected);
     }
 
@@ -254,11 +517,11 @@ mod tests {
         let curve = get_curve();
         let generator = curve.generator.clone();
         let result = generator
-            .add(&generator, &curve)
+            .add(&generator, &curve.curve)
             .unwrap()
-            .add(&generator, &curve)
+            .add(&generator, &curve.curve)
             .unwrap();
-        let expected = generator.multiply(&3.into(), &curve).unwrap();
+        let expected = generator.multiply(&3.into(), &curve.curve).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -266,7 +529,7 @@ mod tests {
     fn test_multiply_by_order_gives_infinity() {
         let curve = get_curve();
         let p1 = curve.generator.clone();
-        let p2 = p1.multiply(&curve.order_of_subgroup, &curve).unwrap();
+        let p2 = p1.multiply(&curve.order_of_subgroup, &curve.curve).unwrap();
         let expected = FiniteFieldEllipticCurvePoint::infinite();
         assert_eq!(p2, expected);
     }
@@ -274,7 +537,7 @@ mod tests {
     #[test]
     fn test_multiply_with_zero() {
         let curve = get_curve();
-        let p2 = curve.generator.multiply(&0.into(), &curve).unwrap();
+        let p2 = curve.generator.multiply(&0.into(), &curve.curve).unwrap();
         let expected = FiniteFieldEllipticCurvePoint::infinite();
         assert_eq!(p2, expected);
     }
@@ -286,11 +549,11 @@ mod tests {
         let infinity = FiniteFieldEllipticCurvePoint::infinite();
 
         // Point + 0 = Point
-        let p2 = generator.add(&infinity, &curve).unwrap();
+        let p2 = generator.add(&infinity, &curve.curve).unwrap();
         assert_eq!(p2, generator);
 
         // 0 + Point = Point
-        let p3 = infinity.add(&generator, &curve).unwrap();
+        let p3 = infinity.add(&generator, &curve.curve).unwrap();
         assert_eq!(p3, generator);
     }
 
@@ -301,7 +564,7 @@ mod tests {
         let negated_generator =
             FiniteFieldEllipticCurvePoint::new(generator.x.clone(), -generator.y.clone());
         // Addiere negativen Generator --> Infinity
-        let result = generator.add(&negated_generator, &curve).unwrap();
+        let result = generator.add(&negated_generator, &curve.curve).unwrap();
         let expected = FiniteFieldEllipticCurvePoint::infinite();
         assert_eq!(
             result, expected,
@@ -309,15 +572,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiply_matches_affine_multiplication() {
+        let curve = get_curve();
+        let generator = curve.generator.clone();
+        for scalar in [0, 1, 2, 3, 7, 8, 255, 1000003] {
+            let scalar = BigInt::from(scalar);
+            let expected = generator.multiply_affine(&scalar, &curve.curve).unwrap();
+            for scalar_kind in [ScalarKind::Secret, ScalarKind::Public] {
+                assert_eq!(
+                    generator
+                        .multiply_with(&scalar, &curve.curve, scalar_kind)
+                        .unwrap(),
+                    expected
+                );
+            }
+        }
+        let negative = generator.multiply(&(-5).into(), &curve.curve).unwrap();
+        assert_eq!(
+            negative
+                .add(
+                    &generator.multiply(&5.into(), &curve.curve).unwrap(),
+                    &curve.curve
+                )
+                .unwrap(),
+            FiniteFieldEllipticCurvePoint::infinite()
+        );
+        let not_on_curve = FiniteFieldEllipticCurvePoint::new(generator.x.clone(), 0.into());
+        assert!(not_on_curve.multiply(&3.into(), &curve.curve).is_err());
+    }
+
     #[test]
     fn test_multiply_by_large_scalar() {
         let curve = get_curve();
         let generator = curve.generator.clone();
         let large_scalar = BigInt::from(1000000000);
         // Multiplying the generator by a large scalar
-        let result = generator.multiply(&large_scalar, &curve).unwrap();
+        let result = generator.multiply(&large_scalar, &curve.curve).unwrap();
         let expected = generator
-            .multiply(&BigInt::from(1000000000), &curve)
+            .multiply(&BigInt::from(1000000000), &curve.curve)
             .unwrap();
         assert_eq!(result, expected);
     }
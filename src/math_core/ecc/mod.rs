@@ -1,2 +1,11 @@
+pub mod curve_audit;
+pub mod finite_field_elliptic_curve;
 pub mod finite_field_elliptic_curve_point;
+pub mod jacobian_point;
+pub mod montgomery_curve;
+pub mod named_curve;
+pub mod point_counting;
+pub mod scalar_multiplication;
+pub mod schoof;
 pub mod secure_finite_field_elliptic_curve;
+pub mod twisted_edwards_curve;
@@ -0,0 +1,423 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::twisted_edwards_curve::{EdwardsPoint, TwistedEdwardsCurve};
+use crate::math_core::number_theory::factorization::is_prime;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EllipticCurveError::{
+    DegenerateCurveCoefficientsError, ExceptionalPointError, InvalidFieldPrimeError,
+    ScalarOutOfRangeError,
+};
+
+///
+/// Repräsentiert eine Montgomery-Kurve B · v^2 = u^3 + A · u^2 + u über dem endlichen Körper
+/// F_p.
+///
+/// Die Punkte werden als `FiniteFieldEllipticCurvePoint` dargestellt, x steht dabei für u und
+/// y für v. Das neutrale Element ist wie in der Weierstraß-Form der Punkt im Unendlichen.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct MontgomeryCurve {
+    /// Der Koeffizient A, bei Curve25519 ist A = 486662
+    pub a: BigInt,
+    /// Der Koeffizient B
+    pub b: BigInt,
+    /// Der Modulus p, über dessen Restklassenkörper die Kurve definiert ist
+    pub prime: BigInt,
+}
+
+impl Display for MontgomeryCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} * v^2 = u^3 + {} * u^2 + u (mod {})",
+            self.b, self.a, self.prime
+        )
+    }
+}
+
+impl MontgomeryCurve {
+    /// Erstellt eine neue Montgomery-Kurve B · v^2 = u^3 + A · u^2 + u (mod p).
+    ///
+    /// # Argumente
+    /// * `a` - Der Koeffizient A.
+    /// * `b` - Der Koeffizient B.
+    /// * `prime` - Der Modulus p.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidFieldPrimeError` - Falls p keine Primzahl größer 3 ist.
+    /// * `EllipticCurveError::DegenerateCurveCoefficientsError` - Falls B · (A^2 - 4) ≡ 0 ist,
+    ///   die Kurve also singulär ist.
+    pub fn new(a: BigInt, b: BigInt, prime: BigInt) -> Result<Self> {
+        ensure!(
+            prime > BigInt::from(3) && is_prime(&prime, NumberTheoryService::new(Fast)),
+            InvalidFieldPrimeError(prime.to_string())
+        );
+        ensure!(
+            !(&b * (&a * &a - BigInt::from(4)))
+                .rem_euclid(&prime)
+                .is_zero(),
+            DegenerateCurveCoefficientsError(a.to_string(), b.to_string())
+        );
+        Ok(MontgomeryCurve { a, b, prime })
+    }
+
+    /// Curve25519 aus RFC 7748 mit A = 486662 und B = 1 über p = 2^255 - 19.
+    pub fn curve25519() -> Self {
+        MontgomeryCurve {
+            a: BigInt::from(486662),
+            b: BigInt::one(),
+            prime: (BigInt::one() << 255) - 19,
+        }
+    }
+
+    /// Prüft, ob der Punkt die Kurvengleichung erfüllt. Der Punkt im Unendlichen liegt immer
+    /// auf der Kurve.
+    pub fn has_point(&self, point: &FiniteFieldEllipticCurvePoint) -> bool {
+        if point.is_infinite {
+            return true;
+        }
+        let u = &point.x;
+        let u_squared = u * u;
+        (&self.b * &point.y * &point.y - &u_squared * u - &self.a * u_squared - u)
+            .rem_euclid(&self.prime)
+            .is_zero()
+    }
+
+    /// Die birational äquivalente Kurve in kurzer Weierstraß-Form mit
+    /// a = (3 - A^2) / (3 · B^2) und b = (2 · A^3 - 9 · A) / (27 · B^3).
+    pub fn to_weierstrass(&self) -> Result<FiniteFieldEllipticCurve> {
+        let a_squared = &self.a * &self.a;
+        let b_squared = &self.b * &self.b;
+        let a = (BigInt::from(3) - &a_squared) * self.inverse(&(BigInt::from(3) * &b_squared))?;
+        let b = (BigInt::from(2) * &a_squared * &self.a - BigInt::from(9) * &self.a)
+            * self.inverse(&(BigInt::from(27) * b_squared * &self.b))?;
+        FiniteFieldEllipticCurve::new(
+            a.rem_euclid(&self.prime),
+            b.rem_euclid(&self.prime),
+            self.prime.clone(),
+        )
+    }
+
+    /// Bildet (u, v) auf (x, y) = ((3 · u + A) / (3 · B), v / B) der Weierstraß-Form ab.
+    pub fn to_weierstrass_point(
+        &self,
+        point: &FiniteFieldEllipticCurvePoint,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        if point.is_infinite {
+            return Ok(FiniteFieldEllipticCurvePoint::infinite());
+        }
+        let x =
+            (BigInt::from(3) * &point.x + &self.a) * self.inverse(&(BigInt::from(3) * &self.b))?;
+        let y = &point.y * self.inverse(&self.b)?;
+        Ok(FiniteFieldEllipticCurvePoint::new(
+            x.rem_euclid(&self.prime),
+            y.rem_euclid(&self.prime),
+        ))
+    }
+
+    /// Die Umkehrung von `to_weierstrass_point`: u = B · x - A / 3 und v = B · y.
+    pub fn from_weierstrass_point(
+        &self,
+        point: &FiniteFieldEllipticCurvePoint,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        if point.is_infinite {
+            return Ok(FiniteFieldEllipticCurvePoint::infinite());
+        }
+        let u = &self.b * &point.x - &self.a * self.inverse(&BigInt::from(3))?;
+        let v = &self.b * &point.y;
+        Ok(FiniteFieldEllipticCurvePoint::new(
+            u.rem_euclid(&self.prime),
+            v.rem_euclid(&self.prime),
+        ))
+    }
+
+    /// Die birational äquivalente getwistete Edwards-Kurve mit a = (A + 2) / B und
+    /// d = (A - 2) / B.
+    pub fn to_twisted_edwards(&self) -> Result<TwistedEdwardsCurve> {
+        let inverse = self.inverse(&self.b)?;
+        TwistedEdwardsCurve::new(
+            ((&self.a + BigInt::from(2)) * &inverse).rem_euclid(&self.prime),
+            ((&self.a - BigInt::from(2)) * inverse).rem_euclid(&self.prime),
+            self.prime.clone(),
+        )
+    }
+
+    /// Bildet (u, v) auf (x, y) = (u / v, (u - 1) / (u + 1)) der Edwards-Form ab. Der Punkt im
+    /// Unendlichen wird zu (0, 1), der Punkt (0, 0) der Ordnung 2 zu (0, -1).
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::ExceptionalPointError` - Falls v = 0 oder u = -1 ist. Diese
+    ///   Punkte entsprechen Punkten im Unendlichen der Edwards-Kurve, die es nur gibt, wenn
+    ///   deren Formeln nicht vollständig sind.
+    pub fn to_edwards_point(&self, point: &FiniteFieldEllipticCurvePoint) -> Result<EdwardsPoint> {
+        if point.is_infinite {
+            return Ok(EdwardsPoint::neutral());
+        }
+        let u = point.x.rem_euclid(&self.prime);
+        let v = point.y.rem_euclid(&self.prime);
+        if u.is_zero() && v.is_zero() {
+            return Ok(EdwardsPoint::new(BigInt::zero(), &self.prime - 1));
+        }
+        let exceptional = || ExceptionalPointError(point.to_string());
+        let x = &u * self.inverse(&v).map_err(|_| exceptional())?;
+        let y = (&u - BigInt::one())
+            * self
+                .inverse(&(&u + BigInt::one()))
+                .map_err(|_| exceptional())?;
+        Ok(EdwardsPoint::new(
+            x.rem_euclid(&self.prime),
+            y.rem_euclid(&self.prime),
+        ))
+    }
+
+    /// Die Umkehrung von `to_edwards_point`: u = (1 + y) / (1 - y) und v = u / x.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::ExceptionalPointError` - Falls der Punkt x = 0 hat, aber weder
+    ///   (0, 1) noch (0, -1) ist.
+    pub fn from_edwards_point(
+        &self,
+        point: &EdwardsPoint,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        let x = point.x.rem_euclid(&self.prime);
+        let y = point.y.rem_euclid(&self.prime);
+        if x.is_zero() && y.is_one() {
+            return Ok(FiniteFieldEllipticCurvePoint::infinite());
+        }
+        if x.is_zero() && y == &self.prime - 1 {
+            return Ok(FiniteFieldEllipticCurvePoint::new(
+                BigInt::zero(),
+                BigInt::zero(),
+            ));
+        }
+        let exceptional = || ExceptionalPointError(point.to_string());
+        let u = (BigInt::one() + &y)
+            * self
+                .inverse(&(BigInt::one() - y))
+                .map_err(|_| exceptional())?;
+        let v = &u * self.inverse(&x).map_err(|_| exceptional())?;
+        Ok(FiniteFieldEllipticCurvePoint::new(
+            u.rem_euclid(&self.prime),
+            v.rem_euclid(&self.prime),
+        ))
+    }
+
+    /// Berechnet die u-Koordinate von k · P allein aus der u-Koordinate von P mit der
+    /// Montgomery-Leiter aus RFC 7748, Abschnitt 5. Die Leiter läuft unabhängig vom Skalar über
+    /// `bits` Bits und tauscht nur bedingt, v und der Koeffizient B werden nicht benötigt. Ist
+    /// k · P der Punkt im Unendlichen, ist das Ergebnis 0.
+    ///
+    /// # Argumente
+    /// * `u` - Die u-Koordinate von P. Sie wird modulo p reduziert und darf auch zu einem Punkt
+    ///   auf dem Twist der Kurve gehören.
+    /// * `scalar` - Der geheime Skalar k.
+    /// * `bits` - Die Anzahl der Bits, über die die Leiter läuft.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::ScalarOutOfRangeError` - Falls k negativ ist oder mehr als `bits`
+    ///   Bits hat.
+    pub fn ladder(&self, u: &BigInt, scalar: &BigInt, bits: u64) -> Result<BigInt> {
+        ensure!(
+            !scalar.is_negative() && scalar.bits() <= bits,
+            ScalarOutOfRangeError(scalar.to_string())
+        );
+        let p = &self.prime;
+        let a24 = ((&self.a - BigInt::from(2)) * self.inverse(&BigInt::from(4))?).rem_euclid(p);
+        let x_1 = u.rem_euclid(p);
+        let (mut x_2, mut z_2) = (BigInt::one(), BigInt::zero());
+        let (mut x_3, mut z_3) = (x_1.clone(), BigInt::one());
+
+        let mut swap = false;
+        for index in (0..bits).rev() {
+            let bit = scalar.bit(index);
+            swap ^= bit;
+            conditional_swap(&mut x_2, &mut x_3, swap);
+            conditional_swap(&mut z_2, &mut z_3, swap);
+            swap = bit;
+
+            let a = &x_2 + &z_2;
+            let aa = &a * &a;
+            let b = &x_2 - &z_2;
+            let bb = &b * &b;
+            let e = &aa - &bb;
+            let da = (&x_3 - &z_3) * a;
+            let cb = (&x_3 + &z_3) * b;
+            x_3 = (&da + &cb).pow(2).rem_euclid(p);
+            z_3 = (&x_1 * (da - cb).pow(2)).rem_euclid(p);
+            z_2 = (&e * (&aa + &a24 * &e)).rem_euclid(p);
+            x_2 = (aa * bb).rem_euclid(p);
+        }
+        conditional_swap(&mut x_2, &mut x_3, swap);
+        conditional_swap(&mut z_2, &mut z_3, swap);
+
+        // z^(p - 2) ist das Inverse von z und 0 für den Punkt im Unendlichen.
+        let z_inverse =
+            NumberTheoryService::new(Fast).fast_exponentiation(&z_2, &(p - BigInt::from(2)), p);
+        Ok((x_2 * z_inverse).rem_euclid(p))
+    }
+
+    fn inverse(&self, value: &BigInt) -> Result<BigInt> {
+        NumberTheoryService::new(Fast).modulo_inverse(&value.rem_euclid(&self.prime), &self.prime)
+    }
+}
+
+fn conditional_swap(first: &mut BigInt, second: &mut BigInt, swap: bool) {
+    let pair = [first.clone(), second.clone()];
+    *first = pair[swap as usize].clone();
+    *second = pair[1 - swap as usize].clone();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+
+    fn ed25519_base_point() -> EdwardsPoint {
+        EdwardsPoint::new(
+            "15112221349535400772501151409588531511454012693041857206046113283949847762202"
+                .parse()
+                .unwrap(),
+            "46316835694926478169428394003475163141307993866256225615783033603165251855960"
+                .parse()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_ed25519_is_birationally_equivalent_to_curve25519() {
+        let edwards = TwistedEdwardsCurve::ed25519();
+        let montgomery = edwards.to_montgomery().unwrap();
+        assert_eq!(montgomery.a, BigInt::from(486662));
+
+        // Der Basispunkt von Ed25519 entspricht u = 9 auf Curve25519.
+        let base_point = montgomery
+            .from_edwards_point(&ed25519_base_point())
+            .unwrap();
+        assert_eq!(base_point.x, BigInt::from(9));
+        assert!(montgomery.has_point(&base_point));
+        assert_eq!(
+            montgomery.to_edwards_point(&base_point).unwrap(),
+            ed25519_base_point()
+        );
+
+        // Die Rückrichtung liefert wieder die Koeffizienten von edwards25519.
+        let back = montgomery.to_twisted_edwards().unwrap();
+        assert_eq!(back.a, &edwards.prime - 1);
+        assert_eq!(back.d, edwards.d);
+    }
+
+    #[test]
+    fn test_curve25519_in_weierstrass_form() {
+        let montgomery = MontgomeryCurve::curve25519();
+        let weierstrass = montgomery.to_weierstrass().unwrap();
+        // Die Koeffizienten von Wei25519 aus dem Entwurf draft-ietf-lwig-curve-representations.
+        assert_eq!(
+            weierstrass.a,
+            "19298681539552699237261830834781317975544997444273427339909597334573241639236"
+                .parse::<BigInt>()
+                .unwrap()
+        );
+        assert_eq!(
+            weierstrass.b,
+            "55751746669818908907645289078257140818241103727901012315294400837956729358436"
+                .parse::<BigInt>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_maps_are_homomorphisms() {
+        let edwards = TwistedEdwardsCurve::ed25519();
+        let montgomery = edwards.to_montgomery().unwrap();
+        let weierstrass = montgomery.to_weierstrass().unwrap();
+        let to_weierstrass = |point: &EdwardsPoint| {
+            montgomery
+                .to_weierstrass_point(&montgomery.from_edwards_point(point).unwrap())
+                .unwrap()
+        };
+
+        let base_point = ed25519_base_point();
+        let mapped = to_weierstrass(&base_point);
+        assert!(weierstrass.has_point(&mapped));
+        for scalar in [2, 3, 1000, 123456789] {
+            let scalar = BigInt::from(scalar);
+            let on_edwards = edwards
+                .multiply(&base_point, &scalar, ScalarKind::Public)
+                .unwrap();
+            let on_weierstrass = mapped.multiply(&scalar, &weierstrass).unwrap();
+            assert_eq!(to_weierstrass(&on_edwards), on_weierstrass);
+
+            let back = montgomery
+                .to_edwards_point(&montgomery.from_weierstrass_point(&on_weierstrass).unwrap())
+                .unwrap();
+            assert_eq!(back, on_edwards);
+        }
+    }
+
+    #[test]
+    fn test_ladder_matches_edwards_multiplication() {
+        let edwards = TwistedEdwardsCurve::ed25519();
+        let montgomery = MontgomeryCurve::curve25519();
+        let base_point = ed25519_base_point();
+        for scalar in [1, 2, 7, 1000, 123456789] {
+            let scalar = BigInt::from(scalar);
+            let expected = montgomery
+                .from_edwards_point(
+                    &edwards
+                        .multiply(&base_point, &scalar, ScalarKind::Public)
+                        .unwrap(),
+                )
+                .unwrap();
+            assert_eq!(
+                montgomery.ladder(&BigInt::from(9), &scalar, 255).unwrap(),
+                expected.x
+            );
+        }
+
+        // Die Ordnung des Basispunkts führt in den Punkt im Unendlichen, der als 0 kodiert wird.
+        let order = (BigInt::one() << 252)
+            + "27742317777372353535851937790883648493"
+                .parse::<BigInt>()
+                .unwrap();
+        assert!(montgomery
+            .ladder(&BigInt::from(9), &order, 255)
+            .unwrap()
+            .is_zero());
+        assert!(montgomery
+            .ladder(&BigInt::from(9), &(BigInt::one() << 255), 255)
+            .is_err());
+    }
+
+    #[test]
+    fn test_special_points() {
+        let montgomery = MontgomeryCurve::curve25519();
+        let neutral = EdwardsPoint::neutral();
+        let two_torsion = EdwardsPoint::new(BigInt::zero(), &montgomery.prime - 1);
+
+        let infinite = montgomery.from_edwards_point(&neutral).unwrap();
+        assert!(infinite.is_infinite);
+        assert_eq!(montgomery.to_edwards_point(&infinite).unwrap(), neutral);
+        let origin = montgomery.from_edwards_point(&two_torsion).unwrap();
+        assert_eq!(
+            origin,
+            FiniteFieldEllipticCurvePoint::new(0.into(), 0.into())
+        );
+        assert_eq!(montgomery.to_edwards_point(&origin).unwrap(), two_torsion);
+
+        assert!(montgomery
+            .to_edwards_point(&FiniteFieldEllipticCurvePoint::new(5.into(), 0.into()))
+            .is_err());
+        assert!(MontgomeryCurve::new(2.into(), 1.into(), montgomery.prime.clone()).is_err());
+        assert!(MontgomeryCurve::new(3.into(), 0.into(), 13.into()).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::Result;
+use bigdecimal::num_bigint::BigInt;
+
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::shared::errors::EllipticCurveError::UnknownCurveError;
+
+/// Standardisierte Kurven mit festen Domainparametern. Anders als die selbst erzeugten Kurven
+/// stehen sie sofort zur Verfügung und sind mit anderen Implementierungen austauschbar.
+///
+/// Alle Kurven haben den Kofaktor 1, der Generator erzeugt also die gesamte Punktgruppe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamedCurve {
+    /// NIST P-256 bzw. secp256r1 aus FIPS 186-4.
+    P256,
+    /// NIST P-384 bzw. secp384r1 aus FIPS 186-4.
+    P384,
+    /// secp256k1 aus SEC 2, eine Koblitz-Kurve mit a = 0.
+    Secp256k1,
+    /// brainpoolP256r1 aus RFC 5639, deren Parameter nachvollziehbar zufällig erzeugt wurden.
+    BrainpoolP256r1,
+}
+
+/// Die Domainparameter einer Kurve als Hexadezimalzahlen: p, a, b, x und y des Generators und
+/// die Ordnung n des Generators.
+struct DomainParameters {
+    prime: &'static str,
+    a: &'static str,
+    b: &'static str,
+    generator_x: &'static str,
+    generator_y: &'static str,
+    order: &'static str,
+}
+
+const P256: DomainParameters = DomainParameters {
+    prime: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+    a: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC",
+    b: "5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+    generator_x: "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+    generator_y: "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+    order: "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+};
+
+const P384: DomainParameters = DomainParameters {
+    prime: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+            FFFFFFFF0000000000000000FFFFFFFF",
+    a: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+        FFFFFFFF0000000000000000FFFFFFFC",
+    b: "B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875A\
+        C656398D8A2ED19D2A85C8EDD3EC2AEF",
+    generator_x: "AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A38\
+                  5502F25DBF55296C3A545E3872760AB7",
+    generator_y: "3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C0\
+                  0A60B1CE1D7E819D7A431D7C90EA0E5F",
+    order: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF\
+            581A0DB248B0A77AECEC196ACCC52973",
+};
+
+const SECP256K1: DomainParameters = DomainParameters {
+    prime: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+    a: "0",
+    b: "7",
+    generator_x: "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    generator_y: "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+    order: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+};
+
+const BRAINPOOL_P256_R1: DomainParameters = DomainParameters {
+    prime: "A9FB57DBA1EEA9BC3E660A909D838D726E3BF623D52620282013481D1F6E5377",
+    a: "7D5A0975FC2C3057EEF67530417AFFE7FB8055C126DC5C6CE94A4B44F330B5D9",
+    b: "26DC5C6CE94A4B44F330B5D9BBD77CBF958416295CF7E1CE6BCCDC18FF8C07B6",
+    generator_x: "8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262",
+    generator_y: "547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997",
+    order: "A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7",
+};
+
+impl NamedCurve {
+    /// Alle verfügbaren Kurven.
+    pub const ALL: [NamedCurve; 4] = [
+        NamedCurve::P256,
+        NamedCurve::P384,
+        NamedCurve::Secp256k1,
+        NamedCurve::BrainpoolP256r1,
+    ];
+
+    /// Erstellt die Kurve mit ihrem Generator und dessen Ordnung. Die Parameter werden nicht
+    /// erneut geprüft, das übernehmen die Tests.
+    pub fn domain_parameters(&self) -> SecureFiniteFieldEllipticCurve {
+        let parameters = match self {
+            NamedCurve::P256 => &P256,
+            NamedCurve::P384 => &P384,
+            NamedCurve::Secp256k1 => &SECP256K1,
+            NamedCurve::BrainpoolP256r1 => &BRAINPOOL_P256_R1,
+        };
+        SecureFiniteFieldEllipticCurve {
+            curve: FiniteFieldEllipticCurve {
+                a: parse_hex(parameters.a),
+                b: parse_hex(parameters.b),
+                prime: parse_hex(parameters.prime),
+            },
+            order_of_subgroup: parse_hex(parameters.order),
+            generator: FiniteFieldEllipticCurvePoint::new(
+                parse_hex(parameters.generator_x),
+                parse_hex(parameters.generator_y),
+            ),
+        }
+    }
+}
+
+fn parse_hex(hex: &str) -> BigInt {
+    BigInt::parse_bytes(hex.as_bytes(), 16).expect("Domainparameter sind gültige Hexadezimalzahlen")
+}
+
+impl Display for NamedCurve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NamedCurve::P256 => write!(f, "P-256"),
+            NamedCurve::P384 => write!(f, "P-384"),
+            NamedCurve::Secp256k1 => write!(f, "secp256k1"),
+            NamedCurve::BrainpoolP256r1 => write!(f, "brainpoolP256r1"),
+        }
+    }
+}
+
+impl FromStr for NamedCurve {
+    type Err = anyhow::Error;
+
+    /// Liest den Namen der Kurve. Groß- und Kleinschreibung sind beliebig, neben den Namen aus
+    /// `Display` werden auch die SEC-2-Namen "secp256r1" und "secp384r1" sowie "prime256v1"
+    /// erkannt.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::UnknownCurveError` - Falls die Kurve unbekannt ist.
+    fn from_str(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "p-256" | "p256" | "secp256r1" | "prime256v1" => Ok(NamedCurve::P256),
+            "p-384" | "p384" | "secp384r1" => Ok(NamedCurve::P384),
+            "secp256k1" => Ok(NamedCurve::Secp256k1),
+            "brainpoolp256r1" => Ok(NamedCurve::BrainpoolP256r1),
+            _ => Err(UnknownCurveError(name.to_string()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::factorization::is_prime;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    #[test]
+    fn test_domain_parameters_are_valid() {
+        let service = NumberTheoryService::new(Fast);
+        for named_curve in NamedCurve::ALL {
+            let parameters = named_curve.domain_parameters();
+            let curve = &parameters.curve;
+            assert!(is_prime(&curve.prime, service), "{}", named_curve);
+            assert!(
+                is_prime(&parameters.order_of_subgroup, service),
+                "{}",
+                named_curve
+            );
+            assert!(!curve.is_singular(), "{}", named_curve);
+            assert!(curve.has_point(&parameters.generator), "{}", named_curve);
+            assert!(
+                parameters
+                    .generator
+                    .multiply(&parameters.order_of_subgroup, curve)
+                    .unwrap()
+                    .is_infinite,
+                "{}",
+                named_curve
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_multiple_on_secp256k1() {
+        // 2G auf secp256k1
+        let parameters = NamedCurve::Secp256k1.domain_parameters();
+        let doubled = parameters.generator.double(&parameters.curve);
+        assert_eq!(
+            doubled,
+            FiniteFieldEllipticCurvePoint::new(
+                parse_hex("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"),
+                parse_hex("1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"),
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        for named_curve in NamedCurve::ALL {
+            assert_eq!(
+                named_curve.to_string().parse::<NamedCurve>().unwrap(),
+                named_curve
+            );
+        }
+        assert_eq!("secp256r1".parse::<NamedCurve>().unwrap(), NamedCurve::P256);
+        assert_eq!(
+            "BrainpoolP256R1".parse::<NamedCurve>().unwrap(),
+            NamedCurve::BrainpoolP256r1
+        );
+        assert!("P-521".parse::<NamedCurve>().is_err());
+    }
+}
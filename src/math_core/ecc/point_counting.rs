@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::ToPrimitive;
+use bigdecimal::{One, Zero};
+use num::Integer;
+
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::ecc::schoof::count_points_schoof;
+use crate::math_core::number_theory::factorization::Factorization;
+use crate::math_core::number_theory::modular_square_root::modular_square_root;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EllipticCurveError::{
+    PointCountingFailedError, PointCountingLimitError, UnknownPointCountingMethodError,
+};
+
+/// Die größte Bitlänge von p, für die alle x-Werte einzeln durchlaufen werden.
+const NAIVE_MAXIMUM_BITS: u64 = 20;
+
+/// Die größte Bitlänge von p für Babystep-Giantstep. Die Tabelle der Babysteps hat etwa
+/// 2 · p^(1/4) Einträge.
+const BABY_STEP_GIANT_STEP_MAXIMUM_BITS: u64 = 64;
+
+/// Die größte Bitlänge von p für Schoof. Im Release-Build dauert die Zählung für 64 Bit etwa
+/// 5 Sekunden, für 96 Bit knapp eine Minute und für 128 Bit über vier Minuten.
+const SCHOOF_MAXIMUM_BITS: u64 = 96;
+
+/// Die Anzahl der Punkte auf Kurve und Twist, nach der Mestres Verfahren aufgibt.
+const MESTRE_MAXIMUM_POINTS: usize = 20;
+
+/// Die Anzahl der Kandidaten, ab der die Vielfachen des kgV der Punktordnungen im Hasse-Intervall
+/// nicht mehr aufgezählt werden, sondern weitere Punkte gesucht werden.
+const MESTRE_MAXIMUM_CANDIDATES: u64 = 1000;
+
+/// Verfahren zur Bestimmung der Anzahl der Punkte #E(F_p) einer elliptischen Kurve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointCountingMethod {
+    /// Summe der Legendre-Symbole von x^3 + ax + b über alle x, für sehr kleine p.
+    Naive,
+    /// Babystep-Giantstep im Hasse-Intervall mit dem Twist-Argument von Mestre, für p bis 64 Bit.
+    BabyStepGiantStep,
+    /// Der Algorithmus von Schoof mit Divisionspolynomen, für p bis 96 Bit.
+    Schoof,
+}
+
+impl PointCountingMethod {
+    pub const ALL: [PointCountingMethod; 3] = [
+        PointCountingMethod::Naive,
+        PointCountingMethod::BabyStepGiantStep,
+        PointCountingMethod::Schoof,
+    ];
+
+    /// Wählt das für die Größe von p schnellste Verfahren.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointCountingLimitError` - Falls p mehr als 96 Bit hat und damit
+    ///   auch für Schoof zu groß ist.
+    pub fn for_prime(prime: &BigInt) -> Result<Self> {
+        ensure!(
+            prime.bits() <= SCHOOF_MAXIMUM_BITS,
+            PointCountingLimitError(PointCountingMethod::Schoof.to_string(), SCHOOF_MAXIMUM_BITS)
+        );
+        Ok(match prime.bits() {
+            0..=16 => PointCountingMethod::Naive,
+            17..=BABY_STEP_GIANT_STEP_MAXIMUM_BITS => PointCountingMethod::BabyStepGiantStep,
+            _ => PointCountingMethod::Schoof,
+        })
+    }
+
+    /// Bestimmt die Anzahl der Punkte der Kurve einschließlich des Punktes im Unendlichen.
+    ///
+    /// # Argumente
+    /// * `curve` - Die Kurve.
+    /// * `number_theory_service` - Der Service für Exponentiation, Faktorisierung und den
+    ///   chinesischen Restsatz.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointCountingLimitError` - Falls p für das Verfahren zu groß ist.
+    /// * `EllipticCurveError::PointCountingFailedError` - Falls die Ordnung nicht eindeutig
+    ///   bestimmt werden konnte.
+    pub fn count_points(
+        &self,
+        curve: &FiniteFieldEllipticCurve,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<BigInt> {
+        match self {
+            PointCountingMethod::Naive => count_points_naive(curve, number_theory_service),
+            PointCountingMethod::BabyStepGiantStep => {
+                count_points_mestre(curve, number_theory_service)
+            }
+            PointCountingMethod::Schoof => {
+                ensure!(
+                    curve.prime.bits() <= SCHOOF_MAXIMUM_BITS,
+                    PointCountingLimitError(self.to_string(), SCHOOF_MAXIMUM_BITS)
+                );
+                count_points_schoof(curve, number_theory_service)
+            }
+        }
+    }
+}
+
+impl Display for PointCountingMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointCountingMethod::Naive => write!(f, "naive"),
+            PointCountingMethod::BabyStepGiantStep => write!(f, "bsgs"),
+            PointCountingMethod::Schoof => write!(f, "schoof"),
+        }
+    }
+}
+
+impl FromStr for PointCountingMethod {
+    type Err = anyhow::Error;
+
+    /// Liest den Namen des Verfahrens. Neben den Namen aus `Display` wird "mestre" für
+    /// Babystep-Giantstep erkannt.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::UnknownPointCountingMethodError` - Falls das Verfahren unbekannt ist.
+    fn from_str(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "naive" => Ok(PointCountingMethod::Naive),
+            "bsgs" | "mestre" => Ok(PointCountingMethod::BabyStepGiantStep),
+            "schoof" => Ok(PointCountingMethod::Schoof),
+            _ => Err(UnknownPointCountingMethodError(name.to_string()).into()),
+        }
+    }
+}
+
+/// Zählt die Punkte als #E = p + 1 + Σ (x^3 + ax + b / p) über alle x in F_p, denn zu jedem x
+/// gibt es 1 + (f(x) / p) Werte für y.
+///
+/// # Fehler
+/// * `EllipticCurveError::PointCountingLimitError` - Falls p mehr als 20 Bit hat.
+pub fn count_points_naive(
+    curve: &FiniteFieldEllipticCurve,
+    number_theory_service: NumberTheoryService,
+) -> Result<BigInt> {
+    let prime = &curve.prime;
+    ensure!(
+        prime.bits() <= NAIVE_MAXIMUM_BITS,
+        PointCountingLimitError(PointCountingMethod::Naive.to_string(), NAIVE_MAXIMUM_BITS)
+    );
+    let p_minus_one: BigInt = prime - 1;
+    let exponent = &p_minus_one >> 1;
+
+    let mut legendre_sum = BigInt::zero();
+    let mut x = BigInt::zero();
+    while &x < prime {
+        let value = curve.right_hand_side(&x);
+        if !value.is_zero() {
+            match number_theory_service
+                .fast_exponentiation(&value, &exponent, prime)
+                .is_one()
+            {
+                true => legendre_sum += 1,
+                false => legendre_sum -= 1,
+            }
+        }
+        x += 1;
+    }
+    Ok(prime + 1 + legendre_sum)
+}
+
+/// Bestimmt #E mit Babystep-Giantstep nach Mestre.
+///
+/// Für Punkte P auf E und auf dem Twist E' wird je ein Vielfaches der Ordnung im
+/// Hasse-Intervall [p + 1 - 2√p, p + 1 + 2√p] gesucht und daraus die genaue Ordnung bestimmt.
+/// Gesucht ist dann das einzige N im Intervall, das durch das kgV der Ordnungen auf E teilbar
+/// ist und für das 2p + 2 - N durch das kgV der Ordnungen auf E' teilbar ist. Nach Mestre hat für
+/// p > 229 die Kurve oder ihr Twist einen Punkt, der N eindeutig festlegt.
+///
+/// Die Punkte werden deterministisch mit aufsteigendem x gewählt.
+///
+/// # Fehler
+/// * `EllipticCurveError::PointCountingLimitError` - Falls p mehr als 64 Bit hat.
+/// * `EllipticCurveError::PointCountingFailedError` - Falls auch nach 20 Punkten je Kurve
+///   mehrere Kandidaten übrig sind, was nur für sehr kleine p vorkommt.
+pub fn count_points_mestre(
+    curve: &FiniteFieldEllipticCurve,
+    number_theory_service: NumberTheoryService,
+) -> Result<BigInt> {
+    let prime = &curve.prime;
+    ensure!(
+        prime.bits() <= BABY_STEP_GIANT_STEP_MAXIMUM_BITS,
+        PointCountingLimitError(
+            PointCountingMethod::BabyStepGiantStep.to_string(),
+            BABY_STEP_GIANT_STEP_MAXIMUM_BITS
+        )
+    );
+    let hasse_bound = (BigInt::from(4) * prime).sqrt();
+    let interval = HasseInterval {
+        lowest: prime + 1 - &hasse_bound,
+        width: BigInt::from(2) * hasse_bound,
+        sum_of_orders: BigInt::from(2) * (prime + 1),
+    };
+
+    let twist = curve.quadratic_twist(number_theory_service);
+    let mut curve_points = points_with_ascending_x(curve, number_theory_service);
+    let mut twist_points = points_with_ascending_x(&twist, number_theory_service);
+    let mut curve_lcm = BigInt::one();
+    let mut twist_lcm = BigInt::one();
+
+    for attempt in 0..2 * MESTRE_MAXIMUM_POINTS {
+        let (current_curve, points, lcm) = match attempt % 2 {
+            0 => (curve, &mut curve_points, &mut curve_lcm),
+            _ => (&twist, &mut twist_points, &mut twist_lcm),
+        };
+        let Some(point) = points.next() else {
+            continue;
+        };
+        let multiple = interval.multiple_of_order(&point, current_curve)?;
+        let order = exact_order(&point, &multiple, current_curve, number_theory_service)?;
+        *lcm = lcm.lcm(&order);
+
+        if let Some(candidates) = interval.candidates(&curve_lcm, &twist_lcm) {
+            if let [order] = candidates.as_slice() {
+                return Ok(order.clone());
+            }
+        }
+    }
+    bail!(PointCountingFailedError(curve.clone()))
+}
+
+/// Das Hasse-Intervall, in dem die Ordnungen von Kurve und Twist liegen. Es ist symmetrisch zu
+/// p + 1, mit #E liegt also auch #E' = 2p + 2 - #E darin.
+struct HasseInterval {
+    lowest: BigInt,
+    width: BigInt,
+    sum_of_orders: BigInt,
+}
+
+impl HasseInterval {
+    /// Sucht m im Intervall mit m · P = O. Babysteps sind j · P für 0 <= j <= s, Giantsteps
+    /// -(lowest + k · s) · P. Stimmen beide überein, ist m = lowest + k · s + j.
+    fn multiple_of_order(
+        &self,
+        point: &FiniteFieldEllipticCurvePoint,
+        curve: &FiniteFieldEllipticCurve,
+    ) -> Result<BigInt> {
+        let steps: BigInt = self.width.sqrt() + 1;
+        let step_count = steps.to_u64().unwrap();
+
+        let mut baby_steps = HashMap::new();
+        let mut baby_step = FiniteFieldEllipticCurvePoint::infinite();
+        for j in 0..=step_count {
+            baby_steps.entry(baby_step.clone()).or_insert(j);
+            baby_step = baby_step.add(point, curve)?;
+        }
+
+        let giant_step = point.multiply_with(&-&steps, curve, ScalarKind::Public)?;
+        let mut current = point.multiply_with(&-&self.lowest, curve, ScalarKind::Public)?;
+        for k in 0..=step_count {
+            if let Some(j) = baby_steps.get(&current) {
+                return Ok(&self.lowest + k * &steps + j);
+            }
+            current = current.add(&giant_step, curve)?;
+        }
+        bail!(PointCountingFailedError(curve.clone()))
+    }
+
+    /// Die Kandidaten N für #E im Intervall mit curve_lcm | N und twist_lcm | 2p + 2 - N.
+    /// Aufgezählt werden die Vielfachen des größeren kgV. Gibt `None` zurück, falls das zu
+    /// viele wären.
+    fn candidates(&self, curve_lcm: &BigInt, twist_lcm: &BigInt) -> Option<Vec<BigInt>> {
+        let (step, other) = match curve_lcm >= twist_lcm {
+            true => (curve_lcm, twist_lcm),
+            false => (twist_lcm, curve_lcm),
+        };
+        if (&self.width / step).to_u64()? > MESTRE_MAXIMUM_CANDIDATES {
+            return None;
+        }
+        let highest = &self.lowest + &self.width;
+        let mut multiple = self.lowest.div_ceil(step) * step;
+        let mut candidates = Vec::new();
+        while multiple <= highest {
+            let complement = &self.sum_of_orders - &multiple;
+            if complement.is_multiple_of(other) {
+                candidates.push(match curve_lcm >= twist_lcm {
+                    true => multiple.clone(),
+                    false => complement,
+                });
+            }
+            multiple += step;
+        }
+        Some(candidates)
+    }
+}
+
+/// Reduziert ein Vielfaches m der Ordnung von P auf die genaue Ordnung, indem Primfaktoren q von
+/// m abgespalten werden, solange (m / q) · P = O gilt.
+fn exact_order(
+    point: &FiniteFieldEllipticCurvePoint,
+    multiple: &BigInt,
+    curve: &FiniteFieldEllipticCurve,
+    number_theory_service: NumberTheoryService,
+) -> Result<BigInt> {
+    let factorization = Factorization::factorize(multiple, number_theory_service)?;
+    let mut order = multiple.clone();
+    for prime in factorization.primes() {
+        while order.is_multiple_of(prime)
+            && point
+                .multiply_with(&(&order / prime), curve, ScalarKind::Public)?
+                .is_infinite
+        {
+            order /= prime;
+        }
+    }
+    Ok(order)
+}
+
+/// Die Punkte (x, y) der Kurve mit aufsteigendem x, jeweils mit einer der beiden Wurzeln y.
+fn points_with_ascending_x(
+    curve: &FiniteFieldEllipticCurve,
+    number_theory_service: NumberTheoryService,
+) -> impl Iterator<Item = FiniteFieldEllipticCurvePoint> + '_ {
+    (0u64..)
+        .map(BigInt::from)
+        .take_while(|x| x < &curve.prime)
+        .filter_map(move |x| {
+            let y = modular_square_root(
+                &curve.right_hand_side(&x),
+                &curve.prime,
+                number_theory_service,
+            )
+            .ok()?;
+            Some(FiniteFieldEllipticCurvePoint::new(x, y))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+    use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    #[test]
+    fn test_naive_on_textbook_curve() {
+        let service = NumberTheoryService::new(Fast);
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        assert_eq!(
+            count_points_naive(&curve, service).unwrap(),
+            BigInt::from(19)
+        );
+        let twist = curve.quadratic_twist(service);
+        assert_eq!(
+            count_points_naive(&twist, service).unwrap(),
+            BigInt::from(17)
+        );
+    }
+
+    #[test]
+    fn test_all_methods_match_cm_order() {
+        // Die Kurven y^2 = x^3 - n^2 x haben nach Konstruktion die Ordnung 8q.
+        let service = NumberTheoryService::new(Fast);
+        for (n, width, methods) in [
+            (5, 12, &PointCountingMethod::ALL[..]),
+            (3, 16, &PointCountingMethod::ALL[..]),
+            (7, 32, &PointCountingMethod::ALL[1..]),
+        ] {
+            let curve = SecureFiniteFieldEllipticCurve::new(n, width, 40).unwrap();
+            let expected = BigInt::from(8) * &curve.order_of_subgroup;
+            for method in methods {
+                assert_eq!(
+                    method.count_points(&curve.curve, service).unwrap(),
+                    expected,
+                    "{} on {}",
+                    method,
+                    curve
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mestre_on_general_curve() {
+        // secp256k1 mit einem 40-Bit-Modul: y^2 = x^3 + 7 (mod 1099511627791)
+        let service = NumberTheoryService::new(Fast);
+        let curve =
+            FiniteFieldEllipticCurve::new(0.into(), 7.into(), 1099511627791u64.into()).unwrap();
+        let order = count_points_mestre(&curve, service).unwrap();
+        assert_eq!(count_points_schoof(&curve, service).unwrap(), order);
+        let point = points_with_ascending_x(&curve, service).next().unwrap();
+        assert!(point.multiply(&order, &curve).unwrap().is_infinite);
+    }
+
+    #[test]
+    fn test_limits_and_method_names() {
+        let service = NumberTheoryService::new(Fast);
+        let p256 = NamedCurve::P256.domain_parameters().curve;
+        assert!(count_points_naive(&p256, service).is_err());
+        assert!(count_points_mestre(&p256, service).is_err());
+
+        for method in PointCountingMethod::ALL {
+            assert_eq!(
+                method.to_string().parse::<PointCountingMethod>().unwrap(),
+                method
+            );
+        }
+        assert_eq!(
+            "Mestre".parse::<PointCountingMethod>().unwrap(),
+            PointCountingMethod::BabyStepGiantStep
+        );
+        assert!("sea".parse::<PointCountingMethod>().is_err());
+        assert!(PointCountingMethod::for_prime(&p256.prime).is_err());
+        assert!(PointCountingMethod::Schoof
+            .count_points(&p256, service)
+            .is_err());
+        assert_eq!(
+            PointCountingMethod::for_prime(&((BigInt::one() << 64) + 13)).unwrap(),
+            PointCountingMethod::Schoof
+        );
+        assert_eq!(
+            PointCountingMethod::for_prime(&17.into()).unwrap(),
+            PointCountingMethod::Naive
+        );
+    }
+}
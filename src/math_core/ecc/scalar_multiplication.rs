@@ -0,0 +1,297 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::jacobian_point::JacobianPoint;
+use crate::shared::errors::EllipticCurveError::{PointNotOnCurveError, ScalarOutOfRangeError};
+
+/// Fensterbreite des w-NAF für öffentliche Skalare. Es werden 2^(w-2) = 4 ungerade Vielfache
+/// vorberechnet.
+const WNAF_WIDTH: u32 = 4;
+
+/// Gibt an, ob ein Skalar geheim ist und wie er daher multipliziert wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalarKind {
+    /// Private Schlüssel und Nonces. Die Montgomery-Leiter führt unabhängig von den Bits des
+    /// Skalars stets dieselbe Folge von Additionen und Verdopplungen aus.
+    Secret,
+    /// Öffentlich bekannte Skalare, z.B. bei der Prüfung einer Signatur. Der w-NAF benötigt
+    /// weniger Additionen, deren Anzahl und Lage aber von den Bits abhängen.
+    Public,
+}
+
+/// Die Anzahl der Bits, über die die Montgomery-Leiter läuft. Sie hängt nur von der Kurve ab,
+/// sofern der Skalar wie üblich kleiner als die Gruppenordnung ist, die nach Hasse höchstens
+/// p + 1 + 2√p < 2p beträgt.
+fn ladder_length(scalar: &BigInt, curve: &FiniteFieldEllipticCurve) -> u64 {
+    (curve.prime.bits() + 1).max(scalar.bits())
+}
+
+/// Multipliziert mit der Montgomery-Leiter. In jedem Schritt wird genau eine Addition und eine
+/// Verdopplung ausgeführt. Invariante ist R1 - R0 = P. Die Bits werden durch Vertauschen
+/// statt durch Verzweigen berücksichtigt.
+///
+/// Die Arithmetik von `BigInt` selbst ist nicht zeitkonstant, die Folge der
+/// Gruppenoperationen verrät aber nichts mehr über den Skalar.
+///
+/// # Argumente
+/// * `point` - Der zu multiplizierende Punkt.
+/// * `scalar` - Der nicht negative Skalar.
+/// * `curve` - Die Kurve, auf der der Punkt liegt.
+pub fn montgomery_ladder(
+    point: &JacobianPoint,
+    scalar: &BigInt,
+    curve: &FiniteFieldEllipticCurve,
+) -> JacobianPoint {
+    let mut r0 = JacobianPoint::infinite();
+    let mut r1 = point.clone();
+    for i in (0..ladder_length(scalar, curve)).rev() {
+        let bit = scalar.bit(i);
+        conditional_swap(&mut r0, &mut r1, bit);
+        r1 = r0.add(&r1, curve);
+        r0 = r0.double(curve);
+        conditional_swap(&mut r0, &mut r1, bit);
+    }
+    r0
+}
+
+fn conditional_swap(r0: &mut JacobianPoint, r1: &mut JacobianPoint, swap: bool) {
+    let pair = [r0.clone(), r1.clone()];
+    *r0 = pair[swap as usize].clone();
+    *r1 = pair[1 - swap as usize].clone();
+}
+
+/// Berechnet die Darstellung des Skalars als w-NAF, beginnend mit der niederwertigsten Stelle.
+/// Jede von 0 verschiedene Ziffer ist ungerade mit |d| < 2^(w-1), und von je w aufeinander
+/// folgenden Ziffern ist höchstens eine von 0 verschieden.
+///
+/// # Argumente
+/// * `scalar` - Der nicht negative Skalar.
+/// * `width` - Die Fensterbreite w >= 2.
+pub fn wnaf(scalar: &BigInt, width: u32) -> Vec<i64> {
+    let modulus = BigInt::one() << width;
+    let half = BigInt::one() << (width - 1);
+    let mut k = scalar.clone();
+    let mut digits = Vec::new();
+    while k.is_positive() {
+        let mut digit = BigInt::zero();
+        if k.bit(0) {
+            digit = k.rem_euclid(&modulus);
+            if digit >= half {
+                digit -= &modulus;
+            }
+            k -= &digit;
+        }
+        digits.push(i64::try_from(digit).expect("Ziffern des w-NAF sind klein"));
+        k >>= 1;
+    }
+    digits
+}
+
+/// Multipliziert mit dem w-NAF des Skalars. Vorberechnet werden die ungeraden Vielfachen
+/// P, 3P, ..., (2^(w-1) - 1)P, negative Ziffern subtrahieren das entsprechende Vielfache.
+///
+/// # Argumente
+/// * `point` - Der zu multiplizierende Punkt.
+/// * `scalar` - Der nicht negative, öffentliche Skalar.
+/// * `curve` - Die Kurve, auf der der Punkt liegt.
+pub fn multiply_wnaf(
+    point: &JacobianPoint,
+    scalar: &BigInt,
+    curve: &FiniteFieldEllipticCurve,
+) -> JacobianPoint {
+    let doubled = point.double(curve);
+    let mut odd_multiples = vec![point.clone()];
+    for _ in 1..(1 << (WNAF_WIDTH - 2)) {
+        let next = odd_multiples.last().unwrap().add(&doubled, curve);
+        odd_multiples.push(next);
+    }
+
+    let mut result = JacobianPoint::infinite();
+    for digit in wnaf(scalar, WNAF_WIDTH).into_iter().rev() {
+        result = result.double(curve);
+        let index = (digit.unsigned_abs() / 2) as usize;
+        if digit > 0 {
+            result = result.add(&odd_multiples[index], curve);
+        } else if digit < 0 {
+            result = result.add(&odd_multiples[index].negate(curve), curve);
+        }
+    }
+    result
+}
+
+///
+/// Vorberechnete Tabelle für die Multiplikation eines festen Punktes, typischerweise des
+/// Generators, nach dem Kammverfahren von Lim und Lee.
+///
+/// Der Skalar wird in w Zeilen zu je d Bits zerlegt. Die Tabelle enthält für jede Spalte von
+/// w Bits die Summe der zugehörigen Punkte 2^(j·d) · P, sodass eine Multiplikation nur noch
+/// d Verdopplungen und d Additionen kostet. Da auch für eine leere Spalte addiert wird, ist die
+/// Folge der Operationen unabhängig vom Skalar.
+///
+#[derive(Clone, Debug)]
+pub struct FixedBaseComb {
+    curve: FiniteFieldEllipticCurve,
+    table: Vec<JacobianPoint>,
+    width: u32,
+    columns: u64,
+}
+
+impl FixedBaseComb {
+    /// Berechnet die Tabelle mit 2^`width` Einträgen.
+    ///
+    /// # Argumente
+    /// * `base` - Der feste Punkt.
+    /// * `curve` - Die Kurve, auf der der Punkt liegt.
+    /// * `width` - Die Anzahl der Zeilen w, zwischen 1 und 8.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der Punkt nicht auf der Kurve liegt.
+    pub fn new(
+        base: &FiniteFieldEllipticCurvePoint,
+        curve: &FiniteFieldEllipticCurve,
+        width: u32,
+    ) -> Result<Self> {
+        ensure!(
+            (1..=8).contains(&width),
+            "Die Breite des Kamms muss zwischen 1 und 8 liegen"
+        );
+        ensure!(
+            curve.has_point(base),
+            PointNotOnCurveError(base.clone(), curve.clone())
+        );
+        let columns = (curve.prime.bits() + 1).div_ceil(width as u64);
+
+        // rows[j] = 2^(j·d) · P
+        let mut rows = vec![JacobianPoint::from_affine(base, curve)];
+        for _ in 1..width {
+            let mut row = rows.last().unwrap().clone();
+            for _ in 0..columns {
+                row = row.double(curve);
+            }
+            rows.push(row);
+        }
+
+        let mut table = vec![JacobianPoint::infinite()];
+        for index in 1usize..(1 << width) {
+            let lowest_bit = index.trailing_zeros() as usize;
+            let entry = table[index & (index - 1)].add(&rows[lowest_bit], curve);
+            table.push(entry);
+        }
+
+        Ok(FixedBaseComb {
+            curve: curve.clone(),
+            table,
+            width,
+            columns,
+        })
+    }
+
+    /// Multipliziert den festen Punkt mit `scalar`.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::ScalarOutOfRangeError` - Falls der Skalar negativ ist oder mehr
+    ///   Bits hat, als die Tabelle abdeckt.
+    pub fn multiply(&self, scalar: &BigInt) -> Result<FiniteFieldEllipticCurvePoint> {
+        ensure!(
+            !scalar.is_negative() && scalar.bits() <= self.columns * self.width as u64,
+            ScalarOutOfRangeError(scalar.to_string())
+        );
+        let mut result = JacobianPoint::infinite();
+        for column in (0..self.columns).rev() {
+            result = result.double(&self.curve);
+            let index = (0..self.width as u64).fold(0usize, |index, row| {
+                index | ((scalar.bit(row * self.columns + column) as usize) << row)
+            });
+            result = result.add(&self.table[index], &self.curve);
+        }
+        Ok(result.to_affine(&self.curve))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+
+    fn get_curve() -> (FiniteFieldEllipticCurve, FiniteFieldEllipticCurvePoint) {
+        // y^2 = x^3 + 2x + 2 (mod 17), (5, 1) hat die Ordnung 19
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        (
+            curve,
+            FiniteFieldEllipticCurvePoint::new(5.into(), 1.into()),
+        )
+    }
+
+    #[test]
+    fn test_wnaf() {
+        // 7 = 8 - 1 und 1122 = 1024 + 3 · 32 + 2
+        assert_eq!(wnaf(&7.into(), 2), vec![-1, 0, 0, 1]);
+        assert_eq!(wnaf(&1122.into(), 3), vec![0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1]);
+        assert_eq!(wnaf(&BigInt::zero(), 4), Vec::<i64>::new());
+
+        for k in 0..500 {
+            let digits = wnaf(&k.into(), 4);
+            let value: i64 = digits
+                .iter()
+                .rev()
+                .fold(0, |value, digit| 2 * value + digit);
+            assert_eq!(value, k);
+            for window in digits.windows(4) {
+                assert!(window.iter().filter(|digit| **digit != 0).count() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ladder_and_wnaf_match_affine_multiplication() {
+        let (curve, generator) = get_curve();
+        let jacobian = JacobianPoint::from_affine(&generator, &curve);
+        for k in 0..45 {
+            let scalar = BigInt::from(k);
+            let expected = generator.multiply_affine(&scalar, &curve).unwrap();
+            assert_eq!(
+                montgomery_ladder(&jacobian, &scalar, &curve).to_affine(&curve),
+                expected
+            );
+            assert_eq!(
+                multiply_wnaf(&jacobian, &scalar, &curve).to_affine(&curve),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_comb() {
+        let (curve, generator) = get_curve();
+        for width in [1, 2, 3, 5] {
+            let comb = FixedBaseComb::new(&generator, &curve, width).unwrap();
+            for k in 0..38 {
+                let scalar = BigInt::from(k);
+                assert_eq!(
+                    comb.multiply(&scalar).unwrap(),
+                    generator.multiply_affine(&scalar, &curve).unwrap()
+                );
+            }
+            assert!(comb.multiply(&(-1).into()).is_err());
+            assert!(comb.multiply(&(BigInt::one() << 64)).is_err());
+        }
+
+        let parameters = NamedCurve::P256.domain_parameters();
+        let comb = FixedBaseComb::new(&parameters.generator, &parameters.curve, 4).unwrap();
+        let scalar = &parameters.order_of_subgroup - 1;
+        let expected = parameters
+            .generator
+            .multiply_with(&scalar, &parameters.curve, ScalarKind::Public)
+            .unwrap();
+        assert_eq!(comb.multiply(&scalar).unwrap(), expected);
+        assert!(
+            comb.multiply(&parameters.order_of_subgroup)
+                .unwrap()
+                .is_infinite
+        );
+    }
+}
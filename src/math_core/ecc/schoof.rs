@@ -0,0 +1,403 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, ToPrimitive};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
+use crate::math_core::finite_field_polynomial::{FiniteFieldPolynomial, PolynomialModulus};
+use crate::math_core::number_theory::congruence::Congruence;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EllipticCurveError::PointCountingFailedError;
+
+/// Bestimmt #E(F_p) = p + 1 - t mit dem Algorithmus von Schoof.
+///
+/// Für kleine Primzahlen l wird t mod l aus der charakteristischen Gleichung
+/// π^2 - t · π + p = 0 des Frobenius π(x, y) = (x^p, y^p) bestimmt, die auf den l-Torsionspunkten
+/// gilt. Gerechnet wird mit einem generischen l-Torsionspunkt im Ring F_p[x, y] / (ψ_l, y^2 - f).
+/// Sobald das Produkt der l größer als 4√p ist, legt der chinesische Restsatz t mit
+/// |t| <= 2√p eindeutig fest.
+///
+/// Die Laufzeit ist polynomiell in log p. Da für die Inversen im Ring weiterhin der euklidische
+/// Algorithmus mit quadratischer Laufzeit im Grad (l^2 - 1) / 2 verwendet wird, ist sie aber
+/// schon für Kurven mit mehr als etwa 100 Bit sehr lang.
+///
+/// # Argumente
+/// * `curve` - Die Kurve.
+/// * `number_theory_service` - Der Service für den chinesischen Restsatz.
+///
+/// # Fehler
+/// * `EllipticCurveError::PointCountingFailedError` - Falls für ein l keine Spur gefunden wird,
+///   was nur bei ungültigen Kurven passieren kann.
+pub fn count_points_schoof(
+    curve: &FiniteFieldEllipticCurve,
+    number_theory_service: NumberTheoryService,
+) -> Result<BigInt> {
+    let prime = &curve.prime;
+    let hasse_bound = BigInt::from(4) * (prime.sqrt() + 1);
+    let f = curve_polynomial(curve);
+
+    // t ist genau dann gerade, wenn E einen Punkt der Ordnung 2 hat, f also eine Nullstelle.
+    let x = FiniteFieldPolynomial::x(prime);
+    let trace_modulo_two = match x.pow_mod(prime, &f).sub(&x).gcd(&f).is_one() {
+        true => 1,
+        false => 0,
+    };
+    let mut congruences = vec![Congruence::new(&trace_modulo_two.into(), &2.into())?];
+    let mut product = BigInt::from(2);
+
+    let mut division_polynomials = DivisionPolynomials::new(curve);
+    for l in (3u64..).step_by(2).filter(|l| is_small_prime(*l)) {
+        if product > hasse_bound {
+            break;
+        }
+        if prime == &BigInt::from(l) {
+            continue;
+        }
+        let division_polynomial = division_polynomials.get(l as usize).clone();
+        let trace = trace_modulo_prime(curve, l, &division_polynomial)?;
+        congruences.push(Congruence::new(&trace.into(), &l.into())?);
+        product *= l;
+    }
+
+    let solution = number_theory_service.chinese_remainder(&congruences)?;
+    let trace = match solution.remainder > (&solution.modulus >> 1) {
+        true => solution.remainder - solution.modulus,
+        false => solution.remainder,
+    };
+    Ok(prime + 1 - trace)
+}
+
+/// Die rechte Seite f(x) = x^3 + ax + b der Kurvengleichung als Polynom.
+fn curve_polynomial(curve: &FiniteFieldEllipticCurve) -> FiniteFieldPolynomial {
+    FiniteFieldPolynomial::new(
+        vec![
+            curve.b.clone(),
+            curve.a.clone(),
+            BigInt::zero(),
+            BigInt::one(),
+        ],
+        &curve.prime,
+    )
+}
+
+fn is_small_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+/// Bestimmt t mod l. Zerfällt ψ_l, kann bei der Rechnung ein Nullteiler auftreten. Dann wird
+/// mit dem kleineren der beiden gefundenen Faktoren weitergerechnet, denn die Gleichung für t
+/// lässt sich auf jeder nichtleeren Menge von l-Torsionspunkten prüfen.
+fn trace_modulo_prime(
+    curve: &FiniteFieldEllipticCurve,
+    l: u64,
+    division_polynomial: &FiniteFieldPolynomial,
+) -> Result<u64> {
+    let mut modulus = division_polynomial.monic();
+    loop {
+        match TorsionRing::new(curve, modulus.clone()).frobenius_trace(l) {
+            Ok(trace) => {
+                ensure!(trace.is_some(), PointCountingFailedError(curve.clone()));
+                return Ok(trace.unwrap());
+            }
+            Err(ZeroDivisor(factor)) => {
+                let cofactor = modulus.div_rem(&factor).0.monic();
+                modulus = match factor.degree() <= cofactor.degree() {
+                    true => factor,
+                    false => cofactor,
+                };
+            }
+        }
+    }
+}
+
+/// Die Divisionspolynome ψ_n, deren Nullstellen die x-Koordinaten der Punkte P ≠ O mit
+/// n · P = O sind. Für gerade n enthält ψ_n den Faktor y, gespeichert wird dann ψ_n / y.
+/// Mit y^2 = f(x) sind so alle Polynome nur in x.
+struct DivisionPolynomials {
+    polynomials: Vec<FiniteFieldPolynomial>,
+    f_squared: FiniteFieldPolynomial,
+    half: BigInt,
+}
+
+impl DivisionPolynomials {
+    fn new(curve: &FiniteFieldEllipticCurve) -> Self {
+        let prime = &curve.prime;
+        let (a, b) = (&curve.a, &curve.b);
+        let f = curve_polynomial(curve);
+        let polynomial =
+            |coefficients: Vec<BigInt>| FiniteFieldPolynomial::new(coefficients, prime);
+
+        // ψ_3 = 3x^4 + 6ax^2 + 12bx - a^2
+        let psi_3 = polynomial(vec![
+            -(a * a),
+            BigInt::from(12) * b,
+            BigInt::from(6) * a,
+            BigInt::zero(),
+            BigInt::from(3),
+        ]);
+        // ψ_4 / y = 4 (x^6 + 5ax^4 + 20bx^3 - 5a^2x^2 - 4abx - 8b^2 - a^3)
+        let psi_4 = polynomial(vec![
+            BigInt::from(-8) * b * b - a * a * a,
+            BigInt::from(-4) * a * b,
+            BigInt::from(-5) * a * a,
+            BigInt::from(20) * b,
+            BigInt::from(5) * a,
+            BigInt::zero(),
+            BigInt::one(),
+        ])
+        .scale(&4.into());
+
+        DivisionPolynomials {
+            polynomials: vec![
+                FiniteFieldPolynomial::zero(prime),
+                polynomial(vec![BigInt::one()]),
+                polynomial(vec![BigInt::from(2)]),
+                psi_3,
+                psi_4,
+            ],
+            f_squared: f.mul(&f),
+            half: (prime + 1) >> 1,
+        }
+    }
+
+    fn get(&mut self, n: usize) -> &FiniteFieldPolynomial {
+        while self.polynomials.len() <= n {
+            let next = self.next();
+            self.polynomials.push(next);
+        }
+        &self.polynomials[n]
+    }
+
+    /// Berechnet ψ_n für n = len >= 5 aus den Rekursionen
+    /// ψ_(2m+1) = ψ_(m+2) ψ_m^3 - ψ_(m-1) ψ_(m+1)^3 und
+    /// ψ_(2m) = ψ_m (ψ_(m+2) ψ_(m-1)^2 - ψ_(m-2) ψ_(m+1)^2) / (2y).
+    /// Je zwei der Faktoren y ergeben dabei f(x).
+    fn next(&self) -> FiniteFieldPolynomial {
+        let n = self.polynomials.len();
+        let m = n / 2;
+        let psi = |i: usize| &self.polynomials[i];
+        let cube = |p: &FiniteFieldPolynomial| p.mul(p).mul(p);
+
+        if n % 2 == 1 {
+            let first = psi(m + 2).mul(&cube(psi(m)));
+            let second = psi(m - 1).mul(&cube(psi(m + 1)));
+            match m.is_multiple_of(2) {
+                true => first.mul(&self.f_squared).sub(&second),
+                false => first.sub(&second.mul(&self.f_squared)),
+            }
+        } else {
+            let first = psi(m + 2).mul(&psi(m - 1).mul(psi(m - 1)));
+            let second = psi(m - 2).mul(&psi(m + 1).mul(psi(m + 1)));
+            psi(m).mul(&first.sub(&second)).scale(&self.half)
+        }
+    }
+}
+
+/// Ein nichttrivialer Faktor des Moduls, der bei einer Division im Ring gefunden wurde.
+struct ZeroDivisor(FiniteFieldPolynomial);
+
+/// Ein Punkt mit Koordinaten im Ring F_p[x, y] / (h(x), y^2 - f(x)). Die y-Koordinate ist stets
+/// ein Vielfaches von y und wird durch diesen Faktor dargestellt.
+#[derive(Clone, PartialEq)]
+enum TorsionPoint {
+    Infinite,
+    Affine {
+        x: FiniteFieldPolynomial,
+        y: FiniteFieldPolynomial,
+    },
+}
+
+struct TorsionRing<'a> {
+    curve: &'a FiniteFieldEllipticCurve,
+    modulus: PolynomialModulus,
+    f: FiniteFieldPolynomial,
+}
+
+impl<'a> TorsionRing<'a> {
+    fn new(curve: &'a FiniteFieldEllipticCurve, modulus: FiniteFieldPolynomial) -> Self {
+        let f = curve_polynomial(curve).rem(&modulus);
+        TorsionRing {
+            curve,
+            modulus: PolynomialModulus::new(&modulus),
+            f,
+        }
+    }
+
+    fn mul(&self, a: &FiniteFieldPolynomial, b: &FiniteFieldPolynomial) -> FiniteFieldPolynomial {
+        self.modulus.mul(a, b)
+    }
+
+    fn divide(
+        &self,
+        numerator: &FiniteFieldPolynomial,
+        denominator: &FiniteFieldPolynomial,
+    ) -> Result<FiniteFieldPolynomial, ZeroDivisor> {
+        match denominator.inverse_mod(self.modulus.modulus()) {
+            Some(inverse) => Ok(self.mul(numerator, &inverse)),
+            None => Err(ZeroDivisor(denominator.gcd(self.modulus.modulus()))),
+        }
+    }
+
+    /// Berechnet t mod l. Mit dem generischen Punkt P = (x, y) wird π^2(P) + (p mod l) · P mit
+    /// den Vielfachen τ · π(P) verglichen, wobei die Vorzeichen über die y-Koordinate
+    /// unterschieden werden.
+    fn frobenius_trace(&self, l: u64) -> Result<Option<u64>, ZeroDivisor> {
+        let prime = &self.curve.prime;
+        let x = self.modulus.reduce(&FiniteFieldPolynomial::x(prime));
+        let x_p = self.modulus.pow(&x, prime);
+        let y_p = self.modulus.pow(&self.f, &((prime - 1) >> 1));
+        // y^(p^2) = y · f^((p^2 - 1) / 2) = y · (f^((p - 1) / 2))^(p + 1)
+        let x_pp = self.modulus.pow(&x_p, prime);
+        let y_pp = self.modulus.pow(&y_p, &(prime + 1));
+
+        let frobenius = TorsionPoint::Affine { x: x_p, y: y_p };
+        let frobenius_squared = TorsionPoint::Affine { x: x_pp, y: y_pp };
+        let generic = TorsionPoint::Affine {
+            x,
+            y: FiniteFieldPolynomial::constant(1.into(), prime),
+        };
+        let p_modulo_l = prime.rem_euclid(&l.into()).to_u64().unwrap();
+
+        let sum = self.add(&frobenius_squared, &self.multiply(&generic, p_modulo_l)?)?;
+        let (sum_x, sum_y) = match &sum {
+            TorsionPoint::Infinite => return Ok(Some(0)),
+            TorsionPoint::Affine { x, y } => (x, y),
+        };
+        let mut multiple = frobenius.clone();
+        for tau in 1..=(l - 1) / 2 {
+            if let TorsionPoint::Affine { x, y } = &multiple {
+                if x == sum_x {
+                    return Ok(Some(if y == sum_y { tau } else { l - tau }));
+                }
+            }
+            multiple = self.add(&multiple, &frobenius)?;
+        }
+        Ok(None)
+    }
+
+    fn add(&self, p: &TorsionPoint, q: &TorsionPoint) -> Result<TorsionPoint, ZeroDivisor> {
+        let ((x1, y1), (x2, y2)) = match (p, q) {
+            (TorsionPoint::Infinite, _) => return Ok(q.clone()),
+            (_, TorsionPoint::Infinite) => return Ok(p.clone()),
+            (TorsionPoint::Affine { x: x1, y: y1 }, TorsionPoint::Affine { x: x2, y: y2 }) => {
+                ((x1, y1), (x2, y2))
+            }
+        };
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(p);
+            }
+            if y1.add(y2).is_zero() {
+                return Ok(TorsionPoint::Infinite);
+            }
+            // Aus y1^2 = y2^2 folgt (y1 - y2)(y1 + y2) = 0, beide Faktoren sind Nullteiler.
+            return Err(ZeroDivisor(y1.sub(y2).gcd(self.modulus.modulus())));
+        }
+        // Die Steigung ist λ · y mit λ = (y2 - y1) / (x2 - x1).
+        let slope = self.divide(&y2.sub(y1), &x2.sub(x1))?;
+        Ok(self.point_from_slope(&slope, x1, y1, x2))
+    }
+
+    fn double(&self, p: &TorsionPoint) -> Result<TorsionPoint, ZeroDivisor> {
+        let (x, y) = match p {
+            TorsionPoint::Infinite => return Ok(TorsionPoint::Infinite),
+            TorsionPoint::Affine { x, y } if y.is_zero() => return Ok(TorsionPoint::Infinite),
+            TorsionPoint::Affine { x, y } => (x, y),
+        };
+        // Die Steigung (3x^2 + a) / (2y) ist wegen 1 / y = y / f gleich λ · y mit
+        // λ = (3x^2 + a) / (2 · y · f).
+        let numerator = self
+            .mul(x, x)
+            .scale(&3.into())
+            .add(&FiniteFieldPolynomial::constant(
+                self.curve.a.clone(),
+                &self.curve.prime,
+            ));
+        let denominator = self.mul(y, &self.f).scale(&2.into());
+        let slope = self.divide(&numerator, &denominator)?;
+        Ok(self.point_from_slope(&slope, x, y, x))
+    }
+
+    /// x3 = λ^2 · f - x1 - x2 und y3 = λ (x1 - x3) - y1, jeweils ohne den Faktor y.
+    fn point_from_slope(
+        &self,
+        slope: &FiniteFieldPolynomial,
+        x1: &FiniteFieldPolynomial,
+        y1: &FiniteFieldPolynomial,
+        x2: &FiniteFieldPolynomial,
+    ) -> TorsionPoint {
+        let x3 = self.mul(&self.mul(slope, slope), &self.f).sub(x1).sub(x2);
+        let y3 = self.mul(slope, &x1.sub(&x3)).sub(y1);
+        TorsionPoint::Affine { x: x3, y: y3 }
+    }
+
+    fn multiply(&self, p: &TorsionPoint, scalar: u64) -> Result<TorsionPoint, ZeroDivisor> {
+        let mut result = TorsionPoint::Infinite;
+        for i in (0..u64::BITS - scalar.leading_zeros()).rev() {
+            result = self.double(&result)?;
+            if scalar >> i & 1 == 1 {
+                result = self.add(&result, p)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+
+    #[test]
+    fn test_division_polynomials_vanish_on_torsion_points() {
+        // Auf y^2 = x^3 + 2x + 2 (mod 17) haben alle Punkte außer O die Ordnung 19.
+        let curve = FiniteFieldEllipticCurve::new(2.into(), 2.into(), 17.into()).unwrap();
+        let generator = FiniteFieldEllipticCurvePoint::new(5.into(), 1.into());
+        let mut division_polynomials = DivisionPolynomials::new(&curve);
+        assert_eq!(division_polynomials.get(19).degree(), Some(180));
+        for k in 1..19 {
+            let point = generator.multiply(&k.into(), &curve).unwrap();
+            assert!(division_polynomials.get(19).evaluate(&point.x).is_zero());
+            for n in 1..19 {
+                assert!(!division_polynomials.get(n).evaluate(&point.x).is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_points_above_64_bits() {
+        // Für p = 2^64 + 13 liegt die Ordnung jenseits der Grenze von Babystep-Giantstep und wird
+        // daher über ein Vielfaches eines Punktes und die Hasse-Schranke geprüft.
+        let service = NumberTheoryService::new(Fast);
+        let prime: BigInt = (BigInt::one() << 64) + 13;
+        let curve = FiniteFieldEllipticCurve::new(3.into(), 5.into(), prime.clone()).unwrap();
+        let order = count_points_schoof(&curve, service).unwrap();
+        assert_eq!(order, "18446744070948299871".parse::<BigInt>().unwrap());
+
+        let trace: BigInt = &prime + 1 - &order;
+        assert!(&trace * &trace <= BigInt::from(4) * &prime);
+        // (1, 3) liegt wegen 1 + 3 + 5 = 3^2 auf der Kurve.
+        let point = FiniteFieldEllipticCurvePoint::new(1.into(), 3.into());
+        assert!(point.multiply(&order, &curve).unwrap().is_infinite);
+    }
+
+    #[test]
+    fn test_count_points_on_small_curves() {
+        let service = NumberTheoryService::new(Fast);
+        // y^2 = x^3 + 2x + 2 (mod 17) hat 19 Punkte, y^2 = x^3 + x + 1 (mod 5) hat 9 Punkte
+        // und y^2 = x^3 - x (mod 13) hat 8 Punkte, darunter drei der Ordnung 2.
+        for (a, b, prime, order) in [(2, 2, 17, 19), (1, 1, 5, 9), (-1, 0, 13, 8)] {
+            let curve = FiniteFieldEllipticCurve::new(a.into(), b.into(), prime.into()).unwrap();
+            assert_eq!(
+                count_points_schoof(&curve, service).unwrap(),
+                BigInt::from(order)
+            );
+        }
+    }
+}
@@ -6,34 +6,38 @@ use anyhow::{ensure, Context, Result};
 use atomic_counter::RelaxedCounter;
 use bigdecimal::num_bigint::BigInt;
 use bigdecimal::num_traits::Euclid;
-use bigdecimal::{One, Signed, Zero};
+use bigdecimal::{One, Zero};
 use log::warn;
 use num::Integer;
 
-use crate::math_core::complex_number::{complex_euclidean_algorithm, ComplexNumber};
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::gaussian_integer::{sum_of_two_squares, GaussianInteger};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
 use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::EllipticCurveError::{
+    InvalidPointEncodingError, PointNotInSubgroupError,
+};
 
 ///
 /// Repräsentiert eine elliptische Kurve mit einer zyklischen Untergruppe, in der das
 /// Problem des diskreten Logarithmus praktisch nicht lösbar ist.
 ///
-/// Die elliptische Kurve ist über die Gleichung y^2 = x^3 + a · x definiert.
-/// Dabei ist a = -n^2, wobei n eine Ganzzahl ungleich 0 ist.
-/// Um die Kurve über einem endlichen Körper zu definieren, wird auch der Modulus p benötigt.
+/// Die Kurve selbst ist eine allgemeine Kurve y^2 = x^3 + a · x + b über F_p. Der Konstruktor
+/// `new` erzeugt Kurven der Familie y^2 = x^3 - n^2 · x, deren Ordnung sich direkt aus der
+/// Zerlegung von p in den gaußschen Zahlen ergibt.
 ///
 #[derive(Clone, PartialEq, Debug)]
 pub struct SecureFiniteFieldEllipticCurve {
-    /// Der Koeffizient a der elliptischen Kurve
-    pub a: i64,
-    /// Der Modulus p der elliptischen Kurve, um sie über einem endlichen Körper zu definieren
-    pub prime: BigInt,
+    /// Die elliptische Kurve, auf der die Untergruppe liegt
+    pub curve: FiniteFieldEllipticCurve,
     /// Die Ordnung der zyklischen Untergruppe / des Generators, in welcher das Problem des
     /// diskreten Logarithmus praktisch nicht lösbar ist
     pub order_of_subgroup: BigInt,
@@ -43,11 +47,7 @@ pub struct SecureFiniteFieldEllipticCurve {
 
 impl Display for SecureFiniteFieldEllipticCurve {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "y^2 = x^3 + {} * x (mod {}),\nGenerator: {}",
-            self.a, self.prime, self.generator
-        )
+        write!(f, "{},\nGenerator: {}", self.curve, self.generator)
     }
 }
 
@@ -55,8 +55,11 @@ impl From<EllipticCurveBean> for SecureFiniteFieldEllipticCurve {
     /// Mapped die Bean in das Domain-Modell
     fn from(curve: EllipticCurveBean) -> Self {
         SecureFiniteFieldEllipticCurve {
-            a: curve.a,
-            prime: curve.prime.parse().unwrap(),
+            curve: FiniteFieldEllipticCurve {
+                a: curve.a.parse().unwrap(),
+                b: curve.b.parse().unwrap(),
+                prime: curve.prime.parse().unwrap(),
+            },
             order_of_subgroup: curve.order_of_subgroup.parse().unwrap(),
             generator: FiniteFieldEllipticCurvePoint::from(curve.generator),
         }
@@ -106,15 +109,7 @@ impl SecureFiniteFieldEllipticCurve {
             let (prime, order_of_subgroup) =
                 Self::calculate_p_and_q(&prime, n, miller_rabin_iterations);
 
-            let curve = Self {
-                a,
-                prime: prime.clone(),
-                order_of_subgroup: order_of_subgroup.clone(),
-                generator: Default::default(),
-            };
-            // Hier kann die Kurve mit dem Defaultwert für den Generator mitgegeben werden, weil dieser
-            // für das Multiplizieren in der Bestimmung des Generators nicht notwendig ist.
-            // Anschließend wird die Kurve mit dem Generator neu erstellt.
+            let curve = FiniteFieldEllipticCurve::congruent_number_curve(&n.into(), &prime)?;
             let generator = Self::calculate_signature_generator(
                 &prime,
                 a,
@@ -125,13 +120,12 @@ impl SecureFiniteFieldEllipticCurve {
             .context("Error while calculating signature generator")?;
 
             let curve = Self {
-                a,
-                prime,
+                curve,
                 order_of_subgroup,
                 generator,
             };
 
-            if curve.has_point(&curve.generator) {
+            if curve.curve.has_point(&curve.generator) {
                 return Ok(curve);
             }
             warn!(
@@ -141,6 +135,28 @@ impl SecureFiniteFieldEllipticCurve {
         }
     }
 
+    /// Dekodiert einen öffentlichen Punkt aus der SEC1-Kodierung und stellt sicher, dass er
+    /// in der vom Generator erzeugten Untergruppe liegt. Bei Kurven mit Kofaktor > 1 wehrt das
+    /// Angriffe mit Punkten kleiner Ordnung ab.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidPointEncodingError` - Falls die Kodierung ungültig ist
+    ///   oder den Punkt im Unendlichen darstellt.
+    /// * `EllipticCurveError::PointNotOnCurveError` - Falls der Punkt nicht auf der Kurve liegt.
+    /// * `EllipticCurveError::PointNotInSubgroupError` - Falls q · P nicht der Punkt im
+    ///   Unendlichen ist.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<FiniteFieldEllipticCurvePoint> {
+        let point = FiniteFieldEllipticCurvePoint::from_sec1(bytes, &self.curve)?;
+        ensure!(
+            !point.is_infinite,
+            InvalidPointEncodingError("the infinite point is not a valid public point".to_string())
+        );
+        let multiple =
+            point.multiply_with(&self.order_of_subgroup, &self.curve, ScalarKind::Public)?;
+        ensure!(multiple.is_infinite, PointNotInSubgroupError(point));
+        Ok(point)
+    }
+
     pub fn calculate_p_and_q(
         prime: &BigInt,
         n: i64,
@@ -186,23 +202,14 @@ impl SecureFiniteFieldEllipticCurve {
     }
 
     fn calculate_big_n(prime: &BigInt, n: i64) -> BigInt {
-        let first_complex_number = ComplexNumber::new(prime.clone(), BigInt::zero());
-        let second_complex_number =
-            ComplexNumber::new(Self::calculate_w(&prime, 2.into()), BigInt::one());
-        let gg_t: ComplexNumber =
-            complex_euclidean_algorithm(first_complex_number, second_complex_number);
-
-        // Der Realteil von alpha ist immer der ungerade Anteil des ggT von p und W(p, 2)
-        // dadurch, dass das obige Verfahren immer einen geraden und ungeraden Anteil liefert,
-        // lässt sich alpha problemlos bestimmen. D.W. muss alpha die Absolutwerte enthalten.
-        let alpha: ComplexNumber;
-        if gg_t.real.is_even() {
-            alpha = ComplexNumber::new(gg_t.imaginary.clone().abs(), gg_t.real.clone().abs());
-        } else {
-            alpha = ComplexNumber::new(gg_t.real.clone().abs(), gg_t.imaginary.clone().abs());
-        }
+        let service = NumberTheoryService::new(Fast); // TODO übergeben lassen
+                                                      // Wegen p ≡ 1 mod 4 zerfällt p in Z[i] als p = alpha * konj(alpha) mit
+                                                      // alpha = x + yi und p = x^2 + y^2. Cornacchia liefert x ungerade und x, y > 0.
+        let (real, imaginary) = sum_of_two_squares(prime, service)
+            .expect("Eine Primzahl p ≡ 5 mod 8 ist eine Summe zweier Quadrate");
+        let alpha = GaussianInteger::new(real, imaginary);
 
-        prime.increment() - Self::calculate_real_part(alpha, &prime, n).double()
+        prime.increment() - Self::calculate_real_part(alpha, prime, n).double()
     }
 
     pub fn calculate_w(prime: &BigInt, z: BigInt) -> BigInt {
@@ -223,14 +230,14 @@ impl SecureFiniteFieldEllipticCurve {
         w
     }
 
-    pub fn calculate_real_part(alpha: ComplexNumber, prime: &BigInt, n: i64) -> BigInt {
+    pub fn calculate_real_part(alpha: GaussianInteger, prime: &BigInt, n: i64) -> BigInt {
         let mut count = 4;
         let mut alpha = alpha.clone();
         // Schleife, die alle möglichen Konjugationen von alpha durchgeht
         loop {
             let complex_legendre_symbol =
-                ComplexNumber::new(Self::calculate_legendre_symbol(&n.into(), prime), 0.into());
-            let two_two = ComplexNumber::new(2.into(), 2.into());
+                GaussianInteger::new(Self::calculate_legendre_symbol(&n.into(), prime), 0.into());
+            let two_two = GaussianInteger::new(2.into(), 2.into());
             // Produkt aus der Differenz von alpha und dessen Legendre-Symbol und dem konjugierten Wert von 2 + 2i
             let product = (&alpha - &complex_legendre_symbol) * two_two.conjugate();
 
@@ -307,7 +314,7 @@ impl SecureFiniteFieldEllipticCurve {
         prime: &BigInt,
         a: i64,
         q: &BigInt,
-        curve: &SecureFiniteFieldEllipticCurve,
+        curve: &FiniteFieldEllipticCurve,
         counter: &RelaxedCounter,
     ) -> Result<FiniteFieldEllipticCurvePoint> {
         let mut generator: FiniteFieldEllipticCurvePoint;
@@ -359,17 +366,6 @@ impl SecureFiniteFieldEllipticCurve {
         }
         Ok(generator)
     }
-
-    /// Überprüft, ob ein Punkt auf der elliptischen Kurve liegt.
-    pub fn has_point(&self, point: &FiniteFieldEllipticCurvePoint) -> bool {
-        let x_squared = &point.x.pow(2);
-        let x_cubed = &point.x * x_squared;
-        let y_squared = point.y.pow(2);
-
-        // y^2 = x^3 + ax (mod p) ist äquivalent zu (x^3 + ax - y^2) % p == 0
-        let remainder = (x_cubed + &self.a * &point.x - y_squared).rem_euclid(&self.prime);
-        remainder == BigInt::zero()
-    }
 }
 
 #[cfg(test)]
@@ -414,21 +410,21 @@ mod tests {
         let curve = SecureFiniteFieldEllipticCurve::new(7, 17, 20).unwrap();
         let point = FiniteFieldEllipticCurvePoint::new(5.into(), 7.into());
         // (5, 7) liegt nicht auf y^2 = x^3 + 7 (mod 17)
-        assert!(!curve.has_point(&point));
+        assert!(!curve.curve.has_point(&point));
 
         let point = FiniteFieldEllipticCurvePoint::new(4.into(), 6.into());
         // (4, 6) liegt nicht auf y^2 = x^3 + 7 (mod 17). Genaugenommen tut es keiner mit x=4.
-        assert!(!curve.has_point(&point));
+        assert!(!curve.curve.has_point(&point));
     }
 
     #[test]
     fn test_has_point() {
         let curve = SecureFiniteFieldEllipticCurve::new(5, 16, 40).unwrap();
-        let point = curve.generator.multiply(&3.into(), &curve).unwrap();
-        assert!(curve.has_point(&point));
+        let point = curve.generator.multiply(&3.into(), &curve.curve).unwrap();
+        assert!(curve.curve.has_point(&point));
 
         let point = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
-        assert!(curve.has_point(&point));
+        assert!(curve.curve.has_point(&point));
     }
 
     #[test]
@@ -472,11 +468,31 @@ mod tests {
     #[test]
     fn test_has_point_on_curve_with_negative_n() {
         let curve = SecureFiniteFieldEllipticCurve::new(-3, 17, 40).unwrap();
-        let point = curve.generator.multiply(&3.into(), &curve).unwrap();
-        assert!(curve.has_point(&point));
+        let point = curve.generator.multiply(&3.into(), &curve.curve).unwrap();
+        assert!(curve.curve.has_point(&point));
 
         let point = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
-        assert!(curve.has_point(&point));
+        assert!(curve.curve.has_point(&point));
+    }
+
+    #[test]
+    fn test_decode_point() {
+        let curve = SecureFiniteFieldEllipticCurve::new(5, 32, 40).unwrap();
+        let point = curve
+            .generator
+            .multiply(&12345.into(), &curve.curve)
+            .unwrap();
+        for compressed in [true, false] {
+            let bytes = point.to_sec1(&curve.curve, compressed).unwrap();
+            assert_eq!(curve.decode_point(&bytes).unwrap(), point);
+        }
+
+        // (0, 0) liegt auf jeder Kurve y^2 = x^3 - n^2 x, hat aber die Ordnung 2.
+        let point_of_order_two = FiniteFieldEllipticCurvePoint::new(0.into(), 0.into());
+        let bytes = point_of_order_two.to_sec1(&curve.curve, false).unwrap();
+        assert!(FiniteFieldEllipticCurvePoint::from_sec1(&bytes, &curve.curve).is_ok());
+        assert!(curve.decode_point(&bytes).is_err());
+        assert!(curve.decode_point(&[0x00]).is_err());
     }
 
     #[test]
@@ -0,0 +1,484 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Context, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::ecc::montgomery_curve::MontgomeryCurve;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::number_theory::factorization::is_prime;
+use crate::math_core::number_theory::modular_square_root::modular_square_root;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::EllipticCurveError::{
+    DegenerateCurveCoefficientsError, InvalidEdwardsPointEncodingError, InvalidFieldPrimeError,
+    ScalarOutOfRangeError,
+};
+
+/// Ein Punkt (x, y) auf einer getwisteten Edwards-Kurve. Das neutrale Element ist (0, 1), einen
+/// Punkt im Unendlichen gibt es nicht.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct EdwardsPoint {
+    pub x: BigInt,
+    pub y: BigInt,
+}
+
+impl Display for EdwardsPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl EdwardsPoint {
+    pub fn new(x: BigInt, y: BigInt) -> Self {
+        EdwardsPoint { x, y }
+    }
+
+    /// Das neutrale Element (0, 1).
+    pub fn neutral() -> Self {
+        EdwardsPoint::new(BigInt::zero(), BigInt::one())
+    }
+
+    pub fn is_neutral(&self) -> bool {
+        self.x.is_zero() && self.y.is_one()
+    }
+}
+
+///
+/// Repräsentiert eine getwistete Edwards-Kurve a · x^2 + y^2 = 1 + d · x^2 · y^2 über dem
+/// endlichen Körper F_p.
+///
+/// Ist a ein Quadrat und d keines modulo p, sind die Additionsformeln vollständig: Sie gelten
+/// ohne Fallunterscheidung für alle Punktpaare, also auch für Verdopplungen, inverse Punkte und
+/// das neutrale Element. Über `to_montgomery` ist die Kurve birational äquivalent zu einer
+/// Montgomery-Kurve und damit auch zu einer Kurve in kurzer Weierstraß-Form.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct TwistedEdwardsCurve {
+    /// Der Koeffizient a, bei edwards25519 ist a = -1
+    pub a: BigInt,
+    /// Der Koeffizient d
+    pub d: BigInt,
+    /// Der Modulus p, über dessen Restklassenkörper die Kurve definiert ist
+    pub prime: BigInt,
+}
+
+impl Display for TwistedEdwardsCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} * x^2 + y^2 = 1 + {} * x^2 * y^2 (mod {})",
+            self.a, self.d, self.prime
+        )
+    }
+}
+
+impl TwistedEdwardsCurve {
+    /// Erstellt eine neue getwistete Edwards-Kurve a · x^2 + y^2 = 1 + d · x^2 · y^2 (mod p).
+    ///
+    /// # Argumente
+    /// * `a` - Der Koeffizient a.
+    /// * `d` - Der Koeffizient d.
+    /// * `prime` - Der Modulus p.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidFieldPrimeError` - Falls p keine Primzahl größer 3 ist.
+    /// * `EllipticCurveError::DegenerateCurveCoefficientsError` - Falls a · d · (a - d) ≡ 0 ist,
+    ///   die Kurve also singulär ist.
+    pub fn new(a: BigInt, d: BigInt, prime: BigInt) -> Result<Self> {
+        ensure!(
+            prime > BigInt::from(3) && is_prime(&prime, NumberTheoryService::new(Fast)),
+            InvalidFieldPrimeError(prime.to_string())
+        );
+        ensure!(
+            !(&a * &d * (&a - &d)).rem_euclid(&prime).is_zero(),
+            DegenerateCurveCoefficientsError(a.to_string(), d.to_string())
+        );
+        Ok(TwistedEdwardsCurve { a, d, prime })
+    }
+
+    /// Die Kurve edwards25519 aus RFC 8032 mit a = -1 und d = -121665 / 121666 über
+    /// p = 2^255 - 19. Sie ist birational äquivalent zu Curve25519.
+    pub fn ed25519() -> Self {
+        let prime = (BigInt::one() << 255) - 19;
+        let inverse = NumberTheoryService::new(Fast)
+            .modulo_inverse(&BigInt::from(121666), &prime)
+            .unwrap();
+        TwistedEdwardsCurve {
+            a: BigInt::from(-1),
+            d: (BigInt::from(-121665) * inverse).rem_euclid(&prime),
+            prime,
+        }
+    }
+
+    /// Prüft, ob der Punkt die Kurvengleichung erfüllt.
+    pub fn has_point(&self, point: &EdwardsPoint) -> bool {
+        let x_squared = &point.x * &point.x;
+        let y_squared = &point.y * &point.y;
+        (&self.a * &x_squared + &y_squared - BigInt::one() - &self.d * x_squared * y_squared)
+            .rem_euclid(&self.prime)
+            .is_zero()
+    }
+
+    /// Ob die Additionsformeln vollständig sind, also a ein quadratischer Rest und d ein
+    /// quadratischer Nichtrest modulo p ist.
+    pub fn is_complete(&self) -> bool {
+        let service = NumberTheoryService::new(Fast);
+        let exponent: BigInt = (&self.prime - 1) >> 1;
+        let legendre = |value: &BigInt| service.fast_exponentiation(value, &exponent, &self.prime);
+        legendre(&self.a).is_one() && legendre(&self.d) == &self.prime - 1
+    }
+
+    /// Addiert zwei Punkte mit den vereinheitlichten Formeln in erweiterten Koordinaten.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NoInverseError` - Falls die Formeln für dieses Punktpaar nicht
+    ///   definiert sind. Das ist nur auf nicht vollständigen Kurven möglich.
+    pub fn add(&self, first: &EdwardsPoint, second: &EdwardsPoint) -> Result<EdwardsPoint> {
+        ExtendedPoint::from_affine(first, self)
+            .add(&ExtendedPoint::from_affine(second, self), self)
+            .to_affine(self)
+    }
+
+    /// Der inverse Punkt (-x, y).
+    pub fn negate(&self, point: &EdwardsPoint) -> EdwardsPoint {
+        EdwardsPoint::new((-&point.x).rem_euclid(&self.prime), point.y.clone())
+    }
+
+    /// Multipliziert einen Punkt mit einem Skalar. Geheime Skalare laufen über eine
+    /// Montgomery-Leiter fester Länge, öffentliche über Verdoppeln und Addieren. Da die Formeln
+    /// vereinheitlicht sind, unterscheiden sich Addition und Verdopplung nicht.
+    ///
+    /// # Argumente
+    /// * `point` - Der Punkt auf der Kurve.
+    /// * `scalar` - Der nicht negative Skalar.
+    /// * `kind` - Ob der Skalar geheim ist.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::ScalarOutOfRangeError` - Falls der Skalar negativ ist.
+    pub fn multiply(
+        &self,
+        point: &EdwardsPoint,
+        scalar: &BigInt,
+        kind: ScalarKind,
+    ) -> Result<EdwardsPoint> {
+        ensure!(
+            !scalar.is_negative(),
+            ScalarOutOfRangeError(scalar.to_string())
+        );
+        let point = ExtendedPoint::from_affine(point, self);
+        let result = match kind {
+            ScalarKind::Secret => {
+                let mut r0 = ExtendedPoint::neutral();
+                let mut r1 = point;
+                for i in (0..(self.prime.bits() + 1).max(scalar.bits())).rev() {
+                    let bit = scalar.bit(i);
+                    conditional_swap(&mut r0, &mut r1, bit);
+                    r1 = r0.add(&r1, self);
+                    r0 = r0.add(&r0, self);
+                    conditional_swap(&mut r0, &mut r1, bit);
+                }
+                r0
+            }
+            ScalarKind::Public => {
+                let mut result = ExtendedPoint::neutral();
+                for i in (0..scalar.bits()).rev() {
+                    result = result.add(&result, self);
+                    if scalar.bit(i) {
+                        result = result.add(&point, self);
+                    }
+                }
+                result
+            }
+        };
+        result.to_affine(self)
+    }
+
+    /// Die Länge der Kodierung nach RFC 8032 in Bytes. Neben y muss ein Bit für das Vorzeichen
+    /// von x Platz finden, bei edwards25519 sind das 32 Bytes.
+    pub fn encoding_length(&self) -> usize {
+        (self.prime.bits() as usize + 1).div_ceil(8)
+    }
+
+    /// Kodiert einen Punkt nach RFC 8032: y als Little-Endian-Zahl, deren höchstes Bit das
+    /// niederwertigste Bit von x aufnimmt.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidEdwardsPointEncodingError` - Falls der Punkt nicht auf der
+    ///   Kurve liegt.
+    pub fn encode_point(&self, point: &EdwardsPoint) -> Result<Vec<u8>> {
+        ensure!(
+            self.has_point(point),
+            InvalidEdwardsPointEncodingError(format!("{} is not on the curve", point))
+        );
+        let length = self.encoding_length();
+        let x = point.x.rem_euclid(&self.prime);
+        let y = point.y.rem_euclid(&self.prime);
+        let mut bytes = to_little_endian(&y, length);
+        if x.bit(0) {
+            bytes[length - 1] |= 0x80;
+        }
+        Ok(bytes)
+    }
+
+    /// Dekodiert einen Punkt nach RFC 8032, Abschnitt 5.1.3. Aus y folgt
+    /// x^2 = (1 - y^2) / (a - d · y^2), das Vorzeichenbit wählt eine der beiden Wurzeln.
+    ///
+    /// # Fehler
+    /// * `EllipticCurveError::InvalidEdwardsPointEncodingError` - Falls die Länge nicht stimmt,
+    ///   y >= p ist, es zu y keinen Punkt gibt oder x = 0 mit gesetztem Vorzeichenbit kodiert
+    ///   wurde.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<EdwardsPoint> {
+        let length = self.encoding_length();
+        ensure!(
+            bytes.len() == length,
+            InvalidEdwardsPointEncodingError(format!(
+                "expected {} bytes, got {}",
+                length,
+                bytes.len()
+            ))
+        );
+        let mut bytes = bytes.to_vec();
+        let sign = bytes[length - 1] & 0x80 != 0;
+        bytes[length - 1] &= 0x7f;
+        let y = from_little_endian(&bytes);
+        ensure!(
+            y < self.prime,
+            InvalidEdwardsPointEncodingError("y is not reduced modulo p".to_string())
+        );
+
+        let service = NumberTheoryService::new(Fast);
+        let y_squared = &y * &y;
+        let denominator = (&self.a - &self.d * &y_squared).rem_euclid(&self.prime);
+        let x_squared = (BigInt::one() - y_squared)
+            * service
+                .modulo_inverse(&denominator, &self.prime)
+                .map_err(|_| {
+                    InvalidEdwardsPointEncodingError(format!("no point with y = {}", y))
+                })?;
+        let mut x = modular_square_root(&x_squared, &self.prime, service)
+            .map_err(|_| InvalidEdwardsPointEncodingError(format!("no point with y = {}", y)))?;
+        ensure!(
+            !(x.is_zero() && sign),
+            InvalidEdwardsPointEncodingError("x = 0 with the sign bit set".to_string())
+        );
+        if x.bit(0) != sign {
+            x = &self.prime - x;
+        }
+        Ok(EdwardsPoint::new(x, y))
+    }
+
+    /// Die birational äquivalente Montgomery-Kurve mit A = 2 · (a + d) / (a - d) und
+    /// B = 4 / (a - d). Die Punkte werden mit `MontgomeryCurve::from_edwards_point` übertragen.
+    pub fn to_montgomery(&self) -> Result<MontgomeryCurve> {
+        let inverse = NumberTheoryService::new(Fast)
+            .modulo_inverse(&(&self.a - &self.d), &self.prime)
+            .context("a - d is not invertible")?;
+        MontgomeryCurve::new(
+            (BigInt::from(2) * (&self.a + &self.d) * &inverse).rem_euclid(&self.prime),
+            (BigInt::from(4) * inverse).rem_euclid(&self.prime),
+            self.prime.clone(),
+        )
+    }
+}
+
+/// Liest eine Zahl aus Bytes in Little-Endian-Reihenfolge, wie sie RFC 8032 und RFC 7748
+/// verwenden.
+pub fn from_little_endian(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_le(bigdecimal::num_bigint::Sign::Plus, bytes)
+}
+
+/// Schreibt eine nicht negative Zahl in genau `length` Bytes in Little-Endian-Reihenfolge.
+/// Höherwertige Bytes, die nicht hineinpassen, werden abgeschnitten.
+pub fn to_little_endian(value: &BigInt, length: usize) -> Vec<u8> {
+    let (_, mut bytes) = value.to_bytes_le();
+    bytes.resize(length, 0);
+    bytes
+}
+
+fn conditional_swap(r0: &mut ExtendedPoint, r1: &mut ExtendedPoint, swap: bool) {
+    let pair = [r0.clone(), r1.clone()];
+    *r0 = pair[swap as usize].clone();
+    *r1 = pair[1 - swap as usize].clone();
+}
+
+/// Ein Punkt in erweiterten Koordinaten (X : Y : Z : T) mit x = X / Z, y = Y / Z und
+/// x · y = T / Z nach Hisil, Wong, Carter und Dawson. Die Addition kommt ohne Inversion aus.
+#[derive(Clone, Debug)]
+struct ExtendedPoint {
+    x: BigInt,
+    y: BigInt,
+    z: BigInt,
+    t: BigInt,
+}
+
+impl ExtendedPoint {
+    fn neutral() -> Self {
+        ExtendedPoint {
+            x: BigInt::zero(),
+            y: BigInt::one(),
+            z: BigInt::one(),
+            t: BigInt::zero(),
+        }
+    }
+
+    fn from_affine(point: &EdwardsPoint, curve: &TwistedEdwardsCurve) -> Self {
+        ExtendedPoint {
+            x: point.x.rem_euclid(&curve.prime),
+            y: point.y.rem_euclid(&curve.prime),
+            z: BigInt::one(),
+            t: (&point.x * &point.y).rem_euclid(&curve.prime),
+        }
+    }
+
+    /// Die vereinheitlichte Addition "add-2008-hwcd". Sie ist auch für Verdopplungen korrekt.
+    fn add(&self, other: &Self, curve: &TwistedEdwardsCurve) -> Self {
+        let p = &curve.prime;
+        let a = (&self.x * &other.x).rem_euclid(p);
+        let b = (&self.y * &other.y).rem_euclid(p);
+        let c = (&curve.d * &self.t * &other.t).rem_euclid(p);
+        let d = (&self.z * &other.z).rem_euclid(p);
+        let e = ((&self.x + &self.y) * (&other.x + &other.y) - &a - &b).rem_euclid(p);
+        let f = (&d - &c).rem_euclid(p);
+        let g = (d + c).rem_euclid(p);
+        let h = (b - &curve.a * a).rem_euclid(p);
+        ExtendedPoint {
+            x: (&e * &f).rem_euclid(p),
+            y: (&g * &h).rem_euclid(p),
+            t: (e * h).rem_euclid(p),
+            z: (f * g).rem_euclid(p),
+        }
+    }
+
+    fn to_affine(&self, curve: &TwistedEdwardsCurve) -> Result<EdwardsPoint> {
+        let inverse = NumberTheoryService::new(Fast).modulo_inverse(&self.z, &curve.prime)?;
+        Ok(EdwardsPoint::new(
+            (&self.x * &inverse).rem_euclid(&curve.prime),
+            (&self.y * inverse).rem_euclid(&curve.prime),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Die Kodierung des Basispunkts von edwards25519 mit y = 4/5.
+    const BASE_POINT_ENCODING: &str =
+        "5866666666666666666666666666666666666666666666666666666666666666";
+
+    fn bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn group_order() -> BigInt {
+        (BigInt::one() << 252)
+            + "27742317777372353535851937790883648493"
+                .parse::<BigInt>()
+                .unwrap()
+    }
+
+    #[test]
+    fn test_ed25519_base_point() {
+        let curve = TwistedEdwardsCurve::ed25519();
+        assert!(curve.is_complete());
+        let base_point = curve.decode_point(&bytes(BASE_POINT_ENCODING)).unwrap();
+        assert_eq!(
+            base_point.x,
+            "15112221349535400772501151409588531511454012693041857206046113283949847762202"
+                .parse::<BigInt>()
+                .unwrap()
+        );
+        assert!(curve.has_point(&base_point));
+        assert_eq!(
+            curve.encode_point(&base_point).unwrap(),
+            bytes(BASE_POINT_ENCODING)
+        );
+
+        for kind in [ScalarKind::Secret, ScalarKind::Public] {
+            assert!(curve
+                .multiply(&base_point, &group_order(), kind)
+                .unwrap()
+                .is_neutral());
+        }
+    }
+
+    #[test]
+    fn test_group_law() {
+        let curve = TwistedEdwardsCurve::ed25519();
+        let base_point = curve.decode_point(&bytes(BASE_POINT_ENCODING)).unwrap();
+        let neutral = EdwardsPoint::neutral();
+
+        assert_eq!(curve.add(&base_point, &neutral).unwrap(), base_point);
+        assert!(curve
+            .add(&base_point, &curve.negate(&base_point))
+            .unwrap()
+            .is_neutral());
+
+        let double = curve.add(&base_point, &base_point).unwrap();
+        let triple = curve.add(&double, &base_point).unwrap();
+        assert!(curve.has_point(&double) && curve.has_point(&triple));
+        for kind in [ScalarKind::Secret, ScalarKind::Public] {
+            assert_eq!(
+                curve.multiply(&base_point, &3.into(), kind).unwrap(),
+                triple
+            );
+            assert_eq!(
+                curve.multiply(&base_point, &BigInt::zero(), kind).unwrap(),
+                neutral
+            );
+        }
+        assert!(curve
+            .multiply(&base_point, &(-1).into(), ScalarKind::Public)
+            .is_err());
+
+        // (0, -1) hat die Ordnung 2.
+        let two_torsion = EdwardsPoint::new(BigInt::zero(), &curve.prime - 1);
+        assert!(curve.add(&two_torsion, &two_torsion).unwrap().is_neutral());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_encodings() {
+        let curve = TwistedEdwardsCurve::ed25519();
+        // y = p ist nicht reduziert.
+        let mut not_reduced = to_little_endian(&curve.prime, 32);
+        assert!(curve.decode_point(&not_reduced).is_err());
+        // y = 1 ergibt x = 0, dazu darf das Vorzeichenbit nicht gesetzt sein.
+        not_reduced = to_little_endian(&BigInt::one(), 32);
+        assert!(curve.decode_point(&not_reduced).unwrap().is_neutral());
+        not_reduced[31] |= 0x80;
+        assert!(curve.decode_point(&not_reduced).is_err());
+        // Zu y = 2 gibt es kein x.
+        assert!(curve
+            .decode_point(&to_little_endian(&2.into(), 32))
+            .is_err());
+        assert!(curve.decode_point(&[0; 31]).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_degenerate_curves() {
+        assert!(TwistedEdwardsCurve::new(1.into(), 1.into(), 13.into()).is_err());
+        assert!(TwistedEdwardsCurve::new(1.into(), 0.into(), 13.into()).is_err());
+        assert!(TwistedEdwardsCurve::new(1.into(), 2.into(), 15.into()).is_err());
+
+        // Über F_13 ist 2 kein quadratischer Rest, die Kurve x^2 + y^2 = 1 + 2x^2y^2 ist also
+        // vollständig.
+        let curve = TwistedEdwardsCurve::new(1.into(), 2.into(), 13.into()).unwrap();
+        assert!(curve.is_complete());
+        let points: Vec<EdwardsPoint> = (0..13)
+            .flat_map(|x| (0..13).map(move |y| EdwardsPoint::new(x.into(), y.into())))
+            .filter(|point| curve.has_point(point))
+            .collect();
+        for first in &points {
+            for second in &points {
+                assert!(curve.has_point(&curve.add(first, second).unwrap()));
+            }
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use std::fmt::{Display, Formatter};
+
+use bigdecimal::num_bigint::{BigInt, BigUint};
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+
+/// Die Anzahl an Koeffizienten, ab der beide Faktoren einer Multiplikation per
+/// Kronecker-Substitution statt nach der Schulmethode multipliziert werden.
+const KRONECKER_MINIMUM_LENGTH: usize = 16;
+
+/// Ein Polynom über dem endlichen Körper F_p. Die Koeffizienten sind aufsteigend nach dem Grad
+/// geordnet, in den Bereich von 0 bis p-1 reduziert und ohne führende Nullen gespeichert, sodass
+/// das Nullpolynom keine Koeffizienten hat.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FiniteFieldPolynomial {
+    coefficients: Vec<BigInt>,
+    prime: BigInt,
+}
+
+impl FiniteFieldPolynomial {
+    /// Erstellt ein Polynom aus seinen Koeffizienten, beginnend mit dem konstanten Term.
+    ///
+    /// # Argumente
+    /// * `coefficients` - Die Koeffizienten, die modulo p reduziert werden.
+    /// * `prime` - Die Primzahl p. Ob sie prim ist, wird nicht geprüft.
+    pub fn new(coefficients: Vec<BigInt>, prime: &BigInt) -> Self {
+        let mut coefficients: Vec<BigInt> = coefficients
+            .into_iter()
+            .map(|coefficient| coefficient.rem_euclid(prime))
+            .collect();
+        while coefficients.last().is_some_and(Zero::is_zero) {
+            coefficients.pop();
+        }
+        FiniteFieldPolynomial {
+            coefficients,
+            prime: prime.clone(),
+        }
+    }
+
+    pub fn zero(prime: &BigInt) -> Self {
+        Self::new(Vec::new(), prime)
+    }
+
+    pub fn constant(value: BigInt, prime: &BigInt) -> Self {
+        Self::new(vec![value], prime)
+    }
+
+    /// Das Polynom x.
+    pub fn x(prime: &BigInt) -> Self {
+        Self::new(vec![BigInt::zero(), BigInt::one()], prime)
+    }
+
+    pub fn coefficients(&self) -> &[BigInt] {
+        &self.coefficients
+    }
+
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    /// Der Grad des Polynoms. Das Nullpolynom hat keinen Grad.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.coefficients.len() == 1 && self.coefficients[0].is_one()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|i| self.coefficient(i) + other.coefficient(i))
+            .collect();
+        Self::new(coefficients, &self.prime)
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn neg(&self) -> Self {
+        let coefficients = self.coefficients.iter().map(|c| -c).collect();
+        Self::new(coefficients, &self.prime)
+    }
+
+    /// Multipliziert alle Koeffizienten mit `factor`.
+    pub fn scale(&self, factor: &BigInt) -> Self {
+        let coefficients = self.coefficients.iter().map(|c| c * factor).collect();
+        Self::new(coefficients, &self.prime)
+    }
+
+    /// Multipliziert zwei Polynome. Kleine Polynome werden nach der Schulmethode multipliziert,
+    /// wobei die Produkte je Koeffizient aufsummiert und erst am Ende reduziert werden. Große
+    /// Polynome werden per Kronecker-Substitution als zwei Zahlen multipliziert.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero(&self.prime);
+        }
+        if self.coefficients.len().min(other.coefficients.len()) >= KRONECKER_MINIMUM_LENGTH {
+            let coefficients =
+                kronecker_product(&self.coefficients, &other.coefficients, &self.prime);
+            return Self::new(coefficients, &self.prime);
+        }
+        let mut coefficients =
+            vec![BigInt::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        Self::new(coefficients, &self.prime)
+    }
+
+    /// Teilt mit Rest durch `divisor`, sodass self = q · divisor + r mit deg r < deg divisor.
+    /// Wie bei der Multiplikation wird nur der jeweils führende Koeffizient sofort reduziert,
+    /// alle übrigen erst am Ende.
+    ///
+    /// # Panics
+    /// Falls `divisor` das Nullpolynom ist.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("Division durch das Nullpolynom");
+        let self_degree = match self.degree() {
+            Some(degree) if degree >= divisor_degree => degree,
+            _ => return (Self::zero(&self.prime), self.clone()),
+        };
+        let leading_inverse = divisor.leading_coefficient_inverse();
+
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![BigInt::zero(); self_degree - divisor_degree + 1];
+        for shift in (0..quotient.len()).rev() {
+            let factor = (remainder[shift + divisor_degree].rem_euclid(&self.prime)
+                * &leading_inverse)
+                .rem_euclid(&self.prime);
+            if factor.is_zero() {
+                continue;
+            }
+            for (i, d) in divisor.coefficients.iter().enumerate() {
+                remainder[shift + i] -= &factor * d;
+            }
+            quotient[shift] = factor;
+        }
+        (
+            Self::new(quotient, &self.prime),
+            Self::new(remainder, &self.prime),
+        )
+    }
+
+    pub fn rem(&self, modulus: &Self) -> Self {
+        self.div_rem(modulus).1
+    }
+
+    /// Normiert das Polynom, sodass der führende Koeffizient 1 ist. Das Nullpolynom bleibt
+    /// unverändert.
+    pub fn monic(&self) -> Self {
+        match self.is_zero() {
+            true => self.clone(),
+            false => self.scale(&self.leading_coefficient_inverse()),
+        }
+    }
+
+    /// Der normierte größte gemeinsame Teiler nach dem euklidischen Algorithmus.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = a.rem(&b);
+            a = b;
+            b = remainder;
+        }
+        a.monic()
+    }
+
+    /// Das Inverse modulo `modulus` nach dem erweiterten euklidischen Algorithmus. Gibt `None`
+    /// zurück, falls das Polynom nicht teilerfremd zu `modulus` ist.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        // Invariante: s_i · self ≡ r_i mod modulus
+        let (mut r0, mut r1) = (modulus.clone(), self.rem(modulus));
+        let (mut s0, mut s1) = (
+            Self::zero(&self.prime),
+            Self::constant(1.into(), &self.prime),
+        );
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s2 = s0.sub(&quotient.mul(&s1));
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+        }
+        match r0.degree() {
+            Some(0) => Some(s0.scale(&r0.leading_coefficient_inverse()).rem(modulus)),
+            _ => None,
+        }
+    }
+
+    /// Multipliziert zwei Polynome und reduziert das Produkt modulo `modulus`.
+    pub fn mul_mod(&self, other: &Self, modulus: &Self) -> Self {
+        self.mul(other).rem(modulus)
+    }
+
+    /// Berechnet self^exponent modulo `modulus` mit Square-and-Multiply.
+    pub fn pow_mod(&self, exponent: &BigInt, modulus: &Self) -> Self {
+        PolynomialModulus::new(modulus).pow(self, exponent)
+    }
+
+    /// Wertet das Polynom mit dem Horner-Schema an der Stelle x aus.
+    pub fn evaluate(&self, x: &BigInt) -> BigInt {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigInt::zero(), |value, coefficient| {
+                (value * x + coefficient).rem_euclid(&self.prime)
+            })
+    }
+
+    fn coefficient(&self, i: usize) -> BigInt {
+        self.coefficients.get(i).cloned().unwrap_or_default()
+    }
+
+    fn leading_coefficient_inverse(&self) -> BigInt {
+        let leading = self
+            .coefficients
+            .last()
+            .expect("Das Nullpolynom hat keinen Leitkoeffizienten");
+        if leading.is_one() {
+            return BigInt::one();
+        }
+        let service = NumberTheoryService::new(Fast); // TODO X: Später korrigieren
+        service.modulo_inverse(leading, &self.prime).unwrap()
+    }
+}
+
+/// Multipliziert zwei Koeffizientenfolgen mit Werten aus [0, p) per Kronecker-Substitution:
+/// Beide Polynome werden an der Stelle 2^(32k) ausgewertet, die beiden Zahlen multipliziert und
+/// die Koeffizienten des Produkts aus den Blöcken zu je k Ziffern gelesen. k ist so gewählt,
+/// dass ein Koeffizient des Produkts, höchstens min(len) · (p - 1)^2, nicht in den nächsten
+/// Block überläuft. So greift die schnelle Multiplikation großer Zahlen von `BigUint`.
+fn kronecker_product(a: &[BigInt], b: &[BigInt], prime: &BigInt) -> Vec<BigInt> {
+    let terms = a.len().min(b.len());
+    let bound_bits = 2 * prime.bits() + u64::from(usize::BITS - terms.leading_zeros());
+    let block_digits = bound_bits.div_ceil(u32::BITS.into()) as usize;
+
+    let pack = |coefficients: &[BigInt]| {
+        let mut digits = vec![0u32; coefficients.len() * block_digits];
+        for (block, coefficient) in digits.chunks_exact_mut(block_digits).zip(coefficients) {
+            let (_, coefficient_digits) = coefficient.to_u32_digits();
+            block[..coefficient_digits.len()].copy_from_slice(&coefficient_digits);
+        }
+        BigUint::new(digits)
+    };
+
+    (pack(a) * pack(b))
+        .to_u32_digits()
+        .chunks(block_digits)
+        .map(|block| BigInt::from(BigUint::from_slice(block)))
+        .collect()
+}
+
+/// Ein Modul m vom Grad d für wiederholte Reduktionen, etwa beim Potenzieren. Wie bei der
+/// Barrett-Reduktion wird der Quotient über das vorab berechnete Inverse des gespiegelten
+/// Moduls x^d · m(1/x) als Potenzreihe bestimmt, sodass eine Reduktion statt einer Division
+/// nur zwei Multiplikationen kostet.
+#[derive(Clone, Debug)]
+pub struct PolynomialModulus {
+    modulus: FiniteFieldPolynomial,
+    /// Die ersten d Koeffizienten von 1 / (x^d · m(1/x)).
+    reversed_inverse: FiniteFieldPolynomial,
+}
+
+impl PolynomialModulus {
+    /// Bereitet die Reduktion modulo `modulus` vor.
+    ///
+    /// # Panics
+    /// Falls `modulus` das Nullpolynom ist.
+    pub fn new(modulus: &FiniteFieldPolynomial) -> Self {
+        let degree = modulus.degree().expect("Reduktion modulo des Nullpolynoms");
+        let prime = &modulus.prime;
+        let reversed: Vec<&BigInt> = modulus.coefficients.iter().rev().collect();
+        let leading_inverse = modulus.leading_coefficient_inverse();
+
+        // Aus (Σ r_j x^j)(Σ g_i x^i) = 1 folgt g_i = -g_0 · Σ_(j=1..i) r_j g_(i-j).
+        let mut inverse: Vec<BigInt> = Vec::with_capacity(degree);
+        for i in 0..degree {
+            let sum = (1..=i.min(degree))
+                .map(|j| reversed[j] * &inverse[i - j])
+                .sum::<BigInt>();
+            let coefficient = match i {
+                0 => leading_inverse.clone(),
+                _ => (-sum * &leading_inverse).rem_euclid(prime),
+            };
+            inverse.push(coefficient);
+        }
+
+        PolynomialModulus {
+            modulus: modulus.clone(),
+            reversed_inverse: FiniteFieldPolynomial::new(inverse, prime),
+        }
+    }
+
+    pub fn modulus(&self) -> &FiniteFieldPolynomial {
+        &self.modulus
+    }
+
+    /// Reduziert ein Polynom vom Grad n modulo m. Für n > 2d - 1 reicht die Genauigkeit des
+    /// Inversen nicht aus, dann wird gewöhnlich dividiert.
+    pub fn reduce(&self, a: &FiniteFieldPolynomial) -> FiniteFieldPolynomial {
+        let degree = self.modulus.coefficients.len() - 1;
+        let quotient_length = match a.degree() {
+            Some(a_degree) if a_degree >= degree => a_degree - degree + 1,
+            _ => return a.clone(),
+        };
+        if quotient_length > degree {
+            return a.rem(&self.modulus);
+        }
+        let prime = &self.modulus.prime;
+
+        // Die Koeffizienten des Quotienten q sind die ersten n - d + 1 Koeffizienten des
+        // gespiegelten a mal dem Inversen, in umgekehrter Reihenfolge.
+        let truncate = |polynomial: &FiniteFieldPolynomial| {
+            let mut coefficients: Vec<BigInt> = polynomial
+                .coefficients
+                .iter()
+                .take(quotient_length)
+                .cloned()
+                .collect();
+            coefficients.resize(quotient_length, BigInt::zero());
+            coefficients
+        };
+        let reversed_a =
+            FiniteFieldPolynomial::new(a.coefficients.iter().rev().cloned().collect(), prime);
+        let mut quotient = truncate(&reversed_a.mul(&FiniteFieldPolynomial::new(
+            truncate(&self.reversed_inverse),
+            prime,
+        )));
+        quotient.reverse();
+
+        a.sub(&FiniteFieldPolynomial::new(quotient, prime).mul(&self.modulus))
+    }
+
+    /// Multipliziert zwei Polynome und reduziert das Produkt modulo m.
+    pub fn mul(
+        &self,
+        a: &FiniteFieldPolynomial,
+        b: &FiniteFieldPolynomial,
+    ) -> FiniteFieldPolynomial {
+        self.reduce(&a.mul(b))
+    }
+
+    /// Berechnet base^exponent modulo m mit Square-and-Multiply.
+    pub fn pow(&self, base: &FiniteFieldPolynomial, exponent: &BigInt) -> FiniteFieldPolynomial {
+        let mut result = FiniteFieldPolynomial::constant(1.into(), &base.prime).rem(&self.modulus);
+        let base = base.rem(&self.modulus);
+        for i in (0..exponent.bits()).rev() {
+            result = self.mul(&result, &result);
+            if exponent.bit(i) {
+                result = self.mul(&result, &base);
+            }
+        }
+        result
+    }
+}
+
+impl Display for FiniteFieldPolynomial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(degree, coefficient)| match degree {
+                0 => coefficient.to_string(),
+                1 => format!("{}x", coefficient),
+                _ => format!("{}x^{}", coefficient, degree),
+            })
+            .collect();
+        write!(f, "{} (mod {})", terms.join(" + "), self.prime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polynomial(coefficients: &[i64]) -> FiniteFieldPolynomial {
+        FiniteFieldPolynomial::new(
+            coefficients.iter().map(|c| BigInt::from(*c)).collect(),
+            &7.into(),
+        )
+    }
+
+    #[test]
+    fn test_new_reduces_and_trims() {
+        let p = polynomial(&[-1, 8, 14, 0]);
+        assert_eq!(p.coefficients(), &[6.into(), 1.into()]);
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(polynomial(&[7, 0]).degree(), None);
+        assert_eq!(p.to_string(), "1x + 6 (mod 7)");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        // (x + 1)(x + 6) = x^2 - 1
+        let a = polynomial(&[1, 1]);
+        let b = polynomial(&[6, 1]);
+        assert_eq!(a.mul(&b), polynomial(&[6, 0, 1]));
+        assert_eq!(a.add(&b), polynomial(&[0, 2]));
+        assert_eq!(a.sub(&b), polynomial(&[2]));
+
+        // x^3 + 2x + 3 = (2x + 1)(4x^2 + 5x + 2) + 1
+        let dividend = polynomial(&[3, 2, 0, 1]);
+        let divisor = polynomial(&[1, 2]);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.mul(&divisor).add(&remainder), dividend);
+        assert_eq!(remainder, polynomial(&[1]));
+        assert_eq!(quotient, polynomial(&[2, 5, 4]));
+    }
+
+    #[test]
+    fn test_gcd_and_inverse() {
+        // (x + 1)(x + 2) und (x + 1)(x + 3) haben den ggT x + 1.
+        let a = polynomial(&[1, 1]).mul(&polynomial(&[2, 1]));
+        let b = polynomial(&[1, 1]).mul(&polynomial(&[3, 1]));
+        assert_eq!(a.gcd(&b), polynomial(&[1, 1]));
+        assert_eq!(a.inverse_mod(&b), None);
+
+        let modulus = polynomial(&[3, 2, 0, 1]);
+        let element = polynomial(&[5, 0, 3]);
+        let inverse = element.inverse_mod(&modulus).unwrap();
+        assert!(element.mul_mod(&inverse, &modulus).is_one());
+    }
+
+    /// Ein Polynom mit `length` pseudozufälligen Koeffizienten modulo 2^61 - 1.
+    fn large_polynomial(length: usize, seed: u64) -> FiniteFieldPolynomial {
+        let prime = BigInt::from((1u64 << 61) - 1);
+        let coefficients = (0..length as u64)
+            .map(|i| BigInt::from(seed.wrapping_mul(i + 1).wrapping_mul(0x9e3779b97f4a7c15)))
+            .collect();
+        FiniteFieldPolynomial::new(coefficients, &prime)
+    }
+
+    #[test]
+    fn test_kronecker_matches_schoolbook() {
+        let a = large_polynomial(100, 3);
+        let b = large_polynomial(KRONECKER_MINIMUM_LENGTH, 5);
+        let mut schoolbook = vec![BigInt::zero(); 100 + KRONECKER_MINIMUM_LENGTH - 1];
+        for (i, x) in a.coefficients().iter().enumerate() {
+            for (j, y) in b.coefficients().iter().enumerate() {
+                schoolbook[i + j] += x * y;
+            }
+        }
+        assert_eq!(a.mul(&b), FiniteFieldPolynomial::new(schoolbook, a.prime()));
+    }
+
+    #[test]
+    fn test_polynomial_modulus_matches_division() {
+        let modulus = large_polynomial(40, 7);
+        let reduction = PolynomialModulus::new(&modulus);
+        for length in [10, 40, 79, 150] {
+            let a = large_polynomial(length, length as u64);
+            assert_eq!(reduction.reduce(&a), a.rem(&modulus));
+        }
+        let (a, b) = (large_polynomial(39, 11), large_polynomial(39, 13));
+        assert_eq!(reduction.mul(&a, &b), a.mul_mod(&b, &modulus));
+    }
+
+    #[test]
+    fn test_pow_mod_matches_frobenius() {
+        // x^7 ≡ x modulo jedem Polynom, dessen Nullstellen in F_7 liegen, etwa x(x - 1)(x - 2).
+        let modulus = polynomial(&[0, 2, 4, 1]);
+        let x = FiniteFieldPolynomial::x(&7.into());
+        assert_eq!(x.pow_mod(&7.into(), &modulus), x);
+        assert_eq!(
+            polynomial(&[3, 2, 0, 1]).evaluate(&2.into()),
+            BigInt::from(1)
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Signed, Zero};
+use num::Integer;
+
+use crate::math_core::number_theory::factorization::{is_prime, Factorization};
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::traits::divisible::Divisible;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::ArithmeticError;
+
+/// Eine ganze gaußsche Zahl a + bi aus dem Ring Z[i]. Z[i] ist euklidisch bezüglich der Norm
+/// N(a + bi) = a^2 + b^2, sodass Division mit Rest, ggT und eindeutige Primfaktorzerlegung
+/// wie in Z funktionieren. Die Einheiten sind 1, i, -1 und -i.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GaussianInteger {
+    pub real: BigInt,
+    pub imaginary: BigInt,
+}
+
+impl GaussianInteger {
+    pub fn new(real: BigInt, imaginary: BigInt) -> Self {
+        Self { real, imaginary }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(BigInt::zero(), BigInt::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::new(BigInt::one(), BigInt::zero())
+    }
+
+    pub fn i() -> Self {
+        Self::new(BigInt::zero(), BigInt::one())
+    }
+
+    pub fn conjugate(&self) -> GaussianInteger {
+        GaussianInteger::new(self.real.clone(), -self.imaginary.clone())
+    }
+
+    pub fn negate(&self) -> GaussianInteger {
+        GaussianInteger::new(-self.real.clone(), -self.imaginary.clone())
+    }
+
+    pub fn is_in_first_quadrant(&self) -> bool {
+        self.real.is_positive() && self.imaginary.is_positive()
+    }
+
+    pub fn is_in_third_quadrant(&self) -> bool {
+        self.real.is_negative() && self.imaginary.is_negative()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.imaginary.is_zero()
+    }
+
+    /// Ob die Zahl eine der Einheiten 1, i, -1 oder -i ist.
+    pub fn is_unit(&self) -> bool {
+        self.norm().is_one()
+    }
+
+    /// Die Norm N(a + bi) = a^2 + b^2 = (a + bi)(a - bi). Sie ist multiplikativ.
+    pub fn norm(&self) -> BigInt {
+        &self.real * &self.real + &self.imaginary * &self.imaginary
+    }
+
+    /// Der Vertreter der Assoziierten u * z (u eine Einheit) mit positivem Realteil und
+    /// nichtnegativem Imaginärteil. Die Null bleibt unverändert.
+    pub fn normalize(&self) -> GaussianInteger {
+        self.normalize_with_unit().0
+    }
+
+    /// Wie `normalize`, gibt aber zusätzlich die Einheit u zurück, mit der multipliziert wurde.
+    fn normalize_with_unit(&self) -> (GaussianInteger, GaussianInteger) {
+        let mut unit = GaussianInteger::one();
+        let mut associate = self.clone();
+        if self.is_zero() {
+            return (associate, unit);
+        }
+        while !associate.real.is_positive() || associate.imaginary.is_negative() {
+            associate = &associate * &GaussianInteger::i();
+            unit = &unit * &GaussianInteger::i();
+        }
+        (associate, unit)
+    }
+
+    /// Division mit Rest: q ist der Quotient z / w, dessen Real- und Imaginärteil auf die
+    /// nächste ganze Zahl gerundet sind, und r = z - q * w. Dadurch gilt N(r) <= N(w) / 2.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::DivisionByZeroError` - Falls `rhs` null ist.
+    pub fn div_rem(&self, rhs: &Self) -> Result<(Self, Self)> {
+        ensure!(!rhs.is_zero(), ArithmeticError::DivisionByZeroError);
+        let numerator = self * &rhs.conjugate();
+        let norm = rhs.norm();
+        // round(n / d) = floor((2n + d) / 2d) für d > 0
+        let round = |n: &BigInt| (n.double() + &norm).div_floor(&norm.double());
+        let quotient = GaussianInteger::new(round(&numerator.real), round(&numerator.imaginary));
+        let remainder = self - &(&quotient * rhs);
+        Ok((quotient, remainder))
+    }
+
+    /// Exakte Division z / w.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::DivisionByZeroError` - Falls `rhs` null ist.
+    /// * `ArithmeticError::NotDivisibleError` - Falls `rhs` kein Teiler von `self` ist.
+    pub fn div_exact(&self, rhs: &Self) -> Result<Self> {
+        ensure!(!rhs.is_zero(), ArithmeticError::DivisionByZeroError);
+        let numerator = self * &rhs.conjugate();
+        let norm = rhs.norm();
+        ensure!(
+            numerator.real.is_divisible_by(&norm) && numerator.imaginary.is_divisible_by(&norm),
+            ArithmeticError::NotDivisibleError(self.to_string(), rhs.to_string())
+        );
+        Ok(GaussianInteger::new(
+            numerator.real / &norm,
+            numerator.imaginary / &norm,
+        ))
+    }
+
+    /// Prüft, ob die Zahl ein gaußsches Prim ist. Das sind genau die Assoziierten von
+    /// - 1 + i,
+    /// - rationalen Primzahlen p ≡ 3 mod 4 und
+    /// - a + bi mit a, b != 0, deren Norm eine Primzahl p ≡ 1 mod 4 ist.
+    pub fn is_gaussian_prime(&self, number_theory_service: NumberTheoryService) -> bool {
+        if self.real.is_zero() || self.imaginary.is_zero() {
+            let rational = (&self.real + &self.imaginary).abs();
+            return is_prime(&rational, number_theory_service)
+                && rational.rem_euclid(&4.into()) == 3.into();
+        }
+        is_prime(&self.norm(), number_theory_service)
+    }
+
+    /// Zerlegt die Zahl in gaußsche Primzahlen. Dazu wird die Norm in Z faktorisiert: Jede
+    /// Primzahl p | N(z) liefert 1 + i für p = 2, p selbst für p ≡ 3 mod 4 und für p ≡ 1 mod 4
+    /// die Faktoren π und π̄ mit p = π * π̄, die mit Cornacchia bestimmt werden.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NonPositiveNumberError` - Falls die Zahl null ist.
+    pub fn factorize(
+        &self,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<GaussianFactorization> {
+        let norm_factorization = Factorization::factorize(&self.norm(), number_theory_service)?;
+
+        let mut remaining = self.clone();
+        let mut factors = Vec::new();
+        for prime in norm_factorization.primes() {
+            let candidates = match prime.rem_euclid(&4.into()) {
+                r if r == 2.into() => vec![GaussianInteger::new(BigInt::one(), BigInt::one())],
+                r if r == 3.into() => vec![GaussianInteger::new(prime.clone(), BigInt::zero())],
+                _ => {
+                    let (x, y) = cornacchia(prime, number_theory_service);
+                    let pi = GaussianInteger::new(x, y);
+                    vec![pi.conjugate().normalize(), pi]
+                }
+            };
+            for candidate in candidates {
+                let mut exponent = 0;
+                while let Ok(quotient) = remaining.div_exact(&candidate) {
+                    remaining = quotient;
+                    exponent += 1;
+                }
+                if exponent > 0 {
+                    factors.push((candidate, exponent));
+                }
+            }
+        }
+
+        Ok(GaussianFactorization {
+            unit: remaining,
+            factors,
+        })
+    }
+}
+
+/// Die Zerlegung z = unit * π_1^e_1 * ... * π_k^e_k in gaußsche Primzahlen. Die Primfaktoren
+/// sind normalisiert und nach der rationalen Primzahl geordnet, die ihre Norm teilt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GaussianFactorization {
+    pub unit: GaussianInteger,
+    pub factors: Vec<(GaussianInteger, u32)>,
+}
+
+impl GaussianFactorization {
+    /// Die Zahl, deren Zerlegung dies ist.
+    pub fn value(&self) -> GaussianInteger {
+        self.factors
+            .iter()
+            .flat_map(|(factor, exponent)| std::iter::repeat_n(factor, *exponent as usize))
+            .fold(self.unit.clone(), |product, factor| &product * factor)
+    }
+}
+
+/// Das Ergebnis des erweiterten euklidischen Algorithmus in Z[i]: ggt = x * a + y * b.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GaussianEuclidResult {
+    pub ggt: GaussianInteger,
+    pub x: GaussianInteger,
+    pub y: GaussianInteger,
+}
+
+/// Der erweiterte euklidische Algorithmus in Z[i] mit Division mit Rest nach `div_rem`.
+/// Der ggT ist nur bis auf Einheiten bestimmt; zurückgegeben wird der normalisierte Vertreter.
+///
+/// # Argumente
+/// * `a` - Die erste Zahl.
+/// * `b` - Die zweite Zahl.
+///
+/// # Rückgabe
+/// * Der ggT und die Bézout-Koeffizienten x und y mit ggt = x * a + y * b.
+pub fn gaussian_extended_euclid(a: &GaussianInteger, b: &GaussianInteger) -> GaussianEuclidResult {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut x0, mut x1) = (GaussianInteger::one(), GaussianInteger::zero());
+    let (mut y0, mut y1) = (GaussianInteger::zero(), GaussianInteger::one());
+    while !r1.is_zero() {
+        let (quotient, remainder) = r0.div_rem(&r1).unwrap();
+        let x2 = &x0 - &(&quotient * &x1);
+        let y2 = &y0 - &(&quotient * &y1);
+        (r0, r1) = (r1, remainder);
+        (x0, x1) = (x1, x2);
+        (y0, y1) = (y1, y2);
+    }
+    let (ggt, unit) = r0.normalize_with_unit();
+    GaussianEuclidResult {
+        ggt,
+        x: &x0 * &unit,
+        y: &y0 * &unit,
+    }
+}
+
+/// Der ggT zweier gaußscher Zahlen, normalisiert nach `GaussianInteger::normalize`.
+pub fn gaussian_gcd(a: &GaussianInteger, b: &GaussianInteger) -> GaussianInteger {
+    gaussian_extended_euclid(a, b).ggt
+}
+
+/// Schreibt eine Primzahl p als Summe zweier Quadrate p = x^2 + y^2. Das ist nach Fermat genau
+/// für p = 2 und p ≡ 1 mod 4 möglich.
+///
+/// # Argumente
+/// * `prime` - Die Primzahl p.
+/// * `number_theory_service` - Der Service für Primzahltest und Exponentiation.
+///
+/// # Rückgabe
+/// * Das Paar (x, y) mit x ungerade und x, y > 0. Für p = 2 ist es (1, 1).
+///
+/// # Fehler
+/// * `ArithmeticError::NoSumOfTwoSquaresError` - Falls p keine Primzahl p = 2 oder
+///   p ≡ 1 mod 4 ist.
+pub fn sum_of_two_squares(
+    prime: &BigInt,
+    number_theory_service: NumberTheoryService,
+) -> Result<(BigInt, BigInt)> {
+    ensure!(
+        is_prime(prime, number_theory_service) && prime.rem_euclid(&4.into()) != 3.into(),
+        ArithmeticError::NoSumOfTwoSquaresError(prime.to_string())
+    );
+    Ok(cornacchia(prime, number_theory_service))
+}
+
+/// Der Algorithmus von Cornacchia für x^2 + y^2 = p. Aus einer Wurzel r von -1 modulo p wird
+/// der euklidische Algorithmus auf (p, r) angewendet, bis der Rest kleiner als √p ist. Dieser
+/// Rest ist x, und y = √(p - x^2). Setzt voraus, dass p = 2 oder eine Primzahl p ≡ 1 mod 4 ist.
+fn cornacchia(prime: &BigInt, number_theory_service: NumberTheoryService) -> (BigInt, BigInt) {
+    if prime == &BigInt::from(2) {
+        return (BigInt::one(), BigInt::one());
+    }
+
+    // Für einen quadratischen Nichtrest z ist z^((p-1)/4) eine Wurzel von -1.
+    let minus_one = prime.decrement();
+    let mut non_residue = BigInt::from(2);
+    while number_theory_service.fast_exponentiation(&non_residue, &minus_one.half(), prime)
+        != minus_one
+    {
+        non_residue.increment_assign();
+    }
+    let root = number_theory_service.fast_exponentiation(&non_residue, &(&minus_one / 4), prime);
+
+    let bound = prime.sqrt();
+    let (mut a, mut b) = (prime.clone(), root);
+    while b > bound {
+        (a, b) = (b.clone(), a % b);
+    }
+    let x = b;
+    let y = (prime - &x * &x).sqrt();
+
+    match x.is_odd() {
+        true => (x, y),
+        false => (y, x),
+    }
+}
+
+impl Display for GaussianInteger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.imaginary.is_negative() {
+            true => write!(f, "{} - {}i", self.real, -&self.imaginary),
+            false => write!(f, "{} + {}i", self.real, self.imaginary),
+        }
+    }
+}
+
+impl Add for GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real: &self.real + &rhs.real,
+            imaginary: &self.imaginary + &rhs.imaginary,
+        }
+    }
+}
+
+impl Add for &GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GaussianInteger {
+            real: &self.real + &rhs.real,
+            imaginary: &self.imaginary + &rhs.imaginary,
+        }
+    }
+}
+
+impl Sub for GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            real: &self.real - &rhs.real,
+            imaginary: &self.imaginary - &rhs.imaginary,
+        }
+    }
+}
+
+impl Sub for &GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GaussianInteger {
+            real: &self.real - &rhs.real,
+            imaginary: &self.imaginary - &rhs.imaginary,
+        }
+    }
+}
+
+impl Mul for GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            real: &self.real * &rhs.real - &self.imaginary * &rhs.imaginary,
+            imaginary: &self.real * &rhs.imaginary + &self.imaginary * &rhs.real,
+        }
+    }
+}
+
+impl Mul for &GaussianInteger {
+    type Output = GaussianInteger;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        GaussianInteger {
+            real: &self.real * &rhs.real - &self.imaginary * &rhs.imaginary,
+            imaginary: &self.real * &rhs.imaginary + &self.imaginary * &rhs.real,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use std::str::FromStr;
+
+    fn gaussian(real: i64, imaginary: i64) -> GaussianInteger {
+        GaussianInteger::new(real.into(), imaginary.into())
+    }
+
+    #[test]
+    fn gcd_test() {
+        let x = gaussian(-6, 17);
+        let y = gaussian(3, 4);
+
+        assert_eq!(gaussian_gcd(&y, &x), y);
+        assert_eq!(gaussian_gcd(&x, &y), y);
+        assert_eq!(
+            gaussian_gcd(&gaussian(5, 0), &gaussian(3, 0)),
+            gaussian(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_extended_euclid() {
+        for (a, b) in [
+            (gaussian(-6, 17), gaussian(3, 4)),
+            (gaussian(11, 3), gaussian(1, 8)),
+            (gaussian(32, 9), gaussian(4, 11)),
+            (gaussian(0, 0), gaussian(0, -7)),
+        ] {
+            let result = gaussian_extended_euclid(&a, &b);
+            assert_eq!(&(&result.x * &a) + &(&result.y * &b), result.ggt);
+            assert_eq!(result.ggt, result.ggt.normalize());
+            if !result.ggt.is_zero() {
+                assert!(a.div_exact(&result.ggt).is_ok());
+                assert!(b.div_exact(&result.ggt).is_ok());
+            }
+        }
+        assert_eq!(
+            gaussian_extended_euclid(&gaussian(11, 3), &gaussian(1, 8)).ggt,
+            gaussian(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_division() {
+        let (quotient, remainder) = gaussian(27, -23).div_rem(&gaussian(8, 1)).unwrap();
+        assert_eq!(
+            &(&quotient * &gaussian(8, 1)) + &remainder,
+            gaussian(27, -23)
+        );
+        assert!(remainder.norm().double() <= gaussian(8, 1).norm());
+
+        assert_eq!(
+            gaussian(-19, 33).div_exact(&gaussian(3, 4)).unwrap(),
+            gaussian(3, 7)
+        );
+        assert!(gaussian(5, 0).div_exact(&gaussian(3, 0)).is_err());
+        assert!(gaussian(5, 0).div_rem(&GaussianInteger::zero()).is_err());
+    }
+
+    #[test]
+    fn test_normalize_and_display() {
+        for z in [
+            gaussian(3, 4),
+            gaussian(-4, 3),
+            gaussian(-3, -4),
+            gaussian(4, -3),
+        ] {
+            assert_eq!(z.normalize(), gaussian(3, 4));
+        }
+        assert_eq!(gaussian(0, -5).normalize(), gaussian(5, 0));
+        assert_eq!(gaussian(3, -4).to_string(), "3 - 4i");
+        assert_eq!(gaussian(-1, 0).to_string(), "-1 + 0i");
+        assert_eq!(gaussian(3, 4).norm(), 25.into());
+    }
+
+    #[test]
+    fn test_gaussian_primes() {
+        let service = NumberTheoryService::new(Fast);
+        let primes = [
+            gaussian(1, 1),
+            gaussian(3, 0),
+            gaussian(0, -7),
+            gaussian(2, 1),
+        ];
+        for prime in primes {
+            assert!(prime.is_gaussian_prime(service), "{}", prime);
+        }
+        let composites = [
+            gaussian(2, 0),
+            gaussian(5, 0),
+            gaussian(3, 3),
+            gaussian(1, 0),
+        ];
+        for composite in composites {
+            assert!(!composite.is_gaussian_prime(service), "{}", composite);
+        }
+    }
+
+    #[test]
+    fn test_factorize() {
+        let service = NumberTheoryService::new(Fast);
+        for z in [
+            gaussian(2, 0),
+            gaussian(-13, 34),
+            gaussian(100, 0),
+            gaussian(0, -1),
+            gaussian(1234, 5678),
+        ] {
+            let factorization = z.factorize(service).unwrap();
+            assert_eq!(factorization.value(), z);
+            assert!(factorization.unit.is_unit());
+            for (factor, _) in &factorization.factors {
+                assert!(factor.is_gaussian_prime(service), "{}", factor);
+                assert_eq!(factor, &factor.normalize());
+            }
+        }
+
+        let factorization = gaussian(2, 0).factorize(service).unwrap();
+        assert_eq!(factorization.factors, vec![(gaussian(1, 1), 2)]);
+        assert_eq!(factorization.unit, gaussian(0, -1));
+        assert!(GaussianInteger::zero().factorize(service).is_err());
+    }
+
+    #[test]
+    fn test_sum_of_two_squares() {
+        let service = NumberTheoryService::new(Fast);
+        assert_eq!(
+            sum_of_two_squares(&2.into(), service).unwrap(),
+            (1.into(), 1.into())
+        );
+        assert_eq!(
+            sum_of_two_squares(&13.into(), service).unwrap(),
+            (3.into(), 2.into())
+        );
+        let prime = BigInt::from_str("340282366920938463463374607431768211297").unwrap();
+        let (x, y) = sum_of_two_squares(&prime, service).unwrap();
+        assert_eq!(&x * &x + &y * &y, prime);
+        assert!(x.is_odd());
+
+        assert!(sum_of_two_squares(&7.into(), service).is_err());
+        assert!(sum_of_two_squares(&25.into(), service).is_err());
+    }
+}
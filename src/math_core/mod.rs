@@ -1,6 +1,13 @@
 pub mod babystep_giantstep;
-pub mod complex_number;
+pub mod blum_blum_shub_generator;
+pub mod blum_micali_generator;
 pub mod ecc;
+pub mod finite_field_polynomial;
+pub mod gaussian_integer;
+pub mod modular_matrix;
 pub mod number_theory;
+pub mod prime_order_group;
 pub mod pseudo_random_number_generator;
+pub mod random_number_generator;
+pub mod randomness;
 pub mod traits;
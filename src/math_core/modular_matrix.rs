@@ -0,0 +1,317 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{One, Zero};
+use num::Integer;
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::MatrixError::{DimensionMismatchError, NotSquareError};
+
+/// Eine quadratische Matrix über dem Restklassenring Z/mZ.
+/// Alle Einträge werden beim Erstellen in den Bereich von 0 bis m-1 reduziert.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModularMatrix {
+    rows: Vec<Vec<BigInt>>,
+    modulus: BigInt,
+}
+
+impl ModularMatrix {
+    /// Erstellt eine neue Matrix.
+    ///
+    /// # Argumente
+    /// * `rows` - Die Zeilen der Matrix.
+    /// * `modulus` - Der Modul m.
+    ///
+    /// # Fehler
+    /// * `MatrixError::NotSquareError` - Falls die Matrix leer oder nicht quadratisch ist.
+    pub fn new(rows: Vec<Vec<BigInt>>, modulus: BigInt) -> Result<Self> {
+        ensure!(
+            !rows.is_empty() && rows.iter().all(|row| row.len() == rows.len()),
+            NotSquareError
+        );
+        let rows = rows
+            .into_iter()
+            .map(|row| row.iter().map(|entry| entry.mod_floor(&modulus)).collect())
+            .collect();
+        Ok(ModularMatrix { rows, modulus })
+    }
+
+    /// Die Anzahl der Zeilen bzw. Spalten.
+    pub fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows(&self) -> &Vec<Vec<BigInt>> {
+        &self.rows
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Multipliziert die Matrix von rechts mit einem Spaltenvektor.
+    ///
+    /// # Fehler
+    /// * `MatrixError::DimensionMismatchError` - Falls die Länge des Vektors nicht zur Matrix passt.
+    pub fn multiply_vector(&self, vector: &[BigInt]) -> Result<Vec<BigInt>> {
+        ensure!(
+            vector.len() == self.dimension(),
+            DimensionMismatchError(self.dimension(), vector.len())
+        );
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(vector)
+                    .map(|(a, b)| a * b)
+                    .sum::<BigInt>()
+                    .mod_floor(&self.modulus)
+            })
+            .collect())
+    }
+
+    /// Multipliziert zwei Matrizen gleicher Dimension.
+    ///
+    /// # Fehler
+    /// * `MatrixError::DimensionMismatchError` - Falls die Dimensionen nicht übereinstimmen.
+    pub fn multiply(&self, other: &ModularMatrix) -> Result<ModularMatrix> {
+        let n = self.dimension();
+        ensure!(
+            other.dimension() == n,
+            DimensionMismatchError(n, other.dimension())
+        );
+        let rows = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| (0..n).map(|k| &self.rows[i][k] * &other.rows[k][j]).sum())
+                    .collect()
+            })
+            .collect();
+        ModularMatrix::new(rows, self.modulus.clone())
+    }
+
+    /// Berechnet die Determinante modulo m mit dem Bareiss-Verfahren in O(n^3).
+    pub fn determinant(&self) -> BigInt {
+        let (_, determinant) = bareiss_elimination(&self.rows, false);
+        determinant.mod_floor(&self.modulus)
+    }
+
+    /// Berechnet die inverse Matrix über die Adjunkte: A^-1 = det(A)^-1 * adj(A) mod m.
+    /// Die Adjunkte ergibt sich aus der bruchfreien Gauß-Jordan-Elimination von (A | I) über den
+    /// ganzen Zahlen, sodass auch zusammengesetzte Moduln wie 26 keine Sonderbehandlung brauchen.
+    ///
+    /// # Argumente
+    /// * `service` - Der Service für die Zahlentheorie, mit dem det(A)^-1 berechnet wird.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NoInverseError` - Falls die Determinante nicht teilerfremd zu m ist.
+    pub fn inverse(&self, service: NumberTheoryService) -> Result<ModularMatrix> {
+        let (adjugate, determinant) = bareiss_elimination(&self.rows, true);
+        let determinant_inverse =
+            service.modulo_inverse(&determinant.mod_floor(&self.modulus), &self.modulus)?;
+        let rows = adjugate
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|entry| entry * &determinant_inverse)
+                    .collect()
+            })
+            .collect();
+        ModularMatrix::new(rows, self.modulus.clone())
+    }
+}
+
+impl Display for ModularMatrix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let entries: Vec<String> = row.iter().map(|entry| entry.to_string()).collect();
+                format!("[{}]", entries.join(", "))
+            })
+            .collect();
+        write!(f, "[{}] mod {}", rows.join(", "), self.modulus)
+    }
+}
+
+/// Bruchfreie Gauß-Jordan-Elimination nach Bareiss über den ganzen Zahlen.
+/// Nach Schritt k ist jedes Pivotelement der Wert eines k-reihigen Minors, sodass die Division
+/// durch das vorherige Pivotelement stets aufgeht und die Einträge polynomiell groß bleiben.
+/// Am Ende steht links det(A) * I und rechts, falls mit der Einheitsmatrix erweitert wird,
+/// det(A) * A^-1 = adj(A).
+///
+/// # Argumente
+/// * `rows` - Die Zeilen der quadratischen Matrix A.
+/// * `with_adjugate` - Ob A um die Einheitsmatrix erweitert und die Adjunkte berechnet wird.
+///
+/// # Rückgabe
+/// * Die Adjunkte (leer, falls nicht angefordert oder A singulär ist) und die Determinante.
+fn bareiss_elimination(rows: &[Vec<BigInt>], with_adjugate: bool) -> (Vec<Vec<BigInt>>, BigInt) {
+    let n = rows.len();
+    let mut augmented: Vec<Vec<BigInt>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            if with_adjugate {
+                row.extend((0..n).map(|j| {
+                    if i == j {
+                        BigInt::one()
+                    } else {
+                        BigInt::zero()
+                    }
+                }));
+            }
+            row
+        })
+        .collect();
+
+    let mut previous_pivot = BigInt::one();
+    let mut sign = BigInt::one();
+    for k in 0..n {
+        let Some(pivot_row) = (k..n).find(|i| !augmented[*i][k].is_zero()) else {
+            return (vec![], BigInt::zero());
+        };
+        if pivot_row != k {
+            augmented.swap(pivot_row, k);
+            sign = -sign;
+        }
+
+        let pivot_row = augmented[k].clone();
+        for (i, row) in augmented.iter_mut().enumerate().filter(|(i, _)| *i != k) {
+            let factor = row[k].clone();
+            for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                *entry = (&pivot_row[k] * &*entry - &factor * pivot_entry) / &previous_pivot;
+            }
+            debug_assert!(row[k].is_zero() && (i > k || !row[i].is_zero()));
+        }
+        previous_pivot = pivot_row[k].clone();
+    }
+
+    // Die Zeilenvertauschungen ändern das Vorzeichen der Determinante, nicht aber A^-1.
+    // Wegen der Vertauschungen steht rechts det(PA) * A^-1 mit det(PA) = sign * det(A).
+    let determinant = &sign * &previous_pivot;
+    let adjugate = augmented
+        .into_iter()
+        .map(|row| row[n..].iter().map(|entry| entry * &sign).collect())
+        .collect();
+    (adjugate, determinant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn matrix(rows: &[&[i64]], modulus: i64) -> ModularMatrix {
+        ModularMatrix::new(
+            rows.iter()
+                .map(|row| row.iter().map(|entry| BigInt::from(*entry)).collect())
+                .collect(),
+            modulus.into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(matrix(&[&[3, 3], &[2, 5]], 26).determinant(), 9.into());
+        assert_eq!(
+            matrix(&[&[6, 24, 1], &[13, 16, 10], &[20, 17, 15]], 26).determinant(),
+            25.into()
+        );
+        // Negative Einträge werden in den Bereich 0 bis m-1 reduziert.
+        assert_eq!(matrix(&[&[-1]], 26).rows()[0][0], 25.into());
+    }
+
+    #[test]
+    fn test_inverse() {
+        for service in [
+            NumberTheoryService::new(Fast),
+            NumberTheoryService::new(Slow),
+        ] {
+            let a = matrix(&[&[6, 24, 1], &[13, 16, 10], &[20, 17, 15]], 26);
+            let inverse = a.inverse(service).unwrap();
+            assert_eq!(
+                inverse,
+                matrix(&[&[8, 5, 10], &[21, 8, 21], &[21, 12, 8]], 26)
+            );
+            assert_eq!(
+                a.multiply(&inverse).unwrap(),
+                matrix(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 1]], 26)
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_invertible() {
+        let service = NumberTheoryService::new(Fast);
+        // det = 2 ist nicht teilerfremd zu 26
+        assert!(matrix(&[&[2, 0], &[0, 1]], 26).inverse(service).is_err());
+    }
+
+    #[test]
+    fn test_multiply_vector() {
+        let a = matrix(&[&[3, 3], &[2, 5]], 26);
+        let result = a.multiply_vector(&[7.into(), 8.into()]).unwrap();
+        assert_eq!(result, vec![BigInt::from(19), BigInt::from(2)]);
+        assert!(a.multiply_vector(&[BigInt::one()]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_shape() {
+        assert!(ModularMatrix::new(vec![], 26.into()).is_err());
+        assert!(ModularMatrix::new(vec![vec![1.into(), 2.into()]], 26.into()).is_err());
+    }
+
+    #[test]
+    fn test_inverse_with_row_swaps() {
+        // Zeilenvertauschungen sind nötig, weil der erste Eintrag 0 ist.
+        let a = matrix(
+            &[&[0, 2, 7, 1], &[3, 0, 5, 9], &[4, 1, 0, 2], &[8, 6, 3, 0]],
+            26,
+        );
+        // det = 327 über den ganzen Zahlen
+        assert_eq!(a.determinant(), BigInt::from(327 % 26));
+        let inverse = a.inverse(NumberTheoryService::new(Fast)).unwrap();
+        let identity: Vec<Vec<i64>> = (0..4)
+            .map(|i| (0..4).map(|j| (i == j) as i64).collect())
+            .collect();
+        let identity: Vec<&[i64]> = identity.iter().map(|row| row.as_slice()).collect();
+        assert_eq!(a.multiply(&inverse).unwrap(), matrix(&identity, 26));
+        assert_eq!(inverse.multiply(&a).unwrap(), matrix(&identity, 26));
+    }
+
+    #[test]
+    fn test_large_dimension() {
+        // Eine obere Dreiecksmatrix mit Einsen auf der Diagonalen hat die Determinante 1.
+        // Mit Laplace-Entwicklung wäre schon n = 12 nicht mehr in sinnvoller Zeit berechenbar.
+        let n = 12;
+        let rows: Vec<Vec<BigInt>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| match j {
+                        j if j == i => BigInt::one(),
+                        j if j > i => BigInt::from(i + 2 * j),
+                        _ => BigInt::zero(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let a = ModularMatrix::new(rows, 26.into()).unwrap();
+        let inverse = a.inverse(NumberTheoryService::new(Fast)).unwrap();
+        assert_eq!(
+            a.multiply(&inverse).unwrap().rows(),
+            &(0..n)
+                .map(|i| (0..n).map(|j| BigInt::from((i == j) as i64)).collect())
+                .collect::<Vec<Vec<BigInt>>>()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use bigdecimal::num_bigint::BigInt;
+
+/// Ein Ergebnis zusammen mit den Rechenschritten, die zu ihm geführt haben. Wird von den
+/// `*_traced`-Methoden zurückgegeben, damit die Berechnung in der GUI nachvollzogen werden kann.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Traced<T, S> {
+    pub result: T,
+    pub steps: Vec<S>,
+}
+
+impl<T, S> Traced<T, S> {
+    pub fn new(result: T, steps: Vec<S>) -> Self {
+        Traced { result, steps }
+    }
+
+    /// Wendet `f` auf das Ergebnis an und behält die Schritte bei.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Traced<U, S> {
+        Traced {
+            result: f(self.result),
+            steps: self.steps,
+        }
+    }
+}
+
+/// Eine Zeile der Tabelle des erweiterten euklidischen Algorithmus für ggT(a, b):
+/// `dividend = quotient * divisor + remainder` mit `remainder = x * a + y * b`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EuclidStep {
+    pub dividend: BigInt,
+    pub divisor: BigInt,
+    pub quotient: BigInt,
+    pub remainder: BigInt,
+    pub x: BigInt,
+    pub y: BigInt,
+}
+
+/// Ein Schritt der Schnellexponentiation, die den Exponenten vom niederwertigsten Bit an liest.
+///
+/// # Felder
+/// * `bit` - Das Bit des Exponenten, das in diesem Schritt verarbeitet wird.
+/// * `power` - Die Potenz base^(2^i) mod modul, die zu diesem Bit gehört.
+/// * `result` - Das Zwischenergebnis nach dem Schritt. Es wurde mit `power` multipliziert,
+///   wenn das Bit gesetzt ist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SquareAndMultiplyStep {
+    pub bit: bool,
+    pub power: BigInt,
+    pub result: BigInt,
+}
+
+/// Ein Schritt des Babystep-Giantstep-Algorithmus von Shanks.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShanksStep {
+    /// Ein Eintrag der Tabelle der Giantsteps base^(m * j) mod modul.
+    GiantStep { j: BigInt, value: BigInt },
+    /// Ein Babystep element * base^(-i) mod modul. Stimmt er mit einem Giantstep überein, enthält
+    /// `matching_giant_step` dessen Index j und der Logarithmus ist m * j + i.
+    BabyStep {
+        i: BigInt,
+        value: BigInt,
+        matching_giant_step: Option<BigInt>,
+    },
+}
+
+/// Eine Runde des Miller-Rabin-Tests mit p - 1 = 2^s * d und d ungerade.
+///
+/// # Felder
+/// * `witness` - Die zufällig gewählte Basis a.
+/// * `sequence` - Die Folge a^d, a^(2d), a^(4d), ... Sie endet, sobald a^d = 1 ist oder p - 1
+///   erreicht wird, spätestens aber nach s Quadrierungen.
+/// * `passed` - Ob p diese Runde bestanden hat. Andernfalls ist a ein Zeuge dafür, dass p
+///   zusammengesetzt ist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MillerRabinRound {
+    pub witness: BigInt,
+    pub sequence: Vec<BigInt>,
+    pub passed: bool,
+}
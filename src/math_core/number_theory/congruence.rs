@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
+use crate::shared::errors::ArithmeticError;
+
+/// Eine Kongruenz x ≡ `remainder` mod `modulus`. Der Rest liegt stets im Bereich
+/// 0 <= `remainder` < `modulus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Congruence {
+    pub remainder: BigInt,
+    pub modulus: BigInt,
+}
+
+impl Congruence {
+    /// Erstellt eine neue Kongruenz und reduziert den Rest modulo `modulus`.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InvalidModulusError` - Falls `modulus` nicht positiv ist.
+    pub fn new(remainder: &BigInt, modulus: &BigInt) -> Result<Self> {
+        ensure!(
+            modulus.is_positive(),
+            ArithmeticError::InvalidModulusError(modulus.to_string())
+        );
+        Ok(Congruence {
+            remainder: remainder.rem_euclid(modulus),
+            modulus: modulus.clone(),
+        })
+    }
+
+    /// Die Kongruenz x ≡ 0 mod 1, die von jeder ganzen Zahl erfüllt wird. Sie ist die Lösung
+    /// eines leeren Systems.
+    pub fn trivial() -> Self {
+        Congruence {
+            remainder: BigInt::zero(),
+            modulus: BigInt::one(),
+        }
+    }
+
+    /// Fasst diese Kongruenz x ≡ a mod m und `other` x ≡ b mod n zu einer Kongruenz modulo
+    /// kgV(m, n) zusammen. Mit x = a + m * t ist m * t ≡ b - a mod n zu lösen, was genau dann
+    /// möglich ist, wenn g = ggT(m, n) die Differenz b - a teilt. Dann ist
+    /// t = (b - a) / g * u mod n / g, wobei u der Faktor von m in der Darstellung
+    /// g = u * m + v * n ist.
+    ///
+    /// # Argumente
+    /// * `other` - Die zweite Kongruenz.
+    /// * `bezout` - Das Ergebnis des erweiterten euklidischen Algorithmus für (m, n).
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InconsistentCongruencesError` - Falls g die Differenz nicht teilt.
+    pub(crate) fn merge(&self, other: &Congruence, bezout: &ExtendedEuclidResult) -> Result<Self> {
+        let difference = &other.remainder - &self.remainder;
+        ensure!(
+            (&difference % &bezout.ggt).is_zero(),
+            ArithmeticError::InconsistentCongruencesError(self.to_string(), other.to_string())
+        );
+
+        let reduced_modulus = &other.modulus / &bezout.ggt;
+        let t = (difference / &bezout.ggt * &bezout.x).rem_euclid(&reduced_modulus);
+        let modulus = &self.modulus * reduced_modulus;
+        Congruence::new(&(&self.remainder + &self.modulus * t), &modulus)
+    }
+}
+
+impl Display for Congruence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x ≡ {} mod {}", self.remainder, self.modulus)
+    }
+}
+
+/// Löst ein System von Kongruenzen nach dem chinesischen Restsatz, indem die Kongruenzen
+/// nacheinander paarweise zusammengefasst werden. Die Moduln müssen nicht paarweise
+/// teilerfremd sein.
+///
+/// # Argumente
+/// * `congruences` - Das System x ≡ a_i mod m_i.
+/// * `extended_euclid` - Der erweiterte euklidische Algorithmus des aufrufenden Services.
+///
+/// # Rückgabe
+/// * Die Lösung x ≡ a mod kgV(m_1, ..., m_k).
+///
+/// # Fehler
+/// * `ArithmeticError::InconsistentCongruencesError` - Falls das System keine Lösung hat.
+pub(crate) fn solve_congruences(
+    congruences: &[Congruence],
+    extended_euclid: impl Fn(&BigInt, &BigInt) -> ExtendedEuclidResult,
+) -> Result<Congruence> {
+    congruences
+        .iter()
+        .try_fold(Congruence::trivial(), |solution, congruence| {
+            let bezout = extended_euclid(&solution.modulus, &congruence.modulus);
+            solution.merge(congruence, &bezout)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces_remainder() {
+        let congruence = Congruence::new(&BigInt::from(-3), &BigInt::from(7)).unwrap();
+        assert_eq!(congruence.remainder, BigInt::from(4));
+        assert_eq!(congruence.to_string(), "x ≡ 4 mod 7");
+
+        assert!(Congruence::new(&BigInt::one(), &BigInt::zero()).is_err());
+        assert!(Congruence::new(&BigInt::one(), &BigInt::from(-5)).is_err());
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Signed;
+use bigdecimal::{One, Zero};
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::number_theory::primality_test::get_primes_to_300;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::traits::divisible::Divisible;
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::ArithmeticError;
+
+/// Anzahl der Runden von Miller-Rabin, mit denen die Faktoren auf Primalität geprüft werden.
+const MILLER_RABIN_ITERATIONS: u32 = 40;
+
+/// Seed der Basen für Miller-Rabin. Die Zerlegung soll reproduzierbar sein.
+const MILLER_RABIN_SEED: u32 = 13;
+
+/// Die Primfaktorzerlegung einer positiven ganzen Zahl, aufsteigend nach Primfaktoren geordnet.
+/// Die Zerlegung von 1 ist leer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Factorization {
+    factors: BTreeMap<BigInt, u32>,
+}
+
+impl Factorization {
+    /// Zerlegt `n` in Primfaktoren. Kleine Faktoren werden durch Probedivision abgespalten,
+    /// der Rest mit Pollards Rho-Methode. Ob ein Faktor prim ist, entscheidet Miller-Rabin.
+    ///
+    /// Die Laufzeit wächst mit der Wurzel des zweitgrößten Primfaktors. Zahlen mit zwei
+    /// Primfaktoren von mehr als etwa 40 Bit sind praktisch nicht zerlegbar.
+    ///
+    /// # Argumente
+    /// * `n` - Die zu zerlegende Zahl.
+    /// * `number_theory_service` - Der Service für Primzahltests und den ggT.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NonPositiveNumberError` - Falls `n` nicht positiv ist.
+    pub fn factorize(n: &BigInt, number_theory_service: NumberTheoryService) -> Result<Self> {
+        ensure!(
+            n.is_positive(),
+            ArithmeticError::NonPositiveNumberError(n.to_string())
+        );
+
+        let (factorization, _) = Self::factorize_bounded(n, None, number_theory_service);
+        Ok(factorization)
+    }
+
+    /// Zerlegt `n` wie `factorize`, bricht Pollards Rho-Methode aber nach `maximum_steps`
+    /// Schritten je zusammengesetzter Zahl ab. So bleibt die Laufzeit auch für Zahlen mit zwei
+    /// großen Primfaktoren beschränkt.
+    ///
+    /// # Argumente
+    /// * `n` - Die zu zerlegende Zahl.
+    /// * `maximum_steps` - Die Anzahl der Schritte der Rho-Methode je zusammengesetzter Zahl.
+    /// * `number_theory_service` - Der Service für Primzahltests und den ggT.
+    ///
+    /// # Rückgabe
+    /// * Die gefundenen Primfaktoren und das Produkt der nicht zerlegten zusammengesetzten
+    ///   Faktoren, das 1 ist, falls `n` vollständig zerlegt wurde.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NonPositiveNumberError` - Falls `n` nicht positiv ist.
+    pub fn factorize_partially(
+        n: &BigInt,
+        maximum_steps: u64,
+        number_theory_service: NumberTheoryService,
+    ) -> Result<(Self, BigInt)> {
+        ensure!(
+            n.is_positive(),
+            ArithmeticError::NonPositiveNumberError(n.to_string())
+        );
+        Ok(Self::factorize_bounded(
+            n,
+            Some(maximum_steps),
+            number_theory_service,
+        ))
+    }
+
+    fn factorize_bounded(
+        n: &BigInt,
+        maximum_steps: Option<u64>,
+        number_theory_service: NumberTheoryService,
+    ) -> (Self, BigInt) {
+        let mut factorization = Factorization::default();
+        let mut unfactored = BigInt::one();
+        let mut remainder = n.clone();
+        for prime in std::iter::once(2).chain(get_primes_to_300()) {
+            let prime = BigInt::from(prime);
+            while remainder.is_divisible_by(&prime) {
+                remainder /= &prime;
+                factorization.insert(prime.clone(), 1);
+            }
+        }
+
+        let mut composites = vec![remainder];
+        while let Some(m) = composites.pop() {
+            if m.is_one() {
+                continue;
+            }
+            if is_prime_without_small_factors(&m, number_theory_service) {
+                factorization.insert(m, 1);
+                continue;
+            }
+            match pollard_rho(&m, maximum_steps, number_theory_service) {
+                Some(divisor) => {
+                    composites.push(&m / &divisor);
+                    composites.push(divisor);
+                }
+                None => unfactored *= m,
+            }
+        }
+        (factorization, unfactored)
+    }
+
+    /// Erstellt die Zerlegung aus bereits bekannten Primfaktoren. Die Faktoren werden nicht auf
+    /// Primalität geprüft.
+    pub(crate) fn from_prime_powers(prime_powers: impl IntoIterator<Item = (BigInt, u32)>) -> Self {
+        let mut factorization = Factorization::default();
+        for (prime, exponent) in prime_powers {
+            factorization.insert(prime, exponent);
+        }
+        factorization
+    }
+
+    fn insert(&mut self, prime: BigInt, exponent: u32) {
+        if exponent > 0 {
+            *self.factors.entry(prime).or_insert(0) += exponent;
+        }
+    }
+
+    /// Die Primfaktoren mit ihren Exponenten in aufsteigender Reihenfolge.
+    pub fn prime_powers(&self) -> impl Iterator<Item = (&BigInt, u32)> {
+        self.factors
+            .iter()
+            .map(|(prime, exponent)| (prime, *exponent))
+    }
+
+    /// Die verschiedenen Primfaktoren in aufsteigender Reihenfolge.
+    pub fn primes(&self) -> impl Iterator<Item = &BigInt> {
+        self.factors.keys()
+    }
+
+    /// Die Zahl, deren Zerlegung dies ist.
+    pub fn value(&self) -> BigInt {
+        self.prime_powers()
+            .map(|(prime, exponent)| prime.pow(exponent))
+            .product()
+    }
+
+    /// Die Zerlegung des Produkts, d.h. die Exponenten werden addiert.
+    pub fn multiply(&self, other: &Factorization) -> Factorization {
+        let mut product = self.clone();
+        for (prime, exponent) in other.prime_powers() {
+            product.insert(prime.clone(), exponent);
+        }
+        product
+    }
+
+    /// Die Zerlegung des kleinsten gemeinsamen Vielfachen, d.h. das Maximum der Exponenten.
+    pub fn lcm(&self, other: &Factorization) -> Factorization {
+        let mut lcm = self.clone();
+        for (prime, exponent) in other.prime_powers() {
+            let current = lcm.factors.entry(prime.clone()).or_insert(0);
+            *current = (*current).max(exponent);
+        }
+        lcm
+    }
+}
+
+/// Prüft, ob `n` prim ist. Kleine Teiler werden durch Probedivision erkannt, größere Zahlen
+/// mit Miller-Rabin geprüft. Anders als `NumberTheoryServiceTrait::is_probably_prime` ist das
+/// Ergebnis auch für kleine Zahlen korrekt.
+///
+/// # Argumente
+/// * `n` - Die zu prüfende Zahl.
+/// * `number_theory_service` - Der Service für Miller-Rabin.
+pub fn is_prime(n: &BigInt, number_theory_service: NumberTheoryService) -> bool {
+    if n < &BigInt::from(2) {
+        return false;
+    }
+    for prime in std::iter::once(2).chain(get_primes_to_300()) {
+        let prime = BigInt::from(prime);
+        if n == &prime {
+            return true;
+        }
+        if n.is_divisible_by(&prime) {
+            return false;
+        }
+    }
+    is_prime_without_small_factors(n, number_theory_service)
+}
+
+/// Prüft eine Zahl ohne Primfaktoren unter 300 auf Primalität. Unter 300^2 ist jede solche Zahl
+/// prim, darüber entscheidet Miller-Rabin.
+fn is_prime_without_small_factors(n: &BigInt, number_theory_service: NumberTheoryService) -> bool {
+    let random_generator =
+        PseudoRandomNumberGenerator::new(MILLER_RABIN_SEED, number_theory_service);
+    n < &BigInt::from(300 * 300)
+        || number_theory_service.is_probably_prime(n, MILLER_RABIN_ITERATIONS, &random_generator)
+}
+
+/// Sucht mit Pollards Rho-Methode einen echten Teiler der zusammengesetzten Zahl `n`.
+/// Die Folge x_(i+1) = x_i^2 + c mod n wird nach Floyd mit einfacher und doppelter
+/// Geschwindigkeit durchlaufen, bis ggT(|x - y|, n) einen Teiler liefert. Trifft der ggT n
+/// selbst, wird die Folge mit dem nächsten c neu gestartet. Ist `maximum_steps` gesetzt, wird
+/// nach so vielen Schritten über alle Neustarts hinweg `None` zurückgegeben.
+fn pollard_rho(
+    n: &BigInt,
+    maximum_steps: Option<u64>,
+    number_theory_service: NumberTheoryService,
+) -> Option<BigInt> {
+    let step = |x: &BigInt, c: &BigInt| (x * x + c) % n;
+    let mut c = BigInt::one();
+    let mut steps = 0u64;
+    loop {
+        let mut x = BigInt::from(2);
+        let mut y = x.clone();
+        let mut divisor = BigInt::one();
+        while divisor.is_one() {
+            if maximum_steps.is_some_and(|maximum| steps >= maximum) {
+                return None;
+            }
+            steps += 1;
+            x = step(&x, &c);
+            y = step(&step(&y, &c), &c);
+            divisor = number_theory_service
+                .extended_euclid(&(&x - &y).abs(), n)
+                .ggt;
+        }
+        if &divisor != n && !divisor.is_zero() {
+            return Some(divisor);
+        }
+        c.increment_assign();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+    use std::str::FromStr;
+
+    fn prime_powers(factorization: &Factorization) -> Vec<(BigInt, u32)> {
+        factorization
+            .prime_powers()
+            .map(|(prime, exponent)| (prime.clone(), exponent))
+            .collect()
+    }
+
+    #[test]
+    fn test_factorize_small_numbers() {
+        let service = NumberTheoryService::new(Slow);
+        assert_eq!(
+            prime_powers(&Factorization::factorize(&BigInt::one(), service).unwrap()),
+            vec![]
+        );
+        assert_eq!(
+            prime_powers(&Factorization::factorize(&BigInt::from(360), service).unwrap()),
+            vec![(2.into(), 3), (3.into(), 2), (5.into(), 1)]
+        );
+        assert_eq!(
+            prime_powers(&Factorization::factorize(&BigInt::from(65537), service).unwrap()),
+            vec![(65537.into(), 1)]
+        );
+        assert!(Factorization::factorize(&BigInt::zero(), service).is_err());
+        assert!(Factorization::factorize(&BigInt::from(-12), service).is_err());
+    }
+
+    #[test]
+    fn test_factorize_with_pollard_rho() {
+        for service in [
+            NumberTheoryService::new(Slow),
+            NumberTheoryService::new(Fast),
+        ] {
+            // 2^64 + 1 = 274177 * 67280421310721
+            let n = BigInt::from_str("18446744073709551617").unwrap();
+            let factorization = Factorization::factorize(&n, service).unwrap();
+            assert_eq!(
+                prime_powers(&factorization),
+                vec![
+                    (274177.into(), 1),
+                    (BigInt::from_str("67280421310721").unwrap(), 1)
+                ]
+            );
+            assert_eq!(factorization.value(), n);
+
+            // 1000003^2 * 999983 * 7
+            let n = BigInt::from(1000003u64).pow(2u32) * 999983 * 7;
+            assert_eq!(
+                prime_powers(&Factorization::factorize(&n, service).unwrap()),
+                vec![(7.into(), 1), (999983.into(), 1), (1000003.into(), 2)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_factorize_partially() {
+        let service = NumberTheoryService::new(Fast);
+        let composite = BigInt::from(999983i64 * 1000003);
+        let n = &composite * 12;
+
+        let (factorization, unfactored) =
+            Factorization::factorize_partially(&n, 10, service).unwrap();
+        assert_eq!(
+            prime_powers(&factorization),
+            vec![(2.into(), 2), (3.into(), 1)]
+        );
+        assert_eq!(unfactored, composite);
+
+        let (factorization, unfactored) =
+            Factorization::factorize_partially(&n, 100000, service).unwrap();
+        assert_eq!(factorization.value(), n);
+        assert!(unfactored.is_one());
+        assert!(Factorization::factorize_partially(&BigInt::zero(), 10, service).is_err());
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let service = NumberTheoryService::new(Fast);
+        let primes: Vec<i64> = (0..400)
+            .filter(|n| is_prime(&BigInt::from(*n), service))
+            .collect();
+        assert_eq!(primes.len(), 78);
+        assert_eq!(primes[..5], [2, 3, 5, 7, 11]);
+        assert!(is_prime(&BigInt::from(1000003), service));
+        assert!(!is_prime(&BigInt::from(1000003i64 * 999983), service));
+        assert!(!is_prime(&BigInt::from(-7), service));
+    }
+
+    #[test]
+    fn test_multiply_and_lcm() {
+        let a = Factorization::from_prime_powers([(2.into(), 3), (3.into(), 1)]);
+        let b = Factorization::from_prime_powers([(2.into(), 1), (5.into(), 2)]);
+        assert_eq!(a.multiply(&b).value(), BigInt::from(24 * 50));
+        assert_eq!(a.lcm(&b).value(), BigInt::from(600));
+        assert_eq!(
+            Factorization::from_prime_powers([(7.into(), 0)]),
+            Factorization::default()
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use crate::math_core::number_theory::computation_trace::{
+    EuclidStep, MillerRabinRound, SquareAndMultiplyStep, Traced,
+};
+use crate::math_core::number_theory::congruence::{solve_congruences, Congruence};
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
 use anyhow::{ensure, Result};
 use bigdecimal::num_bigint::BigInt;
@@ -10,7 +14,8 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory::primality_test::PrimalityTest;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::number_theory::slow_number_theory_service::SlowNumberTheoryService;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::shared::errors::ArithmeticError;
 
 #[derive(Clone, Copy, Debug)]
@@ -28,10 +33,30 @@ impl NumberTheoryServiceTrait for FastNumberTheoryService {
         ExtendedEuclidResult::new(e.gcd, e.x, e.y)
     }
 
+    /// Die Bibliothek `num` gibt keine Zwischenschritte preis. Für das Protokoll wird daher das
+    /// Lehrbuchverfahren des `SlowNumberTheoryService` ausgeführt, das dasselbe Ergebnis liefert.
+    fn extended_euclid_traced(
+        &self,
+        a: &BigInt,
+        b: &BigInt,
+    ) -> Traced<ExtendedEuclidResult, EuclidStep> {
+        SlowNumberTheoryService::new().extended_euclid_traced(a, b)
+    }
+
     fn fast_exponentiation(&self, base: &BigInt, exponent: &BigInt, modul: &BigInt) -> BigInt {
         base.modpow(exponent, modul)
     }
 
+    /// Wie `extended_euclid_traced` über das Lehrbuchverfahren des `SlowNumberTheoryService`.
+    fn fast_exponentiation_traced(
+        &self,
+        base: &BigInt,
+        exponent: &BigInt,
+        modul: &BigInt,
+    ) -> Traced<BigInt, SquareAndMultiplyStep> {
+        SlowNumberTheoryService::new().fast_exponentiation_traced(base, exponent, modul)
+    }
+
     fn modulo_inverse(&self, n: &BigInt, modul: &BigInt) -> Result<BigInt> {
         let number_theory_service = FastNumberTheoryService::new();
         let extended_euclid_result = number_theory_service.extended_euclid(modul, n);
@@ -44,11 +69,24 @@ impl NumberTheoryServiceTrait for FastNumberTheoryService {
         Ok((modul + extended_euclid_result.y).rem_euclid(modul))
     }
 
+    /// Wie `extended_euclid_traced` über das Lehrbuchverfahren des `SlowNumberTheoryService`.
+    fn modulo_inverse_traced(
+        &self,
+        n: &BigInt,
+        modul: &BigInt,
+    ) -> Result<Traced<BigInt, EuclidStep>> {
+        SlowNumberTheoryService::new().modulo_inverse_traced(n, modul)
+    }
+
+    fn chinese_remainder(&self, congruences: &[Congruence]) -> Result<Congruence> {
+        solve_congruences(congruences, |a, b| self.extended_euclid(a, b))
+    }
+
     fn is_probably_prime(
         &self,
         p: &BigInt,
         repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
     ) -> bool {
         let primality_test = PrimalityTest::new(NumberTheoryService::new(Fast));
 
@@ -59,4 +97,19 @@ impl NumberTheoryServiceTrait for FastNumberTheoryService {
         // Sind die primitiven Tests bestanden, läuft miller_rabin an.
         primality_test.miller_rabin(p, repeats, random_generator)
     }
+
+    fn is_probably_prime_traced(
+        &self,
+        p: &BigInt,
+        repeats: u32,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
+    ) -> Traced<bool, MillerRabinRound> {
+        let primality_test = PrimalityTest::new(NumberTheoryService::new(Fast));
+
+        // Fällt p bereits durch die Probedivision, wird keine Runde von Miller-Rabin ausgeführt.
+        if PrimalityTest::fails_primitive_prime_checks(p) {
+            return Traced::new(false, vec![]);
+        }
+        primality_test.miller_rabin_traced(p, repeats, random_generator)
+    }
 }
@@ -1,5 +1,10 @@
+pub mod computation_trace;
+pub mod congruence;
 pub mod extended_euclid_result;
+pub mod factorization;
 pub mod fast_number_theory_service;
+pub mod modular_square_root;
+pub mod multiplicative_group;
 pub mod number_theory_service;
 pub mod primality_test;
 pub mod slow_number_theory_service;
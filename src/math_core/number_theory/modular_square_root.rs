@@ -0,0 +1,167 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::ArithmeticError::NoSquareRootError;
+
+/// Berechnet eine Quadratwurzel r mit r^2 ≡ a (mod p) für eine ungerade Primzahl p.
+/// Die zweite Wurzel ist p - r.
+///
+/// Für p ≡ 3 mod 4 ist r = a^((p+1)/4), für p ≡ 5 mod 8 wird das Verfahren von Atkin
+/// verwendet. Alle übrigen Primzahlen werden mit Tonelli-Shanks behandelt.
+///
+/// # Argumente
+/// * `a` - Die Zahl, deren Wurzel gesucht ist.
+/// * `prime` - Die ungerade Primzahl p. Ob p prim ist, wird nicht geprüft.
+/// * `number_theory_service` - Der Service für die Exponentiation.
+///
+/// # Rückgabe
+/// * Eine Wurzel r im Bereich 0 <= r < p.
+///
+/// # Fehler
+/// * `ArithmeticError::NoSquareRootError` - Falls a kein quadratischer Rest modulo p ist.
+pub fn modular_square_root(
+    a: &BigInt,
+    prime: &BigInt,
+    number_theory_service: NumberTheoryService,
+) -> Result<BigInt> {
+    let a = a.rem_euclid(prime);
+    if a.is_zero() {
+        return Ok(a);
+    }
+    let pow = |base: &BigInt, exponent: &BigInt| {
+        number_theory_service.fast_exponentiation(base, exponent, prime)
+    };
+    let p_minus_one: BigInt = prime - 1;
+
+    // Euler-Kriterium: a ist genau dann ein quadratischer Rest, wenn a^((p-1)/2) ≡ 1 ist.
+    ensure!(
+        pow(&a, &(&p_minus_one >> 1)).is_one(),
+        NoSquareRootError(a.to_string(), prime.to_string())
+    );
+
+    if prime.rem_euclid(&4.into()) == BigInt::from(3) {
+        return Ok(pow(&a, &((prime + 1) >> 2)));
+    }
+
+    if prime.rem_euclid(&8.into()) == BigInt::from(5) {
+        // Atkin: Mit v = (2a)^((p-5)/8) und i = 2a · v^2 gilt i^2 ≡ -1, also ist a · v · (i - 1)
+        // eine Wurzel von a.
+        let two_a = (BigInt::from(2) * &a).rem_euclid(prime);
+        let v = pow(&two_a, &((prime - 5) >> 3));
+        let i = (&two_a * &v * &v).rem_euclid(prime);
+        return Ok((&a * &v * (i - BigInt::one())).rem_euclid(prime));
+    }
+
+    tonelli_shanks(&a, prime, &p_minus_one, pow)
+}
+
+/// Tonelli-Shanks für p - 1 = q · 2^s mit ungeradem q. Ein quadratischer Nichtrest z liefert
+/// eine Einheitswurzel der Ordnung 2^s, mit der der Fehlerterm t schrittweise auf 1 gebracht wird.
+fn tonelli_shanks(
+    a: &BigInt,
+    prime: &BigInt,
+    p_minus_one: &BigInt,
+    pow: impl Fn(&BigInt, &BigInt) -> BigInt,
+) -> Result<BigInt> {
+    let s = p_minus_one.trailing_zeros().unwrap_or(0);
+    let q = p_minus_one >> s;
+    let half = p_minus_one >> 1;
+
+    let mut z = BigInt::from(2);
+    while pow(&z, &half) != *p_minus_one {
+        z += 1;
+    }
+
+    let mut m = s;
+    let mut c = pow(&z, &q);
+    let mut t = pow(a, &q);
+    let mut r = pow(a, &((&q + 1) >> 1));
+    while !t.is_one() {
+        // Kleinstes i mit t^(2^i) = 1. Da t eine Quadratzahl ist, gilt i < m.
+        let mut i = 0;
+        let mut t_power = t.clone();
+        while !t_power.is_one() {
+            t_power = (&t_power * &t_power).rem_euclid(prime);
+            i += 1;
+        }
+        let b = pow(&c, &(BigInt::one() << (m - i - 1)));
+        m = i;
+        c = (&b * &b).rem_euclid(prime);
+        t = (t * &c).rem_euclid(prime);
+        r = (r * b).rem_euclid(prime);
+    }
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn assert_all_roots(p: i64) {
+        let prime = BigInt::from(p);
+        let squares: Vec<BigInt> = (0..p).map(|x| BigInt::from(x * x % p)).collect();
+        for service in [
+            NumberTheoryService::new(Fast),
+            NumberTheoryService::new(Slow),
+        ] {
+            for a in (0..p).map(BigInt::from) {
+                match modular_square_root(&a, &prime, service) {
+                    Ok(root) => {
+                        assert!(root < prime);
+                        assert_eq!((&root * &root).rem_euclid(&prime), a);
+                    }
+                    Err(_) => assert!(!squares.contains(&a), "{} mod {}", a, prime),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prime_congruent_three_mod_four() {
+        assert_all_roots(19);
+        assert_all_roots(43);
+    }
+
+    #[test]
+    fn test_prime_congruent_five_mod_eight() {
+        assert_all_roots(13);
+        assert_all_roots(29);
+        assert_all_roots(101);
+    }
+
+    #[test]
+    fn test_prime_congruent_one_mod_eight() {
+        // 17 - 1 = 2^4 und 113 - 1 = 7 · 2^4
+        assert_all_roots(17);
+        assert_all_roots(113);
+        assert_all_roots(257);
+    }
+
+    #[test]
+    fn test_negative_input_and_non_residue() {
+        let service = NumberTheoryService::new(Fast);
+        let prime = BigInt::from(13);
+        let root = modular_square_root(&(-1).into(), &prime, service).unwrap();
+        assert_eq!((&root * &root).rem_euclid(&prime), BigInt::from(12));
+        assert!(modular_square_root(&2.into(), &prime, service).is_err());
+    }
+
+    #[test]
+    fn test_large_prime() {
+        // p = 2^255 - 19 ≡ 5 mod 8
+        let service = NumberTheoryService::new(Fast);
+        let prime = (BigInt::one() << 255) - 19;
+        let x = BigInt::from(123456789).pow(7);
+        let a = (&x * &x).rem_euclid(&prime);
+        let root = modular_square_root(&a, &prime, service).unwrap();
+        assert!(root == x.rem_euclid(&prime) || root == (&prime - &x).rem_euclid(&prime));
+    }
+}
@@ -0,0 +1,273 @@
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::{Euclid, Signed};
+use bigdecimal::{One, Zero};
+
+use crate::math_core::number_theory::factorization::Factorization;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::traits::increment::Increment;
+use crate::shared::errors::ArithmeticError;
+
+/// Die Einheitengruppe (Z/nZ)* der zu n teilerfremden Restklassen. Bei der Erstellung wird n
+/// einmalig faktorisiert; daraus folgen die Gruppenordnung φ(n), der Exponent λ(n) und deren
+/// Primfaktoren, die für Elementordnungen und den Test auf Primitivwurzeln gebraucht werden.
+#[derive(Clone, Debug)]
+pub struct MultiplicativeGroup {
+    modulus: BigInt,
+    euler_phi: Factorization,
+    carmichael_lambda: Factorization,
+    number_theory_service: NumberTheoryService,
+}
+
+impl MultiplicativeGroup {
+    /// Erstellt die Einheitengruppe modulo `modulus`.
+    ///
+    /// # Argumente
+    /// * `modulus` - Das Modul n >= 1.
+    /// * `number_theory_service` - Der Service für Faktorisierung und Exponentiation.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InvalidModulusError` - Falls `modulus` nicht positiv ist.
+    pub fn new(modulus: &BigInt, number_theory_service: NumberTheoryService) -> Result<Self> {
+        ensure!(
+            modulus.is_positive(),
+            ArithmeticError::InvalidModulusError(modulus.to_string())
+        );
+        let factorization = Factorization::factorize(modulus, number_theory_service)?;
+
+        // φ(p^k) = p^(k-1) * (p - 1) ist multiplikativ, λ(n) ist das kgV der λ(p^k).
+        // λ(p^k) stimmt mit φ(p^k) überein, außer für 2^k mit k >= 3: λ(2^k) = 2^(k-2).
+        let mut euler_phi = Factorization::default();
+        let mut carmichael_lambda = Factorization::default();
+        for (prime, exponent) in factorization.prime_powers() {
+            let prime_power_phi =
+                Factorization::from_prime_powers([(prime.clone(), exponent - 1)]).multiply(
+                    &Factorization::factorize(&prime.decrement(), number_theory_service)?,
+                );
+            let prime_power_lambda = match (prime == &BigInt::from(2), exponent >= 3) {
+                (true, true) => Factorization::from_prime_powers([(prime.clone(), exponent - 2)]),
+                _ => prime_power_phi.clone(),
+            };
+            euler_phi = euler_phi.multiply(&prime_power_phi);
+            carmichael_lambda = carmichael_lambda.lcm(&prime_power_lambda);
+        }
+
+        Ok(MultiplicativeGroup {
+            modulus: modulus.clone(),
+            euler_phi,
+            carmichael_lambda,
+            number_theory_service,
+        })
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Die eulersche φ-Funktion, d.h. die Anzahl der Elemente der Gruppe.
+    pub fn euler_phi(&self) -> BigInt {
+        self.euler_phi.value()
+    }
+
+    /// Die Carmichael-Funktion λ(n), d.h. der Exponent der Gruppe: die kleinste Zahl m mit
+    /// a^m ≡ 1 mod n für alle a. Für RSA genügt λ(n) statt φ(n) zur Berechnung von d.
+    pub fn carmichael_lambda(&self) -> BigInt {
+        self.carmichael_lambda.value()
+    }
+
+    /// Ob die Gruppe zyklisch ist, also Primitivwurzeln besitzt. Das ist genau für
+    /// n = 1, 2, 4, p^k und 2p^k mit einer ungeraden Primzahl p der Fall, und genau dann gilt
+    /// λ(n) = φ(n).
+    pub fn has_primitive_root(&self) -> bool {
+        self.euler_phi == self.carmichael_lambda
+    }
+
+    /// Berechnet die Ordnung von `element`, d.h. den kleinsten Exponenten m > 0 mit
+    /// element^m ≡ 1 mod n. Die Ordnung teilt λ(n); ausgehend von λ(n) wird jeder Primfaktor
+    /// so oft abgespalten, wie die Potenz dabei 1 bleibt.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NotAUnitError` - Falls `element` nicht teilerfremd zu n ist.
+    pub fn order(&self, element: &BigInt) -> Result<BigInt> {
+        let element = self.unit(element)?;
+
+        let mut order = self.carmichael_lambda();
+        for (prime, exponent) in self.carmichael_lambda.prime_powers() {
+            for _ in 0..exponent {
+                let candidate = &order / prime;
+                if !self.is_one(&element, &candidate) {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        Ok(order)
+    }
+
+    /// Prüft, ob `element` eine Primitivwurzel modulo n ist, d.h. die Gruppe erzeugt. Ist die
+    /// Gruppe zyklisch, genügt es, element^(φ(n)/q) ≢ 1 für jeden Primfaktor q von φ(n) zu prüfen.
+    pub fn is_primitive_root(&self, element: &BigInt) -> bool {
+        if !self.has_primitive_root() {
+            return false;
+        }
+        let Ok(element) = self.unit(element) else {
+            return false;
+        };
+        let euler_phi = self.euler_phi();
+        self.euler_phi
+            .primes()
+            .all(|prime| !self.is_one(&element, &(&euler_phi / prime)))
+    }
+
+    /// Sucht die kleinste Primitivwurzel modulo n.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NoPrimitiveRootError` - Falls die Gruppe nicht zyklisch ist.
+    pub fn primitive_root(&self) -> Result<BigInt> {
+        ensure!(
+            self.has_primitive_root(),
+            ArithmeticError::NoPrimitiveRootError(self.modulus.to_string())
+        );
+        let mut candidate = BigInt::zero();
+        while !self.is_primitive_root(&candidate) {
+            candidate.increment_assign();
+        }
+        Ok(candidate)
+    }
+
+    /// Zählt alle Primitivwurzeln modulo n auf. Ist g eine Primitivwurzel, so sind es genau die
+    /// φ(φ(n)) Potenzen g^k mit ggT(k, φ(n)) = 1. Der Aufwand wächst linear mit φ(n).
+    ///
+    /// # Rückgabe
+    /// * Die Primitivwurzeln in aufsteigender Reihenfolge.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::NoPrimitiveRootError` - Falls die Gruppe nicht zyklisch ist.
+    pub fn primitive_roots(&self) -> Result<Vec<BigInt>> {
+        let generator = self.primitive_root()?;
+        let euler_phi = self.euler_phi();
+
+        let mut primitive_roots = Vec::new();
+        let mut power = BigInt::one().rem_euclid(&self.modulus);
+        let mut k = BigInt::zero();
+        while k < euler_phi {
+            power = (power * &generator).rem_euclid(&self.modulus);
+            k.increment_assign();
+            if self
+                .number_theory_service
+                .extended_euclid(&k, &euler_phi)
+                .ggt
+                .is_one()
+            {
+                primitive_roots.push(power.clone());
+            }
+        }
+        primitive_roots.sort();
+        Ok(primitive_roots)
+    }
+
+    /// Reduziert `element` modulo n und prüft, ob es eine Einheit ist.
+    fn unit(&self, element: &BigInt) -> Result<BigInt> {
+        let element = element.rem_euclid(&self.modulus);
+        ensure!(
+            self.number_theory_service
+                .extended_euclid(&element, &self.modulus)
+                .ggt
+                .is_one(),
+            ArithmeticError::NotAUnitError(element.to_string(), self.modulus.to_string())
+        );
+        Ok(element)
+    }
+
+    fn is_one(&self, element: &BigInt, exponent: &BigInt) -> bool {
+        self.number_theory_service
+            .fast_exponentiation(element, exponent, &self.modulus)
+            == BigInt::one().rem_euclid(&self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::{
+        Fast, Slow,
+    };
+
+    fn run_test_for_all_services(test: impl Fn(NumberTheoryService)) {
+        test(NumberTheoryService::new(Slow));
+        test(NumberTheoryService::new(Fast));
+    }
+
+    fn create_group(modulus: i64, service: NumberTheoryService) -> MultiplicativeGroup {
+        MultiplicativeGroup::new(&modulus.into(), service).unwrap()
+    }
+
+    #[test]
+    fn test_euler_phi_and_carmichael_lambda() {
+        run_test_for_all_services(|service| {
+            // (n, φ(n), λ(n))
+            let values = [
+                (1, 1, 1),
+                (2, 1, 1),
+                (4, 2, 2),
+                (8, 4, 2),
+                (9, 6, 6),
+                (15, 8, 4),
+                (32, 16, 8),
+                (561, 320, 80),
+                (1000, 400, 100),
+                (65537, 65536, 65536),
+            ];
+            for (n, phi, lambda) in values {
+                let group = create_group(n, service);
+                assert_eq!(group.euler_phi(), phi.into(), "φ({})", n);
+                assert_eq!(group.carmichael_lambda(), lambda.into(), "λ({})", n);
+            }
+            assert!(MultiplicativeGroup::new(&BigInt::zero(), service).is_err());
+        });
+    }
+
+    #[test]
+    fn test_order() {
+        run_test_for_all_services(|service| {
+            let group = create_group(31, service);
+            assert_eq!(group.order(&1.into()).unwrap(), 1.into());
+            assert_eq!(group.order(&2.into()).unwrap(), 5.into());
+            assert_eq!(group.order(&3.into()).unwrap(), 30.into());
+            assert_eq!(group.order(&(-1).into()).unwrap(), 2.into());
+
+            let group = create_group(15, service);
+            assert_eq!(group.order(&2.into()).unwrap(), 4.into());
+            assert!(group.order(&6.into()).is_err());
+        });
+    }
+
+    #[test]
+    fn test_primitive_roots() {
+        run_test_for_all_services(|service| {
+            for (n, root) in [(1, 0), (2, 1), (4, 3), (7, 3), (23, 5), (25, 2), (54, 5)] {
+                assert_eq!(
+                    create_group(n, service).primitive_root().unwrap(),
+                    root.into()
+                );
+            }
+            assert_eq!(
+                create_group(18, service).primitive_roots().unwrap(),
+                vec![5.into(), 11.into()]
+            );
+            assert_eq!(
+                create_group(41, service).primitive_roots().unwrap().len(),
+                16
+            );
+
+            for n in [8, 12, 15, 63] {
+                let group = create_group(n, service);
+                assert!(!group.has_primitive_root());
+                assert!(!group.is_primitive_root(&3.into()));
+                assert!(group.primitive_root().is_err());
+            }
+        });
+    }
+}
@@ -1,10 +1,14 @@
+use crate::math_core::number_theory::computation_trace::{
+    EuclidStep, MillerRabinRound, SquareAndMultiplyStep, Traced,
+};
+use crate::math_core::number_theory::congruence::Congruence;
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
 use anyhow::Result;
 use num::BigInt;
 
 use crate::math_core::number_theory::fast_number_theory_service::FastNumberTheoryService;
 use crate::math_core::number_theory::slow_number_theory_service::SlowNumberTheoryService;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 
 /// Gibt an, ob die schnelle oder die langsame Implementierung des `NumberTheoryService` verwendet werden soll.
 pub enum NumberTheoryServiceSpeed {
@@ -41,6 +45,17 @@ impl NumberTheoryServiceTrait for NumberTheoryService {
         }
     }
 
+    fn extended_euclid_traced(
+        &self,
+        a: &BigInt,
+        b: &BigInt,
+    ) -> Traced<ExtendedEuclidResult, EuclidStep> {
+        match self {
+            NumberTheoryService::FastService(service) => service.extended_euclid_traced(a, b),
+            NumberTheoryService::SlowService(service) => service.extended_euclid_traced(a, b),
+        }
+    }
+
     fn fast_exponentiation(&self, base: &BigInt, exponent: &BigInt, modul: &BigInt) -> BigInt {
         match self {
             NumberTheoryService::FastService(service) => {
@@ -52,6 +67,22 @@ impl NumberTheoryServiceTrait for NumberTheoryService {
         }
     }
 
+    fn fast_exponentiation_traced(
+        &self,
+        base: &BigInt,
+        exponent: &BigInt,
+        modul: &BigInt,
+    ) -> Traced<BigInt, SquareAndMultiplyStep> {
+        match self {
+            NumberTheoryService::FastService(service) => {
+                service.fast_exponentiation_traced(base, exponent, modul)
+            }
+            NumberTheoryService::SlowService(service) => {
+                service.fast_exponentiation_traced(base, exponent, modul)
+            }
+        }
+    }
+
     fn modulo_inverse(&self, n: &BigInt, modul: &BigInt) -> Result<BigInt> {
         match self {
             NumberTheoryService::FastService(service) => service.modulo_inverse(n, modul),
@@ -59,11 +90,29 @@ impl NumberTheoryServiceTrait for NumberTheoryService {
         }
     }
 
+    fn modulo_inverse_traced(
+        &self,
+        n: &BigInt,
+        modul: &BigInt,
+    ) -> Result<Traced<BigInt, EuclidStep>> {
+        match self {
+            NumberTheoryService::FastService(service) => service.modulo_inverse_traced(n, modul),
+            NumberTheoryService::SlowService(service) => service.modulo_inverse_traced(n, modul),
+        }
+    }
+
+    fn chinese_remainder(&self, congruences: &[Congruence]) -> Result<Congruence> {
+        match self {
+            NumberTheoryService::FastService(service) => service.chinese_remainder(congruences),
+            NumberTheoryService::SlowService(service) => service.chinese_remainder(congruences),
+        }
+    }
+
     fn is_probably_prime(
         &self,
         p: &BigInt,
         repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
     ) -> bool {
         match self {
             NumberTheoryService::FastService(service) => {
@@ -74,6 +123,22 @@ impl NumberTheoryServiceTrait for NumberTheoryService {
             }
         }
     }
+
+    fn is_probably_prime_traced(
+        &self,
+        p: &BigInt,
+        repeats: u32,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
+    ) -> Traced<bool, MillerRabinRound> {
+        match self {
+            NumberTheoryService::FastService(service) => {
+                service.is_probably_prime_traced(p, repeats, random_generator)
+            }
+            NumberTheoryService::SlowService(service) => {
+                service.is_probably_prime_traced(p, repeats, random_generator)
+            }
+        }
+    }
 }
 
 /// Hält die notwendigen Methoden der Zahlentheorie bereit.
@@ -100,6 +165,18 @@ pub trait NumberTheoryServiceTrait {
     /// ```
     fn extended_euclid(&self, a: &BigInt, b: &BigInt) -> ExtendedEuclidResult;
 
+    /// Berechnet wie `extended_euclid` den erweiterten euklidischen Algorithmus und protokolliert
+    /// dabei jede Division als Zeile der Tabelle.
+    ///
+    /// # Rückgabewert
+    ///
+    /// * Das Ergebnis von `extended_euclid` und die Zeilen der Tabelle.
+    fn extended_euclid_traced(
+        &self,
+        a: &BigInt,
+        b: &BigInt,
+    ) -> Traced<ExtendedEuclidResult, EuclidStep>;
+
     /// Berechnet die Schnellexponentiation für eine Basis `base`, einen Exponent `exponent`
     /// und einen Modulus `modul`.
     ///
@@ -126,6 +203,19 @@ pub trait NumberTheoryServiceTrait {
     /// ```
     fn fast_exponentiation(&self, base: &BigInt, exponent: &BigInt, modul: &BigInt) -> BigInt;
 
+    /// Berechnet wie `fast_exponentiation` die Schnellexponentiation und protokolliert dabei
+    /// für jedes Bit des Exponenten die Quadrierung und gegebenenfalls die Multiplikation.
+    ///
+    /// # Rückgabewert
+    ///
+    /// * Das Ergebnis von `fast_exponentiation` und ein Schritt pro Bit des Exponenten.
+    fn fast_exponentiation_traced(
+        &self,
+        base: &BigInt,
+        exponent: &BigInt,
+        modul: &BigInt,
+    ) -> Traced<BigInt, SquareAndMultiplyStep>;
+
     /// Berechnet das modulare Inverse von `n` modulo `modul`.
     ///
     /// # Argumente
@@ -153,6 +243,53 @@ pub trait NumberTheoryServiceTrait {
     /// ```
     fn modulo_inverse(&self, n: &BigInt, modul: &BigInt) -> Result<BigInt>;
 
+    /// Berechnet wie `modulo_inverse` das modulare Inverse und protokolliert dabei die Tabelle
+    /// des erweiterten euklidischen Algorithmus für ggT(`modul`, `n`).
+    ///
+    /// # Fehler
+    ///
+    /// * `ArithmeticError::NoInverseError` - Wenn `n` und `modul` nicht teilerfremd sind.
+    fn modulo_inverse_traced(
+        &self,
+        n: &BigInt,
+        modul: &BigInt,
+    ) -> Result<Traced<BigInt, EuclidStep>>;
+
+    /// Löst ein System x ≡ a_i mod m_i nach dem chinesischen Restsatz. Die Moduln müssen nicht
+    /// paarweise teilerfremd sein; gemeinsame Teiler werden über den ggT auf Widersprüche
+    /// geprüft.
+    ///
+    /// # Argumente
+    /// * `congruences` - Die Kongruenzen des Systems. Ein leeres System hat die Lösung x ≡ 0 mod 1.
+    ///
+    /// # Rückgabe
+    /// * Die Lösung x ≡ a mod kgV(m_1, ..., m_k) mit 0 <= a < kgV(m_1, ..., m_k).
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InconsistentCongruencesError` - Wenn das System keine Lösung hat.
+    ///
+    /// # Beispiel
+    ///
+    /// ```rust
+    /// # use bigdecimal::num_bigint::BigInt;
+    /// # use encryption_tool::math_core::number_theory::congruence::Congruence;
+    /// # use encryption_tool::math_core::number_theory::number_theory_service::{
+    /// #     NumberTheoryService, NumberTheoryServiceSpeed::Fast, NumberTheoryServiceTrait,
+    /// # };
+    /// # fn main() -> anyhow::Result<()> {
+    /// let congruences = [
+    ///     Congruence::new(&BigInt::from(3), &BigInt::from(4))?,
+    ///     Congruence::new(&BigInt::from(5), &BigInt::from(6))?,
+    /// ];
+    ///
+    /// let result = NumberTheoryService::new(Fast).chinese_remainder(&congruences)?;
+    ///
+    /// assert_eq!(result, Congruence::new(&BigInt::from(11), &BigInt::from(12))?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn chinese_remainder(&self, congruences: &[Congruence]) -> Result<Congruence>;
+
     /// Diese Methode führt einen probabilistischen Primzahltest für den angegebenen Integer durch.
     ///
     /// # Argumente
@@ -167,8 +304,21 @@ pub trait NumberTheoryServiceTrait {
         &self,
         p: &BigInt,
         repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
     ) -> bool;
+
+    /// Führt wie `is_probably_prime` einen Primzahltest durch und protokolliert dabei für jede
+    /// Runde von Miller-Rabin die gewählte Basis und die Folge der Potenzen. Die Runden werden
+    /// nacheinander ausgeführt und enden beim ersten Zeugen für eine zusammengesetzte Zahl.
+    ///
+    /// # Rückgabe
+    /// * Das Testergebnis und die ausgeführten Runden.
+    fn is_probably_prime_traced(
+        &self,
+        p: &BigInt,
+        repeats: u32,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
+    ) -> Traced<bool, MillerRabinRound>;
 }
 
 #[cfg(test)]
@@ -180,6 +330,9 @@ mod tests {
     };
 
     use super::*;
+    use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+    use crate::shared::errors::ArithmeticError;
+    use bigdecimal::One;
 
     fn run_test_for_all_services(test: impl Fn(NumberTheoryService)) {
         test(NumberTheoryService::new(Slow)); // Langsame, eigene Implementierung
@@ -216,6 +369,49 @@ mod tests {
         });
     }
 
+    #[test]
+    fn extended_euclid_traced_test() {
+        run_test_for_all_services(|service| {
+            let (a, b) = (BigInt::from(78), BigInt::from(99));
+            let traced = service.extended_euclid_traced(&a, &b);
+            assert_eq!(traced.result, service.extended_euclid(&a, &b));
+            assert_eq!(traced.steps.len(), 6);
+            assert_eq!(
+                traced.steps[1],
+                EuclidStep {
+                    dividend: 99.into(),
+                    divisor: 78.into(),
+                    quotient: 1.into(),
+                    remainder: 21.into(),
+                    x: BigInt::from(-1),
+                    y: 1.into(),
+                }
+            );
+            for step in &traced.steps {
+                assert_eq!(
+                    step.dividend,
+                    &step.quotient * &step.divisor + &step.remainder
+                );
+                assert_eq!(step.remainder, &step.x * &a + &step.y * &b);
+            }
+            assert_eq!(traced.steps.last().unwrap().remainder, 0.into());
+        });
+    }
+
+    #[test]
+    fn modulo_inverse_traced_test() {
+        run_test_for_all_services(|service| {
+            let traced = service
+                .modulo_inverse_traced(&315.into(), &661643.into())
+                .unwrap();
+            assert_eq!(traced.result, 342374.into());
+            assert_eq!(traced.steps[0].dividend, 661643.into());
+            assert!(service
+                .modulo_inverse_traced(&78.into(), &99.into())
+                .is_err());
+        });
+    }
+
     #[test]
     fn modulo_inverse_test() {
         run_test_for_all_services(|service| {
@@ -245,6 +441,76 @@ mod tests {
         });
     }
 
+    #[test]
+    fn chinese_remainder_test() {
+        let system = |pairs: &[(i64, i64)]| -> Vec<Congruence> {
+            pairs
+                .iter()
+                .map(|(a, m)| Congruence::new(&(*a).into(), &(*m).into()).unwrap())
+                .collect()
+        };
+
+        run_test_for_all_services(|service| {
+            // Teilerfremde Moduln: das klassische Beispiel aus dem Sunzi Suanjing.
+            let result = service
+                .chinese_remainder(&system(&[(2, 3), (3, 5), (2, 7)]))
+                .unwrap();
+            assert_eq!(result, system(&[(23, 105)])[0]);
+
+            // Nicht teilerfremde Moduln: Die Lösung ist modulo kgV(4, 6, 10) = 60 eindeutig.
+            let result = service
+                .chinese_remainder(&system(&[(3, 4), (5, 6), (7, 10)]))
+                .unwrap();
+            assert_eq!(result, system(&[(47, 60)])[0]);
+
+            // Ein Modul, das ein anderes teilt, ändert die Lösung nicht.
+            let result = service
+                .chinese_remainder(&system(&[(5, 12), (1, 4)]))
+                .unwrap();
+            assert_eq!(result, system(&[(5, 12)])[0]);
+
+            assert_eq!(
+                service.chinese_remainder(&[]).unwrap(),
+                Congruence::trivial()
+            );
+        });
+    }
+
+    #[test]
+    fn chinese_remainder_big_numbers_test() {
+        run_test_for_all_services(|service| {
+            let p = BigInt::from_str("170141183460469231731687303715884105727").unwrap();
+            let q = BigInt::from_str("618970019642690137449562111").unwrap();
+            let x = BigInt::from_str("12345678901234567890123456789012345").unwrap();
+            let congruences = [
+                Congruence::new(&x, &(&p * 6)).unwrap(),
+                Congruence::new(&x, &(&q * 10)).unwrap(),
+            ];
+            let result = service.chinese_remainder(&congruences).unwrap();
+            assert_eq!(result.modulus, &p * &q * 30);
+            assert_eq!(result.remainder, x);
+        });
+    }
+
+    #[test]
+    fn chinese_remainder_inconsistent_test() {
+        run_test_for_all_services(|service| {
+            let congruences = [
+                Congruence::new(&1.into(), &4.into()).unwrap(),
+                Congruence::new(&2.into(), &6.into()).unwrap(),
+            ];
+            let error = service.chinese_remainder(&congruences).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<ArithmeticError>(),
+                Some(ArithmeticError::InconsistentCongruencesError(_, _))
+            ));
+            assert_eq!(
+                error.to_string(),
+                "Inconsistent congruences error: x ≡ 1 mod 4 contradicts x ≡ 2 mod 6"
+            );
+        });
+    }
+
     #[test]
     fn is_probably_prime_test() {
         let slow_service = NumberTheoryService::new(Slow);
@@ -303,6 +569,79 @@ mod tests {
         });
     }
 
+    #[test]
+    fn is_probably_prime_traced_test() {
+        run_test_for_all_services(|service| {
+            let random_generator = &PseudoRandomNumberGenerator::new(11, service);
+            let p = BigInt::from(7919);
+            let traced = service.is_probably_prime_traced(&p, 10, random_generator);
+            assert!(traced.result);
+            assert_eq!(traced.steps.len(), 10);
+            for round in &traced.steps {
+                assert!(round.passed);
+                assert!(
+                    round.sequence[0] == BigInt::one()
+                        || round.sequence.last() == Some(&(&p - BigInt::one()))
+                );
+            }
+        });
+
+        // Die Carmichael-Zahl 561 besteht den Fermat-Test zu jeder teilerfremden Basis, aber nicht
+        // Miller-Rabin. Der schnelle Service erkennt sie schon an der Probedivision durch 3.
+        let slow_service = NumberTheoryService::new(Slow);
+        let traced = slow_service.is_probably_prime_traced(
+            &561.into(),
+            20,
+            &PseudoRandomNumberGenerator::new(11, slow_service),
+        );
+        assert!(!traced.result);
+        assert!(!traced.steps.last().unwrap().passed);
+
+        let fast_service = NumberTheoryService::new(Fast);
+        let traced = fast_service.is_probably_prime_traced(
+            &561.into(),
+            20,
+            &PseudoRandomNumberGenerator::new(11, fast_service),
+        );
+        assert!(!traced.result);
+        assert!(traced.steps.is_empty());
+    }
+
+    #[test]
+    fn fast_exponentiation_traced_test() {
+        run_test_for_all_services(|service| {
+            let traced = service.fast_exponentiation_traced(&37.into(), &2.into(), &89.into());
+            assert_eq!(traced.result, 34.into());
+            assert_eq!(
+                traced.steps,
+                vec![
+                    SquareAndMultiplyStep {
+                        bit: false,
+                        power: 37.into(),
+                        result: 1.into(),
+                    },
+                    SquareAndMultiplyStep {
+                        bit: true,
+                        power: 34.into(),
+                        result: 34.into(),
+                    },
+                ]
+            );
+
+            let (base, exponent, modul) = (
+                BigInt::from(56156334590832345u64),
+                BigInt::from(109458390583094852904812340u128),
+                BigInt::from(564234859),
+            );
+            let traced = service.fast_exponentiation_traced(&base, &exponent, &modul);
+            assert_eq!(
+                traced.result,
+                service.fast_exponentiation(&base, &exponent, &modul)
+            );
+            assert_eq!(traced.steps.len() as u64, exponent.bits());
+        });
+    }
+
     #[test]
     fn fast_exponentiation_happy_flow() {
         run_test_for_all_services(|service| {
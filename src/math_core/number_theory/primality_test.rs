@@ -3,9 +3,10 @@ use log::warn;
 use num::{BigInt, One, Zero};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
+use crate::math_core::number_theory::computation_trace::{MillerRabinRound, Traced};
 use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
 use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceTrait;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::increment::Increment;
 use crate::math_core::traits::parity::Parity;
@@ -62,7 +63,7 @@ impl PrimalityTest {
         &self,
         p: &BigInt,
         repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
     ) -> bool {
         let mut d = p.decrement();
         let mut s = BigInt::zero();
@@ -80,10 +81,58 @@ impl PrimalityTest {
             while p.is_divisible_by(&a) {
                 a = random_generator.take(&2.into(), &p, &n_counter);
             }
-            self.miller_rabin_iteration(p, &s, &d, &a)
+            self.miller_rabin_iteration(p, &s, &d, &a, None)
         })
     }
 
+    /// Führt wie `miller_rabin` den Miller-Rabin-Test durch, jedoch Runde für Runde
+    /// nacheinander, und protokolliert für jede Runde die Basis und die Folge der Potenzen.
+    /// Der Test endet mit der ersten Runde, die einen Zeugen für eine zusammengesetzte Zahl findet.
+    ///
+    /// # Arguments
+    /// * `p`: Der Integer, für den der Primzahltest durchgeführt werden soll.
+    /// * `repeats`: Die Anzahl der Wiederholungen des Tests.
+    /// * `random_generator`: Ein Pseudozufallszahlengenerator, der für die Erzeugung
+    ///   der Zufallszahlen verwendet wird.
+    ///
+    /// # Rückgabe
+    /// * Das Testergebnis und die ausgeführten Runden.
+    pub fn miller_rabin_traced(
+        &self,
+        p: &BigInt,
+        repeats: u32,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
+    ) -> Traced<bool, MillerRabinRound> {
+        let mut d = p.decrement();
+        let mut s = BigInt::zero();
+
+        while d.is_even() {
+            d.half_assign();
+            s.increment_assign();
+        }
+
+        let n_counter = RelaxedCounter::new(0);
+        let mut rounds = Vec::new();
+
+        for _ in 0..repeats {
+            let mut a = random_generator.take(&2.into(), p, &n_counter);
+            while p.is_divisible_by(&a) {
+                a = random_generator.take(&2.into(), p, &n_counter);
+            }
+            let mut sequence = Vec::new();
+            let passed = self.miller_rabin_iteration(p, &s, &d, &a, Some(&mut sequence));
+            rounds.push(MillerRabinRound {
+                witness: a,
+                sequence,
+                passed,
+            });
+            if !passed {
+                return Traced::new(false, rounds);
+            }
+        }
+        Traced::new(true, rounds)
+    }
+
     /// Diese Methode führt eine Iteration des Miller-Rabin-Primzahltests für den angegebenen Integer durch.
     ///
     /// # Arguments
@@ -91,11 +140,22 @@ impl PrimalityTest {
     /// * `s`: Der Exponent 's' des Miller-Rabin-Tests.
     /// * `d`: Der Defekt des Integers 'p'.
     /// * `a`: Die Zufallszahl, die für den Test verwendet wird.
+    /// * `sequence`: Falls gegeben, werden hier alle berechneten Potenzen von `a` abgelegt.
     ///
     /// # Rückgabe
     /// * `true`, wenn der Integer wahrscheinlich eine Primzahl ist, `false`, wenn nicht.
-    fn miller_rabin_iteration(&self, p: &BigInt, s: &BigInt, d: &BigInt, a: &BigInt) -> bool {
+    fn miller_rabin_iteration(
+        &self,
+        p: &BigInt,
+        s: &BigInt,
+        d: &BigInt,
+        a: &BigInt,
+        mut sequence: Option<&mut Vec<BigInt>>,
+    ) -> bool {
         let mut x = self.number_theory_service.fast_exponentiation(a, d, p);
+        if let Some(sequence) = sequence.as_mut() {
+            sequence.push(x.clone());
+        }
 
         if x.is_one() || x == p.decrement() {
             return true;
@@ -107,6 +167,9 @@ impl PrimalityTest {
             x = self
                 .number_theory_service
                 .fast_exponentiation(&x, &2.into(), p);
+            if let Some(sequence) = sequence.as_mut() {
+                sequence.push(x.clone());
+            }
             if x == p.decrement() {
                 return true;
             }
@@ -116,7 +179,7 @@ impl PrimalityTest {
     }
 }
 
-fn get_primes_to_300() -> [u32; 61] {
+pub(crate) fn get_primes_to_300() -> [u32; 61] {
     return [
         3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
         97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181,
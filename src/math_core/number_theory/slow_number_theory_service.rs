@@ -1,3 +1,7 @@
+use crate::math_core::number_theory::computation_trace::{
+    EuclidStep, MillerRabinRound, SquareAndMultiplyStep, Traced,
+};
+use crate::math_core::number_theory::congruence::{solve_congruences, Congruence};
 use crate::math_core::number_theory::extended_euclid_result::ExtendedEuclidResult;
 use anyhow::{ensure, Result};
 use bigdecimal::num_bigint::BigInt;
@@ -9,7 +13,7 @@ use crate::math_core::number_theory::number_theory_service::{
     NumberTheoryService, NumberTheoryServiceTrait,
 };
 use crate::math_core::number_theory::primality_test::PrimalityTest;
-use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
 use crate::math_core::traits::divisible::Divisible;
 use crate::math_core::traits::parity::Parity;
 use crate::shared::errors::ArithmeticError;
@@ -21,10 +25,14 @@ impl SlowNumberTheoryService {
     pub fn new() -> SlowNumberTheoryService {
         SlowNumberTheoryService
     }
-}
 
-impl NumberTheoryServiceTrait for SlowNumberTheoryService {
-    fn extended_euclid(&self, a: &BigInt, b: &BigInt) -> ExtendedEuclidResult {
+    /// Der erweiterte euklidische Algorithmus. Ist `steps` gegeben, wird jede Division als
+    /// Zeile der Tabelle protokolliert.
+    fn run_extended_euclid(
+        a: &BigInt,
+        b: &BigInt,
+        mut steps: Option<&mut Vec<EuclidStep>>,
+    ) -> ExtendedEuclidResult {
         let mut m = b.clone();
         let mut n = a.clone();
         //rotierendes Array, zur Berechnung und Speicherung der Faktoren `x` und `y`
@@ -35,6 +43,16 @@ impl NumberTheoryServiceTrait for SlowNumberTheoryService {
             xy[0] = &xy[0] - (&div * &xy[2]);
             xy[1] = &xy[1] - (&div * &xy[3]);
             let tmp = &n % &m;
+            if let Some(steps) = steps.as_mut() {
+                steps.push(EuclidStep {
+                    dividend: n.clone(),
+                    divisor: m.clone(),
+                    quotient: div.clone(),
+                    remainder: tmp.clone(),
+                    x: xy[0].clone(),
+                    y: xy[1].clone(),
+                });
+            }
             n = m;
             m = tmp;
             xy.rotate_right(2);
@@ -42,7 +60,14 @@ impl NumberTheoryServiceTrait for SlowNumberTheoryService {
         ExtendedEuclidResult::new(n, xy[0].clone(), xy[1].clone())
     }
 
-    fn fast_exponentiation(&self, base: &BigInt, exponent: &BigInt, modul: &BigInt) -> BigInt {
+    /// Die Schnellexponentiation nach dem Square-and-Multiply-Verfahren. Ist `steps` gegeben,
+    /// wird jedes Bit des Exponenten protokolliert.
+    fn run_fast_exponentiation(
+        base: &BigInt,
+        exponent: &BigInt,
+        modul: &BigInt,
+        mut steps: Option<&mut Vec<SquareAndMultiplyStep>>,
+    ) -> BigInt {
         if base.is_zero() && !exponent.is_zero() {
             return BigInt::zero();
         }
@@ -61,14 +86,53 @@ impl NumberTheoryServiceTrait for SlowNumberTheoryService {
         let mut exp = exponent.clone();
 
         while !exp.is_zero() {
-            if exp.is_odd() {
+            let bit = exp.is_odd();
+            if bit {
                 result = (result * &base).rem_euclid(modul);
             }
+            if let Some(steps) = steps.as_mut() {
+                steps.push(SquareAndMultiplyStep {
+                    bit,
+                    power: base.clone(),
+                    result: result.clone(),
+                });
+            }
             base = (&base * &base).rem_euclid(modul);
             exp.half_assign();
         }
         result
     }
+}
+
+impl NumberTheoryServiceTrait for SlowNumberTheoryService {
+    fn extended_euclid(&self, a: &BigInt, b: &BigInt) -> ExtendedEuclidResult {
+        Self::run_extended_euclid(a, b, None)
+    }
+
+    fn extended_euclid_traced(
+        &self,
+        a: &BigInt,
+        b: &BigInt,
+    ) -> Traced<ExtendedEuclidResult, EuclidStep> {
+        let mut steps = Vec::new();
+        let result = Self::run_extended_euclid(a, b, Some(&mut steps));
+        Traced::new(result, steps)
+    }
+
+    fn fast_exponentiation(&self, base: &BigInt, exponent: &BigInt, modul: &BigInt) -> BigInt {
+        Self::run_fast_exponentiation(base, exponent, modul, None)
+    }
+
+    fn fast_exponentiation_traced(
+        &self,
+        base: &BigInt,
+        exponent: &BigInt,
+        modul: &BigInt,
+    ) -> Traced<BigInt, SquareAndMultiplyStep> {
+        let mut steps = Vec::new();
+        let result = Self::run_fast_exponentiation(base, exponent, modul, Some(&mut steps));
+        Traced::new(result, steps)
+    }
 
     fn modulo_inverse(&self, n: &BigInt, modul: &BigInt) -> Result<BigInt> {
         let number_theory_service = SlowNumberTheoryService::new();
@@ -83,13 +147,42 @@ impl NumberTheoryServiceTrait for SlowNumberTheoryService {
         return Ok((modul + extended_euclid_result.y).rem_euclid(modul));
     }
 
+    fn modulo_inverse_traced(
+        &self,
+        n: &BigInt,
+        modul: &BigInt,
+    ) -> Result<Traced<BigInt, EuclidStep>> {
+        let traced = self.extended_euclid_traced(modul, n);
+
+        ensure!(
+            traced.result.ggt.is_one(),
+            ArithmeticError::NoInverseError(n.to_string(), modul.to_string())
+        );
+
+        Ok(traced.map(|result| (modul + result.y).rem_euclid(modul)))
+    }
+
+    fn chinese_remainder(&self, congruences: &[Congruence]) -> Result<Congruence> {
+        solve_congruences(congruences, |a, b| Self::run_extended_euclid(a, b, None))
+    }
+
     fn is_probably_prime(
         &self,
         p: &BigInt,
         repeats: u32,
-        random_generator: &PseudoRandomNumberGenerator,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
     ) -> bool {
         let primality_test = PrimalityTest::new(NumberTheoryService::new(Slow));
         primality_test.miller_rabin(p, repeats, random_generator)
     }
+
+    fn is_probably_prime_traced(
+        &self,
+        p: &BigInt,
+        repeats: u32,
+        random_generator: &(impl RandomNumberGenerator + ?Sized),
+    ) -> Traced<bool, MillerRabinRound> {
+        let primality_test = PrimalityTest::new(NumberTheoryService::new(Slow));
+        primality_test.miller_rabin_traced(p, repeats, random_generator)
+    }
 }
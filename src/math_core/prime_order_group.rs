@@ -0,0 +1,270 @@
+use std::fmt::{Debug, Display};
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::num_traits::Euclid;
+use bigdecimal::{One, Zero};
+
+use crate::encryption::string_schemes::byte_block_schemes::byte_conversion_core::i2osp;
+use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
+use crate::math_core::ecc::scalar_multiplication::ScalarKind;
+use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
+use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::shared::errors::ArithmeticError::InvalidSubgroupError;
+
+/// Eine zyklische Gruppe mit Primzahlordnung q und festem Erzeuger g, in der das Problem des
+/// diskreten Logarithmus praktisch nicht lösbar ist. Verfahren wie Schnorr-Signaturen werden
+/// damit einmal für prime Restklassengruppen und für elliptische Kurven formuliert.
+///
+/// Die Gruppe wird multiplikativ geschrieben, auf elliptischen Kurven entspricht
+/// `exponentiate` also der Skalarmultiplikation.
+pub trait PrimeOrderGroup {
+    type Element: Clone + PartialEq + Debug + Display;
+
+    /// Die Primzahlordnung q.
+    fn order(&self) -> &BigInt;
+
+    /// Der Erzeuger g.
+    fn generator(&self) -> Self::Element;
+
+    /// Das neutrale Element.
+    fn identity(&self) -> Self::Element;
+
+    /// Verknüpft zwei Elemente.
+    fn operate(&self, first: &Self::Element, second: &Self::Element) -> Result<Self::Element>;
+
+    /// Berechnet element^scalar. Der Skalar wird zuvor modulo q reduziert.
+    fn exponentiate(
+        &self,
+        element: &Self::Element,
+        scalar: &BigInt,
+        kind: ScalarKind,
+    ) -> Result<Self::Element>;
+
+    /// Ob das Element in der Untergruppe der Ordnung q liegt und nicht das neutrale Element
+    /// ist. Öffentliche Werte anderer Parteien müssen diese Prüfung bestehen.
+    fn is_valid_element(&self, element: &Self::Element) -> bool;
+
+    /// Eine eindeutige Bytedarstellung des Elements, z.B. als Eingabe einer Hashfunktion.
+    fn encode(&self, element: &Self::Element) -> Result<Vec<u8>>;
+
+    /// Die Bytedarstellung eines gemeinsamen Geheimnisses, aus der ein Schlüssel abgeleitet
+    /// wird. Standardmäßig ist das `encode`.
+    fn encode_shared_secret(&self, element: &Self::Element) -> Result<Vec<u8>> {
+        self.encode(element)
+    }
+}
+
+///
+/// Die Untergruppe der Ordnung q der Einheitengruppe (Z/pZ)^*. Für eine sichere Primzahl
+/// p = 2q + 1 sind das die quadratischen Reste modulo p.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct PrimeFieldSubgroup {
+    /// Der Modulus p
+    pub prime: BigInt,
+    /// Die Primzahlordnung q, ein Teiler von p - 1
+    pub order: BigInt,
+    /// Der Erzeuger g der Ordnung q
+    pub generator: BigInt,
+}
+
+impl PrimeFieldSubgroup {
+    /// Erstellt die Untergruppe der Ordnung q mit Erzeuger g. Dass p und q prim sind, wird
+    /// nicht geprüft.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InvalidSubgroupError` - Falls q kein Teiler von p - 1 ist oder g
+    ///   nicht die Ordnung q hat.
+    pub fn new(prime: BigInt, order: BigInt, generator: BigInt) -> Result<Self> {
+        let group = PrimeFieldSubgroup {
+            prime,
+            order,
+            generator,
+        };
+        ensure!(
+            (&group.prime - BigInt::one())
+                .rem_euclid(&group.order)
+                .is_zero()
+                && group.is_valid_element(&group.generator),
+            InvalidSubgroupError(group.generator.to_string(), group.order.to_string())
+        );
+        Ok(group)
+    }
+
+    /// Erstellt die Gruppe der quadratischen Reste zu einer sicheren Primzahl p = 2q + 1, wie
+    /// sie `generate_secure_prime_with_primitive_root` liefert. Das Quadrat der Primitivwurzel
+    /// erzeugt die Untergruppe der Ordnung q.
+    ///
+    /// # Fehler
+    /// * `ArithmeticError::InvalidSubgroupError` - Falls g^2 nicht die Ordnung q hat, p also
+    ///   keine sichere Primzahl oder g keine Primitivwurzel ist.
+    pub fn from_safe_prime(prime: &BigInt, primitive_root: &BigInt) -> Result<Self> {
+        Self::new(
+            prime.clone(),
+            (prime - BigInt::one()) >> 1,
+            (primitive_root * primitive_root).rem_euclid(prime),
+        )
+    }
+
+    fn byte_length(&self) -> usize {
+        (self.prime.bits() as usize).div_ceil(8)
+    }
+}
+
+impl PrimeOrderGroup for PrimeFieldSubgroup {
+    type Element = BigInt;
+
+    fn order(&self) -> &BigInt {
+        &self.order
+    }
+
+    fn generator(&self) -> BigInt {
+        self.generator.clone()
+    }
+
+    fn identity(&self) -> BigInt {
+        BigInt::one()
+    }
+
+    fn operate(&self, first: &BigInt, second: &BigInt) -> Result<BigInt> {
+        Ok((first * second).rem_euclid(&self.prime))
+    }
+
+    /// Die Exponentiation unterscheidet nicht zwischen geheimen und öffentlichen Exponenten.
+    fn exponentiate(&self, element: &BigInt, scalar: &BigInt, _kind: ScalarKind) -> Result<BigInt> {
+        Ok(NumberTheoryService::new(Fast).fast_exponentiation(
+            element,
+            &scalar.rem_euclid(&self.order),
+            &self.prime,
+        ))
+    }
+
+    fn is_valid_element(&self, element: &BigInt) -> bool {
+        element > &BigInt::one()
+            && element < &self.prime
+            && NumberTheoryService::new(Fast)
+                .fast_exponentiation(element, &self.order, &self.prime)
+                .is_one()
+    }
+
+    /// Das Element als Big-Endian-Zahl mit der Bytelänge von p.
+    fn encode(&self, element: &BigInt) -> Result<Vec<u8>> {
+        i2osp(element, self.byte_length())
+    }
+}
+
+impl PrimeOrderGroup for SecureFiniteFieldEllipticCurve {
+    type Element = FiniteFieldEllipticCurvePoint;
+
+    fn order(&self) -> &BigInt {
+        &self.order_of_subgroup
+    }
+
+    fn generator(&self) -> FiniteFieldEllipticCurvePoint {
+        self.generator.clone()
+    }
+
+    fn identity(&self) -> FiniteFieldEllipticCurvePoint {
+        FiniteFieldEllipticCurvePoint::infinite()
+    }
+
+    fn operate(
+        &self,
+        first: &FiniteFieldEllipticCurvePoint,
+        second: &FiniteFieldEllipticCurvePoint,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        first.add(second, &self.curve)
+    }
+
+    fn exponentiate(
+        &self,
+        element: &FiniteFieldEllipticCurvePoint,
+        scalar: &BigInt,
+        kind: ScalarKind,
+    ) -> Result<FiniteFieldEllipticCurvePoint> {
+        element.multiply_with(
+            &scalar.rem_euclid(&self.order_of_subgroup),
+            &self.curve,
+            kind,
+        )
+    }
+
+    fn is_valid_element(&self, element: &FiniteFieldEllipticCurvePoint) -> bool {
+        !element.is_infinite
+            && self.curve.has_point(element)
+            && element
+                .multiply_with(&self.order_of_subgroup, &self.curve, ScalarKind::Public)
+                .is_ok_and(|point| point.is_infinite)
+    }
+
+    /// Der Punkt in komprimierter SEC1-Kodierung.
+    fn encode(&self, element: &FiniteFieldEllipticCurvePoint) -> Result<Vec<u8>> {
+        element.to_sec1(&self.curve, true)
+    }
+
+    /// Nach SEC 1, Abschnitt 3.3.1, nur die x-Koordinate mit der Bytelänge von p.
+    fn encode_shared_secret(&self, element: &FiniteFieldEllipticCurvePoint) -> Result<Vec<u8>> {
+        i2osp(&element.x, self.curve.field_byte_length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::ecc::named_curve::NamedCurve;
+
+    #[test]
+    fn test_prime_field_subgroup() {
+        // 23 = 2 · 11 + 1 mit Primitivwurzel 5, die quadratischen Reste werden von 5^2 = 2 erzeugt.
+        let group = PrimeFieldSubgroup::from_safe_prime(&23.into(), &5.into()).unwrap();
+        assert_eq!(group.order, BigInt::from(11));
+        assert_eq!(group.generator, BigInt::from(2));
+
+        let element = group
+            .exponentiate(&group.generator(), &7.into(), ScalarKind::Secret)
+            .unwrap();
+        assert_eq!(element, BigInt::from(13));
+        assert!(group.is_valid_element(&element));
+        // Der Exponent wird modulo q reduziert.
+        assert_eq!(
+            group
+                .exponentiate(&group.generator(), &18.into(), ScalarKind::Public)
+                .unwrap(),
+            element
+        );
+        assert_eq!(group.operate(&element, &2.into()).unwrap(), BigInt::from(3));
+        assert_eq!(group.encode(&element).unwrap(), vec![13]);
+
+        // 5 ist kein quadratischer Rest, 1 und 22 haben nicht die Ordnung 11.
+        for invalid in [1, 5, 22, 23] {
+            assert!(!group.is_valid_element(&invalid.into()));
+        }
+        assert!(PrimeFieldSubgroup::new(23.into(), 11.into(), 5.into()).is_err());
+        assert!(PrimeFieldSubgroup::new(23.into(), 7.into(), 2.into()).is_err());
+    }
+
+    #[test]
+    fn test_curve_group() {
+        let curve = NamedCurve::P256.domain_parameters();
+        let generator = curve.generator();
+        assert!(curve.is_valid_element(&generator));
+        assert!(!curve.is_valid_element(&curve.identity()));
+        assert!(!curve.is_valid_element(&FiniteFieldEllipticCurvePoint::new(
+            generator.x.clone(),
+            &generator.y + 1
+        )));
+
+        let double = curve.operate(&generator, &generator).unwrap();
+        assert_eq!(
+            curve
+                .exponentiate(&generator, &2.into(), ScalarKind::Secret)
+                .unwrap(),
+            double
+        );
+        assert_eq!(curve.encode(&double).unwrap().len(), 33);
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Mutex;
+
+use atomic_counter::{AtomicCounter, RelaxedCounter};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::One;
+use log::{debug, trace};
+use num::Integer;
+
+use crate::math_core::number_theory::number_theory_service::{
+    NumberTheoryService, NumberTheoryServiceTrait,
+};
+use crate::math_core::traits::divisible::Divisible;
+use crate::math_core::traits::increment::Increment;
+
+/// Anzahl zusätzlicher Bits, die beim bitweisen Ziehen einer Zufallszahl erzeugt werden,
+/// damit die Verzerrung durch die Modulo-Reduktion vernachlässigbar bleibt.
+const ADDITIONAL_BITS: u64 = 64;
+
+/// Schnittstelle für deterministische Zufallszahlengeneratoren, deren Folge über einen
+/// Zähler indiziert wird. Auf dem Ziehen von Zahlen aus einem Bereich bauen die Erzeugung
+/// von Primzahlen und damit alle Schlüsselgeneratoren auf.
+pub trait RandomNumberGenerator: Sync {
+    /// Diese Methode gibt eine Zufallszahl im Bereich von a bis b zurück.
+    /// Inkrementiert den AtomicCounter n_counter!
+    ///
+    /// # Argumente
+    /// * `a` - Die untere Grenze des Bereichs.
+    /// * `b` - Die obere Grenze des Bereichs.
+    /// * `n_counter` - Der AtomicCounter, der den Index des Elementes aus der Zufallsfolge enthält.
+    ///
+    /// # Rückgabe
+    /// Die Zufallszahl.
+    fn take(&self, a: &BigInt, b: &BigInt, n_counter: &RelaxedCounter) -> BigInt;
+
+    /// Der Service für die Zahlentheorie, der für Primzahltests verwendet wird.
+    fn number_theory_service(&self) -> NumberTheoryService;
+
+    /// Diese Methode gibt eine ungerade Zufallszahl im Bereich von a bis b zurück.
+    /// Inkrementiert den AtomicCounter n_counter!
+    ///
+    /// # Argumente
+    /// * `a` - Die untere Grenze des Bereichs.
+    /// * `b` - Die obere Grenze des Bereichs.
+    /// * `n_counter` - Index des Elementes aus der Zufallsfolge.
+    ///
+    /// # Rückgabe
+    /// Die ungerade Zufallszahl.
+    fn take_uneven(&self, a: &BigInt, b: &BigInt, n_counter: &RelaxedCounter) -> BigInt {
+        self.take(a, b, n_counter) | BigInt::one()
+    }
+
+    /// Generiert eine Primzahl mit der angegebenen Breite.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    /// * `n_counter` - Der Zähler für den Zugriff auf die Zufallsfolge. Achtung: Der Zähler wird inkrementiert!
+    ///
+    /// # Rückgabe
+    /// Die generierte Primzahl.
+    fn generate_prime(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        n_counter: &RelaxedCounter,
+    ) -> BigInt {
+        debug!(
+            "Generiere eine Primzahl mit size {} und Miller-Rabin-Iterations {}",
+            size, miller_rabin_iterations
+        );
+
+        let upper_bound = &BigInt::from(2).pow(size);
+        let lower_bound = &BigInt::from(2).pow(size - 1);
+
+        let mut prime_candidate = self.take_uneven(lower_bound, upper_bound, n_counter);
+
+        while !self.number_theory_service().is_probably_prime(
+            &prime_candidate,
+            miller_rabin_iterations,
+            self, // Ggf sollte hier eine neue Instanz mit zufälligem Seed übergeben werden?
+        ) {
+            trace!(
+                "Generierter Primkandidat {} ist keine Primzahl",
+                prime_candidate
+            );
+            prime_candidate = self.take_uneven(lower_bound, upper_bound, n_counter);
+        }
+        debug!(
+            "Generierter Primkandidat {} ist eine Primzahl",
+            prime_candidate
+        );
+        prime_candidate
+    }
+
+    /// Generiert eine sichere Primzahl mit der angegebenen Breite und liefert eine passende
+    /// Primitivwurzel.
+    /// Eine sichere Primzahl ist eine Primzahl p, bei der auch (p-1)/2 eine Primzahl ist.
+    /// Eine Primitivwurzel ist ein Element g, welches jede Zahl aus der Menge {1, 2, ..., p-1}
+    /// als Potenz von g darstellen kann.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahl.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für die Miller-Rabin-Tests.
+    /// * `n_counter` - Der Zähler für den Zugriff auf die Zufallsfolge. Achtung: Der Zähler wird inkrementiert!
+    ///
+    /// # Rückgabe
+    /// Die generierte sichere Primzahl und die Primitivwurzel.
+    fn generate_secure_prime_with_primitive_root(
+        &self,
+        size: u32,
+        miller_rabin_iterations: u32,
+        n_counter: &RelaxedCounter,
+    ) -> (BigInt, BigInt) {
+        debug!(
+            "Generiere eine sichere Primzahl mit size {} und Miller-Rabin-Iterations {}",
+            size, miller_rabin_iterations
+        );
+
+        let mut prime_candidate: BigInt;
+        let mut source_prime: BigInt;
+        // Bestimmung der sicheren Primzahl
+        loop {
+            prime_candidate = self.generate_prime(size, miller_rabin_iterations, n_counter);
+            source_prime = prime_candidate.decrement().half();
+            if self.number_theory_service().is_probably_prime(
+                &source_prime,
+                miller_rabin_iterations,
+                self, // Ggf sollte hier eine neue Instanz mit zufälligem Seed übergeben werden?
+            ) {
+                debug!(
+                    "Generierter Primkandidat {} ist eine sichere Primzahl",
+                    prime_candidate
+                );
+                break;
+            }
+            trace!(
+                "Generierter Primkandidat {} ist keine sichere Primzahl",
+                prime_candidate
+            );
+        }
+
+        debug!(
+            "Generiere Primitivwurzel für die sichere Primzahl {}",
+            prime_candidate
+        );
+        let mut primitive_root_candidate: BigInt;
+        // Bestimmung der Primitivwurzel
+        loop {
+            primitive_root_candidate =
+                self.take(&2.into(), &(&prime_candidate - BigInt::from(2)), n_counter);
+            // Eine Zahl g ist eine Primitivwurzel, wenn g^(q) mod p = p - 1
+            // mit q = source_prime und p = prime_candidate
+            // Die Prüfung geschieht normalerweise mit -1, aber weil fast_exponentiation mit
+            // euklidischem Rest rechnet, muss hier p - 1 verwendet werden.
+            let is_primitive_root = self.number_theory_service().fast_exponentiation(
+                &primitive_root_candidate,
+                &source_prime,
+                &prime_candidate,
+            ) == prime_candidate.decrement();
+
+            if is_primitive_root {
+                debug!(
+                    "Generierter Primitivwurzelkandidat {} ist eine Primitivwurzel",
+                    primitive_root_candidate
+                );
+                break;
+            }
+            trace!(
+                "Generierter Primitivwurzelkandidat {} ist keine Primitivwurzel",
+                primitive_root_candidate
+            );
+        }
+
+        (prime_candidate, primitive_root_candidate)
+    }
+
+    /// Generiert zwei verschiedene Primzahlen mit der angegebenen Breite.
+    ///
+    /// # Argumente
+    /// * `size` - Die Bit-Breite der Primzahlen.
+    /// * `miller_rabin_iterations` - Die Anzahl der Iterationen für den Miller-Rabin-Test.
+    fn get_distinct_primes(&self, size: u32, miller_rabin_iterations: u32) -> (BigInt, BigInt) {
+        let (prim_size_one, prim_size_two) = if size.is_even() {
+            (size / 2, size / 2)
+        } else {
+            (size / 2 + 1, size / 2)
+        };
+        let n_counter = RelaxedCounter::new(1);
+        let prime_one = self.generate_prime(prim_size_one, miller_rabin_iterations, &n_counter);
+        let mut prime_two = self.generate_prime(prim_size_two, miller_rabin_iterations, &n_counter);
+
+        while prime_one == prime_two {
+            trace!(
+                "Generierter prime_one {} ist gleich prime_two {}. Starte neuen Versuch",
+                prime_one,
+                prime_two
+            );
+            prime_two = self.generate_prime(prim_size_two, miller_rabin_iterations, &n_counter);
+        }
+        (prime_one, prime_two)
+    }
+}
+
+/// Setzt eine Zufallszahl im Bereich von a bis b aus einzelnen Bits zusammen.
+/// Für jedes Bit wird der Zähler einmal inkrementiert und das Bit an diesem Index der Folge
+/// verwendet.
+///
+/// # Argumente
+/// * `a` - Die untere Grenze des Bereichs.
+/// * `b` - Die obere Grenze des Bereichs.
+/// * `n_counter` - Der Zähler für den Zugriff auf die Bitfolge.
+/// * `bit_at` - Liefert das Bit der Folge am angegebenen Index.
+///
+/// # Rückgabe
+/// Die Zufallszahl.
+pub(crate) fn take_from_bits(
+    a: &BigInt,
+    b: &BigInt,
+    n_counter: &RelaxedCounter,
+    bit_at: impl Fn(usize) -> bool,
+) -> BigInt {
+    let range = b - a + BigInt::one();
+    let bit_count = range.bits() + ADDITIONAL_BITS;
+    let bytes: Vec<u8> = (0..bit_count.div_ceil(8))
+        .map(|_| (0..8).fold(0u8, |byte, _| (byte << 1) | bit_at(n_counter.inc()) as u8))
+        .collect();
+    a + BigInt::from_bytes_be(Sign::Plus, &bytes).mod_floor(&range)
+}
+
+/// Zwischenspeicher für Generatoren, deren Folge nur sequenziell berechnet werden kann.
+/// Bereits berechnete Bits werden gespeichert, sodass auch parallele Zugriffe mit
+/// unterschiedlichen Indizes die Folge nicht neu berechnen müssen. Der Speicherbedarf wächst
+/// daher mit dem größten abgefragten Index, ein Byte pro Bit der Folge.
+pub(crate) struct SequentialBitCache {
+    state: Mutex<(BigInt, Vec<bool>)>,
+}
+
+impl SequentialBitCache {
+    /// Erstellt einen neuen Zwischenspeicher mit dem Startzustand der Folge.
+    pub(crate) fn new(initial_state: BigInt) -> Self {
+        Self {
+            state: Mutex::new((initial_state, Vec::new())),
+        }
+    }
+
+    /// Liefert das Bit am angegebenen Index und berechnet die Folge bei Bedarf weiter.
+    ///
+    /// # Argumente
+    /// * `index` - Der Index des Bits.
+    /// * `step` - Berechnet aus einem Zustand den Folgezustand und das ausgegebene Bit.
+    pub(crate) fn bit_at(&self, index: usize, step: impl Fn(&BigInt) -> (BigInt, bool)) -> bool {
+        let mut guard = self.state.lock().unwrap();
+        let (state, bits) = &mut *guard;
+        while bits.len() <= index {
+            let (next_state, bit) = step(state);
+            *state = next_state;
+            bits.push(bit);
+        }
+        bits[index]
+    }
+}
@@ -0,0 +1,77 @@
+use atomic_counter::RelaxedCounter;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{One, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+use crate::math_core::pseudo_random_number_generator::PseudoRandomNumberGenerator;
+use crate::math_core::random_number_generator::RandomNumberGenerator;
+
+/// Eine Quelle für Zufallsbits, deren Ausgabe statistisch untersucht werden kann.
+pub trait BitSource {
+    /// Liefert das nächste Bit der Folge.
+    fn next_bit(&mut self) -> bool;
+
+    /// Liefert die nächsten `count` Bits der Folge.
+    fn take_bits(&mut self, count: usize) -> Vec<bool> {
+        (0..count).map(|_| self.next_bit()).collect()
+    }
+}
+
+/// Bitquelle auf Basis des PseudoRandomNumberGenerator.
+/// Jedes Bit ist ein Element der Zufallsfolge im Bereich von 0 bis 1.
+pub struct PseudoRandomBitSource {
+    generator: PseudoRandomNumberGenerator,
+    counter: RelaxedCounter,
+    zero: BigInt,
+    one: BigInt,
+}
+
+impl PseudoRandomBitSource {
+    /// Erstellt eine neue Bitquelle für den PseudoRandomNumberGenerator.
+    ///
+    /// # Argumente
+    /// * `random_seed` - Seed für die Zufallszahlfolge.
+    /// * `number_theory_service` - Der zu verwendende NumberTheoryService.
+    pub fn new(random_seed: u32, number_theory_service: NumberTheoryService) -> Self {
+        Self {
+            generator: PseudoRandomNumberGenerator::new(random_seed, number_theory_service),
+            counter: RelaxedCounter::new(1),
+            zero: BigInt::zero(),
+            one: BigInt::one(),
+        }
+    }
+}
+
+impl BitSource for PseudoRandomBitSource {
+    fn next_bit(&mut self) -> bool {
+        self.generator
+            .take(&self.zero, &self.one, &self.counter)
+            .is_one()
+    }
+}
+
+/// Bitquelle auf Basis eines kryptographisch sicheren Zufallszahlengenerators (ChaCha-basiert).
+/// Dient als Vergleichsmaßstab für den PseudoRandomNumberGenerator.
+pub struct CsprngBitSource {
+    rng: StdRng,
+}
+
+impl CsprngBitSource {
+    /// Erstellt eine neue, deterministisch initialisierte Bitquelle.
+    ///
+    /// # Argumente
+    /// * `random_seed` - Seed für den Generator.
+    pub fn new(random_seed: u32) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(random_seed as u64),
+        }
+    }
+}
+
+impl BitSource for CsprngBitSource {
+    fn next_bit(&mut self) -> bool {
+        self.rng.gen()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod bit_source;
+pub mod nist_test_suite;
+pub mod special_functions;
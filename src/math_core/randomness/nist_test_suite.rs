@@ -0,0 +1,563 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use anyhow::{ensure, Result};
+use rayon::prelude::*;
+
+use crate::math_core::randomness::bit_source::BitSource;
+use crate::math_core::randomness::special_functions::{
+    erfc, standard_normal_cdf, upper_incomplete_gamma,
+};
+use crate::shared::errors::RandomnessTestError::{InvalidParameterError, SequenceTooShortError};
+
+/// Signifikanzniveau, unterhalb dessen eine Bitfolge als nicht zufällig gilt.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.01;
+
+/// Mindestlänge einer Bitfolge für die vollständige Testsuite.
+/// Sie ergibt sich aus dem Test auf den längsten Einserlauf.
+pub const MINIMUM_SUITE_LENGTH: usize = 128;
+
+/// Ergebnis eines statistischen Tests aus NIST SP 800-22.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomnessTestResult {
+    /// Der Name des Tests.
+    pub name: String,
+    /// Die berechneten p-Werte. Manche Tests liefern mehr als einen p-Wert.
+    pub p_values: Vec<f64>,
+    /// Gibt an, ob alle p-Werte mindestens dem Signifikanzniveau entsprechen.
+    pub passed: bool,
+}
+
+impl RandomnessTestResult {
+    fn new(name: &str, p_values: Vec<f64>) -> Self {
+        let passed = p_values.iter().all(|p| *p >= SIGNIFICANCE_LEVEL);
+        Self {
+            name: name.to_string(),
+            p_values,
+            passed,
+        }
+    }
+}
+
+/// Stellt sicher, dass die Bitfolge mindestens `required` Bits lang ist.
+fn ensure_length(test: &str, bits: &[bool], required: usize) -> Result<()> {
+    ensure!(
+        bits.len() >= required,
+        SequenceTooShortError(test.to_string(), required, bits.len())
+    );
+    Ok(())
+}
+
+/// Bildet ein Bit auf +1 (für 1) bzw. -1 (für 0) ab.
+fn to_sign(bit: bool) -> i64 {
+    if bit {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Frequency (Monobit) Test.
+/// Prüft, ob der Anteil an Einsen und Nullen in der gesamten Folge etwa gleich ist.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn frequency_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Frequency (Monobit)";
+    ensure_length(NAME, bits, 1)?;
+
+    let sum: i64 = bits.iter().map(|bit| to_sign(*bit)).sum();
+    let s_obs = sum.abs() as f64 / (bits.len() as f64).sqrt();
+    Ok(RandomnessTestResult::new(NAME, vec![erfc(s_obs / SQRT_2)]))
+}
+
+/// Frequency Test within a Block.
+/// Prüft, ob der Anteil an Einsen in Blöcken der Länge `block_length` etwa 1/2 ist.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+/// * `block_length` - Die Länge M eines Blocks.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn block_frequency_test(bits: &[bool], block_length: usize) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Frequency within a Block";
+    ensure!(
+        block_length > 0,
+        InvalidParameterError(
+            NAME.to_string(),
+            "block length must be positive".to_string()
+        )
+    );
+    ensure_length(NAME, bits, block_length)?;
+
+    let block_count = bits.len() / block_length;
+    let chi_squared = 4.0
+        * block_length as f64
+        * bits
+            .chunks_exact(block_length)
+            .map(|block| {
+                let proportion =
+                    block.iter().filter(|bit| **bit).count() as f64 / block_length as f64;
+                (proportion - 0.5).powi(2)
+            })
+            .sum::<f64>();
+    let p_value = upper_incomplete_gamma(block_count as f64 / 2.0, chi_squared / 2.0);
+    Ok(RandomnessTestResult::new(NAME, vec![p_value]))
+}
+
+/// Runs Test.
+/// Prüft, ob die Anzahl an Läufen (ununterbrochene Folgen gleicher Bits) der einer
+/// zufälligen Folge entspricht.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn runs_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Runs";
+    ensure_length(NAME, bits, 2)?;
+
+    let n = bits.len() as f64;
+    let proportion = bits.iter().filter(|bit| **bit).count() as f64 / n;
+
+    // Voraussetzung ist ein bestandener Frequency-Test, sonst ist der p-Wert 0.
+    if (proportion - 0.5).abs() >= 2.0 / n.sqrt() {
+        return Ok(RandomnessTestResult::new(NAME, vec![0.0]));
+    }
+
+    let runs = 1 + bits.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    let expected = 2.0 * n * proportion * (1.0 - proportion);
+    let p_value = erfc(
+        (runs as f64 - expected).abs() / (2.0 * (2.0 * n).sqrt() * proportion * (1.0 - proportion)),
+    );
+    Ok(RandomnessTestResult::new(NAME, vec![p_value]))
+}
+
+/// Test for the Longest Run of Ones in a Block.
+/// Prüft, ob die Länge des längsten Einserlaufs innerhalb der Blöcke der einer zufälligen Folge
+/// entspricht. Blocklänge und Klasseneinteilung werden anhand der Länge der Folge gewählt.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge, mindestens 128 Bit lang.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn longest_run_of_ones_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Longest Run of Ones in a Block";
+    ensure_length(NAME, bits, MINIMUM_SUITE_LENGTH)?;
+
+    // Blocklänge, kleinste und größte Klasse sowie die Klassenwahrscheinlichkeiten nach NIST.
+    let (block_length, min_class, max_class, probabilities): (usize, usize, usize, &[f64]) =
+        match bits.len() {
+            n if n < 6272 => (8, 1, 4, &[0.2148, 0.3672, 0.2305, 0.1875]),
+            n if n < 750_000 => (128, 4, 9, &[0.1174, 0.2430, 0.2493, 0.1752, 0.1027, 0.1124]),
+            _ => (
+                10_000,
+                10,
+                16,
+                &[0.0882, 0.2092, 0.2483, 0.1933, 0.1208, 0.0675, 0.0727],
+            ),
+        };
+
+    let mut frequencies = vec![0usize; probabilities.len()];
+    for block in bits.chunks_exact(block_length) {
+        let (longest, _) = block.iter().fold((0, 0), |(longest, current), bit| {
+            let current = if *bit { current + 1 } else { 0 };
+            (longest.max(current), current)
+        });
+        frequencies[longest.clamp(min_class, max_class) - min_class] += 1;
+    }
+
+    let block_count = (bits.len() / block_length) as f64;
+    let chi_squared: f64 = frequencies
+        .iter()
+        .zip(probabilities)
+        .map(|(frequency, probability)| {
+            let expected = block_count * probability;
+            (*frequency as f64 - expected).powi(2) / expected
+        })
+        .sum();
+    let degrees_of_freedom = (probabilities.len() - 1) as f64;
+    let p_value = upper_incomplete_gamma(degrees_of_freedom / 2.0, chi_squared / 2.0);
+    Ok(RandomnessTestResult::new(NAME, vec![p_value]))
+}
+
+/// Zählt die Vorkommen aller überlappenden Bitmuster der Länge `pattern_length`.
+/// Die Folge wird dabei zyklisch um ihre ersten `pattern_length - 1` Bits erweitert.
+fn count_overlapping_patterns(bits: &[bool], pattern_length: usize) -> Vec<usize> {
+    let n = bits.len();
+    let mut counts = vec![0usize; 1 << pattern_length];
+    for i in 0..n {
+        let pattern = (0..pattern_length).fold(0usize, |pattern, j| {
+            (pattern << 1) | bits[(i + j) % n] as usize
+        });
+        counts[pattern] += 1;
+    }
+    counts
+}
+
+/// Berechnet die Statistik ψ²_m des Serial Tests.
+fn psi_squared(bits: &[bool], pattern_length: usize) -> f64 {
+    if pattern_length == 0 {
+        return 0.0;
+    }
+    let n = bits.len() as f64;
+    let sum_of_squares: f64 = count_overlapping_patterns(bits, pattern_length)
+        .iter()
+        .map(|count| (*count as f64).powi(2))
+        .sum();
+    (1u64 << pattern_length) as f64 / n * sum_of_squares - n
+}
+
+/// Serial Test.
+/// Prüft, ob alle überlappenden Bitmuster der Länge `pattern_length` etwa gleich häufig
+/// vorkommen.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+/// * `pattern_length` - Die Musterlänge m, mindestens 2.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit zwei p-Werten.
+pub fn serial_test(bits: &[bool], pattern_length: usize) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Serial";
+    ensure!(
+        (2..=20).contains(&pattern_length),
+        InvalidParameterError(
+            NAME.to_string(),
+            "pattern length must be between 2 and 20".to_string()
+        )
+    );
+    ensure_length(NAME, bits, pattern_length)?;
+
+    let psi_m = psi_squared(bits, pattern_length);
+    let psi_m1 = psi_squared(bits, pattern_length - 1);
+    let psi_m2 = psi_squared(bits, pattern_length - 2);
+
+    let delta = psi_m - psi_m1;
+    let delta_squared = psi_m - 2.0 * psi_m1 + psi_m2;
+
+    let p_value_1 = upper_incomplete_gamma(2f64.powi(pattern_length as i32 - 2), delta / 2.0);
+    let p_value_2 =
+        upper_incomplete_gamma(2f64.powi(pattern_length as i32 - 3), delta_squared / 2.0);
+    Ok(RandomnessTestResult::new(NAME, vec![p_value_1, p_value_2]))
+}
+
+/// Berechnet die Statistik φ_m des Approximate Entropy Tests.
+fn phi(bits: &[bool], pattern_length: usize) -> f64 {
+    let n = bits.len() as f64;
+    count_overlapping_patterns(bits, pattern_length)
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let frequency = *count as f64 / n;
+            frequency * frequency.ln()
+        })
+        .sum()
+}
+
+/// Approximate Entropy Test.
+/// Vergleicht die Häufigkeiten überlappender Muster der Längen m und m + 1 mit den für eine
+/// zufällige Folge erwarteten Häufigkeiten.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+/// * `pattern_length` - Die Musterlänge m, mindestens 1.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn approximate_entropy_test(
+    bits: &[bool],
+    pattern_length: usize,
+) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Approximate Entropy";
+    ensure!(
+        (1..=20).contains(&pattern_length),
+        InvalidParameterError(
+            NAME.to_string(),
+            "pattern length must be between 1 and 20".to_string()
+        )
+    );
+    ensure_length(NAME, bits, pattern_length + 1)?;
+
+    let approximate_entropy = phi(bits, pattern_length) - phi(bits, pattern_length + 1);
+    let chi_squared = 2.0 * bits.len() as f64 * (2f64.ln() - approximate_entropy);
+    let p_value = upper_incomplete_gamma(2f64.powi(pattern_length as i32 - 1), chi_squared / 2.0);
+    Ok(RandomnessTestResult::new(NAME, vec![p_value]))
+}
+
+/// Berechnet den p-Wert des Cumulative Sums Tests für die maximale Auslenkung `z`.
+/// Die Summationsgrenzen werden wie in der Referenzimplementierung des NIST mit ganzzahliger
+/// Division bestimmt.
+fn cumulative_sums_p_value(n: usize, z: i64) -> f64 {
+    let n_int = n as i64;
+    let n = n as f64;
+    let sqrt_n = n.sqrt();
+    let term = |k: i64, upper_offset: f64, lower_offset: f64| {
+        let k = k as f64;
+        standard_normal_cdf((4.0 * k + upper_offset) * z as f64 / sqrt_n)
+            - standard_normal_cdf((4.0 * k + lower_offset) * z as f64 / sqrt_n)
+    };
+    let upper = (n_int / z - 1) / 4;
+
+    let first_sum: f64 = ((-n_int / z + 1) / 4..=upper)
+        .map(|k| term(k, 1.0, -1.0))
+        .sum();
+    let second_sum: f64 = ((-n_int / z - 3) / 4..=upper)
+        .map(|k| term(k, 3.0, 1.0))
+        .sum();
+    1.0 - first_sum + second_sum
+}
+
+/// Berechnet die maximale absolute Partialsumme der in ±1 umgewandelten Folge.
+fn maximum_partial_sum<'a>(bits: impl Iterator<Item = &'a bool>) -> i64 {
+    bits.scan(0i64, |sum, bit| {
+        *sum += to_sign(*bit);
+        Some(sum.abs())
+    })
+    .max()
+    .unwrap_or(0)
+}
+
+/// Cumulative Sums (Cusum) Test.
+/// Prüft, ob die Partialsummen der in ±1 umgewandelten Folge zu weit von 0 abweichen.
+/// Der Test wird vorwärts und rückwärts durchgeführt.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit den p-Werten für die Vorwärts- und Rückwärtsrichtung.
+pub fn cumulative_sums_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Cumulative Sums";
+    ensure_length(NAME, bits, 1)?;
+
+    let forward = maximum_partial_sum(bits.iter());
+    let backward = maximum_partial_sum(bits.iter().rev());
+    Ok(RandomnessTestResult::new(
+        NAME,
+        vec![
+            cumulative_sums_p_value(bits.len(), forward),
+            cumulative_sums_p_value(bits.len(), backward),
+        ],
+    ))
+}
+
+/// Discrete Fourier Transform (Spectral) Test.
+/// Prüft, ob das Betragsspektrum der Folge periodische Muster aufweist. Dafür wird gezählt,
+/// wie viele Spitzen der ersten Hälfte des Spektrums unter der 95%-Schranke liegen.
+///
+/// Die Transformation wird direkt berechnet und hat damit eine Laufzeit von O(n²).
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge.
+///
+/// # Rückgabe
+/// * `RandomnessTestResult` mit einem p-Wert.
+pub fn discrete_fourier_transform_test(bits: &[bool]) -> Result<RandomnessTestResult> {
+    const NAME: &str = "Discrete Fourier Transform (Spectral)";
+    ensure_length(NAME, bits, 2)?;
+
+    let n = bits.len();
+    let signs: Vec<f64> = bits.iter().map(|bit| to_sign(*bit) as f64).collect();
+    let (cosines, sines): (Vec<f64>, Vec<f64>) = (0..n)
+        .map(|k| {
+            let angle = 2.0 * PI * k as f64 / n as f64;
+            (angle.cos(), angle.sin())
+        })
+        .unzip();
+
+    let threshold = ((1.0 / 0.05f64).ln() * n as f64).sqrt();
+    let peaks_below_threshold = (0..n / 2)
+        .into_par_iter()
+        .filter(|frequency| {
+            let mut real = 0.0;
+            let mut imaginary = 0.0;
+            let mut index = 0;
+            for sign in &signs {
+                real += sign * cosines[index];
+                imaginary -= sign * sines[index];
+                index = (index + frequency) % n;
+            }
+            (real * real + imaginary * imaginary).sqrt() < threshold
+        })
+        .count();
+
+    let expected = 0.95 * n as f64 / 2.0;
+    let d = (peaks_below_threshold as f64 - expected) / (n as f64 * 0.95 * 0.05 / 4.0).sqrt();
+    Ok(RandomnessTestResult::new(
+        NAME,
+        vec![erfc(d.abs() / SQRT_2)],
+    ))
+}
+
+/// Führt alle Tests der Suite mit aus der Länge der Folge abgeleiteten Parametern durch.
+///
+/// # Argumente
+/// * `bits` - Die zu untersuchende Bitfolge, mindestens `MINIMUM_SUITE_LENGTH` Bits lang.
+///
+/// # Rückgabe
+/// * Die Ergebnisse aller Tests.
+pub fn run_test_suite(bits: &[bool]) -> Result<Vec<RandomnessTestResult>> {
+    ensure_length("Test Suite", bits, MINIMUM_SUITE_LENGTH)?;
+
+    let n = bits.len();
+    let log2_n = n.ilog2() as usize;
+    // Empfehlungen aus NIST SP 800-22: M >= 20, M > n / 100, m < log2(n) - 2 bzw. m < log2(n) - 5
+    let block_length = (n / 99 + 1).max(20);
+    let serial_pattern_length = (log2_n - 3).clamp(2, 16);
+    let entropy_pattern_length = (log2_n - 6).clamp(1, 14);
+
+    Ok(vec![
+        frequency_test(bits)?,
+        block_frequency_test(bits, block_length)?,
+        runs_test(bits)?,
+        longest_run_of_ones_test(bits)?,
+        serial_test(bits, serial_pattern_length)?,
+        approximate_entropy_test(bits, entropy_pattern_length)?,
+        cumulative_sums_test(bits)?,
+        discrete_fourier_transform_test(bits)?,
+    ])
+}
+
+/// Entnimmt einer Bitquelle `sample_size` Bits und führt die vollständige Testsuite aus.
+///
+/// # Argumente
+/// * `source` - Die zu untersuchende Bitquelle.
+/// * `sample_size` - Die Anzahl der zu untersuchenden Bits.
+///
+/// # Rückgabe
+/// * Die Ergebnisse aller Tests.
+pub fn analyse_bit_source(
+    source: &mut impl BitSource,
+    sample_size: usize,
+) -> Result<Vec<RandomnessTestResult>> {
+    run_test_suite(&source.take_bits(sample_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryService;
+    use crate::math_core::number_theory::number_theory_service::NumberTheoryServiceSpeed::Fast;
+    use crate::math_core::randomness::bit_source::{CsprngBitSource, PseudoRandomBitSource};
+
+    /// Die ersten 100 Binärstellen von π aus den Beispielen in NIST SP 800-22.
+    const PI_BITS: &str = "1100100100001111110110101010001000100001011010001100001000110100110001001100011001100010100010111000";
+
+    fn bits(sequence: &str) -> Vec<bool> {
+        sequence.chars().map(|c| c == '1').collect()
+    }
+
+    fn assert_p_values(result: RandomnessTestResult, expected: &[f64]) {
+        assert_eq!(result.p_values.len(), expected.len());
+        for (actual, expected) in result.p_values.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "{}: {} ist nicht {}",
+                result.name,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_p_values(frequency_test(&bits("1011010101")).unwrap(), &[0.527089]);
+        assert_p_values(frequency_test(&bits(PI_BITS)).unwrap(), &[0.109599]);
+    }
+
+    #[test]
+    fn test_block_frequency() {
+        assert_p_values(
+            block_frequency_test(&bits("0110011010"), 3).unwrap(),
+            &[0.801252],
+        );
+        assert_p_values(
+            block_frequency_test(&bits(PI_BITS), 10).unwrap(),
+            &[0.706438],
+        );
+    }
+
+    #[test]
+    fn test_runs() {
+        assert_p_values(runs_test(&bits("1001101011")).unwrap(), &[0.147232]);
+        assert_p_values(runs_test(&bits(PI_BITS)).unwrap(), &[0.500798]);
+    }
+
+    #[test]
+    fn test_longest_run_of_ones() {
+        let sequence = "11001100000101010110110001001100111000000000001001001101010100010001001111010110100000001101011111001100111001101101100010110010";
+        let result = longest_run_of_ones_test(&bits(sequence)).unwrap();
+        assert!((result.p_values[0] - 0.180609).abs() < 1e-4);
+        assert!(longest_run_of_ones_test(&bits(PI_BITS)).is_err());
+    }
+
+    #[test]
+    fn test_serial() {
+        assert_p_values(
+            serial_test(&bits("0011011101"), 3).unwrap(),
+            &[0.808792, 0.670320],
+        );
+        assert!(serial_test(&bits("0011011101"), 1).is_err());
+    }
+
+    #[test]
+    fn test_approximate_entropy() {
+        assert_p_values(
+            approximate_entropy_test(&bits("0100110101"), 3).unwrap(),
+            &[0.261961],
+        );
+        assert_p_values(
+            approximate_entropy_test(&bits(PI_BITS), 2).unwrap(),
+            &[0.235301],
+        );
+    }
+
+    #[test]
+    fn test_cumulative_sums() {
+        let result = cumulative_sums_test(&bits("1011010111")).unwrap();
+        assert!((result.p_values[0] - 0.4116588).abs() < 1e-6);
+        assert_p_values(
+            cumulative_sums_test(&bits(PI_BITS)).unwrap(),
+            &[0.219194, 0.114866],
+        );
+    }
+
+    #[test]
+    fn test_discrete_fourier_transform() {
+        // Die Beispielwerte im NIST-Dokument beruhen noch auf einer älteren Varianz von N1.
+        // Mit den aktuellen Formeln liegen 48 von 50 Spitzen unter der Schranke.
+        assert_p_values(
+            discrete_fourier_transform_test(&bits(PI_BITS)).unwrap(),
+            &[0.646355],
+        );
+    }
+
+    #[test]
+    fn test_constant_sequence_fails() {
+        let results = run_test_suite(&[true; 1000]).unwrap();
+        assert_eq!(results.len(), 8);
+        assert!(!results[0].passed);
+    }
+
+    #[test]
+    fn test_compare_generators() {
+        let mut csprng = CsprngBitSource::new(42);
+        let csprng_results = analyse_bit_source(&mut csprng, 2000).unwrap();
+        assert!(csprng_results.iter().all(|result| result.passed));
+
+        let mut prng = PseudoRandomBitSource::new(42, NumberTheoryService::new(Fast));
+        let prng_results = analyse_bit_source(&mut prng, 2000).unwrap();
+        assert_eq!(prng_results.len(), csprng_results.len());
+        assert!(prng_results
+            .iter()
+            .flat_map(|result| &result.p_values)
+            .all(|p| (0.0..=1.0 + 1e-9).contains(p)));
+    }
+}
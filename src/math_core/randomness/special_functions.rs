@@ -0,0 +1,163 @@
+use std::f64::consts::{PI, SQRT_2};
+
+/// Maximale Anzahl an Iterationen für die Reihen- und Kettenbruchentwicklungen.
+const MAX_ITERATIONS: usize = 1000;
+/// Relative Genauigkeit, ab der die Entwicklungen abgebrochen werden.
+const EPSILON: f64 = 1e-15;
+/// Ersatzwert für 0, um Divisionen durch 0 im Kettenbruch zu vermeiden.
+const TINY: f64 = 1e-300;
+
+/// Berechnet den natürlichen Logarithmus der Gammafunktion mithilfe der Lanczos-Approximation.
+///
+/// # Argumente
+/// * `x` - Das Argument, muss größer als 0 sein.
+///
+/// # Rückgabe
+/// * `ln(Γ(x))`
+pub fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    let x = x - 1.0;
+    let sum = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |acc, (i, c)| acc + c / (x + i as f64));
+    let t = x + G + 0.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Berechnet die regularisierte obere unvollständige Gammafunktion Q(a, x) = Γ(a, x) / Γ(a).
+/// Sie entspricht der Funktion `igamc` aus NIST SP 800-22.
+///
+/// # Argumente
+/// * `a` - Der Formparameter, muss größer als 0 sein.
+/// * `x` - Die untere Integrationsgrenze.
+///
+/// # Rückgabe
+/// * `Q(a, x)` im Intervall [0, 1].
+pub fn upper_incomplete_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    // Für kleine x konvergiert die Reihe für P(a, x) schneller, für große x der Kettenbruch.
+    if x < a + 1.0 {
+        1.0 - lower_incomplete_gamma_series(a, x)
+    } else {
+        upper_incomplete_gamma_continued_fraction(a, x)
+    }
+}
+
+/// Berechnet P(a, x) über die Reihenentwicklung.
+fn lower_incomplete_gamma_series(a: f64, x: f64) -> f64 {
+    let mut denominator = a;
+    let mut term = 1.0 / a;
+    let mut sum = term;
+    for _ in 0..MAX_ITERATIONS {
+        denominator += 1.0;
+        term *= x / denominator;
+        sum += term;
+        if term.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// Berechnet Q(a, x) über den Kettenbruch nach dem modifizierten Verfahren von Lentz.
+fn upper_incomplete_gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let i = i as f64;
+        let an = -i * (i - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// Berechnet die komplementäre Fehlerfunktion erfc(x).
+/// Es gilt erfc(x) = Q(1/2, x^2) für x >= 0 und erfc(-x) = 2 - erfc(x).
+pub fn erfc(x: f64) -> f64 {
+    let q = upper_incomplete_gamma(0.5, x * x);
+    if x >= 0.0 {
+        q
+    } else {
+        2.0 - q
+    }
+}
+
+/// Berechnet die Verteilungsfunktion Φ(x) der Standardnormalverteilung.
+pub fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} ist nicht {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_close(ln_gamma(1.0), 0.0);
+        assert_close(ln_gamma(5.0), 24f64.ln());
+        assert_close(ln_gamma(0.5), PI.sqrt().ln());
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert_close(erfc(0.0), 1.0);
+        assert_close(erfc(1.0), 0.157_299_207_050_285_13);
+        assert_close(erfc(-1.0), 1.842_700_792_949_715);
+        assert_close(erfc(3.0), 2.209_049_699_858_544e-5);
+    }
+
+    #[test]
+    fn test_upper_incomplete_gamma() {
+        // Q(1, x) = e^(-x)
+        assert_close(upper_incomplete_gamma(1.0, 2.0), (-2f64).exp());
+        assert_close(upper_incomplete_gamma(1.0, 0.5), (-0.5f64).exp());
+        assert_close(upper_incomplete_gamma(3.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_standard_normal_cdf() {
+        assert_close(standard_normal_cdf(0.0), 0.5);
+        assert_close(standard_normal_cdf(1.96), 0.975_002_104_851_780_1);
+    }
+}
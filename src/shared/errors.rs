@@ -1,8 +1,9 @@
+use crate::math_core::ecc::finite_field_elliptic_curve::FiniteFieldEllipticCurve;
 use crate::math_core::ecc::finite_field_elliptic_curve_point::FiniteFieldEllipticCurvePoint;
-use crate::math_core::ecc::secure_finite_field_elliptic_curve::SecureFiniteFieldEllipticCurve;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum ArithmeticError {
     /// Wird geworfen, wenn eine Zahl kein Inverses hat.
     ///
@@ -19,6 +20,133 @@ pub enum ArithmeticError {
     /// * `element` - Das Element, zu dem der diskrete Logarithmus nicht existiert.
     #[error("No discrete logarithm error: base {0} with element {1}")]
     NoDiscreteLogarithmError(String, String),
+
+    /// Wird geworfen, wenn ein Modul nicht positiv ist.
+    ///
+    /// # Argumente
+    /// * `modulus` - Das ungültige Modul.
+    #[error("Invalid modulus error: modulus {0} must be positive")]
+    InvalidModulusError(String),
+
+    /// Wird geworfen, wenn ein System von Kongruenzen keine Lösung hat.
+    ///
+    /// # Argumente
+    /// * `solution` - Die zusammengefasste Lösung der vorherigen Kongruenzen.
+    /// * `congruence` - Die Kongruenz, die ihr widerspricht.
+    #[error("Inconsistent congruences error: {0} contradicts {1}")]
+    InconsistentCongruencesError(String, String),
+
+    /// Wird geworfen, wenn eine Zahl nicht positiv ist, obwohl sie es sein muss.
+    ///
+    /// # Argumente
+    /// * `number` - Die Zahl, die nicht positiv ist.
+    #[error("Non positive number error: {0} must be positive")]
+    NonPositiveNumberError(String),
+
+    /// Wird geworfen, wenn ein Element nicht in der Einheitengruppe eines Moduls liegt.
+    ///
+    /// # Argumente
+    /// * `element` - Das Element, das nicht teilerfremd zum Modul ist.
+    /// * `modulus` - Das Modul.
+    #[error("Not a unit error: {0} is not coprime to modulus {1}")]
+    NotAUnitError(String, String),
+
+    /// Wird geworfen, wenn die Einheitengruppe eines Moduls nicht zyklisch ist.
+    ///
+    /// # Argumente
+    /// * `modulus` - Das Modul, zu dem es keine Primitivwurzel gibt.
+    #[error("No primitive root error: modulus {0} has no primitive root")]
+    NoPrimitiveRootError(String),
+
+    /// Wird geworfen, wenn durch null geteilt wird.
+    #[error("Division by zero error")]
+    DivisionByZeroError,
+
+    /// Wird geworfen, wenn eine exakte Division nicht aufgeht.
+    ///
+    /// # Argumente
+    /// * `dividend` - Der Dividend.
+    /// * `divisor` - Der Divisor, der den Dividenden nicht teilt.
+    #[error("Not divisible error: {0} is not divisible by {1}")]
+    NotDivisibleError(String, String),
+
+    /// Wird geworfen, wenn eine Zahl keine Primzahl der Form p = 2 oder p ≡ 1 mod 4 ist und
+    /// daher nicht mit Cornacchia als Summe zweier Quadrate geschrieben werden kann.
+    ///
+    /// # Argumente
+    /// * `number` - Die Zahl.
+    #[error("No sum of two squares error: {0} is not a prime p = 2 or p ≡ 1 mod 4")]
+    NoSumOfTwoSquaresError(String),
+
+    /// Wird geworfen, wenn eine Zahl kein quadratischer Rest modulo einer Primzahl ist.
+    ///
+    /// # Argumente
+    /// * `number` - Die Zahl, die keine Quadratwurzel hat.
+    /// * `prime` - Die Primzahl.
+    #[error("No square root error: {0} is not a quadratic residue modulo {1}")]
+    NoSquareRootError(String, String),
+
+    /// Wird geworfen, wenn ein Element keine Untergruppe der angegebenen Primzahlordnung erzeugt.
+    ///
+    /// # Argumente
+    /// * `generator` - Das Element.
+    /// * `order` - Die erwartete Ordnung.
+    #[error("Invalid subgroup error: {0} does not generate a subgroup of prime order {1}")]
+    InvalidSubgroupError(String, String),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum EcdsaError {
+    /// Wird geworfen, wenn eine Signatur nicht der strikten DER-Kodierung entspricht.
+    ///
+    /// # Argumente
+    /// * `reason` - Der Grund, warum die Kodierung ungültig ist.
+    #[error("Invalid DER signature: {0}")]
+    InvalidDerSignatureError(String),
+
+    /// Wird geworfen, wenn die Recovery-ID einer Signatur auf kein Körperelement r + j · q zeigt.
+    #[error("Invalid recovery id {0}, r + j * q is not a field element")]
+    InvalidRecoveryIdError(u8),
+
+    /// Wird geworfen, wenn aus einer Signatur kein öffentlicher Schlüssel berechnet werden kann.
+    ///
+    /// # Argumente
+    /// * `reason` - Der Grund, warum die Berechnung fehlschlägt.
+    #[error("Public key recovery failed: {0}")]
+    PublicKeyRecoveryError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum EddsaError {
+    /// Wird geworfen, wenn ein Ed25519-Schlüssel nicht die Länge von 32 Bytes hat.
+    #[error("Ed25519 key must be 32 bytes, but is {0} bytes")]
+    KeyLengthError(usize),
+
+    /// Wird geworfen, wenn eine Ed25519-Signatur nicht die Länge von 64 Bytes hat.
+    #[error("Ed25519 signature must be 64 bytes, but is {0} bytes")]
+    SignatureLengthError(usize),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum KeyExchangeError {
+    /// Wird geworfen, wenn der öffentliche Wert der Gegenseite nicht in der Untergruppe mit
+    /// Primzahlordnung liegt oder das neutrale Element ist.
+    ///
+    /// # Argumente
+    /// * `value` - Der abgelehnte öffentliche Wert.
+    #[error("Invalid public value {0}, it is not an element of the prime-order subgroup")]
+    InvalidPublicValueError(String),
+
+    /// Wird geworfen, wenn X25519 das Nullergebnis liefert, der öffentliche Wert also kleine
+    /// Ordnung hat.
+    #[error("X25519 produced the all-zero shared secret, the public value has small order")]
+    AllZeroSharedSecretError,
+
+    /// Wird geworfen, wenn das Verfahren zur Schlüsselvereinbarung unbekannt ist.
+    #[error("Unknown key exchange method {0}")]
+    UnknownKeyExchangeMethodError(String),
 }
 
 #[derive(Debug, Error)]
@@ -34,10 +162,302 @@ pub enum MenezesVanstoneError {
 }
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum EllipticCurveError {
     #[error("Point {0} is not on curve {1}")]
-    PointNotOnCurveError(
-        FiniteFieldEllipticCurvePoint,
-        SecureFiniteFieldEllipticCurve,
-    ),
+    PointNotOnCurveError(FiniteFieldEllipticCurvePoint, FiniteFieldEllipticCurve),
+    #[error("Curve {0} is singular, its discriminant vanishes")]
+    SingularCurveError(FiniteFieldEllipticCurve),
+    #[error("Modulus {0} is not a prime greater than 3")]
+    InvalidFieldPrimeError(String),
+    #[error("Unknown curve {0}")]
+    UnknownCurveError(String),
+    #[error("Scalar {0} is out of range for this precomputation")]
+    ScalarOutOfRangeError(String),
+    #[error("Invalid SEC1 point encoding: {0}")]
+    InvalidPointEncodingError(String),
+    #[error("Point {0} is not in the prime-order subgroup")]
+    PointNotInSubgroupError(FiniteFieldEllipticCurvePoint),
+    #[error("Unknown point counting method {0}")]
+    UnknownPointCountingMethodError(String),
+    #[error("Point counting method {0} supports primes of at most {1} bits")]
+    PointCountingLimitError(String, u64),
+    #[error("Could not determine the order of curve {0}")]
+    PointCountingFailedError(FiniteFieldEllipticCurve),
+    #[error("Subgroup order {0} does not divide the curve order {1}")]
+    SubgroupOrderMismatchError(String, String),
+    #[error("Curve coefficients {0} and {1} are zero or equal modulo the prime")]
+    DegenerateCurveCoefficientsError(String, String),
+    #[error("Point {0} has no image under the birational map")]
+    ExceptionalPointError(String),
+    #[error("Invalid point encoding according to RFC 8032: {0}")]
+    InvalidEdwardsPointEncodingError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum RandomnessTestError {
+    /// Wird geworfen, wenn die Bitfolge für einen statistischen Test zu kurz ist.
+    ///
+    /// # Argumente
+    /// * `test` - Der Name des Tests.
+    /// * `required` - Die minimal benötigte Anzahl an Bits.
+    /// * `actual` - Die tatsächliche Anzahl an Bits.
+    #[error("Test {0} requires at least {1} bits, but only {2} were given")]
+    SequenceTooShortError(String, usize, usize),
+
+    /// Wird geworfen, wenn ein Parameter eines Tests ungültig ist.
+    ///
+    /// # Argumente
+    /// * `test` - Der Name des Tests.
+    /// * `parameter` - Beschreibung des ungültigen Parameters.
+    #[error("Invalid parameter for test {0}: {1}")]
+    InvalidParameterError(String, String),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum ByteEncodingError {
+    /// Wird geworfen, wenn eine negative Zahl in eine Bytefolge umgewandelt werden soll.
+    #[error("Negative integer {0} cannot be encoded as octet string")]
+    NegativeIntegerError(String),
+
+    /// Wird geworfen, wenn eine Zahl nicht in die vorgegebene Anzahl an Bytes passt.
+    ///
+    /// # Argumente
+    /// * `number` - Die Zahl.
+    /// * `length` - Die vorgegebene Anzahl an Bytes.
+    #[error("Integer {0} is too large for {1} bytes")]
+    IntegerTooLargeError(String, usize),
+
+    /// Wird geworfen, wenn die Länge einer Bytefolge kein Vielfaches der Blockgröße ist.
+    ///
+    /// # Argumente
+    /// * `length` - Die Länge der Bytefolge.
+    /// * `block_size` - Die Blockgröße.
+    #[error("Length {0} is not a multiple of block size {1}")]
+    InvalidBlockSizeError(usize, usize),
+
+    /// Wird geworfen, wenn die Längenangabe einer Nachricht fehlt oder die Nachricht übersteigt.
+    #[error("Invalid length prefix {0}")]
+    InvalidLengthPrefixError(u64),
+
+    /// Wird geworfen, wenn die Auffüllung einer Nachricht nicht nur aus Nullbytes besteht.
+    #[error("Padding contains non-zero bytes")]
+    InvalidPaddingError,
+
+    /// Wird geworfen, wenn der Modulus zu klein ist, um mindestens ein Byte pro Block aufzunehmen.
+    #[error("Modulus {0} is too small to hold a single byte per block")]
+    ModulusTooSmallError(String),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum ContainerError {
+    /// Wird geworfen, wenn die Bytefolge nicht mit der Kennung des Containerformats beginnt.
+    #[error("Invalid magic bytes, the data is not a ciphertext container")]
+    InvalidMagicError,
+
+    /// Wird geworfen, wenn die Version des Containers nicht unterstützt wird.
+    #[error("Unsupported container version {0}")]
+    UnsupportedVersionError(u8),
+
+    /// Wird geworfen, wenn die Kennung des Verschlüsselungsschemas unbekannt ist.
+    #[error("Unknown scheme identifier {0}")]
+    UnknownSchemeError(u8),
+
+    /// Wird geworfen, wenn der Container mit einem anderen Schema erstellt wurde.
+    ///
+    /// # Argumente
+    /// * `expected` - Das erwartete Schema.
+    /// * `actual` - Das Schema des Containers.
+    #[error("Scheme mismatch: expected {0}, container was created with {1}")]
+    SchemeMismatchError(String, String),
+
+    /// Wird geworfen, wenn der Fingerabdruck des Schlüssels nicht mit dem des Containers übereinstimmt.
+    #[error("The key does not match the key fingerprint of the container")]
+    KeyFingerprintMismatchError,
+
+    /// Wird geworfen, wenn die Bytefolge vor dem Ende des Containers abbricht.
+    #[error("Container is truncated at offset {0}")]
+    TruncatedError(usize),
+
+    /// Wird geworfen, wenn nach dem Ende des Containers weitere Bytes folgen.
+    #[error("Container has {0} trailing bytes")]
+    TrailingBytesError(usize),
+
+    /// Wird geworfen, wenn die Base64-Darstellung nicht dekodiert werden kann.
+    #[error("Invalid Base64 representation: {0}")]
+    InvalidBase64Error(String),
+
+    /// Wird geworfen, wenn die Kodierungsparameter nicht zum Modulus des Schlüssels passen.
+    ///
+    /// # Argumente
+    /// * `radix` - Die Basis der Kodierung.
+    /// * `block_size` - Die Blockgröße der Kodierung.
+    #[error("Invalid encoding parameters: radix {0} with block size {1}")]
+    InvalidEncodingParametersError(u32, u32),
+
+    /// Wird geworfen, wenn die Anzahl der Blöcke nicht zur ursprünglichen Länge passt.
+    ///
+    /// # Argumente
+    /// * `original_length` - Die ursprüngliche Länge des Klartextes.
+    /// * `block_count` - Die Anzahl der Blöcke.
+    #[error("Original length {0} does not match {1} blocks")]
+    LengthMismatchError(u64, usize),
+
+    /// Wird geworfen, wenn ein entschlüsselter Block nicht in Zeichen umgewandelt werden kann.
+    #[error("Block {0} cannot be decoded with the given encoding parameters")]
+    InvalidBlockError(usize),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum HybridEncryptionError {
+    /// Wird geworfen, wenn ein unbekanntes AEAD-Verfahren angefordert wird.
+    #[error("Unknown AEAD algorithm {0}")]
+    UnknownAeadAlgorithmError(String),
+
+    /// Wird geworfen, wenn der gekapselte Schlüssel nicht die erwartete Länge hat.
+    ///
+    /// # Argumente
+    /// * `expected` - Die erwartete Länge in Bytes.
+    /// * `actual` - Die tatsächliche Länge in Bytes.
+    #[error("Encapsulated key must be {0} bytes, but is {1} bytes")]
+    InvalidEncapsulatedKeyLengthError(usize, usize),
+
+    /// Wird geworfen, wenn der gekapselte Schlüssel außerhalb des gültigen Wertebereichs liegt.
+    #[error("Encapsulated key is out of range")]
+    EncapsulatedKeyOutOfRangeError,
+
+    /// Wird geworfen, wenn der gekapselte Punkt nicht auf der Kurve liegt.
+    #[error("Encapsulated point is not on the curve")]
+    InvalidEncapsulatedPointError,
+
+    /// Wird geworfen, wenn die Nonce nicht die für das AEAD-Verfahren erforderliche Länge hat.
+    #[error("Nonce must be 12 bytes, but is {0} bytes")]
+    InvalidNonceLengthError(usize),
+
+    /// Wird geworfen, wenn der Chiffretext oder die zugehörigen Daten manipuliert wurden.
+    #[error("Authentication of the ciphertext failed")]
+    AuthenticationFailedError,
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum AesError {
+    /// Wird geworfen, wenn der Schlüssel nicht 16, 24 oder 32 Bytes lang ist.
+    #[error("AES key must be 16, 24 or 32 bytes, but is {0} bytes")]
+    InvalidKeyLengthError(usize),
+
+    /// Wird geworfen, wenn der Initialisierungsvektor nicht die für den Modus erforderliche Länge hat.
+    ///
+    /// # Argumente
+    /// * `expected` - Die erwartete Länge in Bytes.
+    /// * `actual` - Die tatsächliche Länge in Bytes.
+    #[error("Initialization vector must be {0} bytes, but is {1} bytes")]
+    InvalidIvLengthError(usize, usize),
+
+    /// Wird geworfen, wenn die Länge des Geheimtextes nicht zum Modus passt.
+    #[error("Invalid ciphertext length {0}")]
+    InvalidCiphertextLengthError(usize),
+
+    /// Wird geworfen, wenn das PKCS#7-Padding ungültig ist.
+    #[error("Invalid PKCS#7 padding")]
+    InvalidPaddingError,
+
+    /// Wird geworfen, wenn der Authentifizierungs-Tag im GCM-Modus nicht übereinstimmt.
+    #[error("Authentication tag mismatch")]
+    AuthenticationFailedError,
+
+    /// Wird geworfen, wenn ein unbekannter Betriebsmodus angefordert wird.
+    #[error("Unknown block cipher mode {0}")]
+    UnknownModeError(String),
+}
+
+#[derive(Debug, Error)]
+pub enum MatrixError {
+    /// Wird geworfen, wenn eine Matrix leer oder nicht quadratisch ist.
+    #[error("Matrix must be square and non-empty")]
+    NotSquareError,
+
+    /// Wird geworfen, wenn die Dimensionen zweier Operanden nicht zusammenpassen.
+    ///
+    /// # Argumente
+    /// * `expected` - Die erwartete Dimension.
+    /// * `actual` - Die tatsächliche Dimension.
+    #[error("Dimension mismatch, expected {0} but got {1}")]
+    DimensionMismatchError(usize, usize),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum ClassicCipherError {
+    /// Wird geworfen, wenn ein Schlüssel für eine klassische Chiffre ungültig ist.
+    ///
+    /// # Argumente
+    /// * `reason` - Der Grund, warum der Schlüssel ungültig ist.
+    #[error("Invalid key: {0}")]
+    InvalidKeyError(String),
+
+    /// Wird geworfen, wenn der Geheimtext Zeichen enthält, die die Chiffre nicht erzeugen kann.
+    #[error("Ciphertext contains invalid character '{0}'")]
+    InvalidCharacterError(char),
+
+    /// Wird geworfen, wenn die Länge des Geheimtextes kein Vielfaches der Blocklänge ist.
+    ///
+    /// # Argumente
+    /// * `block_length` - Die Blocklänge der Chiffre.
+    /// * `length` - Die Länge des Geheimtextes.
+    #[error("Ciphertext length must be a multiple of {0}, but is {1}")]
+    InvalidCiphertextLengthError(usize, usize),
+
+    /// Wird geworfen, wenn eine unbekannte klassische Chiffre angefordert wird.
+    #[error("Unknown classic cipher {0}")]
+    UnknownCipherError(String),
+
+    /// Wird geworfen, wenn für die Kryptoanalyse eine unbekannte Sprache angegeben wird.
+    #[error("Unknown language {0}, expected de or en")]
+    UnknownLanguageError(String),
+
+    /// Wird geworfen, wenn für eine Chiffre kein automatischer Angriff verfügbar ist.
+    #[error("No automated attack available for cipher {0}")]
+    UnsupportedAttackError(String),
+
+    /// Wird geworfen, wenn der Text für einen Angriff nicht ausreicht.
+    ///
+    /// # Argumente
+    /// * `reason` - Der Grund, warum der Angriff nicht durchgeführt werden kann.
+    #[error("Not enough text for the attack: {0}")]
+    InsufficientTextError(String),
+}
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+pub enum HashError {
+    /// Wird geworfen, wenn ein unbekanntes Hashverfahren angefordert wird.
+    #[error("Unknown hash algorithm {0}")]
+    UnknownAlgorithmError(String),
+
+    /// Wird geworfen, wenn die Ausgabelänge einer SHAKE-Funktion ungültig ist.
+    ///
+    /// # Argumente
+    /// * `bits` - Die angeforderte Ausgabelänge in Bits.
+    #[error(
+        "Output length must be a positive multiple of 8 bits of at most 8192 bits, but is {0}"
+    )]
+    InvalidOutputLengthError(String),
+
+    /// Wird geworfen, wenn HMAC mit einer Funktion variabler Ausgabelänge (SHAKE) gebildet
+    /// werden soll.
+    #[error("HMAC is not defined for {0}")]
+    UnsupportedHmacAlgorithmError(String),
+
+    /// Wird geworfen, wenn eine Schlüsselableitung nicht die geforderte Anzahl an Bytes
+    /// liefern kann.
+    #[error("Invalid length of derived key: {0}")]
+    InvalidDerivedKeyLengthError(String),
+
+    /// Wird geworfen, wenn PBKDF2 mit weniger als einer Iteration aufgerufen wird.
+    #[error("Iteration count must be at least 1, but is {0}")]
+    InvalidIterationCountError(String),
 }
@@ -0,0 +1,142 @@
+use crate::shared::hashing::educational::trace::{
+    merkle_damgard_padding, BlockTrace, HashTrace, RoundTrace, TracedHashAlgorithm,
+    MERKLE_DAMGARD_BLOCK_SIZE,
+};
+
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+/// Die Rundenkonstanten K_i = ⌊|sin(i + 1)| · 2^32⌋.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Die Rotationsweiten, die sich in jedem der vier Abschnitte nach vier Runden wiederholen.
+const ROTATIONS: [[u32; 4]; 4] = [
+    [7, 12, 17, 22],
+    [5, 9, 14, 20],
+    [4, 11, 16, 23],
+    [6, 10, 15, 21],
+];
+
+/// Berechnet MD5 (RFC 1321) und protokolliert jede der 64 Runden pro Block. Anders als bei
+/// SHA werden Wörter und Nachrichtenlänge im Little-Endian-Format gelesen.
+/// MD5 ist seit 2004 praktisch gebrochen und dient nur dem Vergleich.
+pub fn trace_md5(message: &[u8]) -> HashTrace {
+    let padding = merkle_damgard_padding(message.len(), true);
+    let padded_message = [message, &padding].concat();
+
+    let mut state = INITIAL_STATE;
+    let blocks = padded_message
+        .chunks_exact(MERKLE_DAMGARD_BLOCK_SIZE)
+        .map(|block| compress(&mut state, block))
+        .collect();
+
+    HashTrace {
+        algorithm: TracedHashAlgorithm::Md5,
+        message_length: message.len(),
+        padding,
+        blocks,
+        digest: state.iter().flat_map(|word| word.to_le_bytes()).collect(),
+    }
+}
+
+fn compress(state: &mut [u32; 4], block: &[u8]) -> BlockTrace {
+    let words: Vec<u32> = block
+        .chunks_exact(4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect();
+
+    // MD5 erweitert die Nachricht nicht, sondern liest die 16 Wörter in jedem Abschnitt
+    // in einer anderen Reihenfolge. Diese Reihenfolge bildet hier den Message Schedule.
+    let schedule: Vec<u32> = (0..64)
+        .map(|i| match i / 16 {
+            0 => words[i],
+            1 => words[(5 * i + 1) % 16],
+            2 => words[(3 * i + 5) % 16],
+            _ => words[(7 * i) % 16],
+        })
+        .collect();
+
+    let mut rounds = Vec::with_capacity(64);
+    let [mut a, mut b, mut c, mut d] = *state;
+    for (i, (word, constant)) in schedule.iter().zip(ROUND_CONSTANTS).enumerate() {
+        let function = match i / 16 {
+            0 => (b & c) | (!b & d),
+            1 => (b & d) | (c & !d),
+            2 => b ^ c ^ d,
+            _ => c ^ (b | !d),
+        };
+        let temp = a
+            .wrapping_add(function)
+            .wrapping_add(constant)
+            .wrapping_add(*word);
+
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(temp.rotate_left(ROTATIONS[i / 16][i % 4]));
+
+        rounds.push(RoundTrace {
+            word: *word,
+            constant,
+            working_variables: vec![a, b, c, d],
+        });
+    }
+
+    let initial_state = state.to_vec();
+    for (value, working_variable) in state.iter_mut().zip([a, b, c, d]) {
+        *value = value.wrapping_add(working_variable);
+    }
+
+    BlockTrace {
+        message_schedule: schedule,
+        initial_state,
+        rounds,
+        final_state: state.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    /// Berechnet MD5 ohne das Protokoll.
+    fn md5(message: &[u8]) -> Vec<u8> {
+        trace_md5(message).digest
+    }
+
+    #[test]
+    fn test_known_digests() {
+        // Die ersten beiden Werte stammen aus der Testsuite von RFC 1321.
+        let long_message: Vec<u8> = (0..200u8).collect();
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "d41d8cd98f00b204e9800998ecf8427e"),
+            (b"abc", "900150983cd24fb0d6963f7d28e17f72"),
+            (&long_message, "fb7001d34b8e82c9b579be5005d5b0a5"),
+        ];
+        for (message, digest) in vectors {
+            assert_eq!(md5(message), hex(digest));
+        }
+    }
+
+    #[test]
+    fn test_trace_reads_little_endian_words() {
+        let trace = trace_md5(b"abc");
+        let block = &trace.blocks[0];
+        assert_eq!(block.message_schedule.len(), 64);
+        assert_eq!(block.message_schedule[0], 0x80636261);
+        assert_eq!(block.message_schedule[14], 24);
+        assert_eq!(
+            trace.padding[trace.padding.len() - 8..],
+            24u64.to_le_bytes()
+        );
+    }
+}
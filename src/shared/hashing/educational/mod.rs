@@ -0,0 +1,4 @@
+pub mod md5;
+pub mod sha1;
+pub mod sha256;
+pub mod trace;
@@ -0,0 +1,122 @@
+use crate::shared::hashing::educational::trace::{
+    merkle_damgard_padding, BlockTrace, HashTrace, RoundTrace, TracedHashAlgorithm,
+    MERKLE_DAMGARD_BLOCK_SIZE,
+};
+
+const INITIAL_STATE: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// Die Konstanten der vier Abschnitte zu je 20 Runden: 2^30 mal die Wurzeln aus 2, 3, 5 und 10.
+const SECTION_CONSTANTS: [u32; 4] = [0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xca62c1d6];
+
+/// Berechnet SHA-1 (FIPS 180-4) und protokolliert jede der 80 Runden pro Block.
+/// SHA-1 ist seit der Kollision SHAttered (2017) gebrochen und dient nur dem Vergleich.
+pub fn trace_sha1(message: &[u8]) -> HashTrace {
+    let padding = merkle_damgard_padding(message.len(), false);
+    let padded_message = [message, &padding].concat();
+
+    let mut state = INITIAL_STATE;
+    let blocks = padded_message
+        .chunks_exact(MERKLE_DAMGARD_BLOCK_SIZE)
+        .map(|block| compress(&mut state, block))
+        .collect();
+
+    HashTrace {
+        algorithm: TracedHashAlgorithm::Sha1,
+        message_length: message.len(),
+        padding,
+        blocks,
+        digest: state.iter().flat_map(|word| word.to_be_bytes()).collect(),
+    }
+}
+
+fn compress(state: &mut [u32; 5], block: &[u8]) -> BlockTrace {
+    let mut schedule = [0u32; 80];
+    for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for t in 16..80 {
+        schedule[t] = (schedule[t - 3] ^ schedule[t - 8] ^ schedule[t - 14] ^ schedule[t - 16])
+            .rotate_left(1);
+    }
+
+    let mut rounds = Vec::with_capacity(80);
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+    for (t, word) in schedule.iter().enumerate() {
+        let function = match t / 20 {
+            0 => (b & c) | (!b & d),
+            2 => (b & c) | (b & d) | (c & d),
+            _ => b ^ c ^ d,
+        };
+        let constant = SECTION_CONSTANTS[t / 20];
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(function)
+            .wrapping_add(e)
+            .wrapping_add(constant)
+            .wrapping_add(*word);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+
+        rounds.push(RoundTrace {
+            word: *word,
+            constant,
+            working_variables: vec![a, b, c, d, e],
+        });
+    }
+
+    let initial_state = state.to_vec();
+    for (value, working_variable) in state.iter_mut().zip([a, b, c, d, e]) {
+        *value = value.wrapping_add(working_variable);
+    }
+
+    BlockTrace {
+        message_schedule: schedule.to_vec(),
+        initial_state,
+        rounds,
+        final_state: state.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    /// Berechnet SHA-1 ohne das Protokoll.
+    fn sha1(message: &[u8]) -> Vec<u8> {
+        trace_sha1(message).digest
+    }
+
+    #[test]
+    fn test_known_digests() {
+        let long_message: Vec<u8> = (0..200u8).collect();
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+            (b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            (&long_message, "54d11e99127d159799dbce10f51a75e697780478"),
+        ];
+        for (message, digest) in vectors {
+            assert_eq!(sha1(message), hex(digest));
+        }
+    }
+
+    #[test]
+    fn test_trace_of_abc() {
+        // Werte aus dem Beispiel "abc" in FIPS 180-2, Anhang A.1.
+        let trace = trace_sha1(b"abc");
+        let block = &trace.blocks[0];
+        assert_eq!(block.rounds.len(), 80);
+        assert_eq!(
+            block.rounds[0].working_variables,
+            vec![0x0116fc33, 0x67452301, 0x7bf36ae2, 0x98badcfe, 0x10325476]
+        );
+        assert_eq!(
+            block.rounds[79].working_variables,
+            vec![0x42541b35, 0x5738d5e1, 0x21834873, 0x681e6df6, 0xd8fdf6ad]
+        );
+    }
+}
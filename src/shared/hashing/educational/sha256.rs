@@ -0,0 +1,159 @@
+use crate::shared::hashing::educational::trace::{
+    merkle_damgard_padding, BlockTrace, HashTrace, RoundTrace, TracedHashAlgorithm,
+    MERKLE_DAMGARD_BLOCK_SIZE,
+};
+
+/// Die Rundenkonstanten: die ersten 32 Bits der Nachkommastellen der Kubikwurzeln der ersten
+/// 64 Primzahlen.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Der Anfangszustand: die ersten 32 Bits der Nachkommastellen der Quadratwurzeln der ersten
+/// 8 Primzahlen.
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Berechnet SHA-256 (FIPS 180-4) und protokolliert Padding, Nachrichtenexpansion und jede der
+/// 64 Runden pro Block.
+pub fn trace_sha256(message: &[u8]) -> HashTrace {
+    let padding = merkle_damgard_padding(message.len(), false);
+    let padded_message = [message, &padding].concat();
+
+    let mut state = INITIAL_STATE;
+    let blocks = padded_message
+        .chunks_exact(MERKLE_DAMGARD_BLOCK_SIZE)
+        .map(|block| compress(&mut state, block))
+        .collect();
+
+    HashTrace {
+        algorithm: TracedHashAlgorithm::Sha256,
+        message_length: message.len(),
+        padding,
+        blocks,
+        digest: state.iter().flat_map(|word| word.to_be_bytes()).collect(),
+    }
+}
+
+/// Die Kompressionsfunktion: Verarbeitet einen Block von 64 Bytes und aktualisiert den Zustand.
+fn compress(state: &mut [u32; 8], block: &[u8]) -> BlockTrace {
+    // Nachrichtenexpansion: Die 16 Wörter des Blocks werden auf 64 Wörter erweitert.
+    let mut schedule = [0u32; 64];
+    for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for t in 16..64 {
+        let w15 = schedule[t - 15];
+        let w2 = schedule[t - 2];
+        let sigma0 = w15.rotate_right(7) ^ w15.rotate_right(18) ^ (w15 >> 3);
+        let sigma1 = w2.rotate_right(17) ^ w2.rotate_right(19) ^ (w2 >> 10);
+        schedule[t] = schedule[t - 16]
+            .wrapping_add(sigma0)
+            .wrapping_add(schedule[t - 7])
+            .wrapping_add(sigma1);
+    }
+
+    let mut rounds = Vec::with_capacity(64);
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (word, constant) in schedule.iter().zip(ROUND_CONSTANTS) {
+        let big_sigma1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choose = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(big_sigma1)
+            .wrapping_add(choose)
+            .wrapping_add(constant)
+            .wrapping_add(*word);
+        let big_sigma0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = big_sigma0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+
+        rounds.push(RoundTrace {
+            word: *word,
+            constant,
+            working_variables: vec![a, b, c, d, e, f, g, h],
+        });
+    }
+
+    let initial_state = state.to_vec();
+    for (value, working_variable) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *value = value.wrapping_add(working_variable);
+    }
+
+    BlockTrace {
+        message_schedule: schedule.to_vec(),
+        initial_state,
+        rounds,
+        final_state: state.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    /// Berechnet SHA-256 ohne das Protokoll.
+    fn sha256(message: &[u8]) -> Vec<u8> {
+        trace_sha256(message).digest
+    }
+
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    #[test]
+    fn test_matches_sha2_crate() {
+        let long_message: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        for message in [&b""[..], b"abc", &[0x61; 55], &[0x61; 56], &long_message] {
+            assert_eq!(sha256(message), Sha256::digest(message).to_vec());
+        }
+    }
+
+    #[test]
+    fn test_trace_of_abc() {
+        // Das Beispiel "abc" aus FIPS 180-4, Anhang B.1.
+        let trace = trace_sha256(b"abc");
+        assert_eq!(trace.message_length, 3);
+        assert_eq!(trace.padding.len(), 61);
+        assert_eq!(trace.blocks.len(), 1);
+
+        let block = &trace.blocks[0];
+        assert_eq!(block.message_schedule[0], 0x61626380);
+        assert_eq!(block.message_schedule[15], 0x00000018);
+        assert_eq!(block.initial_state, INITIAL_STATE.to_vec());
+        assert_eq!(block.rounds.len(), 64);
+        assert_eq!(
+            block.rounds[0].working_variables,
+            vec![
+                0x5d6aebcd, 0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xfa2a4622, 0x510e527f, 0x9b05688c,
+                0x1f83d9ab
+            ]
+        );
+        assert_eq!(
+            block.rounds[63].working_variables,
+            vec![
+                0x506e3058, 0xd39a2165, 0x04d24d6c, 0xb85e2ce9, 0x5ef50f24, 0xfb121210, 0x948d25b6,
+                0x961f4894
+            ]
+        );
+        assert_eq!(
+            trace.digest,
+            hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::shared::errors::HashError::UnknownAlgorithmError;
+use crate::shared::hashing::educational::{md5, sha1, sha256};
+
+/// Die Länge eines Blocks der Merkle-Damgård-Konstruktion von MD5, SHA-1 und SHA-256 in Bytes.
+pub const MERKLE_DAMGARD_BLOCK_SIZE: usize = 64;
+
+/// Die Hashfunktionen, die zu Lehrzwecken selbst implementiert sind und ihre Berechnung
+/// Runde für Runde protokollieren können. MD5 und SHA-1 gelten als gebrochen und dienen nur
+/// dem Vergleich mit SHA-256.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TracedHashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+impl TracedHashAlgorithm {
+    /// Berechnet den Hashwert und protokolliert dabei jeden Schritt.
+    pub fn trace(&self, message: &[u8]) -> HashTrace {
+        match self {
+            TracedHashAlgorithm::Md5 => md5::trace_md5(message),
+            TracedHashAlgorithm::Sha1 => sha1::trace_sha1(message),
+            TracedHashAlgorithm::Sha256 => sha256::trace_sha256(message),
+        }
+    }
+
+    /// Die Bezeichnungen der Arbeitsvariablen in der Reihenfolge, in der sie im Protokoll
+    /// einer Runde stehen.
+    pub fn working_variable_names(&self) -> &'static [&'static str] {
+        match self {
+            TracedHashAlgorithm::Md5 => &["a", "b", "c", "d"],
+            TracedHashAlgorithm::Sha1 => &["a", "b", "c", "d", "e"],
+            TracedHashAlgorithm::Sha256 => &["a", "b", "c", "d", "e", "f", "g", "h"],
+        }
+    }
+}
+
+impl Display for TracedHashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TracedHashAlgorithm::Md5 => write!(f, "MD5"),
+            TracedHashAlgorithm::Sha1 => write!(f, "SHA-1"),
+            TracedHashAlgorithm::Sha256 => write!(f, "SHA-256"),
+        }
+    }
+}
+
+impl FromStr for TracedHashAlgorithm {
+    type Err = anyhow::Error;
+
+    /// Liest die Textform, z.B. "SHA-256" oder "sha1".
+    ///
+    /// # Fehler
+    /// * `HashError::UnknownAlgorithmError` - Falls das Verfahren unbekannt ist.
+    fn from_str(name: &str) -> Result<Self> {
+        match name.trim().to_uppercase().replace('-', "").as_str() {
+            "MD5" => Ok(TracedHashAlgorithm::Md5),
+            "SHA1" => Ok(TracedHashAlgorithm::Sha1),
+            "SHA256" => Ok(TracedHashAlgorithm::Sha256),
+            _ => Err(UnknownAlgorithmError(name.to_string()).into()),
+        }
+    }
+}
+
+/// Das vollständige Protokoll einer Hashberechnung.
+///
+/// # Felder
+/// * `algorithm` - Die Hashfunktion.
+/// * `message_length` - Die Länge der Nachricht in Bytes.
+/// * `padding` - Die angehängten Bytes: 0x80, Nullbytes und die Nachrichtenlänge in Bits.
+/// * `blocks` - Das Protokoll der Kompressionsfunktion für jeden Block.
+/// * `digest` - Der Hashwert.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashTrace {
+    pub algorithm: TracedHashAlgorithm,
+    pub message_length: usize,
+    pub padding: Vec<u8>,
+    pub blocks: Vec<BlockTrace>,
+    pub digest: Vec<u8>,
+}
+
+/// Das Protokoll der Kompressionsfunktion für einen Block.
+///
+/// # Felder
+/// * `message_schedule` - Die Wörter W_t in der Reihenfolge, in der die Runden sie verwenden.
+/// * `initial_state` - Der Zustand (Verkettungswert) vor dem Block.
+/// * `rounds` - Das Protokoll jeder Runde.
+/// * `final_state` - Der Zustand nach dem Block, d.h. nach der Addition des Ausgangszustands.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockTrace {
+    pub message_schedule: Vec<u32>,
+    pub initial_state: Vec<u32>,
+    pub rounds: Vec<RoundTrace>,
+    pub final_state: Vec<u32>,
+}
+
+/// Das Protokoll einer Runde.
+///
+/// # Felder
+/// * `word` - Das in der Runde verwendete Wort W_t.
+/// * `constant` - Die Rundenkonstante K_t.
+/// * `working_variables` - Die Arbeitsvariablen nach der Runde, siehe
+///   `TracedHashAlgorithm::working_variable_names`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundTrace {
+    pub word: u32,
+    pub constant: u32,
+    pub working_variables: Vec<u32>,
+}
+
+/// Erstellt das Padding der Merkle-Damgård-Konstruktion: ein 1-Bit, so viele 0-Bits, dass
+/// 8 Bytes vor dem Ende eines Blocks erreicht sind, und die Nachrichtenlänge in Bits als 64-Bit-Zahl.
+///
+/// # Argumente
+/// * `message_length` - Die Länge der Nachricht in Bytes.
+/// * `little_endian` - Ob die Länge wie bei MD5 im Little-Endian-Format angehängt wird.
+pub(crate) fn merkle_damgard_padding(message_length: usize, little_endian: bool) -> Vec<u8> {
+    let zero_bytes =
+        (MERKLE_DAMGARD_BLOCK_SIZE * 2 - 9 - message_length % MERKLE_DAMGARD_BLOCK_SIZE)
+            % MERKLE_DAMGARD_BLOCK_SIZE;
+    let bit_length = (message_length as u64).wrapping_mul(8);
+
+    let mut padding = vec![0x80];
+    padding.resize(1 + zero_bytes, 0);
+    padding.extend_from_slice(&match little_endian {
+        true => bit_length.to_le_bytes(),
+        false => bit_length.to_be_bytes(),
+    });
+    padding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_fills_last_block() {
+        for length in [0, 1, 55, 56, 63, 64, 119, 120] {
+            let padding = merkle_damgard_padding(length, false);
+            assert_eq!((length + padding.len()) % MERKLE_DAMGARD_BLOCK_SIZE, 0);
+            assert!(padding.len() >= 9 && padding.len() <= 72);
+            assert_eq!(padding[0], 0x80);
+        }
+
+        let padding = merkle_damgard_padding(3, false);
+        assert_eq!(padding[padding.len() - 8..], 24u64.to_be_bytes());
+        let padding = merkle_damgard_padding(3, true);
+        assert_eq!(padding[padding.len() - 8..], 24u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for algorithm in [
+            TracedHashAlgorithm::Md5,
+            TracedHashAlgorithm::Sha1,
+            TracedHashAlgorithm::Sha256,
+        ] {
+            assert_eq!(
+                algorithm
+                    .to_string()
+                    .parse::<TracedHashAlgorithm>()
+                    .unwrap(),
+                algorithm
+            );
+        }
+        assert!("SHA-512".parse::<TracedHashAlgorithm>().is_err());
+    }
+}
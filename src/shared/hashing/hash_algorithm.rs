@@ -0,0 +1,356 @@
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use anyhow::{ensure, Result};
+use bigdecimal::num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+use crate::shared::errors::HashError::{InvalidOutputLengthError, UnknownAlgorithmError};
+use crate::shared::hashing::keccak::{KeccakSponge, SHA3_SUFFIX, SHAKE_SUFFIX};
+
+/// Die größte Ausgabelänge in Bytes, die für SHAKE aus der Textform gelesen wird.
+pub const MAXIMUM_SHAKE_OUTPUT_LENGTH: usize = 1024;
+
+/// Die verfügbaren Hashverfahren. Die SHA-2-Familie stammt aus dem Crate `sha2`, SHA3 und SHAKE
+/// sind in `keccak` selbst implementiert.
+///
+/// Die Textform ist "SHA-256", "SHA3-512" bzw. für SHAKE die Ausgabelänge in Bits, z.B.
+/// "SHAKE128-256". Ohne Angabe der Länge gibt SHAKE128 256 und SHAKE256 512 Bits aus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha224,
+    #[default]
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_256,
+    Sha3_512,
+    /// SHAKE128 mit der Ausgabelänge in Bytes.
+    Shake128(usize),
+    /// SHAKE256 mit der Ausgabelänge in Bytes.
+    Shake256(usize),
+}
+
+impl HashAlgorithm {
+    /// Die Länge des Hashwerts in Bytes.
+    pub fn output_length(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha224 => 28,
+            HashAlgorithm::Sha256 | HashAlgorithm::Sha3_256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 | HashAlgorithm::Sha3_512 => 64,
+            HashAlgorithm::Shake128(length) | HashAlgorithm::Shake256(length) => *length,
+        }
+    }
+
+    /// Die Länge der Blöcke in Bytes, die die Kompressionsfunktion bzw. der Schwamm auf einmal
+    /// verarbeitet. HMAC füllt den Schlüssel auf diese Länge auf.
+    pub fn block_size(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha224 | HashAlgorithm::Sha256 => 64,
+            HashAlgorithm::Sha384 | HashAlgorithm::Sha512 => 128,
+            HashAlgorithm::Sha3_256 | HashAlgorithm::Shake256(_) => 136,
+            HashAlgorithm::Sha3_512 => 72,
+            HashAlgorithm::Shake128(_) => 168,
+        }
+    }
+
+    /// Erstellt einen Hasher, dem die Eingabe schrittweise übergeben werden kann.
+    pub fn hasher(&self) -> Hasher {
+        let state = match self {
+            HashAlgorithm::Sha224 => HashState::Sha224(Sha224::new()),
+            HashAlgorithm::Sha256 => HashState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => HashState::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => HashState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha3_256 => HashState::Keccak(KeccakSponge::new(64, SHA3_SUFFIX)),
+            HashAlgorithm::Sha3_512 => HashState::Keccak(KeccakSponge::new(128, SHA3_SUFFIX)),
+            HashAlgorithm::Shake128(_) => HashState::Keccak(KeccakSponge::new(32, SHAKE_SUFFIX)),
+            HashAlgorithm::Shake256(_) => HashState::Keccak(KeccakSponge::new(64, SHAKE_SUFFIX)),
+        };
+        Hasher {
+            algorithm: *self,
+            state,
+        }
+    }
+
+    /// Berechnet den Hashwert einer Bytefolge.
+    pub fn digest(&self, data: &[u8]) -> Vec<u8> {
+        let mut hasher = self.hasher();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Berechnet den Hashwert aller Daten, die aus dem Reader gelesen werden können, ohne sie
+    /// vollständig im Speicher zu halten.
+    ///
+    /// # Fehler
+    /// * `std::io::Error` - Falls das Lesen fehlschlägt.
+    pub fn digest_reader(&self, mut reader: impl Read) -> Result<Vec<u8>> {
+        let mut hasher = self.hasher();
+        std::io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
+    /// Berechnet den Hashwert und interpretiert ihn als nicht-negative Zahl (Big-Endian),
+    /// wie sie von den Signaturverfahren benötigt wird.
+    pub fn digest_to_big_int(&self, data: &[u8]) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &self.digest(data))
+    }
+}
+
+impl Display for HashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashAlgorithm::Sha224 => write!(f, "SHA-224"),
+            HashAlgorithm::Sha256 => write!(f, "SHA-256"),
+            HashAlgorithm::Sha384 => write!(f, "SHA-384"),
+            HashAlgorithm::Sha512 => write!(f, "SHA-512"),
+            HashAlgorithm::Sha3_256 => write!(f, "SHA3-256"),
+            HashAlgorithm::Sha3_512 => write!(f, "SHA3-512"),
+            HashAlgorithm::Shake128(length) => write!(f, "SHAKE128-{}", length * 8),
+            HashAlgorithm::Shake256(length) => write!(f, "SHAKE256-{}", length * 8),
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = anyhow::Error;
+
+    /// Liest die Textform. Groß- und Kleinschreibung sowie der Bindestrich der SHA-2-Namen sind
+    /// beliebig, d.h. "sha256" wird ebenso erkannt wie "SHA-256".
+    ///
+    /// # Fehler
+    /// * `HashError::UnknownAlgorithmError` - Falls das Verfahren unbekannt ist.
+    /// * `HashError::InvalidOutputLengthError` - Falls die SHAKE-Ausgabelänge kein positives
+    ///   Vielfaches von 8 Bits ist oder `MAXIMUM_SHAKE_OUTPUT_LENGTH` Bytes übersteigt.
+    fn from_str(name: &str) -> Result<Self> {
+        let normalized = name.trim().to_uppercase();
+        let (base, bits) = match normalized.split_once('-') {
+            Some((base, bits)) if base.starts_with("SHAKE") => (base, Some(bits)),
+            _ => (normalized.as_str(), None),
+        };
+        let shake_length = |default: usize| -> Result<usize> {
+            let Some(bits) = bits else {
+                return Ok(default);
+            };
+            let bits: usize = bits
+                .parse()
+                .map_err(|_| InvalidOutputLengthError(bits.to_string()))?;
+            ensure!(
+                bits > 0 && bits.is_multiple_of(8) && bits / 8 <= MAXIMUM_SHAKE_OUTPUT_LENGTH,
+                InvalidOutputLengthError(bits.to_string())
+            );
+            Ok(bits / 8)
+        };
+
+        match base.replace('-', "").as_str() {
+            "SHA224" => Ok(HashAlgorithm::Sha224),
+            "SHA256" => Ok(HashAlgorithm::Sha256),
+            "SHA384" => Ok(HashAlgorithm::Sha384),
+            "SHA512" => Ok(HashAlgorithm::Sha512),
+            "SHA3256" => Ok(HashAlgorithm::Sha3_256),
+            "SHA3512" => Ok(HashAlgorithm::Sha3_512),
+            "SHAKE128" => Ok(HashAlgorithm::Shake128(shake_length(32)?)),
+            "SHAKE256" => Ok(HashAlgorithm::Shake256(shake_length(64)?)),
+            _ => Err(UnknownAlgorithmError(name.to_string()).into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum HashState {
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Keccak(KeccakSponge),
+}
+
+/// Ein Hasher, der die Eingabe schrittweise verarbeitet. Er implementiert `std::io::Write`,
+/// sodass z.B. mit `std::io::copy` direkt aus Dateien oder Netzwerkströmen gehasht werden kann.
+#[derive(Clone, Debug)]
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HashState,
+}
+
+impl Hasher {
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Verarbeitet weitere Eingabedaten.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HashState::Sha224(hasher) => hasher.update(data),
+            HashState::Sha256(hasher) => hasher.update(data),
+            HashState::Sha384(hasher) => hasher.update(data),
+            HashState::Sha512(hasher) => hasher.update(data),
+            HashState::Keccak(sponge) => sponge.absorb(data),
+        }
+    }
+
+    /// Schließt die Eingabe ab und gibt den Hashwert zurück.
+    pub fn finalize(self) -> Vec<u8> {
+        match self.state {
+            HashState::Sha224(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha384(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha512(hasher) => hasher.finalize().to_vec(),
+            HashState::Keccak(sponge) => sponge.squeeze(self.algorithm.output_length()),
+        }
+    }
+}
+
+impl Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    #[test]
+    fn test_digests_of_abc() {
+        let vectors = [
+            (
+                HashAlgorithm::Sha224,
+                "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7",
+            ),
+            (
+                HashAlgorithm::Sha256,
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                HashAlgorithm::Sha384,
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+                 8086072ba1e7cc2358baeca134c825a7",
+            ),
+            (
+                HashAlgorithm::Sha512,
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+            (
+                HashAlgorithm::Sha3_256,
+                "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            ),
+            (
+                HashAlgorithm::Sha3_512,
+                "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+                 10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+            ),
+        ];
+        for (algorithm, expected) in vectors {
+            let digest = algorithm.digest(b"abc");
+            assert_eq!(digest, hex(expected), "{}", algorithm);
+            assert_eq!(digest.len(), algorithm.output_length());
+        }
+    }
+
+    #[test]
+    fn test_sha3_and_shake() {
+        assert_eq!(
+            HashAlgorithm::Sha3_256.digest(b""),
+            hex("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+        );
+        assert_eq!(
+            HashAlgorithm::Shake128(32).digest(b""),
+            hex("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")
+        );
+        // Die Ausgabe von SHAKE ist länger als jede feste Hashlänge möglich.
+        assert_eq!(
+            HashAlgorithm::Shake256(100).digest(b"abc"),
+            hex(
+                "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+                 d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4\
+                 1385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78\
+                 dbcddbd9"
+            )
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+        for algorithm in [
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Sha3_256,
+            HashAlgorithm::Shake128(200),
+        ] {
+            let mut hasher = algorithm.hasher();
+            for chunk in data.chunks(7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), algorithm.digest(&data), "{}", algorithm);
+            assert_eq!(
+                algorithm.digest_reader(data.as_slice()).unwrap(),
+                algorithm.digest(&data)
+            );
+        }
+        assert_eq!(
+            HashAlgorithm::Sha3_256.digest(&data),
+            hex("d4728ea5e9f3819f2b4760151a8f802dbe9f941fd6fb59b3715892436555772a")
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        for algorithm in [
+            HashAlgorithm::Sha224,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Sha3_256,
+            HashAlgorithm::Sha3_512,
+            HashAlgorithm::Shake128(16),
+            HashAlgorithm::Shake256(64),
+        ] {
+            assert_eq!(
+                algorithm.to_string().parse::<HashAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert_eq!(
+            "sha256".parse::<HashAlgorithm>().unwrap(),
+            HashAlgorithm::Sha256
+        );
+        assert_eq!(
+            "shake128".parse::<HashAlgorithm>().unwrap(),
+            HashAlgorithm::Shake128(32)
+        );
+        assert!("SHAKE128-12".parse::<HashAlgorithm>().is_err());
+        assert!("SHAKE256-0".parse::<HashAlgorithm>().is_err());
+        assert_eq!(
+            format!("SHAKE256-{}", MAXIMUM_SHAKE_OUTPUT_LENGTH * 8)
+                .parse::<HashAlgorithm>()
+                .unwrap(),
+            HashAlgorithm::Shake256(MAXIMUM_SHAKE_OUTPUT_LENGTH)
+        );
+        assert!(
+            format!("SHAKE256-{}", (MAXIMUM_SHAKE_OUTPUT_LENGTH + 1) * 8)
+                .parse::<HashAlgorithm>()
+                .is_err()
+        );
+        assert!("MD4".parse::<HashAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_digest_to_big_int() {
+        let value = HashAlgorithm::Sha256.digest_to_big_int(b"abc");
+        assert_eq!(
+            value.to_str_radix(16),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::{ensure, Result};
+
+use crate::shared::errors::HashError::UnsupportedHmacAlgorithmError;
+use crate::shared::hashing::hash_algorithm::{HashAlgorithm, Hasher};
+
+const INNER_PAD: u8 = 0x36;
+const OUTER_PAD: u8 = 0x5C;
+
+/// Ein Message Authentication Code nach RFC 2104: HMAC(K, m) = H((K ⊕ opad) || H((K ⊕ ipad) || m)).
+///
+/// Die Nachricht kann wie beim `Hasher` schrittweise übergeben werden. Da der Schlüssel bereits
+/// im Konstruktor verarbeitet wird, kann eine geklonte Instanz für weitere Nachrichten mit
+/// demselben Schlüssel wiederverwendet werden, ohne den Schlüssel erneut zu hashen.
+#[derive(Clone, Debug)]
+pub struct Hmac {
+    inner: Hasher,
+    outer: Hasher,
+}
+
+impl Hmac {
+    /// Erstellt einen HMAC mit dem gegebenen Schlüssel. Schlüssel, die länger als ein Block
+    /// der Hashfunktion sind, werden zuvor gehasht.
+    ///
+    /// # Argumente
+    /// * `algorithm` - Die zugrunde liegende Hashfunktion.
+    /// * `key` - Der Schlüssel beliebiger Länge.
+    ///
+    /// # Fehler
+    /// * `HashError::UnsupportedHmacAlgorithmError` - Falls die Hashfunktion SHAKE ist.
+    pub fn new(algorithm: HashAlgorithm, key: &[u8]) -> Result<Self> {
+        ensure!(
+            !matches!(
+                algorithm,
+                HashAlgorithm::Shake128(_) | HashAlgorithm::Shake256(_)
+            ),
+            UnsupportedHmacAlgorithmError(algorithm.to_string())
+        );
+
+        let block_size = algorithm.block_size();
+        let mut padded_key = match key.len() > block_size {
+            true => algorithm.digest(key),
+            false => key.to_vec(),
+        };
+        padded_key.resize(block_size, 0);
+
+        let xor_pad = |pad: u8| padded_key.iter().map(|b| b ^ pad).collect::<Vec<u8>>();
+
+        let mut inner = algorithm.hasher();
+        inner.update(&xor_pad(INNER_PAD));
+        let mut outer = algorithm.hasher();
+        outer.update(&xor_pad(OUTER_PAD));
+        Ok(Hmac { inner, outer })
+    }
+
+    /// Verarbeitet weitere Teile der Nachricht.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Schließt die Nachricht ab und gibt den MAC zurück.
+    pub fn finalize(self) -> Vec<u8> {
+        let mut outer = self.outer;
+        outer.update(&self.inner.finalize());
+        outer.finalize()
+    }
+
+    /// Schließt die Nachricht ab und vergleicht den MAC in konstanter Zeit mit dem erwarteten
+    /// Wert, sodass die Laufzeit nicht verrät, ab welchem Byte sich die Werte unterscheiden.
+    pub fn verify(self, expected: &[u8]) -> bool {
+        let mac = self.finalize();
+        mac.len() == expected.len()
+            && mac
+                .iter()
+                .zip(expected)
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+/// Berechnet den HMAC einer vollständig vorliegenden Nachricht.
+///
+/// # Argumente
+/// * `algorithm` - Die zugrunde liegende Hashfunktion.
+/// * `key` - Der Schlüssel.
+/// * `message` - Die Nachricht.
+///
+/// # Fehler
+/// * `HashError::UnsupportedHmacAlgorithmError` - Falls die Hashfunktion SHAKE ist.
+pub fn hmac(algorithm: HashAlgorithm, key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = Hmac::new(algorithm, key)?;
+    mac.update(message);
+    Ok(mac.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+    use crate::shared::errors::HashError;
+
+    #[test]
+    fn test_rfc_4231_vectors() {
+        // Testfälle 1, 2, 3 und 6 aus RFC 4231. Fall 6 verwendet einen Schlüssel, der länger
+        // als ein Block ist.
+        let cases: [(Vec<u8>, Vec<u8>, &str, &str); 4] = [
+            (
+                vec![0x0b; 20],
+                b"Hi There".to_vec(),
+                "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+                "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+                 daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+            ),
+            (
+                b"Jefe".to_vec(),
+                b"what do ya want for nothing?".to_vec(),
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+                 9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+            ),
+            (
+                vec![0xaa; 20],
+                vec![0xdd; 50],
+                "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+                "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39\
+                 bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb",
+            ),
+            (
+                vec![0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+                "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+                "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352\
+                 6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+            ),
+        ];
+
+        for (key, message, sha256, sha512) in cases {
+            assert_eq!(
+                hmac(HashAlgorithm::Sha256, &key, &message).unwrap(),
+                hex(sha256)
+            );
+            assert_eq!(
+                hmac(HashAlgorithm::Sha512, &key, &message).unwrap(),
+                hex(sha512)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hmac_sha3() {
+        let mac = hmac(
+            HashAlgorithm::Sha3_256,
+            b"key",
+            b"The quick brown fox jumps over the lazy dog",
+        )
+        .unwrap();
+        assert_eq!(
+            mac,
+            hex("8c6e0683409427f8931711b10ca92a506eb1fafa48fadd66d76126f47ac2c333")
+        );
+    }
+
+    #[test]
+    fn test_streaming_and_verify() {
+        let mut mac = Hmac::new(HashAlgorithm::Sha256, b"Jefe").unwrap();
+        mac.update(b"what do ya want ");
+        mac.update(b"for nothing?");
+        let expected = hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert!(mac.clone().verify(&expected));
+
+        let mut tampered = expected.clone();
+        tampered[31] ^= 1;
+        assert!(!mac.clone().verify(&tampered));
+        assert!(!mac.verify(&expected[..16]));
+    }
+
+    #[test]
+    fn test_shake_is_rejected() {
+        let error = Hmac::new(HashAlgorithm::Shake128(32), b"key").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<HashError>(),
+            Some(HashError::UnsupportedHmacAlgorithmError(_))
+        ));
+    }
+}
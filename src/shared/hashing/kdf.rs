@@ -0,0 +1,251 @@
+use anyhow::{ensure, Result};
+
+use crate::shared::errors::HashError::{InvalidDerivedKeyLengthError, InvalidIterationCountError};
+use crate::shared::hashing::hash_algorithm::HashAlgorithm;
+use crate::shared::hashing::hmac::{hmac, Hmac};
+
+/// Der erste Schritt von HKDF (RFC 5869): Verdichtet das Ausgangsmaterial zu einem
+/// pseudozufälligen Schlüssel PRK = HMAC(salt, ikm) der Länge des Hashwerts.
+///
+/// # Argumente
+/// * `algorithm` - Die Hashfunktion des HMAC.
+/// * `salt` - Das optionale Salt. Ein leeres Salt entspricht dem Salt aus Nullbytes, das
+///   RFC 5869 in diesem Fall vorschreibt, da HMAC den Schlüssel ohnehin mit Nullen auffüllt.
+/// * `input_key_material` - Das Ausgangsmaterial, z.B. ein gemeinsames Geheimnis.
+///
+/// # Fehler
+/// * `HashError::UnsupportedHmacAlgorithmError` - Falls die Hashfunktion SHAKE ist.
+pub fn hkdf_extract(
+    algorithm: HashAlgorithm,
+    salt: &[u8],
+    input_key_material: &[u8],
+) -> Result<Vec<u8>> {
+    hmac(algorithm, salt, input_key_material)
+}
+
+/// Der zweite Schritt von HKDF (RFC 5869): Erweitert den pseudozufälligen Schlüssel zu
+/// `length` Bytes T(1) || T(2) || ... mit T(i) = HMAC(PRK, T(i-1) || info || i).
+///
+/// # Argumente
+/// * `algorithm` - Die Hashfunktion des HMAC.
+/// * `pseudo_random_key` - Der Schlüssel aus `hkdf_extract`, mindestens so lang wie der Hashwert.
+/// * `info` - Kontextinformationen, an die der abgeleitete Schlüssel gebunden wird.
+/// * `length` - Die Länge des abgeleiteten Schlüssels, höchstens das 255-fache des Hashwerts.
+///
+/// # Fehler
+/// * `HashError::InvalidDerivedKeyLengthError` - Falls `length` oder die Länge des PRK
+///   ungültig ist.
+/// * `HashError::UnsupportedHmacAlgorithmError` - Falls die Hashfunktion SHAKE ist.
+pub fn hkdf_expand(
+    algorithm: HashAlgorithm,
+    pseudo_random_key: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>> {
+    let hash_length = algorithm.output_length();
+    ensure!(
+        pseudo_random_key.len() >= hash_length,
+        InvalidDerivedKeyLengthError(format!(
+            "pseudo random key has {} bytes, at least {} are required",
+            pseudo_random_key.len(),
+            hash_length
+        ))
+    );
+    ensure!(
+        length <= 255 * hash_length,
+        InvalidDerivedKeyLengthError(format!(
+            "{} bytes requested, at most {} are possible",
+            length,
+            255 * hash_length
+        ))
+    );
+
+    let keyed = Hmac::new(algorithm, pseudo_random_key)?;
+    let mut output = Vec::with_capacity(length);
+    let mut block = Vec::new();
+    for counter in 1..=length.div_ceil(hash_length) as u8 {
+        let mut mac = keyed.clone();
+        mac.update(&block);
+        mac.update(info);
+        mac.update(&[counter]);
+        block = mac.finalize();
+        output.extend_from_slice(&block);
+    }
+    output.truncate(length);
+    Ok(output)
+}
+
+/// Leitet mit HKDF (RFC 5869) einen Schlüssel ab, d.h. `hkdf_extract` gefolgt von `hkdf_expand`.
+///
+/// # Fehler
+/// Siehe `hkdf_extract` und `hkdf_expand`.
+pub fn hkdf(
+    algorithm: HashAlgorithm,
+    salt: &[u8],
+    input_key_material: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>> {
+    let pseudo_random_key = hkdf_extract(algorithm, salt, input_key_material)?;
+    hkdf_expand(algorithm, &pseudo_random_key, info, length)
+}
+
+/// Leitet mit PBKDF2 (RFC 8018) einen Schlüssel aus einem Passwort ab. Jeder Block ist
+/// U(1) ⊕ ... ⊕ U(c) mit U(1) = HMAC(P, S || i) und U(j) = HMAC(P, U(j-1)). Die Anzahl der
+/// Iterationen c macht das Durchprobieren von Passwörtern entsprechend teurer.
+///
+/// # Argumente
+/// * `algorithm` - Die Hashfunktion des HMAC.
+/// * `password` - Das Passwort.
+/// * `salt` - Das Salt, das vorberechnete Wörterbücher verhindert.
+/// * `iterations` - Die Anzahl der Iterationen, mindestens 1.
+/// * `length` - Die Länge des abgeleiteten Schlüssels in Bytes, mindestens 1.
+///
+/// # Fehler
+/// * `HashError::InvalidIterationCountError` - Falls `iterations` 0 ist.
+/// * `HashError::InvalidDerivedKeyLengthError` - Falls `length` 0 oder zu groß ist.
+/// * `HashError::UnsupportedHmacAlgorithmError` - Falls die Hashfunktion SHAKE ist.
+pub fn pbkdf2(
+    algorithm: HashAlgorithm,
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    length: usize,
+) -> Result<Vec<u8>> {
+    ensure!(
+        iterations > 0,
+        InvalidIterationCountError(iterations.to_string())
+    );
+    let hash_length = algorithm.output_length();
+    let block_count = length.div_ceil(hash_length);
+    ensure!(
+        length > 0 && block_count <= u32::MAX as usize,
+        InvalidDerivedKeyLengthError(format!("{} bytes requested", length))
+    );
+
+    let keyed = Hmac::new(algorithm, password)?;
+    let mut output = Vec::with_capacity(block_count * hash_length);
+    for index in 1..=block_count as u32 {
+        let mut mac = keyed.clone();
+        mac.update(salt);
+        mac.update(&index.to_be_bytes());
+        let mut u = mac.finalize();
+        let mut block = u.clone();
+        for _ in 1..iterations {
+            let mut mac = keyed.clone();
+            mac.update(&u);
+            u = mac.finalize();
+            block.iter_mut().zip(&u).for_each(|(b, u)| *b ^= u);
+        }
+        output.extend_from_slice(&block);
+    }
+    output.truncate(length);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+    use crate::shared::errors::HashError;
+
+    #[test]
+    fn test_rfc_5869_vectors() {
+        // Testfall 1 aus RFC 5869.
+        let input_key_material = vec![0x0b; 22];
+        let salt = hex("000102030405060708090a0b0c");
+        let info = hex("f0f1f2f3f4f5f6f7f8f9");
+        let pseudo_random_key =
+            hkdf_extract(HashAlgorithm::Sha256, &salt, &input_key_material).unwrap();
+        assert_eq!(
+            pseudo_random_key,
+            hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+        );
+        assert_eq!(
+            hkdf_expand(HashAlgorithm::Sha256, &pseudo_random_key, &info, 42).unwrap(),
+            hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c\
+                 5db02d56ecc4c5bf34007208d5b887185865")
+        );
+
+        // Testfall 3 aus RFC 5869 mit leerem Salt und leerer Info.
+        assert_eq!(
+            hkdf(HashAlgorithm::Sha256, &[], &input_key_material, &[], 42).unwrap(),
+            hex("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879e\
+                 c3454e5f3c738d2d9d201395faa4b61a96c8")
+        );
+    }
+
+    #[test]
+    fn test_hkdf_sha512_over_several_blocks() {
+        let okm = hkdf(HashAlgorithm::Sha512, b"salt", b"secret", b"ctx", 100).unwrap();
+        assert_eq!(
+            okm,
+            hex(
+                "89698492a23e36ebccaf8ec0ca73037a5aea7ff233d83884d764a0b6a866003c\
+                 6bbe14c5636a83c1297290b302b67f9a2336b3af9e1f6f8e9d5d1c8efcc877d4\
+                 a85ebe09a566ad846d6599e72b56bf4805a41552c9ba1396341dfc56d49656aa\
+                 78208896"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hkdf_rejects_invalid_lengths() {
+        let prk = vec![0u8; 32];
+        let too_long = hkdf_expand(HashAlgorithm::Sha256, &prk, &[], 255 * 32 + 1).unwrap_err();
+        assert!(matches!(
+            too_long.downcast_ref::<HashError>(),
+            Some(HashError::InvalidDerivedKeyLengthError(_))
+        ));
+        assert!(hkdf_expand(HashAlgorithm::Sha256, &prk[..16], &[], 32).is_err());
+        assert_eq!(
+            hkdf_expand(HashAlgorithm::Sha256, &prk, &[], 255 * 32)
+                .unwrap()
+                .len(),
+            255 * 32
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_vectors() {
+        // PBKDF2-HMAC-SHA256 aus RFC 7914, Abschnitt 11.
+        assert_eq!(
+            pbkdf2(HashAlgorithm::Sha256, b"passwd", b"salt", 1, 64).unwrap(),
+            hex(
+                "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+                 49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+            )
+        );
+        assert_eq!(
+            pbkdf2(
+                HashAlgorithm::Sha256,
+                b"passwordPASSWORDpassword",
+                b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+                4096,
+                40
+            )
+            .unwrap(),
+            hex(
+                "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1\
+                 c635518c7dac47e9"
+            )
+        );
+        assert_eq!(
+            pbkdf2(HashAlgorithm::Sha512, b"password", b"salt", 4096, 64).unwrap(),
+            hex(
+                "d197b1b33db0143e018b12f3d1d1479e6cdebdcc97c5c0f87f6902e072f457b5\
+                 143f30602641b3d55cd335988cb36b84376060ecd532e039b742a239434af2d5"
+            )
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_rejects_invalid_parameters() {
+        let error = pbkdf2(HashAlgorithm::Sha256, b"pw", b"salt", 0, 32).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<HashError>(),
+            Some(HashError::InvalidIterationCountError(_))
+        ));
+        assert!(pbkdf2(HashAlgorithm::Sha256, b"pw", b"salt", 1, 0).is_err());
+    }
+}
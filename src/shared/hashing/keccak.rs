@@ -0,0 +1,190 @@
+/// Die Rundenkonstanten der 24 Runden von Keccak-f[1600] (Schritt ι).
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Die Rotationen der Lanes im Schritt ρ in der Reihenfolge, in der π die Lanes durchläuft.
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Die Reihenfolge, in der π die Lanes ab Lane 1 vertauscht. Lane (x, y) liegt an Index x + 5y.
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Das Domain-Suffix der SHA3-Hashfunktionen (Bits 01) einschließlich des ersten Padding-Bits.
+pub const SHA3_SUFFIX: u8 = 0x06;
+
+/// Das Domain-Suffix der SHAKE-Funktionen (Bits 1111) einschließlich des ersten Padding-Bits.
+pub const SHAKE_SUFFIX: u8 = 0x1F;
+
+/// Die Permutation Keccak-f[1600] auf einem Zustand aus 25 Lanes zu je 64 Bit.
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // θ: Jedes Bit wird mit der Parität zweier benachbarter Spalten verknüpft.
+        let mut parities = [0u64; 5];
+        for (x, parity) in parities.iter_mut().enumerate() {
+            *parity = (0..5).fold(0, |acc, y| acc ^ state[x + 5 * y]);
+        }
+        for x in 0..5 {
+            let d = parities[(x + 4) % 5] ^ parities[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // ρ und π: Die Lanes werden rotiert und an neue Positionen verschoben.
+        let mut current = state[1];
+        for (lane, rotation) in PI_LANES.iter().zip(ROTATIONS) {
+            let next = state[*lane];
+            state[*lane] = current.rotate_left(rotation);
+            current = next;
+        }
+
+        // χ: Die einzige nichtlineare Abbildung, zeilenweise angewendet.
+        for y in 0..5 {
+            let row: [u64; 5] = std::array::from_fn(|x| state[x + 5 * y]);
+            for x in 0..5 {
+                state[x + 5 * y] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // ι: Die Rundenkonstante bricht die Symmetrie zwischen den Runden.
+        state[0] ^= round_constant;
+    }
+}
+
+/// Die Schwammkonstruktion über Keccak-f[1600], auf der SHA3 und SHAKE (FIPS 202) beruhen.
+/// Die Eingabe wird blockweise in die ersten `rate` Bytes des Zustands absorbiert. Die Ausgabe
+/// wird anschließend in beliebiger Länge aus denselben Bytes ausgepresst.
+#[derive(Clone, Debug)]
+pub struct KeccakSponge {
+    state: [u64; 25],
+    rate: usize,
+    suffix: u8,
+    buffer: Vec<u8>,
+}
+
+impl KeccakSponge {
+    /// Erstellt einen leeren Schwamm.
+    ///
+    /// # Argumente
+    /// * `capacity` - Die Kapazität in Bytes, das Doppelte des Sicherheitsniveaus.
+    ///   Die Rate ist 200 - `capacity`.
+    /// * `suffix` - Das Domain-Suffix, `SHA3_SUFFIX` oder `SHAKE_SUFFIX`.
+    pub fn new(capacity: usize, suffix: u8) -> Self {
+        let rate = 200 - capacity;
+        KeccakSponge {
+            state: [0; 25],
+            rate,
+            suffix,
+            buffer: Vec::with_capacity(rate),
+        }
+    }
+
+    /// Absorbiert weitere Eingabedaten. Vollständige Blöcke werden sofort verarbeitet, der Rest
+    /// wird bis zum nächsten Aufruf gepuffert.
+    pub fn absorb(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let taken = (self.rate - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..taken]);
+            data = &data[taken..];
+            if self.buffer.len() == self.rate {
+                let block = std::mem::take(&mut self.buffer);
+                self.absorb_block(&block);
+                self.buffer = block;
+                self.buffer.clear();
+            }
+        }
+    }
+
+    /// Schließt die Eingabe mit dem Padding pad10*1 ab und presst `output_length` Bytes aus.
+    pub fn squeeze(mut self, output_length: usize) -> Vec<u8> {
+        let mut block = std::mem::take(&mut self.buffer);
+        let length = block.len();
+        block.resize(self.rate, 0);
+        block[length] ^= self.suffix;
+        block[self.rate - 1] ^= 0x80;
+        self.absorb_block(&block);
+
+        let mut output = Vec::with_capacity(output_length);
+        loop {
+            let bytes = self
+                .state
+                .iter()
+                .flat_map(|lane| lane.to_le_bytes())
+                .take(self.rate);
+            output.extend(bytes.take(output_length - output.len()));
+            if output.len() == output_length {
+                return output;
+            }
+            keccak_f(&mut self.state);
+        }
+    }
+
+    fn absorb_block(&mut self, block: &[u8]) {
+        for (lane, chunk) in self.state.iter_mut().zip(block.chunks(8)) {
+            let mut bytes = [0u8; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            *lane ^= u64::from_le_bytes(bytes);
+        }
+        keccak_f(&mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::core::aes::aes_block_cipher::tests::hex;
+
+    #[test]
+    fn test_keccak_f_on_zero_state() {
+        // Erste Lane nach einer Anwendung von Keccak-f[1600] auf den Nullzustand (Keccak-Team).
+        let mut state = [0u64; 25];
+        keccak_f(&mut state);
+        assert_eq!(state[0], 0xF1258F7940E1DDE7);
+    }
+
+    #[test]
+    fn test_squeeze_across_blocks() {
+        // SHAKE128 hat eine Rate von 168 Bytes, 200 Bytes Ausgabe benötigen zwei Blöcke.
+        let mut sponge = KeccakSponge::new(32, SHAKE_SUFFIX);
+        let data: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+        sponge.absorb(&data);
+        let output = sponge.squeeze(200);
+        assert_eq!(
+            output,
+            hex(
+                "8890ed204d2289e172e9ae68481823770820908060a4df3351a3f184ebb6dd0f9d231560680f2c65\
+                 8ac48497adb5a4839936a3165516fa5e13bf8a15babc141f864500e3013ed50904be703d8176b3fa\
+                 9c41c86a2938dab6e6e5872a2ce378c278cec5e32690877e77ffd415530238bc4205db89221d6ea9\
+                 598bf5530a38fac23d5611333c68d04e45757a78a57f132c7a1bc7c338ed699079087f7ebd90e723\
+                 7e0df69d9ea201effda1c54667daa8d5e30c1e334e23cf38ff9b5c6ca8ea36b936b3f6ad27682387"
+            )
+        );
+    }
+}
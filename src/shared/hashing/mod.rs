@@ -0,0 +1,5 @@
+pub mod educational;
+pub mod hash_algorithm;
+pub mod hmac;
+pub mod kdf;
+pub mod keccak;